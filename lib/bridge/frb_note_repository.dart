@@ -384,7 +384,7 @@ final class FrbNoteRepository implements NoteRepository {
   @override
   Future<void> removePairedDevice(String peerId) async {
     _ensureOpen();
-    await api.removePairedDevice(store: _store, peerId: peerId);
+    await api.removePairedDevice(svc: _sync, store: _store, peerId: peerId);
   }
 
   void close() {
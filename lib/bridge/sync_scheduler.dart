@@ -128,7 +128,7 @@ class FrbSyncApi implements SyncApi {
 /// - **编辑保存即推送**（决策 4）：repository 保存成功后调用 [noteEdited]，
 ///   fire-and-forget 触发 `pushPending`（不阻塞编辑；失败静默，决策 18）。
 /// - **周期拉取**（决策 4）：`Timer.periodic` 按 `SYNC_POLL_INTERVAL_SECS`
-///   调 `runSyncCycle`（对等推拉：push 给所有对端 + 短窗口 accept 对端 push）。
+///   调 `runSyncCycle`（与每台对端一次双向同步会话：拉取缺失 + 推回对端缺失）。
 /// - **持续接收器**（任务 O）：[start] 时启动 Rust 后台接收任务——不依赖周期
 ///   相位，A 任意时间 push，B 在 10 秒内 receive/import 并更新 last_seen；
 ///   [stop] 时停止（3 秒内返回，不留下永久 task）。
@@ -4,184 +4,130 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import 'discovery.dart';
+import 'events.dart';
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'search.dart';
 import 'store.dart';
 import 'sync.dart';
+import 'vault.dart';
 
-/// 创建同步服务
-Future<SyncService> createSyncService() =>
-    RustLib.instance.api.crateApiCreateSyncService();
+
+            
+
+            /// 创建同步服务
+Future<SyncService>  createSyncService() => RustLib.instance.api.crateApiCreateSyncService();
 
 /// 创建绑定数据目录的持久化同步服务。
-Future<SyncService> createPersistentSyncService({required String path}) =>
-    RustLib.instance.api.crateApiCreatePersistentSyncService(path: path);
+Future<SyncService>  createPersistentSyncService({required String path }) => RustLib.instance.api.crateApiCreatePersistentSyncService(path: path);
 
 /// 获取本设备 iroh 身份 ID（SecretKey 持久化后跨重启稳定）。
-Future<String> getDeviceId({required SyncService svc}) =>
-    RustLib.instance.api.crateApiGetDeviceId(svc: svc);
+Future<String>  getDeviceId({required SyncService svc }) => RustLib.instance.api.crateApiGetDeviceId(svc: svc);
 
 /// 获取本设备名（配对握手时发送给对端）。
-Future<String> getDeviceName({required SyncService svc}) =>
-    RustLib.instance.api.crateApiGetDeviceName(svc: svc);
+Future<String>  getDeviceName({required SyncService svc }) => RustLib.instance.api.crateApiGetDeviceName(svc: svc);
 
 /// 设置本设备名。
-Future<void> setDeviceName({required SyncService svc, required String name}) =>
-    RustLib.instance.api.crateApiSetDeviceName(svc: svc, name: name);
+Future<void>  setDeviceName({required SyncService svc , required String name }) => RustLib.instance.api.crateApiSetDeviceName(svc: svc, name: name);
 
 /// 本端点当前绑定的 IPv4 地址列表（"ip:port"，配对目标/mDNS 广播用）。
-Future<List<String>> localAddrs({required SyncService svc}) =>
-    RustLib.instance.api.crateApiLocalAddrs(svc: svc);
+Future<List<String>>  localAddrs({required SyncService svc }) => RustLib.instance.api.crateApiLocalAddrs(svc: svc);
 
 /// 配对 — 确认方：生成 6 位数字配对码（密码学随机，10 分钟有效）。返回码。
-Future<String> beginPairingAccept({required SyncService svc}) =>
-    RustLib.instance.api.crateApiBeginPairingAccept(svc: svc);
+Future<String>  beginPairingAccept({required SyncService svc }) => RustLib.instance.api.crateApiBeginPairingAccept(svc: svc);
 
 /// 配对 — 确认方：生成配对码并启动 mDNS 广播（任务 J 组合 API）。
 ///
 /// 码与广播在同一调用内完成（保证配对期间广播一定在）；port 用本端点实际
 /// 监听端口。配对结束（弹窗关闭/完成/取消）时调用 [`stop_pairing_advertising`]。
-Future<String> beginPairingAcceptWithAdvertising({required SyncService svc}) =>
-    RustLib.instance.api.crateApiBeginPairingAcceptWithAdvertising(svc: svc);
+Future<String>  beginPairingAcceptWithAdvertising({required SyncService svc }) => RustLib.instance.api.crateApiBeginPairingAcceptWithAdvertising(svc: svc);
 
 /// 配对 — 停止 mDNS 广播（幂等；配对弹窗关闭/完成/取消时调用）。
-Future<void> stopPairingAdvertising({required SyncService svc}) =>
-    RustLib.instance.api.crateApiStopPairingAdvertising(svc: svc);
+Future<void>  stopPairingAdvertising({required SyncService svc }) => RustLib.instance.api.crateApiStopPairingAdvertising(svc: svc);
 
 /// 设备发现 — 经 SyncService 扫描对端（任务 J：发起方设备 ID 留空时自动填充）。
-Future<List<PeerInfo>> syncDiscoverPeers({required SyncService svc}) =>
-    RustLib.instance.api.crateApiSyncDiscoverPeers(svc: svc);
+Future<List<PeerInfo>>  syncDiscoverPeers({required SyncService svc }) => RustLib.instance.api.crateApiSyncDiscoverPeers(svc: svc);
 
 /// 配对 — 确认方：阻塞接收发起方的配对请求（等待发起方连接）。
 ///
 /// 等待期间抢到的推送帧会立即导入（不丢失），随后继续等待配对请求。
-Future<PairingRequest> acceptPairingRequest({required SyncService svc}) =>
-    RustLib.instance.api.crateApiAcceptPairingRequest(svc: svc);
+Future<PairingRequest>  acceptPairingRequest({required SyncService svc }) => RustLib.instance.api.crateApiAcceptPairingRequest(svc: svc);
 
 /// 配对 — 确认方：在 [timeout] 内接收发起方配对请求（**有界等待**；超时返回 None）。
 ///
 /// 任务 M 决策点 1 的落点：FRB opaque 上的阻塞等待无法安全取消，必须有界——
 /// UI 侧显示码流程以短窗口（10s）轮询调用本方法，弹窗关闭/取消后等待任务在
 /// 窗口内释放，不留下永久阻塞任务（设计目标 5）。总时限由 Flutter 侧控制。
-Future<PairingRequest?> acceptPairingRequestWithTimeout({
-  required SyncService svc,
-  required Duration timeout,
-}) => RustLib.instance.api.crateApiAcceptPairingRequestWithTimeout(
-  svc: svc,
-  timeout: timeout,
-);
+Future<PairingRequest?>  acceptPairingRequestWithTimeout({required SyncService svc , required Duration timeout }) => RustLib.instance.api.crateApiAcceptPairingRequestWithTimeout(svc: svc, timeout: timeout);
 
 /// 配对 — 确认方：校验配对码并完成配对（upsert 发起方 + 回复握手 + 自动推送全量快照）。
-Future<PairingResult> confirmPairing({
-  required SyncService svc,
-  required NoteStore store,
-  required String code,
-  required PairingRequest requester,
-}) => RustLib.instance.api.crateApiConfirmPairing(
-  svc: svc,
-  store: store,
-  code: code,
-  requester: requester,
-);
+Future<PairingResult>  confirmPairing({required SyncService svc , required NoteStore store , required String code , required PairingRequest requester }) => RustLib.instance.api.crateApiConfirmPairing(svc: svc, store: store, code: code, requester: requester);
 
 /// 配对 — 发起方：连接确认方发送配对请求，接收握手响应并 upsert 确认方。
-Future<PairingResult> beginPairingConnect({
-  required SyncService svc,
-  required NoteStore store,
-  required String code,
-  required PairingTarget target,
-}) => RustLib.instance.api.crateApiBeginPairingConnect(
-  svc: svc,
-  store: store,
-  code: code,
-  target: target,
-);
+Future<PairingResult>  beginPairingConnect({required SyncService svc , required NoteStore store , required String code , required PairingTarget target }) => RustLib.instance.api.crateApiBeginPairingConnect(svc: svc, store: store, code: code, target: target);
 
 /// 配对 — 显示方：生成签名配对凭证（code + credential + RFC3339 过期时间）。
-Future<PairingCredentialDisplay> beginPairingCredential({
-  required SyncService svc,
-}) => RustLib.instance.api.crateApiBeginPairingCredential(svc: svc);
+Future<PairingCredentialDisplay>  beginPairingCredential({required SyncService svc }) => RustLib.instance.api.crateApiBeginPairingCredential(svc: svc);
 
 /// 配对 — 显示方：生成签名配对凭证并启动 mDNS 广播（任务 Q 组合 API）。
 ///
 /// 凭证/会话与广播在同一调用内完成（与 `begin_pairing_accept_with_advertising`
 /// 同模式）；配对结束（弹窗关闭/完成/取消）时调用 [`stop_pairing_advertising`]。
-Future<PairingCredentialDisplay> beginPairingCredentialWithAdvertising({
-  required SyncService svc,
-}) => RustLib.instance.api.crateApiBeginPairingCredentialWithAdvertising(
-  svc: svc,
-);
+Future<PairingCredentialDisplay>  beginPairingCredentialWithAdvertising({required SyncService svc }) => RustLib.instance.api.crateApiBeginPairingCredentialWithAdvertising(svc: svc);
 
 /// 配对 — 发起方：解析并验证凭证字符串（验签 + 时间窗口 + 长度）。
 ///
 /// 错误为稳定的 [`PairingCredentialError`]（kind + message），Dart 侧按 kind
 /// 映射中文文案，避免字符串匹配。
-Future<ParsedPairingCredential> parsePairingCredential({
-  required SyncService svc,
-  required String credential,
-}) => RustLib.instance.api.crateApiParsePairingCredential(
-  svc: svc,
-  credential: credential,
-);
+Future<ParsedPairingCredential>  parsePairingCredential({required SyncService svc , required String credential }) => RustLib.instance.api.crateApiParsePairingCredential(svc: svc, credential: credential);
 
 /// 配对 — 发起方：凭证垂直入口（parse/verify → 目标构造 → 直连/relay 连接）。
 ///
 /// 错误为稳定的 [`PairingCredentialError`]；凭证解析错误精确分类，
 /// 连接类错误归类为 `Unreachable`。
-Future<PairingResult> beginPairingConnectWithCredential({
-  required SyncService svc,
-  required NoteStore store,
-  required String credential,
-}) => RustLib.instance.api.crateApiBeginPairingConnectWithCredential(
-  svc: svc,
-  store: store,
-  credential: credential,
-);
+Future<PairingResult>  beginPairingConnectWithCredential({required SyncService svc , required NoteStore store , required String credential }) => RustLib.instance.api.crateApiBeginPairingConnectWithCredential(svc: svc, store: store, credential: credential);
 
 /// 接受对端推送并导入（首次全量同步接收端；配对成功后发起方调用）。
-Future<void> acceptPushAndImport({required SyncService svc}) =>
-    RustLib.instance.api.crateApiAcceptPushAndImport(svc: svc);
+Future<void>  acceptPushAndImport({required SyncService svc }) => RustLib.instance.api.crateApiAcceptPushAndImport(svc: svc);
 
 /// 将所有 CRDT 笔记同步到 SQLite 存储
 ///
 /// 同时清理墓碑（Loro 中已彻底删除的笔记）对应的投影行，防止被删笔记复活。
-Future<void> syncNotesToStore({
-  required SyncService svc,
-  required NoteStore store,
-}) => RustLib.instance.api.crateApiSyncNotesToStore(svc: svc, store: store);
+Future<void>  syncNotesToStore({required SyncService svc , required NoteStore store }) => RustLib.instance.api.crateApiSyncNotesToStore(svc: svc, store: store);
 
 /// 设置同步开关（决策 6 能力）：false 时调度器暂停推送与拉取。
 /// 移动端由 Flutter 侧按网络类型（WiFi vs 蜂窝）调用；桌面端恒 true。
-Future<void> setSyncAllowed({
-  required SyncService svc,
-  required bool allowed,
-}) => RustLib.instance.api.crateApiSetSyncAllowed(svc: svc, allowed: allowed);
+Future<void>  setSyncAllowed({required SyncService svc , required bool allowed }) => RustLib.instance.api.crateApiSetSyncAllowed(svc: svc, allowed: allowed);
 
 /// 当前同步开关状态。
-Future<bool> getSyncAllowed({required SyncService svc}) =>
-    RustLib.instance.api.crateApiGetSyncAllowed(svc: svc);
+Future<bool>  getSyncAllowed({required SyncService svc }) => RustLib.instance.api.crateApiGetSyncAllowed(svc: svc);
+
+/// 待同步笔记计数（模块 5 基础；至少一台对端尚未确认的笔记数，跨重启保持）。
+Future<int>  pendingSyncCount({required SyncService svc }) => RustLib.instance.api.crateApiPendingSyncCount(svc: svc);
+
+/// 单台对端尚未确认的笔记数。
+Future<int>  pendingSyncCountForPeer({required SyncService svc , required String peerId }) => RustLib.instance.api.crateApiPendingSyncCountForPeer(svc: svc, peerId: peerId);
+
+/// 清除全部对端的同步退避（用户手动"立即同步"时先调用，离线过的设备也立即重试）。
+Future<void>  resetSyncBackoff({required SyncService svc }) => RustLib.instance.api.crateApiResetSyncBackoff(svc: svc);
 
-/// 待同步笔记计数（模块 5 基础）。
-Future<int> pendingSyncCount({required SyncService svc}) =>
-    RustLib.instance.api.crateApiPendingSyncCount(svc: svc);
+/// 设置入站同步数据上限（单条记录 / 单次传输 / 整帧缓冲字节数；超限的推送
+/// 被中止）。
+Future<void>  setTransferLimits({required SyncService svc , required int maxRecordBytes , required BigInt maxTransferBytes , required BigInt maxBufferedBytes }) => RustLib.instance.api.crateApiSetTransferLimits(svc: svc, maxRecordBytes: maxRecordBytes, maxTransferBytes: maxTransferBytes, maxBufferedBytes: maxBufferedBytes);
+
+/// 设置墓碑回收期限（天）：未被全部配对设备确认的墓碑超过该期限后回收。
+Future<void>  setTombstoneHorizonDays({required SyncService svc , required int days }) => RustLib.instance.api.crateApiSetTombstoneHorizonDays(svc: svc, days: days);
 
 /// 周期拉取间隔（秒）——Flutter 侧 Timer 周期用。
-Future<int> syncPollIntervalSecs() =>
-    RustLib.instance.api.crateApiSyncPollIntervalSecs();
+Future<int>  syncPollIntervalSecs() => RustLib.instance.api.crateApiSyncPollIntervalSecs();
 
-/// 推送待办（编辑保存即推送）：向所有配对设备推全量快照。
+/// 推送待办（编辑保存即推送）：向所有配对设备推送对端缺失的增量。
 ///
 /// 失败静默（决策 18）：返回每台设备结果，不抛错；调用方 fire-and-forget 即可。
-Future<List<DevicePushResult>> pushPending({
-  required SyncService svc,
-  required NoteStore store,
-}) => RustLib.instance.api.crateApiPushPending(svc: svc, store: store);
+Future<List<DevicePushResult>>  pushPending({required SyncService svc , required NoteStore store }) => RustLib.instance.api.crateApiPushPending(svc: svc, store: store);
 
-/// 周期同步任务体：push 给所有对端 + 短窗口 accept 对端 push + 刷新 SQLite 投影。
-Future<SyncCycleResult> runSyncCycle({
-  required SyncService svc,
-  required NoteStore store,
-}) => RustLib.instance.api.crateApiRunSyncCycle(svc: svc, store: store);
+/// 周期同步任务体：与每台对端一次双向同步会话（拉取本端缺失 + 推回对端缺失）+ 刷新 SQLite 投影。
+Future<SyncCycleResult>  runSyncCycle({required SyncService svc , required NoteStore store }) => RustLib.instance.api.crateApiRunSyncCycle(svc: svc, store: store);
 
 /// 启动被动接收任务（幂等）：持续短窗口 accept 对端 push，收到即
 /// import → 刷新 SQLite 投影 → 更新发送方 last_seen。
@@ -193,184 +139,198 @@ Future<SyncCycleResult> runSyncCycle({
 /// Dart 侧 RustArc 视为 move/消费（`Auto_Owned` 编码），导致调用返回后
 /// `_store` 已 disposed、下一周期 `run_sync_cycle` 抛 DroppableDisposedException。
 /// 接收器内部仍持有自己的 clone（`SyncService::start_receiver` 内 clone）。
-Future<void> startReceiver({
-  required SyncService svc,
-  required NoteStore store,
-}) => RustLib.instance.api.crateApiStartReceiver(svc: svc, store: store);
+Future<void>  startReceiver({required SyncService svc , required NoteStore store }) => RustLib.instance.api.crateApiStartReceiver(svc: svc, store: store);
 
 /// 停止被动接收任务（幂等；3 秒内返回）。
-Future<void> stopReceiver({required SyncService svc}) =>
-    RustLib.instance.api.crateApiStopReceiver(svc: svc);
+Future<void>  stopReceiver({required SyncService svc }) => RustLib.instance.api.crateApiStopReceiver(svc: svc);
 
 /// 接收任务是否运行中（诊断/测试用）。
-Future<bool> receiverRunning({required SyncService svc}) =>
-    RustLib.instance.api.crateApiReceiverRunning(svc: svc);
+Future<bool>  receiverRunning({required SyncService svc }) => RustLib.instance.api.crateApiReceiverRunning(svc: svc);
 
 /// Monotonic revision of successfully projected inbound receiver content.
-Future<BigInt> receiverContentRevision({required SyncService svc}) =>
-    RustLib.instance.api.crateApiReceiverContentRevision(svc: svc);
+Future<BigInt>  receiverContentRevision({required SyncService svc }) => RustLib.instance.api.crateApiReceiverContentRevision(svc: svc);
+
+/// 启动后台同步任务（幂等）：编辑后防抖推送 + 每 `SYNC_POLL_INTERVAL_SECS`
+/// 一次同步会话，遵守同步开关。启用后 Flutter 侧无需再驱动周期计时器。
+Future<void>  startSyncLoop({required SyncService svc , required NoteStore store }) => RustLib.instance.api.crateApiStartSyncLoop(svc: svc, store: store);
+
+/// 停止后台同步任务（幂等；3 秒内返回）。
+Future<void>  stopSyncLoop({required SyncService svc }) => RustLib.instance.api.crateApiStopSyncLoop(svc: svc);
+
+/// 订阅同步服务事件流：笔记变更（含 id）、回收站/彻底删除、设备上下线、
+/// 同步开始/结束/失败、配对请求。UI 按事件只刷新受影响的内容。
+///
+/// 转发任务在 Dart 侧取消订阅或服务释放后结束；处理过慢丢失事件时收到
+/// `SyncEvent::Lagged`，应整体重新加载。
+Stream<SyncEvent>  syncEvents({required SyncService svc }) => RustLib.instance.api.crateApiSyncEvents(svc: svc);
 
 /// 创建笔记
-Future<void> noteCreate({
-  required SyncService svc,
-  required String id,
-  required String content,
-}) =>
-    RustLib.instance.api.crateApiNoteCreate(svc: svc, id: id, content: content);
+Future<void>  noteCreate({required SyncService svc , required String id , required String content }) => RustLib.instance.api.crateApiNoteCreate(svc: svc, id: id, content: content);
 
 /// 读取笔记内容
-Future<String?> noteGet({required SyncService svc, required String id}) =>
-    RustLib.instance.api.crateApiNoteGet(svc: svc, id: id);
+Future<String?>  noteGet({required SyncService svc , required String id }) => RustLib.instance.api.crateApiNoteGet(svc: svc, id: id);
 
 /// 导出所有笔记的序列化快照
-Future<Uint8List> noteExportAll({required SyncService svc}) =>
-    RustLib.instance.api.crateApiNoteExportAll(svc: svc);
+Future<Uint8List>  noteExportAll({required SyncService svc }) => RustLib.instance.api.crateApiNoteExportAll(svc: svc);
 
 /// 导入快照
-Future<void> noteImportAll({
-  required SyncService svc,
-  required List<int> data,
-}) => RustLib.instance.api.crateApiNoteImportAll(svc: svc, data: data);
+Future<void>  noteImportAll({required SyncService svc , required List<int> data }) => RustLib.instance.api.crateApiNoteImportAll(svc: svc, data: data);
 
 /// 推送到对端
-Future<void> pushToPeer({
-  required SyncService svc,
-  required String peerId,
-  required List<String> ips,
-}) =>
-    RustLib.instance.api.crateApiPushToPeer(svc: svc, peerId: peerId, ips: ips);
+Future<void>  pushToPeer({required SyncService svc , required String peerId , required List<String> ips }) => RustLib.instance.api.crateApiPushToPeer(svc: svc, peerId: peerId, ips: ips);
 
 /// 接受对端推送
-Future<Uint8List> acceptPush({required SyncService svc}) =>
-    RustLib.instance.api.crateApiAcceptPush(svc: svc);
+Future<Uint8List>  acceptPush({required SyncService svc }) => RustLib.instance.api.crateApiAcceptPush(svc: svc);
 
-/// 向多台设备逐个推送全量快照（含墓碑），返回每台设备的结果。
+/// 向多台设备逐个增量推送（含墓碑），返回每台设备的结果。
 ///
 /// `devices`: `(peer_id, Option<IP 列表>)`；IP 缺省（None/空）时经 relay/地址解析尝试连接。
 /// 单台失败不中断整体；单台超时 10 秒记为失败。
-Future<List<DevicePushResult>> pushToDevices({
-  required SyncService svc,
-  required List<(String, List<String>?)> devices,
-}) => RustLib.instance.api.crateApiPushToDevices(svc: svc, devices: devices);
+Future<List<DevicePushResult>>  pushToDevices({required SyncService svc , required List<(String,List<String>?)> devices }) => RustLib.instance.api.crateApiPushToDevices(svc: svc, devices: devices);
 
 /// SQLite — 列出所有配对设备（最近连接优先）。
-Future<List<PairedDeviceRow>> listPairedDevices({required NoteStore store}) =>
-    RustLib.instance.api.crateApiListPairedDevices(store: store);
-
-/// SQLite — 移除一台配对设备。
-Future<void> removePairedDevice({
-  required NoteStore store,
-  required String peerId,
-}) => RustLib.instance.api.crateApiRemovePairedDevice(
-  store: store,
-  peerId: peerId,
-);
+Future<List<PairedDeviceRow>>  listPairedDevices({required NoteStore store }) => RustLib.instance.api.crateApiListPairedDevices(store: store);
+
+/// 移除一台配对设备。
+///
+/// 签名的移除记录随后续同步传播，其他设备同样移除它，名册不会再把它加回
+/// （之后该设备的同步帧因不在配对表而被拒）；该设备仍可重新配对。要让整个
+/// 设备网永久拒绝它，使用 [`revoke_device`]。
+Future<void>  removePairedDevice({required SyncService svc , required NoteStore store , required String peerId }) => RustLib.instance.api.crateApiRemovePairedDevice(svc: svc, store: store, peerId: peerId);
+
+/// 撤销一台设备：签名撤销记录并移除配对，记录随后续同步会话传播给其他设备。
+Future<void>  revokeDevice({required SyncService svc , required NoteStore store , required String peerId }) => RustLib.instance.api.crateApiRevokeDevice(svc: svc, store: store, peerId: peerId);
+
+/// SQLite — 列出已撤销的设备。
+Future<List<RevokedDeviceRow>>  listRevokedDevices({required NoteStore store }) => RustLib.instance.api.crateApiListRevokedDevices(store: store);
 
 /// 设备发现 — 广播本设备
-Future<void> startAdvertising({
-  required DiscoveryService disc,
-  required String deviceId,
-  required int port,
-  required String nonce,
-}) => RustLib.instance.api.crateApiStartAdvertising(
-  disc: disc,
-  deviceId: deviceId,
-  port: port,
-  nonce: nonce,
-);
+Future<void>  startAdvertising({required DiscoveryService disc , required String deviceId , required int port , required String nonce }) => RustLib.instance.api.crateApiStartAdvertising(disc: disc, deviceId: deviceId, port: port, nonce: nonce);
 
 /// 设备发现 — 扫描对端
-Future<List<PeerInfo>> discoverPeers({required DiscoveryService disc}) =>
-    RustLib.instance.api.crateApiDiscoverPeers(disc: disc);
+Future<List<PeerInfo>>  discoverPeers({required DiscoveryService disc }) => RustLib.instance.api.crateApiDiscoverPeers(disc: disc);
 
-/// 创建 SQLite 存储
-Future<NoteStore> createNoteStore({required String path}) =>
-    RustLib.instance.api.crateApiCreateNoteStore(path: path);
+/// 创建 SQLite 存储（数据目录已启用静态加密时为内存投影，需先 [`vault_unlock`]）
+Future<NoteStore>  createNoteStore({required String path }) => RustLib.instance.api.crateApiCreateNoteStore(path: path);
 
 /// SQLite — 列出所有笔记
-Future<List<NoteRow>> storeList({required NoteStore store}) =>
-    RustLib.instance.api.crateApiStoreList(store: store);
+Future<List<NoteRow>>  storeList({required NoteStore store }) => RustLib.instance.api.crateApiStoreList(store: store);
 
 /// SQLite — 搜索笔记
-Future<List<NoteRow>> storeSearch({
-  required NoteStore store,
-  required String query,
-}) => RustLib.instance.api.crateApiStoreSearch(store: store, query: query);
+Future<List<NoteRow>>  storeSearch({required NoteStore store , required String query }) => RustLib.instance.api.crateApiStoreSearch(store: store, query: query);
 
 /// 生成新笔记 ID（UUID v7）
-Future<String> generateNoteId() =>
-    RustLib.instance.api.crateApiGenerateNoteId();
+Future<String>  generateNoteId() => RustLib.instance.api.crateApiGenerateNoteId();
 
 /// 更新笔记元数据（meta tags）
-Future<void> noteUpdateMetadata({
-  required SyncService svc,
-  required String noteId,
-  required List<String> tags,
-}) => RustLib.instance.api.crateApiNoteUpdateMetadata(
-  svc: svc,
-  noteId: noteId,
-  tags: tags,
-);
+Future<void>  noteUpdateMetadata({required SyncService svc , required String noteId , required List<String> tags }) => RustLib.instance.api.crateApiNoteUpdateMetadata(svc: svc, noteId: noteId, tags: tags);
 
 /// SQLite — 出链查询
-Future<List<LinkRow>> getOutgoingLinks({
-  required NoteStore store,
-  required String noteId,
-}) =>
-    RustLib.instance.api.crateApiGetOutgoingLinks(store: store, noteId: noteId);
+Future<List<LinkRow>>  getOutgoingLinks({required NoteStore store , required String noteId }) => RustLib.instance.api.crateApiGetOutgoingLinks(store: store, noteId: noteId);
 
 /// SQLite — 反链查询
-Future<List<LinkRow>> getBacklinks({
-  required NoteStore store,
-  required String noteId,
-}) => RustLib.instance.api.crateApiGetBacklinks(store: store, noteId: noteId);
+Future<List<LinkRow>>  getBacklinks({required NoteStore store , required String noteId }) => RustLib.instance.api.crateApiGetBacklinks(store: store, noteId: noteId);
 
-/// SQLite — 全文搜索（FTS5）
-Future<List<NoteRow>> searchNotes({
-  required NoteStore store,
-  required String query,
-}) => RustLib.instance.api.crateApiSearchNotes(store: store, query: query);
+/// SQLite — 全文搜索（FTS5，支持 `tag:`、`updated:`、`links-to:`、`in:trash`、`OR`、`-` 等语法）
+Future<List<NoteRow>>  searchNotes({required NoteStore store , required String query }) => RustLib.instance.api.crateApiSearchNotes(store: store, query: query);
+
+/// 校验搜索语法（输入时实时提示）。
+///
+/// 错误为稳定的 [`SearchQueryError`]（kind + 出错位置），Dart 侧按 kind 映射文案。
+Future<void>  checkSearchQuery({required String query }) => RustLib.instance.api.crateApiCheckSearchQuery(query: query);
 
 /// SQLite — 链接自动补全（标题前缀，最近 20 条）
-Future<List<NoteRow>> autoCompleteLinks({
-  required NoteStore store,
-  required String prefix,
-}) => RustLib.instance.api.crateApiAutoCompleteLinks(
-  store: store,
-  prefix: prefix,
-);
+Future<List<NoteRow>>  autoCompleteLinks({required NoteStore store , required String prefix }) => RustLib.instance.api.crateApiAutoCompleteLinks(store: store, prefix: prefix);
+
+/// SQLite — 知识图谱全图（节点含标题/标签/度数，边含显示名）
+Future<NoteGraph>  getNoteGraph({required NoteStore store }) => RustLib.instance.api.crateApiGetNoteGraph(store: store);
+
+/// SQLite — 局部图谱：note_id 周围 `depth` 跳内的笔记及其间的边
+Future<NoteGraph>  getNoteNeighborhood({required NoteStore store , required String noteId , required int depth }) => RustLib.instance.api.crateApiGetNoteNeighborhood(store: store, noteId: noteId, depth: depth);
+
+/// SQLite — 孤立笔记（无出链也无反链）
+Future<List<NoteRow>>  getOrphanNotes({required NoteStore store }) => RustLib.instance.api.crateApiGetOrphanNotes(store: store);
+
+/// SQLite — 两篇笔记间的最短链接路径（含首尾；不连通 = 空）
+Future<List<String>>  getShortestPath({required NoteStore store , required String from , required String to }) => RustLib.instance.api.crateApiGetShortestPath(store: store, from: from, to: to);
+
+/// SQLite — 连通分量（按大小降序）
+Future<List<List<String>>>  getConnectedComponents({required NoteStore store }) => RustLib.instance.api.crateApiGetConnectedComponents(store: store);
 
 /// SQLite — 全部标签（去重排序）
-Future<List<String>> getAllTags({required NoteStore store}) =>
-    RustLib.instance.api.crateApiGetAllTags(store: store);
+Future<List<String>>  getAllTags({required NoteStore store }) => RustLib.instance.api.crateApiGetAllTags(store: store);
 
 /// SQLite — 按标签搜索
-Future<List<NoteRow>> searchByTag({
-  required NoteStore store,
-  required String tag,
-}) => RustLib.instance.api.crateApiSearchByTag(store: store, tag: tag);
+Future<List<NoteRow>>  searchByTag({required NoteStore store , required String tag }) => RustLib.instance.api.crateApiSearchByTag(store: store, tag: tag);
 
 /// SQLite — 回收站列表（deleted_at 非空，按删除时间倒序）
-Future<List<NoteRow>> storeTrashList({required NoteStore store}) =>
-    RustLib.instance.api.crateApiStoreTrashList(store: store);
+Future<List<NoteRow>>  storeTrashList({required NoteStore store }) => RustLib.instance.api.crateApiStoreTrashList(store: store);
 
 /// 软删除：给笔记 meta 打 deleted_at 标记（进回收站）。
 /// 删除状态来自 Loro；调用后需由 repository 跟随 `sync_notes_to_store` 刷新投影。
-Future<void> noteSoftDelete({required SyncService svc, required String id}) =>
-    RustLib.instance.api.crateApiNoteSoftDelete(svc: svc, id: id);
+Future<void>  noteSoftDelete({required SyncService svc , required String id }) => RustLib.instance.api.crateApiNoteSoftDelete(svc: svc, id: id);
 
 /// 恢复：清除笔记 meta 的 deleted_at 标记。
-Future<void> noteRestore({required SyncService svc, required String id}) =>
-    RustLib.instance.api.crateApiNoteRestore(svc: svc, id: id);
+Future<void>  noteRestore({required SyncService svc , required String id }) => RustLib.instance.api.crateApiNoteRestore(svc: svc, id: id);
 
 /// 彻底删除：从 Loro notes 移除并入墓碑（删除信息随快照传播，防复活）。
-Future<void> notePurge({required SyncService svc, required String id}) =>
-    RustLib.instance.api.crateApiNotePurge(svc: svc, id: id);
+Future<void>  notePurge({required SyncService svc , required String id }) => RustLib.instance.api.crateApiNotePurge(svc: svc, id: id);
 
 /// 过期清理：purge 回收站中 meta.deleted_at < cutoff 的笔记，返回清理数。
 ///
 /// `cutoff` 为 RFC3339 时间字符串（Flutter 侧 `now - 30d`）。
-Future<BigInt> purgeExpiredTrash({
-  required SyncService svc,
-  required String cutoff,
-}) => RustLib.instance.api.crateApiPurgeExpiredTrash(svc: svc, cutoff: cutoff);
+Future<BigInt>  purgeExpiredTrash({required SyncService svc , required String cutoff }) => RustLib.instance.api.crateApiPurgeExpiredTrash(svc: svc, cutoff: cutoff);
+
+/// 改标题：改写笔记首行，并把指向它的 `[[旧标题]]` 链接改为新标题；
+/// `rewrite_aliases` 时显示名等于旧标题的链接一并改写。返回被改写的其他笔记数。
+/// 改动已写入 `store` 投影（含 links）。
+Future<BigInt>  renameNote({required SyncService svc , required NoteStore store , required String id , required String newTitle , required bool rewriteAliases }) => RustLib.instance.api.crateApiRenameNote(svc: svc, store: store, id: id, newTitle: newTitle, rewriteAliases: rewriteAliases);
+
+/// 合并笔记：`source_id` 正文并入 `into_id` 后移入回收站，指向它的链接改指向
+/// `into_id`。返回被改写的其他笔记数。改动已写入 `store` 投影（含 links）。
+Future<BigInt>  mergeNotes({required SyncService svc , required NoteStore store , required String sourceId , required String intoId }) => RustLib.instance.api.crateApiMergeNotes(svc: svc, store: store, sourceId: sourceId, intoId: intoId);
+
+/// SQLite — 未链接提及：正文提到该笔记标题但尚未链接它的其他笔记，附上下文。
+Future<List<MentionRow>>  getUnlinkedMentions({required NoteStore store , required String noteId }) => RustLib.instance.api.crateApiGetUnlinkedMentions(store: store, noteId: noteId);
+
+/// 把 `source_id` 中第一处未链接的提及改为指向 `target_id` 的链接。
+/// 改动已写入 `store` 投影（含 links）。
+Future<void>  linkMention({required SyncService svc , required NoteStore store , required String sourceId , required String targetId }) => RustLib.instance.api.crateApiLinkMention(svc: svc, store: store, sourceId: sourceId, targetId: targetId);
+
+/// 笔记历史版本列表（新 → 旧）：提交时间、发起设备、变更大小。
+Future<List<NoteVersion>>  noteVersions({required SyncService svc , required String id }) => RustLib.instance.api.crateApiNoteVersions(svc: svc, id: id);
+
+/// 渲染笔记在指定历史版本时的正文（只读预览，不改变当前内容）。
+Future<String>  noteContentAtVersion({required SyncService svc , required String id , required String version }) => RustLib.instance.api.crateApiNoteContentAtVersion(svc: svc, id: id, version: version);
+
+/// 恢复到指定历史版本：以一次新编辑写入，随后像普通编辑一样同步到其他设备。
+/// 调用后需由 repository 跟随 `sync_notes_to_store` 刷新投影。
+Future<void>  noteRestoreVersion({required SyncService svc , required String id , required String version }) => RustLib.instance.api.crateApiNoteRestoreVersion(svc: svc, id: id, version: version);
+
+/// 未处理的并发编辑冲突（两台设备离线编辑同一笔记，合并后供用户复核）。
+/// 收到 `NotesChanged { remote: true }` 事件后刷新。
+Future<List<ConflictDetail>>  listConflicts({required SyncService svc }) => RustLib.instance.api.crateApiListConflicts(svc: svc);
+
+/// 处理冲突：保留合并结果，或以本端/对端一侧的正文覆盖。
+/// 覆盖时需由 repository 跟随 `sync_notes_to_store` 刷新投影。
+Future<void>  resolveConflict({required SyncService svc , required String id , required ConflictResolution resolution }) => RustLib.instance.api.crateApiResolveConflict(svc: svc, id: id, resolution: resolution);
+
+/// 数据目录的加密状态（启动时先查询：`Locked` → 先请求口令 [`vault_unlock`]，
+/// 再 `create_persistent_sync_service`）。
+Future<VaultStatus>  vaultStatus({required String path }) => RustLib.instance.api.crateApiVaultStatus(path: path);
+
+/// 用口令解锁数据目录（口令错误返回 Err）。解锁后本进程才能加载该目录。
+Future<void>  vaultUnlock({required String path , required String passphrase }) => RustLib.instance.api.crateApiVaultUnlock(path: path, passphrase: passphrase);
+
+/// 撤销本进程的解锁（已打开的服务不受影响；下次打开前需重新解锁）。
+Future<void>  vaultLock({required String path }) => RustLib.instance.api.crateApiVaultLock(path: path);
+
+/// 为当前数据目录启用静态加密：笔记快照/日志、device.key、配对设备表改为
+/// 密文，SQLite 投影改为内存。之后每次启动需先 [`vault_unlock`]。
+Future<void>  enableEncryption({required SyncService svc , required NoteStore store , required String passphrase }) => RustLib.instance.api.crateApiEnableEncryption(svc: svc, store: store, passphrase: passphrase);
+
+/// 修改加密口令（只重新包裹数据密钥，不重写数据文件）。
+Future<void>  vaultChangePassphrase({required String path , required String oldPassphrase , required String newPassphrase }) => RustLib.instance.api.crateApiVaultChangePassphrase(path: path, oldPassphrase: oldPassphrase, newPassphrase: newPassphrase);
+
+            
+            
\ No newline at end of file
@@ -6,34 +6,44 @@
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DiscoveryService>>
-abstract class DiscoveryService implements RustOpaqueInterface {}
+
+            
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DiscoveryService>>
+                abstract class DiscoveryService implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
 
 /// 局域网内发现的对端设备信息
-class PeerInfo {
-  final String deviceId;
-  final String ip;
-  final int port;
-  final String nonce;
-
-  const PeerInfo({
-    required this.deviceId,
-    required this.ip,
-    required this.port,
-    required this.nonce,
-  });
-
-  @override
-  int get hashCode =>
-      deviceId.hashCode ^ ip.hashCode ^ port.hashCode ^ nonce.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is PeerInfo &&
-          runtimeType == other.runtimeType &&
-          deviceId == other.deviceId &&
-          ip == other.ip &&
-          port == other.port &&
-          nonce == other.nonce;
-}
+class PeerInfo  {
+                final String deviceId;
+final String ip;
+final int port;
+final String nonce;
+
+                const PeerInfo({required this.deviceId ,required this.ip ,required this.port ,required this.nonce ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => deviceId.hashCode^ip.hashCode^port.hashCode^nonce.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PeerInfo &&
+                runtimeType == other.runtimeType
+                && deviceId == other.deviceId&& ip == other.ip&& port == other.port&& nonce == other.nonce;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,46 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'events.freezed.dart';
+
+            
+
+            
+
+            @freezed
+                sealed class SyncEvent with _$SyncEvent  {
+                    const SyncEvent._();
+
+                     /// 笔记新建或内容/元数据变更；`remote` = 来自对端同步。
+const factory SyncEvent.notesChanged({   required List<String> noteIds ,  required bool remote , }) = SyncEvent_NotesChanged;
+ /// 笔记移入回收站（软删除）。
+const factory SyncEvent.noteDeleted({   required String noteId ,  required bool remote , }) = SyncEvent_NoteDeleted;
+ /// 笔记从回收站恢复。
+const factory SyncEvent.noteRestored({   required String noteId ,  required bool remote , }) = SyncEvent_NoteRestored;
+ /// 笔记彻底删除（入墓碑）。
+const factory SyncEvent.notesPurged({   required List<String> noteIds ,  required bool remote , }) = SyncEvent_NotesPurged;
+ /// 对端设备变为可达（同步成功、入站连接或 mDNS 发现）。
+const factory SyncEvent.peerOnline({   required String peerId , }) = SyncEvent_PeerOnline;
+ /// 此前可达的对端同步失败。
+const factory SyncEvent.peerOffline({   required String peerId , }) = SyncEvent_PeerOffline;
+ /// 一轮同步（周期同步或推送待办）开始。
+const factory SyncEvent.syncStarted() = SyncEvent_SyncStarted;
+ /// 一轮同步结束（至少一台对端成功）。
+const factory SyncEvent.syncFinished({   required int okCount ,  required int failedCount ,  required int pulledCount , }) = SyncEvent_SyncFinished;
+ /// 一轮同步失败（全部对端失败或刷新投影出错）。
+const factory SyncEvent.syncFailed({   required String message , }) = SyncEvent_SyncFailed;
+ /// 收到配对请求，等待用户确认。
+const factory SyncEvent.pairingRequested({   required String deviceId ,  required String deviceName , }) = SyncEvent_PairingRequested;
+ /// 订阅方处理过慢，丢失了 `missed` 条事件：应整体重新加载。
+const factory SyncEvent.lagged({   required BigInt missed , }) = SyncEvent_Lagged;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
@@ -7,3898 +7,3684 @@ import 'api.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'discovery.dart';
+import 'events.dart';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'search.dart';
 import 'store.dart';
 import 'sync.dart';
+import 'vault.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({required RustLibApi api}) {
-    instance.initMockImpl(api: api);
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {}
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.12.0';
-
-  @override
-  int get rustContentHash => 1493639640;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-        stem: 'cardmind_backend',
-        ioDirectory: 'rust-backend/target/release/',
-        webPrefix: 'pkg/',
-        wasmBindgenName: 'wasm_bindgen',
-      );
-}
-
-abstract class RustLibApi extends BaseApi {
-  Future<PairingRequest> crateApiAcceptPairingRequest({
-    required SyncService svc,
-  });
-
-  Future<PairingRequest?> crateApiAcceptPairingRequestWithTimeout({
-    required SyncService svc,
-    required Duration timeout,
-  });
-
-  Future<Uint8List> crateApiAcceptPush({required SyncService svc});
-
-  Future<void> crateApiAcceptPushAndImport({required SyncService svc});
-
-  Future<List<NoteRow>> crateApiAutoCompleteLinks({
-    required NoteStore store,
-    required String prefix,
-  });
-
-  Future<String> crateApiBeginPairingAccept({required SyncService svc});
-
-  Future<String> crateApiBeginPairingAcceptWithAdvertising({
-    required SyncService svc,
-  });
-
-  Future<PairingResult> crateApiBeginPairingConnect({
-    required SyncService svc,
-    required NoteStore store,
-    required String code,
-    required PairingTarget target,
-  });
-
-  Future<PairingResult> crateApiBeginPairingConnectWithCredential({
-    required SyncService svc,
-    required NoteStore store,
-    required String credential,
-  });
-
-  Future<PairingCredentialDisplay> crateApiBeginPairingCredential({
-    required SyncService svc,
-  });
-
-  Future<PairingCredentialDisplay>
-  crateApiBeginPairingCredentialWithAdvertising({required SyncService svc});
-
-  Future<PairingResult> crateApiConfirmPairing({
-    required SyncService svc,
-    required NoteStore store,
-    required String code,
-    required PairingRequest requester,
-  });
-
-  Future<NoteStore> crateApiCreateNoteStore({required String path});
-
-  Future<SyncService> crateApiCreatePersistentSyncService({
-    required String path,
-  });
-
-  Future<SyncService> crateApiCreateSyncService();
-
-  Future<List<PeerInfo>> crateApiDiscoverPeers({
-    required DiscoveryService disc,
-  });
 
-  Future<String> crateApiGenerateNoteId();
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
 
-  Future<List<String>> crateApiGetAllTags({required NoteStore store});
+                  RustLib._();
 
-  Future<List<LinkRow>> crateApiGetBacklinks({
-    required NoteStore store,
-    required String noteId,
-  });
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
 
-  Future<String> crateApiGetDeviceId({required SyncService svc});
-
-  Future<String> crateApiGetDeviceName({required SyncService svc});
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
 
-  Future<List<LinkRow>> crateApiGetOutgoingLinks({
-    required NoteStore store,
-    required String noteId,
-  });
-
-  Future<bool> crateApiGetSyncAllowed({required SyncService svc});
-
-  Future<List<PairedDeviceRow>> crateApiListPairedDevices({
-    required NoteStore store,
-  });
-
-  Future<List<String>> crateApiLocalAddrs({required SyncService svc});
-
-  Future<void> crateApiNoteCreate({
-    required SyncService svc,
-    required String id,
-    required String content,
-  });
-
-  Future<Uint8List> crateApiNoteExportAll({required SyncService svc});
-
-  Future<String?> crateApiNoteGet({
-    required SyncService svc,
-    required String id,
-  });
-
-  Future<void> crateApiNoteImportAll({
-    required SyncService svc,
-    required List<int> data,
-  });
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
 
-  Future<void> crateApiNotePurge({
-    required SyncService svc,
-    required String id,
-  });
-
-  Future<void> crateApiNoteRestore({
-    required SyncService svc,
-    required String id,
-  });
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
 
-  Future<void> crateApiNoteSoftDelete({
-    required SyncService svc,
-    required String id,
-  });
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
 
-  Future<void> crateApiNoteUpdateMetadata({
-    required SyncService svc,
-    required String noteId,
-    required List<String> tags,
-  });
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    
+                  }
 
-  Future<ParsedPairingCredential> crateApiParsePairingCredential({
-    required SyncService svc,
-    required String credential,
-  });
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
 
-  Future<int> crateApiPendingSyncCount({required SyncService svc});
+                  @override
+                  String get codegenVersion => '2.12.0';
 
-  Future<BigInt> crateApiPurgeExpiredTrash({
-    required SyncService svc,
-    required String cutoff,
-  });
+                  @override
+                  int get rustContentHash => -414880187;
 
-  Future<List<DevicePushResult>> crateApiPushPending({
-    required SyncService svc,
-    required NoteStore store,
-  });
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'cardmind_backend',
+                    ioDirectory: 'rust-backend/target/release/',
+                    webPrefix: 'pkg/',
+                    wasmBindgenName: 'wasm_bindgen',
+                  );
+                }
+                
 
-  Future<List<DevicePushResult>> crateApiPushToDevices({
-    required SyncService svc,
-    required List<(String, List<String>?)> devices,
-  });
+                abstract class RustLibApi extends BaseApi {
+                  Future<PairingRequest> crateApiAcceptPairingRequest({required SyncService svc });
 
-  Future<void> crateApiPushToPeer({
-    required SyncService svc,
-    required String peerId,
-    required List<String> ips,
-  });
+Future<PairingRequest?> crateApiAcceptPairingRequestWithTimeout({required SyncService svc , required Duration timeout });
 
-  Future<BigInt> crateApiReceiverContentRevision({required SyncService svc});
+Future<Uint8List> crateApiAcceptPush({required SyncService svc });
 
-  Future<bool> crateApiReceiverRunning({required SyncService svc});
+Future<void> crateApiAcceptPushAndImport({required SyncService svc });
 
-  Future<void> crateApiRemovePairedDevice({
-    required NoteStore store,
-    required String peerId,
-  });
+Future<List<NoteRow>> crateApiAutoCompleteLinks({required NoteStore store , required String prefix });
 
-  Future<SyncCycleResult> crateApiRunSyncCycle({
-    required SyncService svc,
-    required NoteStore store,
-  });
-
-  Future<List<NoteRow>> crateApiSearchByTag({
-    required NoteStore store,
-    required String tag,
-  });
-
-  Future<List<NoteRow>> crateApiSearchNotes({
-    required NoteStore store,
-    required String query,
-  });
-
-  Future<void> crateApiSetDeviceName({
-    required SyncService svc,
-    required String name,
-  });
-
-  Future<void> crateApiSetSyncAllowed({
-    required SyncService svc,
-    required bool allowed,
-  });
-
-  Future<void> crateApiStartAdvertising({
-    required DiscoveryService disc,
-    required String deviceId,
-    required int port,
-    required String nonce,
-  });
-
-  Future<void> crateApiStartReceiver({
-    required SyncService svc,
-    required NoteStore store,
-  });
-
-  Future<void> crateApiStopPairingAdvertising({required SyncService svc});
-
-  Future<void> crateApiStopReceiver({required SyncService svc});
-
-  Future<List<NoteRow>> crateApiStoreList({required NoteStore store});
-
-  Future<List<NoteRow>> crateApiStoreSearch({
-    required NoteStore store,
-    required String query,
-  });
-
-  Future<List<NoteRow>> crateApiStoreTrashList({required NoteStore store});
-
-  Future<List<PeerInfo>> crateApiSyncDiscoverPeers({required SyncService svc});
-
-  Future<void> crateApiSyncNotesToStore({
-    required SyncService svc,
-    required NoteStore store,
-  });
-
-  Future<int> crateApiSyncPollIntervalSecs();
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_DiscoveryService;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_DiscoveryService;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_DiscoveryServicePtr;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_NoteStore;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_NoteStore;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_NoteStorePtr;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_SyncService;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_SyncService;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SyncServicePtr;
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  Future<PairingRequest> crateApiAcceptPairingRequest({
-    required SyncService svc,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 1,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+Future<String> crateApiBeginPairingAccept({required SyncService svc });
+
+Future<String> crateApiBeginPairingAcceptWithAdvertising({required SyncService svc });
+
+Future<PairingResult> crateApiBeginPairingConnect({required SyncService svc , required NoteStore store , required String code , required PairingTarget target });
+
+Future<PairingResult> crateApiBeginPairingConnectWithCredential({required SyncService svc , required NoteStore store , required String credential });
+
+Future<PairingCredentialDisplay> crateApiBeginPairingCredential({required SyncService svc });
+
+Future<PairingCredentialDisplay> crateApiBeginPairingCredentialWithAdvertising({required SyncService svc });
+
+Future<void> crateApiCheckSearchQuery({required String query });
+
+Future<PairingResult> crateApiConfirmPairing({required SyncService svc , required NoteStore store , required String code , required PairingRequest requester });
+
+Future<NoteStore> crateApiCreateNoteStore({required String path });
+
+Future<SyncService> crateApiCreatePersistentSyncService({required String path });
+
+Future<SyncService> crateApiCreateSyncService();
+
+Future<List<PeerInfo>> crateApiDiscoverPeers({required DiscoveryService disc });
+
+Future<void> crateApiEnableEncryption({required SyncService svc , required NoteStore store , required String passphrase });
+
+Future<String> crateApiGenerateNoteId();
+
+Future<List<String>> crateApiGetAllTags({required NoteStore store });
+
+Future<List<LinkRow>> crateApiGetBacklinks({required NoteStore store , required String noteId });
+
+Future<List<List<String>>> crateApiGetConnectedComponents({required NoteStore store });
+
+Future<String> crateApiGetDeviceId({required SyncService svc });
+
+Future<String> crateApiGetDeviceName({required SyncService svc });
+
+Future<NoteGraph> crateApiGetNoteGraph({required NoteStore store });
+
+Future<NoteGraph> crateApiGetNoteNeighborhood({required NoteStore store , required String noteId , required int depth });
+
+Future<List<NoteRow>> crateApiGetOrphanNotes({required NoteStore store });
+
+Future<List<LinkRow>> crateApiGetOutgoingLinks({required NoteStore store , required String noteId });
+
+Future<List<String>> crateApiGetShortestPath({required NoteStore store , required String from , required String to });
+
+Future<bool> crateApiGetSyncAllowed({required SyncService svc });
+
+Future<List<MentionRow>> crateApiGetUnlinkedMentions({required NoteStore store , required String noteId });
+
+Future<void> crateApiLinkMention({required SyncService svc , required NoteStore store , required String sourceId , required String targetId });
+
+Future<List<ConflictDetail>> crateApiListConflicts({required SyncService svc });
+
+Future<List<PairedDeviceRow>> crateApiListPairedDevices({required NoteStore store });
+
+Future<List<RevokedDeviceRow>> crateApiListRevokedDevices({required NoteStore store });
+
+Future<List<String>> crateApiLocalAddrs({required SyncService svc });
+
+Future<BigInt> crateApiMergeNotes({required SyncService svc , required NoteStore store , required String sourceId , required String intoId });
+
+Future<String> crateApiNoteContentAtVersion({required SyncService svc , required String id , required String version });
+
+Future<void> crateApiNoteCreate({required SyncService svc , required String id , required String content });
+
+Future<Uint8List> crateApiNoteExportAll({required SyncService svc });
+
+Future<String?> crateApiNoteGet({required SyncService svc , required String id });
+
+Future<void> crateApiNoteImportAll({required SyncService svc , required List<int> data });
+
+Future<void> crateApiNotePurge({required SyncService svc , required String id });
+
+Future<void> crateApiNoteRestore({required SyncService svc , required String id });
+
+Future<void> crateApiNoteRestoreVersion({required SyncService svc , required String id , required String version });
+
+Future<void> crateApiNoteSoftDelete({required SyncService svc , required String id });
+
+Future<void> crateApiNoteUpdateMetadata({required SyncService svc , required String noteId , required List<String> tags });
+
+Future<List<NoteVersion>> crateApiNoteVersions({required SyncService svc , required String id });
+
+Future<ParsedPairingCredential> crateApiParsePairingCredential({required SyncService svc , required String credential });
+
+Future<int> crateApiPendingSyncCount({required SyncService svc });
+
+Future<int> crateApiPendingSyncCountForPeer({required SyncService svc , required String peerId });
+
+Future<BigInt> crateApiPurgeExpiredTrash({required SyncService svc , required String cutoff });
+
+Future<List<DevicePushResult>> crateApiPushPending({required SyncService svc , required NoteStore store });
+
+Future<List<DevicePushResult>> crateApiPushToDevices({required SyncService svc , required List<(String,List<String>?)> devices });
+
+Future<void> crateApiPushToPeer({required SyncService svc , required String peerId , required List<String> ips });
+
+Future<BigInt> crateApiReceiverContentRevision({required SyncService svc });
+
+Future<bool> crateApiReceiverRunning({required SyncService svc });
+
+Future<void> crateApiRemovePairedDevice({required SyncService svc , required NoteStore store , required String peerId });
+
+Future<BigInt> crateApiRenameNote({required SyncService svc , required NoteStore store , required String id , required String newTitle , required bool rewriteAliases });
+
+Future<void> crateApiResetSyncBackoff({required SyncService svc });
+
+Future<void> crateApiResolveConflict({required SyncService svc , required String id , required ConflictResolution resolution });
+
+Future<void> crateApiRevokeDevice({required SyncService svc , required NoteStore store , required String peerId });
+
+Future<SyncCycleResult> crateApiRunSyncCycle({required SyncService svc , required NoteStore store });
+
+Future<List<NoteRow>> crateApiSearchByTag({required NoteStore store , required String tag });
+
+Future<List<NoteRow>> crateApiSearchNotes({required NoteStore store , required String query });
+
+Future<void> crateApiSetDeviceName({required SyncService svc , required String name });
+
+Future<void> crateApiSetSyncAllowed({required SyncService svc , required bool allowed });
+
+Future<void> crateApiSetTombstoneHorizonDays({required SyncService svc , required int days });
+
+Future<void> crateApiSetTransferLimits({required SyncService svc , required int maxRecordBytes , required BigInt maxTransferBytes , required BigInt maxBufferedBytes });
+
+Future<void> crateApiStartAdvertising({required DiscoveryService disc , required String deviceId , required int port , required String nonce });
+
+Future<void> crateApiStartReceiver({required SyncService svc , required NoteStore store });
+
+Future<void> crateApiStartSyncLoop({required SyncService svc , required NoteStore store });
+
+Future<void> crateApiStopPairingAdvertising({required SyncService svc });
+
+Future<void> crateApiStopReceiver({required SyncService svc });
+
+Future<void> crateApiStopSyncLoop({required SyncService svc });
+
+Future<List<NoteRow>> crateApiStoreList({required NoteStore store });
+
+Future<List<NoteRow>> crateApiStoreSearch({required NoteStore store , required String query });
+
+Future<List<NoteRow>> crateApiStoreTrashList({required NoteStore store });
+
+Future<List<PeerInfo>> crateApiSyncDiscoverPeers({required SyncService svc });
+
+Stream<SyncEvent> crateApiSyncEvents({required SyncService svc });
+
+Future<void> crateApiSyncNotesToStore({required SyncService svc , required NoteStore store });
+
+Future<int> crateApiSyncPollIntervalSecs();
+
+Future<void> crateApiVaultChangePassphrase({required String path , required String oldPassphrase , required String newPassphrase });
+
+Future<void> crateApiVaultLock({required String path });
+
+Future<VaultStatus> crateApiVaultStatus({required String path });
+
+Future<void> crateApiVaultUnlock({required String path , required String passphrase });
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_DiscoveryService;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_DiscoveryService;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_DiscoveryServicePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_NoteStore;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_NoteStore;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_NoteStorePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SyncService;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SyncService;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SyncServicePtr;
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override Future<PairingRequest> crateApiAcceptPairingRequest({required SyncService svc })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_pairing_request,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiAcceptPairingRequestConstMeta,
-        argValues: [svc],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAcceptPairingRequestConstMeta =>
-      const TaskConstMeta(
-        debugName: "accept_pairing_request",
-        argNames: ["svc"],
-      );
-
-  @override
-  Future<PairingRequest?> crateApiAcceptPairingRequestWithTimeout({
-    required SyncService svc,
-    required Duration timeout,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          sse_encode_Chrono_Duration(timeout, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 2,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiAcceptPairingRequestConstMeta,
+            argValues: [svc],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAcceptPairingRequestConstMeta => const TaskConstMeta(
+            debugName: "accept_pairing_request",
+            argNames: ["svc"],
+        );
+        
+
+@override Future<PairingRequest?> crateApiAcceptPairingRequestWithTimeout({required SyncService svc , required Duration timeout })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_Chrono_Duration(timeout, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_opt_box_autoadd_pairing_request,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiAcceptPairingRequestWithTimeoutConstMeta,
-        argValues: [svc, timeout],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAcceptPairingRequestWithTimeoutConstMeta =>
-      const TaskConstMeta(
-        debugName: "accept_pairing_request_with_timeout",
-        argNames: ["svc", "timeout"],
-      );
-
-  @override
-  Future<Uint8List> crateApiAcceptPush({required SyncService svc}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 3,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiAcceptPairingRequestWithTimeoutConstMeta,
+            argValues: [svc, timeout],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAcceptPairingRequestWithTimeoutConstMeta => const TaskConstMeta(
+            debugName: "accept_pairing_request_with_timeout",
+            argNames: ["svc", "timeout"],
+        );
+        
+
+@override Future<Uint8List> crateApiAcceptPush({required SyncService svc })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiAcceptPushConstMeta,
-        argValues: [svc],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAcceptPushConstMeta =>
-      const TaskConstMeta(debugName: "accept_push", argNames: ["svc"]);
-
-  @override
-  Future<void> crateApiAcceptPushAndImport({required SyncService svc}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 4,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiAcceptPushConstMeta,
+            argValues: [svc],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAcceptPushConstMeta => const TaskConstMeta(
+            debugName: "accept_push",
+            argNames: ["svc"],
+        );
+        
+
+@override Future<void> crateApiAcceptPushAndImport({required SyncService svc })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiAcceptPushAndImportConstMeta,
-        argValues: [svc],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAcceptPushAndImportConstMeta =>
-      const TaskConstMeta(
-        debugName: "accept_push_and_import",
-        argNames: ["svc"],
-      );
-
-  @override
-  Future<List<NoteRow>> crateApiAutoCompleteLinks({
-    required NoteStore store,
-    required String prefix,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-            store,
-            serializer,
-          );
-          sse_encode_String(prefix, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 5,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiAcceptPushAndImportConstMeta,
+            argValues: [svc],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAcceptPushAndImportConstMeta => const TaskConstMeta(
+            debugName: "accept_push_and_import",
+            argNames: ["svc"],
+        );
+        
+
+@override Future<List<NoteRow>> crateApiAutoCompleteLinks({required NoteStore store , required String prefix })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+sse_encode_String(prefix, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_note_row,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiAutoCompleteLinksConstMeta,
-        argValues: [store, prefix],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAutoCompleteLinksConstMeta => const TaskConstMeta(
-    debugName: "auto_complete_links",
-    argNames: ["store", "prefix"],
-  );
-
-  @override
-  Future<String> crateApiBeginPairingAccept({required SyncService svc}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 6,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiAutoCompleteLinksConstMeta,
+            argValues: [store, prefix],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAutoCompleteLinksConstMeta => const TaskConstMeta(
+            debugName: "auto_complete_links",
+            argNames: ["store", "prefix"],
+        );
+        
+
+@override Future<String> crateApiBeginPairingAccept({required SyncService svc })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiBeginPairingAcceptConstMeta,
-        argValues: [svc],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiBeginPairingAcceptConstMeta =>
-      const TaskConstMeta(debugName: "begin_pairing_accept", argNames: ["svc"]);
-
-  @override
-  Future<String> crateApiBeginPairingAcceptWithAdvertising({
-    required SyncService svc,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 7,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiBeginPairingAcceptConstMeta,
+            argValues: [svc],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBeginPairingAcceptConstMeta => const TaskConstMeta(
+            debugName: "begin_pairing_accept",
+            argNames: ["svc"],
+        );
+        
+
+@override Future<String> crateApiBeginPairingAcceptWithAdvertising({required SyncService svc })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiBeginPairingAcceptWithAdvertisingConstMeta,
-        argValues: [svc],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiBeginPairingAcceptWithAdvertisingConstMeta =>
-      const TaskConstMeta(
-        debugName: "begin_pairing_accept_with_advertising",
-        argNames: ["svc"],
-      );
-
-  @override
-  Future<PairingResult> crateApiBeginPairingConnect({
-    required SyncService svc,
-    required NoteStore store,
-    required String code,
-    required PairingTarget target,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-            store,
-            serializer,
-          );
-          sse_encode_String(code, serializer);
-          sse_encode_box_autoadd_pairing_target(target, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 8,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiBeginPairingAcceptWithAdvertisingConstMeta,
+            argValues: [svc],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBeginPairingAcceptWithAdvertisingConstMeta => const TaskConstMeta(
+            debugName: "begin_pairing_accept_with_advertising",
+            argNames: ["svc"],
+        );
+        
+
+@override Future<PairingResult> crateApiBeginPairingConnect({required SyncService svc , required NoteStore store , required String code , required PairingTarget target })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+sse_encode_String(code, serializer);
+sse_encode_box_autoadd_pairing_target(target, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_pairing_result,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiBeginPairingConnectConstMeta,
-        argValues: [svc, store, code, target],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiBeginPairingConnectConstMeta =>
-      const TaskConstMeta(
-        debugName: "begin_pairing_connect",
-        argNames: ["svc", "store", "code", "target"],
-      );
-
-  @override
-  Future<PairingResult> crateApiBeginPairingConnectWithCredential({
-    required SyncService svc,
-    required NoteStore store,
-    required String credential,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-            store,
-            serializer,
-          );
-          sse_encode_String(credential, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 9,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiBeginPairingConnectConstMeta,
+            argValues: [svc, store, code, target],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBeginPairingConnectConstMeta => const TaskConstMeta(
+            debugName: "begin_pairing_connect",
+            argNames: ["svc", "store", "code", "target"],
+        );
+        
+
+@override Future<PairingResult> crateApiBeginPairingConnectWithCredential({required SyncService svc , required NoteStore store , required String credential })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+sse_encode_String(credential, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_pairing_result,
           decodeErrorData: sse_decode_pairing_credential_error,
-        ),
-        constMeta: kCrateApiBeginPairingConnectWithCredentialConstMeta,
-        argValues: [svc, store, credential],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiBeginPairingConnectWithCredentialConstMeta =>
-      const TaskConstMeta(
-        debugName: "begin_pairing_connect_with_credential",
-        argNames: ["svc", "store", "credential"],
-      );
-
-  @override
-  Future<PairingCredentialDisplay> crateApiBeginPairingCredential({
-    required SyncService svc,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 10,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiBeginPairingConnectWithCredentialConstMeta,
+            argValues: [svc, store, credential],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBeginPairingConnectWithCredentialConstMeta => const TaskConstMeta(
+            debugName: "begin_pairing_connect_with_credential",
+            argNames: ["svc", "store", "credential"],
+        );
+        
+
+@override Future<PairingCredentialDisplay> crateApiBeginPairingCredential({required SyncService svc })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_pairing_credential_display,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiBeginPairingCredentialConstMeta,
-        argValues: [svc],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiBeginPairingCredentialConstMeta =>
-      const TaskConstMeta(
-        debugName: "begin_pairing_credential",
-        argNames: ["svc"],
-      );
-
-  @override
-  Future<PairingCredentialDisplay>
-  crateApiBeginPairingCredentialWithAdvertising({required SyncService svc}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 11,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiBeginPairingCredentialConstMeta,
+            argValues: [svc],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBeginPairingCredentialConstMeta => const TaskConstMeta(
+            debugName: "begin_pairing_credential",
+            argNames: ["svc"],
+        );
+        
+
+@override Future<PairingCredentialDisplay> crateApiBeginPairingCredentialWithAdvertising({required SyncService svc })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_pairing_credential_display,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiBeginPairingCredentialWithAdvertisingConstMeta,
-        argValues: [svc],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiBeginPairingCredentialWithAdvertisingConstMeta =>
-      const TaskConstMeta(
-        debugName: "begin_pairing_credential_with_advertising",
-        argNames: ["svc"],
-      );
-
-  @override
-  Future<PairingResult> crateApiConfirmPairing({
-    required SyncService svc,
-    required NoteStore store,
-    required String code,
-    required PairingRequest requester,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-            store,
-            serializer,
-          );
-          sse_encode_String(code, serializer);
-          sse_encode_box_autoadd_pairing_request(requester, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 12,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiBeginPairingCredentialWithAdvertisingConstMeta,
+            argValues: [svc],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBeginPairingCredentialWithAdvertisingConstMeta => const TaskConstMeta(
+            debugName: "begin_pairing_credential_with_advertising",
+            argNames: ["svc"],
+        );
+        
+
+@override Future<void> crateApiCheckSearchQuery({required String query })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(query, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_search_query_error,
+        )
+        ,
+            constMeta: kCrateApiCheckSearchQueryConstMeta,
+            argValues: [query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCheckSearchQueryConstMeta => const TaskConstMeta(
+            debugName: "check_search_query",
+            argNames: ["query"],
+        );
+        
+
+@override Future<PairingResult> crateApiConfirmPairing({required SyncService svc , required NoteStore store , required String code , required PairingRequest requester })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+sse_encode_String(code, serializer);
+sse_encode_box_autoadd_pairing_request(requester, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_pairing_result,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiConfirmPairingConstMeta,
-        argValues: [svc, store, code, requester],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiConfirmPairingConstMeta => const TaskConstMeta(
-    debugName: "confirm_pairing",
-    argNames: ["svc", "store", "code", "requester"],
-  );
-
-  @override
-  Future<NoteStore> crateApiCreateNoteStore({required String path}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(path, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 13,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore,
+        )
+        ,
+            constMeta: kCrateApiConfirmPairingConstMeta,
+            argValues: [svc, store, code, requester],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConfirmPairingConstMeta => const TaskConstMeta(
+            debugName: "confirm_pairing",
+            argNames: ["svc", "store", "code", "requester"],
+        );
+        
+
+@override Future<NoteStore> crateApiCreateNoteStore({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiCreateNoteStoreConstMeta,
-        argValues: [path],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCreateNoteStoreConstMeta =>
-      const TaskConstMeta(debugName: "create_note_store", argNames: ["path"]);
-
-  @override
-  Future<SyncService> crateApiCreatePersistentSyncService({
-    required String path,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(path, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 14,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService,
+        )
+        ,
+            constMeta: kCrateApiCreateNoteStoreConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateNoteStoreConstMeta => const TaskConstMeta(
+            debugName: "create_note_store",
+            argNames: ["path"],
+        );
+        
+
+@override Future<SyncService> crateApiCreatePersistentSyncService({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiCreatePersistentSyncServiceConstMeta,
-        argValues: [path],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCreatePersistentSyncServiceConstMeta =>
-      const TaskConstMeta(
-        debugName: "create_persistent_sync_service",
-        argNames: ["path"],
-      );
-
-  @override
-  Future<SyncService> crateApiCreateSyncService() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 15,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService,
+        )
+        ,
+            constMeta: kCrateApiCreatePersistentSyncServiceConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreatePersistentSyncServiceConstMeta => const TaskConstMeta(
+            debugName: "create_persistent_sync_service",
+            argNames: ["path"],
+        );
+        
+
+@override Future<SyncService> crateApiCreateSyncService()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiCreateSyncServiceConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCreateSyncServiceConstMeta =>
-      const TaskConstMeta(debugName: "create_sync_service", argNames: []);
-
-  @override
-  Future<List<PeerInfo>> crateApiDiscoverPeers({
-    required DiscoveryService disc,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(
-            disc,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 16,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiCreateSyncServiceConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateSyncServiceConstMeta => const TaskConstMeta(
+            debugName: "create_sync_service",
+            argNames: [],
+        );
+        
+
+@override Future<List<PeerInfo>> crateApiDiscoverPeers({required DiscoveryService disc })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(disc, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_peer_info,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiDiscoverPeersConstMeta,
-        argValues: [disc],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDiscoverPeersConstMeta =>
-      const TaskConstMeta(debugName: "discover_peers", argNames: ["disc"]);
-
-  @override
-  Future<String> crateApiGenerateNoteId() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 17,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiDiscoverPeersConstMeta,
+            argValues: [disc],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDiscoverPeersConstMeta => const TaskConstMeta(
+            debugName: "discover_peers",
+            argNames: ["disc"],
+        );
+        
+
+@override Future<void> crateApiEnableEncryption({required SyncService svc , required NoteStore store , required String passphrase })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+sse_encode_String(passphrase, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiEnableEncryptionConstMeta,
+            argValues: [svc, store, passphrase],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnableEncryptionConstMeta => const TaskConstMeta(
+            debugName: "enable_encryption",
+            argNames: ["svc", "store", "passphrase"],
+        );
+        
+
+@override Future<String> crateApiGenerateNoteId()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGenerateNoteIdConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGenerateNoteIdConstMeta =>
-      const TaskConstMeta(debugName: "generate_note_id", argNames: []);
-
-  @override
-  Future<List<String>> crateApiGetAllTags({required NoteStore store}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-            store,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 18,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGenerateNoteIdConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGenerateNoteIdConstMeta => const TaskConstMeta(
+            debugName: "generate_note_id",
+            argNames: [],
+        );
+        
+
+@override Future<List<String>> crateApiGetAllTags({required NoteStore store })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetAllTagsConstMeta,
-        argValues: [store],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetAllTagsConstMeta =>
-      const TaskConstMeta(debugName: "get_all_tags", argNames: ["store"]);
-
-  @override
-  Future<List<LinkRow>> crateApiGetBacklinks({
-    required NoteStore store,
-    required String noteId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-            store,
-            serializer,
-          );
-          sse_encode_String(noteId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 19,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetAllTagsConstMeta,
+            argValues: [store],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllTagsConstMeta => const TaskConstMeta(
+            debugName: "get_all_tags",
+            argNames: ["store"],
+        );
+        
+
+@override Future<List<LinkRow>> crateApiGetBacklinks({required NoteStore store , required String noteId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+sse_encode_String(noteId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_link_row,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetBacklinksConstMeta,
-        argValues: [store, noteId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetBacklinksConstMeta => const TaskConstMeta(
-    debugName: "get_backlinks",
-    argNames: ["store", "noteId"],
-  );
-
-  @override
-  Future<String> crateApiGetDeviceId({required SyncService svc}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 20,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetBacklinksConstMeta,
+            argValues: [store, noteId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetBacklinksConstMeta => const TaskConstMeta(
+            debugName: "get_backlinks",
+            argNames: ["store", "noteId"],
+        );
+        
+
+@override Future<List<List<String>>> crateApiGetConnectedComponents({required NoteStore store })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_list_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiGetConnectedComponentsConstMeta,
+            argValues: [store],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetConnectedComponentsConstMeta => const TaskConstMeta(
+            debugName: "get_connected_components",
+            argNames: ["store"],
+        );
+        
+
+@override Future<String> crateApiGetDeviceId({required SyncService svc })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGetDeviceIdConstMeta,
-        argValues: [svc],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetDeviceIdConstMeta =>
-      const TaskConstMeta(debugName: "get_device_id", argNames: ["svc"]);
-
-  @override
-  Future<String> crateApiGetDeviceName({required SyncService svc}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 21,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetDeviceIdConstMeta,
+            argValues: [svc],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetDeviceIdConstMeta => const TaskConstMeta(
+            debugName: "get_device_id",
+            argNames: ["svc"],
+        );
+        
+
+@override Future<String> crateApiGetDeviceName({required SyncService svc })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGetDeviceNameConstMeta,
-        argValues: [svc],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetDeviceNameConstMeta =>
-      const TaskConstMeta(debugName: "get_device_name", argNames: ["svc"]);
-
-  @override
-  Future<List<LinkRow>> crateApiGetOutgoingLinks({
-    required NoteStore store,
-    required String noteId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-            store,
-            serializer,
-          );
-          sse_encode_String(noteId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 22,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetDeviceNameConstMeta,
+            argValues: [svc],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetDeviceNameConstMeta => const TaskConstMeta(
+            debugName: "get_device_name",
+            argNames: ["svc"],
+        );
+        
+
+@override Future<NoteGraph> crateApiGetNoteGraph({required NoteStore store })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_note_graph,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiGetNoteGraphConstMeta,
+            argValues: [store],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetNoteGraphConstMeta => const TaskConstMeta(
+            debugName: "get_note_graph",
+            argNames: ["store"],
+        );
+        
+
+@override Future<NoteGraph> crateApiGetNoteNeighborhood({required NoteStore store , required String noteId , required int depth })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+sse_encode_String(noteId, serializer);
+sse_encode_u_32(depth, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_note_graph,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiGetNoteNeighborhoodConstMeta,
+            argValues: [store, noteId, depth],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetNoteNeighborhoodConstMeta => const TaskConstMeta(
+            debugName: "get_note_neighborhood",
+            argNames: ["store", "noteId", "depth"],
+        );
+        
+
+@override Future<List<NoteRow>> crateApiGetOrphanNotes({required NoteStore store })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_note_row,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiGetOrphanNotesConstMeta,
+            argValues: [store],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetOrphanNotesConstMeta => const TaskConstMeta(
+            debugName: "get_orphan_notes",
+            argNames: ["store"],
+        );
+        
+
+@override Future<List<LinkRow>> crateApiGetOutgoingLinks({required NoteStore store , required String noteId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+sse_encode_String(noteId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_link_row,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetOutgoingLinksConstMeta,
-        argValues: [store, noteId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetOutgoingLinksConstMeta => const TaskConstMeta(
-    debugName: "get_outgoing_links",
-    argNames: ["store", "noteId"],
-  );
-
-  @override
-  Future<bool> crateApiGetSyncAllowed({required SyncService svc}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 23,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetOutgoingLinksConstMeta,
+            argValues: [store, noteId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetOutgoingLinksConstMeta => const TaskConstMeta(
+            debugName: "get_outgoing_links",
+            argNames: ["store", "noteId"],
+        );
+        
+
+@override Future<List<String>> crateApiGetShortestPath({required NoteStore store , required String from , required String to })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+sse_encode_String(from, serializer);
+sse_encode_String(to, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiGetShortestPathConstMeta,
+            argValues: [store, from, to],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetShortestPathConstMeta => const TaskConstMeta(
+            debugName: "get_shortest_path",
+            argNames: ["store", "from", "to"],
+        );
+        
+
+@override Future<bool> crateApiGetSyncAllowed({required SyncService svc })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGetSyncAllowedConstMeta,
-        argValues: [svc],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetSyncAllowedConstMeta =>
-      const TaskConstMeta(debugName: "get_sync_allowed", argNames: ["svc"]);
-
-  @override
-  Future<List<PairedDeviceRow>> crateApiListPairedDevices({
-    required NoteStore store,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-            store,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 24,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetSyncAllowedConstMeta,
+            argValues: [svc],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSyncAllowedConstMeta => const TaskConstMeta(
+            debugName: "get_sync_allowed",
+            argNames: ["svc"],
+        );
+        
+
+@override Future<List<MentionRow>> crateApiGetUnlinkedMentions({required NoteStore store , required String noteId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+sse_encode_String(noteId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_mention_row,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiGetUnlinkedMentionsConstMeta,
+            argValues: [store, noteId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetUnlinkedMentionsConstMeta => const TaskConstMeta(
+            debugName: "get_unlinked_mentions",
+            argNames: ["store", "noteId"],
+        );
+        
+
+@override Future<void> crateApiLinkMention({required SyncService svc , required NoteStore store , required String sourceId , required String targetId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+sse_encode_String(sourceId, serializer);
+sse_encode_String(targetId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiLinkMentionConstMeta,
+            argValues: [svc, store, sourceId, targetId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLinkMentionConstMeta => const TaskConstMeta(
+            debugName: "link_mention",
+            argNames: ["svc", "store", "sourceId", "targetId"],
+        );
+        
+
+@override Future<List<ConflictDetail>> crateApiListConflicts({required SyncService svc })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_conflict_detail,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiListConflictsConstMeta,
+            argValues: [svc],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListConflictsConstMeta => const TaskConstMeta(
+            debugName: "list_conflicts",
+            argNames: ["svc"],
+        );
+        
+
+@override Future<List<PairedDeviceRow>> crateApiListPairedDevices({required NoteStore store })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_paired_device_row,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiListPairedDevicesConstMeta,
-        argValues: [store],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiListPairedDevicesConstMeta => const TaskConstMeta(
-    debugName: "list_paired_devices",
-    argNames: ["store"],
-  );
-
-  @override
-  Future<List<String>> crateApiLocalAddrs({required SyncService svc}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 25,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiListPairedDevicesConstMeta,
+            argValues: [store],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListPairedDevicesConstMeta => const TaskConstMeta(
+            debugName: "list_paired_devices",
+            argNames: ["store"],
+        );
+        
+
+@override Future<List<RevokedDeviceRow>> crateApiListRevokedDevices({required NoteStore store })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_revoked_device_row,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiListRevokedDevicesConstMeta,
+            argValues: [store],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListRevokedDevicesConstMeta => const TaskConstMeta(
+            debugName: "list_revoked_devices",
+            argNames: ["store"],
+        );
+        
+
+@override Future<List<String>> crateApiLocalAddrs({required SyncService svc })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiLocalAddrsConstMeta,
-        argValues: [svc],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLocalAddrsConstMeta =>
-      const TaskConstMeta(debugName: "local_addrs", argNames: ["svc"]);
-
-  @override
-  Future<void> crateApiNoteCreate({
-    required SyncService svc,
-    required String id,
-    required String content,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          sse_encode_String(id, serializer);
-          sse_encode_String(content, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 26,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLocalAddrsConstMeta,
+            argValues: [svc],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLocalAddrsConstMeta => const TaskConstMeta(
+            debugName: "local_addrs",
+            argNames: ["svc"],
+        );
+        
+
+@override Future<BigInt> crateApiMergeNotes({required SyncService svc , required NoteStore store , required String sourceId , required String intoId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+sse_encode_String(sourceId, serializer);
+sse_encode_String(intoId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiMergeNotesConstMeta,
+            argValues: [svc, store, sourceId, intoId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMergeNotesConstMeta => const TaskConstMeta(
+            debugName: "merge_notes",
+            argNames: ["svc", "store", "sourceId", "intoId"],
+        );
+        
+
+@override Future<String> crateApiNoteContentAtVersion({required SyncService svc , required String id , required String version })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_String(id, serializer);
+sse_encode_String(version, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiNoteContentAtVersionConstMeta,
+            argValues: [svc, id, version],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNoteContentAtVersionConstMeta => const TaskConstMeta(
+            debugName: "note_content_at_version",
+            argNames: ["svc", "id", "version"],
+        );
+        
+
+@override Future<void> crateApiNoteCreate({required SyncService svc , required String id , required String content })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_String(id, serializer);
+sse_encode_String(content, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiNoteCreateConstMeta,
-        argValues: [svc, id, content],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiNoteCreateConstMeta => const TaskConstMeta(
-    debugName: "note_create",
-    argNames: ["svc", "id", "content"],
-  );
-
-  @override
-  Future<Uint8List> crateApiNoteExportAll({required SyncService svc}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 27,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiNoteCreateConstMeta,
+            argValues: [svc, id, content],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNoteCreateConstMeta => const TaskConstMeta(
+            debugName: "note_create",
+            argNames: ["svc", "id", "content"],
+        );
+        
+
+@override Future<Uint8List> crateApiNoteExportAll({required SyncService svc })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiNoteExportAllConstMeta,
-        argValues: [svc],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiNoteExportAllConstMeta =>
-      const TaskConstMeta(debugName: "note_export_all", argNames: ["svc"]);
-
-  @override
-  Future<String?> crateApiNoteGet({
-    required SyncService svc,
-    required String id,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          sse_encode_String(id, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 28,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiNoteExportAllConstMeta,
+            argValues: [svc],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNoteExportAllConstMeta => const TaskConstMeta(
+            debugName: "note_export_all",
+            argNames: ["svc"],
+        );
+        
+
+@override Future<String?> crateApiNoteGet({required SyncService svc , required String id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_String(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_opt_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiNoteGetConstMeta,
-        argValues: [svc, id],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiNoteGetConstMeta =>
-      const TaskConstMeta(debugName: "note_get", argNames: ["svc", "id"]);
-
-  @override
-  Future<void> crateApiNoteImportAll({
-    required SyncService svc,
-    required List<int> data,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 29,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiNoteGetConstMeta,
+            argValues: [svc, id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNoteGetConstMeta => const TaskConstMeta(
+            debugName: "note_get",
+            argNames: ["svc", "id"],
+        );
+        
+
+@override Future<void> crateApiNoteImportAll({required SyncService svc , required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiNoteImportAllConstMeta,
-        argValues: [svc, data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiNoteImportAllConstMeta => const TaskConstMeta(
-    debugName: "note_import_all",
-    argNames: ["svc", "data"],
-  );
-
-  @override
-  Future<void> crateApiNotePurge({
-    required SyncService svc,
-    required String id,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          sse_encode_String(id, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 30,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiNoteImportAllConstMeta,
+            argValues: [svc, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNoteImportAllConstMeta => const TaskConstMeta(
+            debugName: "note_import_all",
+            argNames: ["svc", "data"],
+        );
+        
+
+@override Future<void> crateApiNotePurge({required SyncService svc , required String id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_String(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiNotePurgeConstMeta,
-        argValues: [svc, id],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiNotePurgeConstMeta =>
-      const TaskConstMeta(debugName: "note_purge", argNames: ["svc", "id"]);
-
-  @override
-  Future<void> crateApiNoteRestore({
-    required SyncService svc,
-    required String id,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          sse_encode_String(id, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 31,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiNotePurgeConstMeta,
+            argValues: [svc, id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNotePurgeConstMeta => const TaskConstMeta(
+            debugName: "note_purge",
+            argNames: ["svc", "id"],
+        );
+        
+
+@override Future<void> crateApiNoteRestore({required SyncService svc , required String id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_String(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiNoteRestoreConstMeta,
-        argValues: [svc, id],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiNoteRestoreConstMeta =>
-      const TaskConstMeta(debugName: "note_restore", argNames: ["svc", "id"]);
-
-  @override
-  Future<void> crateApiNoteSoftDelete({
-    required SyncService svc,
-    required String id,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          sse_encode_String(id, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 32,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiNoteRestoreConstMeta,
+            argValues: [svc, id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNoteRestoreConstMeta => const TaskConstMeta(
+            debugName: "note_restore",
+            argNames: ["svc", "id"],
+        );
+        
+
+@override Future<void> crateApiNoteRestoreVersion({required SyncService svc , required String id , required String version })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_String(id, serializer);
+sse_encode_String(version, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiNoteSoftDeleteConstMeta,
-        argValues: [svc, id],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiNoteSoftDeleteConstMeta => const TaskConstMeta(
-    debugName: "note_soft_delete",
-    argNames: ["svc", "id"],
-  );
-
-  @override
-  Future<void> crateApiNoteUpdateMetadata({
-    required SyncService svc,
-    required String noteId,
-    required List<String> tags,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          sse_encode_String(noteId, serializer);
-          sse_encode_list_String(tags, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 33,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiNoteRestoreVersionConstMeta,
+            argValues: [svc, id, version],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNoteRestoreVersionConstMeta => const TaskConstMeta(
+            debugName: "note_restore_version",
+            argNames: ["svc", "id", "version"],
+        );
+        
+
+@override Future<void> crateApiNoteSoftDelete({required SyncService svc , required String id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_String(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiNoteUpdateMetadataConstMeta,
-        argValues: [svc, noteId, tags],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiNoteUpdateMetadataConstMeta => const TaskConstMeta(
-    debugName: "note_update_metadata",
-    argNames: ["svc", "noteId", "tags"],
-  );
-
-  @override
-  Future<ParsedPairingCredential> crateApiParsePairingCredential({
-    required SyncService svc,
-    required String credential,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          sse_encode_String(credential, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 34,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiNoteSoftDeleteConstMeta,
+            argValues: [svc, id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNoteSoftDeleteConstMeta => const TaskConstMeta(
+            debugName: "note_soft_delete",
+            argNames: ["svc", "id"],
+        );
+        
+
+@override Future<void> crateApiNoteUpdateMetadata({required SyncService svc , required String noteId , required List<String> tags })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_String(noteId, serializer);
+sse_encode_list_String(tags, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiNoteUpdateMetadataConstMeta,
+            argValues: [svc, noteId, tags],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNoteUpdateMetadataConstMeta => const TaskConstMeta(
+            debugName: "note_update_metadata",
+            argNames: ["svc", "noteId", "tags"],
+        );
+        
+
+@override Future<List<NoteVersion>> crateApiNoteVersions({required SyncService svc , required String id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_String(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_note_version,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiNoteVersionsConstMeta,
+            argValues: [svc, id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNoteVersionsConstMeta => const TaskConstMeta(
+            debugName: "note_versions",
+            argNames: ["svc", "id"],
+        );
+        
+
+@override Future<ParsedPairingCredential> crateApiParsePairingCredential({required SyncService svc , required String credential })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_String(credential, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_parsed_pairing_credential,
           decodeErrorData: sse_decode_pairing_credential_error,
-        ),
-        constMeta: kCrateApiParsePairingCredentialConstMeta,
-        argValues: [svc, credential],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiParsePairingCredentialConstMeta =>
-      const TaskConstMeta(
-        debugName: "parse_pairing_credential",
-        argNames: ["svc", "credential"],
-      );
-
-  @override
-  Future<int> crateApiPendingSyncCount({required SyncService svc}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 35,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiParsePairingCredentialConstMeta,
+            argValues: [svc, credential],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiParsePairingCredentialConstMeta => const TaskConstMeta(
+            debugName: "parse_pairing_credential",
+            argNames: ["svc", "credential"],
+        );
+        
+
+@override Future<int> crateApiPendingSyncCount({required SyncService svc })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_32,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiPendingSyncCountConstMeta,
-        argValues: [svc],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPendingSyncCountConstMeta =>
-      const TaskConstMeta(debugName: "pending_sync_count", argNames: ["svc"]);
-
-  @override
-  Future<BigInt> crateApiPurgeExpiredTrash({
-    required SyncService svc,
-    required String cutoff,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          sse_encode_String(cutoff, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 36,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPendingSyncCountConstMeta,
+            argValues: [svc],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPendingSyncCountConstMeta => const TaskConstMeta(
+            debugName: "pending_sync_count",
+            argNames: ["svc"],
+        );
+        
+
+@override Future<int> crateApiPendingSyncCountForPeer({required SyncService svc , required String peerId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_String(peerId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPendingSyncCountForPeerConstMeta,
+            argValues: [svc, peerId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPendingSyncCountForPeerConstMeta => const TaskConstMeta(
+            debugName: "pending_sync_count_for_peer",
+            argNames: ["svc", "peerId"],
+        );
+        
+
+@override Future<BigInt> crateApiPurgeExpiredTrash({required SyncService svc , required String cutoff })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_String(cutoff, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_usize,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiPurgeExpiredTrashConstMeta,
-        argValues: [svc, cutoff],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPurgeExpiredTrashConstMeta => const TaskConstMeta(
-    debugName: "purge_expired_trash",
-    argNames: ["svc", "cutoff"],
-  );
-
-  @override
-  Future<List<DevicePushResult>> crateApiPushPending({
-    required SyncService svc,
-    required NoteStore store,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-            store,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 37,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPurgeExpiredTrashConstMeta,
+            argValues: [svc, cutoff],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPurgeExpiredTrashConstMeta => const TaskConstMeta(
+            debugName: "purge_expired_trash",
+            argNames: ["svc", "cutoff"],
+        );
+        
+
+@override Future<List<DevicePushResult>> crateApiPushPending({required SyncService svc , required NoteStore store })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_device_push_result,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiPushPendingConstMeta,
-        argValues: [svc, store],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPushPendingConstMeta => const TaskConstMeta(
-    debugName: "push_pending",
-    argNames: ["svc", "store"],
-  );
-
-  @override
-  Future<List<DevicePushResult>> crateApiPushToDevices({
-    required SyncService svc,
-    required List<(String, List<String>?)> devices,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          sse_encode_list_record_string_opt_list_string(devices, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 38,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPushPendingConstMeta,
+            argValues: [svc, store],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPushPendingConstMeta => const TaskConstMeta(
+            debugName: "push_pending",
+            argNames: ["svc", "store"],
+        );
+        
+
+@override Future<List<DevicePushResult>> crateApiPushToDevices({required SyncService svc , required List<(String,List<String>?)> devices })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_list_record_string_opt_list_string(devices, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_device_push_result,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiPushToDevicesConstMeta,
-        argValues: [svc, devices],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPushToDevicesConstMeta => const TaskConstMeta(
-    debugName: "push_to_devices",
-    argNames: ["svc", "devices"],
-  );
-
-  @override
-  Future<void> crateApiPushToPeer({
-    required SyncService svc,
-    required String peerId,
-    required List<String> ips,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          sse_encode_String(peerId, serializer);
-          sse_encode_list_String(ips, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 39,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPushToDevicesConstMeta,
+            argValues: [svc, devices],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPushToDevicesConstMeta => const TaskConstMeta(
+            debugName: "push_to_devices",
+            argNames: ["svc", "devices"],
+        );
+        
+
+@override Future<void> crateApiPushToPeer({required SyncService svc , required String peerId , required List<String> ips })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_String(peerId, serializer);
+sse_encode_list_String(ips, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiPushToPeerConstMeta,
-        argValues: [svc, peerId, ips],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPushToPeerConstMeta => const TaskConstMeta(
-    debugName: "push_to_peer",
-    argNames: ["svc", "peerId", "ips"],
-  );
-
-  @override
-  Future<BigInt> crateApiReceiverContentRevision({required SyncService svc}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 40,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPushToPeerConstMeta,
+            argValues: [svc, peerId, ips],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPushToPeerConstMeta => const TaskConstMeta(
+            debugName: "push_to_peer",
+            argNames: ["svc", "peerId", "ips"],
+        );
+        
+
+@override Future<BigInt> crateApiReceiverContentRevision({required SyncService svc })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_64,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiReceiverContentRevisionConstMeta,
-        argValues: [svc],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiReceiverContentRevisionConstMeta =>
-      const TaskConstMeta(
-        debugName: "receiver_content_revision",
-        argNames: ["svc"],
-      );
-
-  @override
-  Future<bool> crateApiReceiverRunning({required SyncService svc}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 41,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiReceiverContentRevisionConstMeta,
+            argValues: [svc],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiReceiverContentRevisionConstMeta => const TaskConstMeta(
+            debugName: "receiver_content_revision",
+            argNames: ["svc"],
+        );
+        
+
+@override Future<bool> crateApiReceiverRunning({required SyncService svc })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiReceiverRunningConstMeta,
-        argValues: [svc],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiReceiverRunningConstMeta =>
-      const TaskConstMeta(debugName: "receiver_running", argNames: ["svc"]);
-
-  @override
-  Future<void> crateApiRemovePairedDevice({
-    required NoteStore store,
-    required String peerId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-            store,
-            serializer,
-          );
-          sse_encode_String(peerId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 42,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiReceiverRunningConstMeta,
+            argValues: [svc],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiReceiverRunningConstMeta => const TaskConstMeta(
+            debugName: "receiver_running",
+            argNames: ["svc"],
+        );
+        
+
+@override Future<void> crateApiRemovePairedDevice({required SyncService svc , required NoteStore store , required String peerId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+sse_encode_String(peerId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiRemovePairedDeviceConstMeta,
+            argValues: [svc, store, peerId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRemovePairedDeviceConstMeta => const TaskConstMeta(
+            debugName: "remove_paired_device",
+            argNames: ["svc", "store", "peerId"],
+        );
+        
+
+@override Future<BigInt> crateApiRenameNote({required SyncService svc , required NoteStore store , required String id , required String newTitle , required bool rewriteAliases })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+sse_encode_String(id, serializer);
+sse_encode_String(newTitle, serializer);
+sse_encode_bool(rewriteAliases, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiRenameNoteConstMeta,
+            argValues: [svc, store, id, newTitle, rewriteAliases],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRenameNoteConstMeta => const TaskConstMeta(
+            debugName: "rename_note",
+            argNames: ["svc", "store", "id", "newTitle", "rewriteAliases"],
+        );
+        
+
+@override Future<void> crateApiResetSyncBackoff({required SyncService svc })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiResetSyncBackoffConstMeta,
+            argValues: [svc],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiResetSyncBackoffConstMeta => const TaskConstMeta(
+            debugName: "reset_sync_backoff",
+            argNames: ["svc"],
+        );
+        
+
+@override Future<void> crateApiResolveConflict({required SyncService svc , required String id , required ConflictResolution resolution })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_String(id, serializer);
+sse_encode_conflict_resolution(resolution, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiResolveConflictConstMeta,
+            argValues: [svc, id, resolution],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiResolveConflictConstMeta => const TaskConstMeta(
+            debugName: "resolve_conflict",
+            argNames: ["svc", "id", "resolution"],
+        );
+        
+
+@override Future<void> crateApiRevokeDevice({required SyncService svc , required NoteStore store , required String peerId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+sse_encode_String(peerId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiRemovePairedDeviceConstMeta,
-        argValues: [store, peerId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiRemovePairedDeviceConstMeta => const TaskConstMeta(
-    debugName: "remove_paired_device",
-    argNames: ["store", "peerId"],
-  );
-
-  @override
-  Future<SyncCycleResult> crateApiRunSyncCycle({
-    required SyncService svc,
-    required NoteStore store,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-            store,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 43,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiRevokeDeviceConstMeta,
+            argValues: [svc, store, peerId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRevokeDeviceConstMeta => const TaskConstMeta(
+            debugName: "revoke_device",
+            argNames: ["svc", "store", "peerId"],
+        );
+        
+
+@override Future<SyncCycleResult> crateApiRunSyncCycle({required SyncService svc , required NoteStore store })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_sync_cycle_result,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiRunSyncCycleConstMeta,
-        argValues: [svc, store],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiRunSyncCycleConstMeta => const TaskConstMeta(
-    debugName: "run_sync_cycle",
-    argNames: ["svc", "store"],
-  );
-
-  @override
-  Future<List<NoteRow>> crateApiSearchByTag({
-    required NoteStore store,
-    required String tag,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-            store,
-            serializer,
-          );
-          sse_encode_String(tag, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 44,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiRunSyncCycleConstMeta,
+            argValues: [svc, store],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRunSyncCycleConstMeta => const TaskConstMeta(
+            debugName: "run_sync_cycle",
+            argNames: ["svc", "store"],
+        );
+        
+
+@override Future<List<NoteRow>> crateApiSearchByTag({required NoteStore store , required String tag })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+sse_encode_String(tag, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_note_row,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiSearchByTagConstMeta,
-        argValues: [store, tag],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSearchByTagConstMeta => const TaskConstMeta(
-    debugName: "search_by_tag",
-    argNames: ["store", "tag"],
-  );
-
-  @override
-  Future<List<NoteRow>> crateApiSearchNotes({
-    required NoteStore store,
-    required String query,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-            store,
-            serializer,
-          );
-          sse_encode_String(query, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 45,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSearchByTagConstMeta,
+            argValues: [store, tag],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchByTagConstMeta => const TaskConstMeta(
+            debugName: "search_by_tag",
+            argNames: ["store", "tag"],
+        );
+        
+
+@override Future<List<NoteRow>> crateApiSearchNotes({required NoteStore store , required String query })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+sse_encode_String(query, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_note_row,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiSearchNotesConstMeta,
-        argValues: [store, query],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSearchNotesConstMeta => const TaskConstMeta(
-    debugName: "search_notes",
-    argNames: ["store", "query"],
-  );
-
-  @override
-  Future<void> crateApiSetDeviceName({
-    required SyncService svc,
-    required String name,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          sse_encode_String(name, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 46,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSearchNotesConstMeta,
+            argValues: [store, query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchNotesConstMeta => const TaskConstMeta(
+            debugName: "search_notes",
+            argNames: ["store", "query"],
+        );
+        
+
+@override Future<void> crateApiSetDeviceName({required SyncService svc , required String name })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_String(name, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSetDeviceNameConstMeta,
-        argValues: [svc, name],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSetDeviceNameConstMeta => const TaskConstMeta(
-    debugName: "set_device_name",
-    argNames: ["svc", "name"],
-  );
-
-  @override
-  Future<void> crateApiSetSyncAllowed({
-    required SyncService svc,
-    required bool allowed,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          sse_encode_bool(allowed, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 47,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSetDeviceNameConstMeta,
+            argValues: [svc, name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetDeviceNameConstMeta => const TaskConstMeta(
+            debugName: "set_device_name",
+            argNames: ["svc", "name"],
+        );
+        
+
+@override Future<void> crateApiSetSyncAllowed({required SyncService svc , required bool allowed })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_bool(allowed, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSetSyncAllowedConstMeta,
-        argValues: [svc, allowed],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSetSyncAllowedConstMeta => const TaskConstMeta(
-    debugName: "set_sync_allowed",
-    argNames: ["svc", "allowed"],
-  );
-
-  @override
-  Future<void> crateApiStartAdvertising({
-    required DiscoveryService disc,
-    required String deviceId,
-    required int port,
-    required String nonce,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(
-            disc,
-            serializer,
-          );
-          sse_encode_String(deviceId, serializer);
-          sse_encode_u_16(port, serializer);
-          sse_encode_String(nonce, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 48,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSetSyncAllowedConstMeta,
+            argValues: [svc, allowed],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetSyncAllowedConstMeta => const TaskConstMeta(
+            debugName: "set_sync_allowed",
+            argNames: ["svc", "allowed"],
+        );
+        
+
+@override Future<void> crateApiSetTombstoneHorizonDays({required SyncService svc , required int days })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_u_32(days, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiStartAdvertisingConstMeta,
-        argValues: [disc, deviceId, port, nonce],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiStartAdvertisingConstMeta => const TaskConstMeta(
-    debugName: "start_advertising",
-    argNames: ["disc", "deviceId", "port", "nonce"],
-  );
-
-  @override
-  Future<void> crateApiStartReceiver({
-    required SyncService svc,
-    required NoteStore store,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-            store,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 49,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSetTombstoneHorizonDaysConstMeta,
+            argValues: [svc, days],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetTombstoneHorizonDaysConstMeta => const TaskConstMeta(
+            debugName: "set_tombstone_horizon_days",
+            argNames: ["svc", "days"],
+        );
+        
+
+@override Future<void> crateApiSetTransferLimits({required SyncService svc , required int maxRecordBytes , required BigInt maxTransferBytes , required BigInt maxBufferedBytes })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_u_32(maxRecordBytes, serializer);
+sse_encode_u_64(maxTransferBytes, serializer);
+sse_encode_u_64(maxBufferedBytes, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSetTransferLimitsConstMeta,
+            argValues: [svc, maxRecordBytes, maxTransferBytes, maxBufferedBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetTransferLimitsConstMeta => const TaskConstMeta(
+            debugName: "set_transfer_limits",
+            argNames: ["svc", "maxRecordBytes", "maxTransferBytes", "maxBufferedBytes"],
+        );
+        
+
+@override Future<void> crateApiStartAdvertising({required DiscoveryService disc , required String deviceId , required int port , required String nonce })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(disc, serializer);
+sse_encode_String(deviceId, serializer);
+sse_encode_u_16(port, serializer);
+sse_encode_String(nonce, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiStartReceiverConstMeta,
-        argValues: [svc, store],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiStartReceiverConstMeta => const TaskConstMeta(
-    debugName: "start_receiver",
-    argNames: ["svc", "store"],
-  );
-
-  @override
-  Future<void> crateApiStopPairingAdvertising({required SyncService svc}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 50,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiStartAdvertisingConstMeta,
+            argValues: [disc, deviceId, port, nonce],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStartAdvertisingConstMeta => const TaskConstMeta(
+            debugName: "start_advertising",
+            argNames: ["disc", "deviceId", "port", "nonce"],
+        );
+        
+
+@override Future<void> crateApiStartReceiver({required SyncService svc , required NoteStore store })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiStopPairingAdvertisingConstMeta,
-        argValues: [svc],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiStopPairingAdvertisingConstMeta =>
-      const TaskConstMeta(
-        debugName: "stop_pairing_advertising",
-        argNames: ["svc"],
-      );
-
-  @override
-  Future<void> crateApiStopReceiver({required SyncService svc}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 51,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiStartReceiverConstMeta,
+            argValues: [svc, store],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStartReceiverConstMeta => const TaskConstMeta(
+            debugName: "start_receiver",
+            argNames: ["svc", "store"],
+        );
+        
+
+@override Future<void> crateApiStartSyncLoop({required SyncService svc , required NoteStore store })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiStopReceiverConstMeta,
-        argValues: [svc],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiStopReceiverConstMeta =>
-      const TaskConstMeta(debugName: "stop_receiver", argNames: ["svc"]);
-
-  @override
-  Future<List<NoteRow>> crateApiStoreList({required NoteStore store}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-            store,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 52,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiStartSyncLoopConstMeta,
+            argValues: [svc, store],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStartSyncLoopConstMeta => const TaskConstMeta(
+            debugName: "start_sync_loop",
+            argNames: ["svc", "store"],
+        );
+        
+
+@override Future<void> crateApiStopPairingAdvertising({required SyncService svc })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiStopPairingAdvertisingConstMeta,
+            argValues: [svc],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStopPairingAdvertisingConstMeta => const TaskConstMeta(
+            debugName: "stop_pairing_advertising",
+            argNames: ["svc"],
+        );
+        
+
+@override Future<void> crateApiStopReceiver({required SyncService svc })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiStopReceiverConstMeta,
+            argValues: [svc],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStopReceiverConstMeta => const TaskConstMeta(
+            debugName: "stop_receiver",
+            argNames: ["svc"],
+        );
+        
+
+@override Future<void> crateApiStopSyncLoop({required SyncService svc })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiStopSyncLoopConstMeta,
+            argValues: [svc],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStopSyncLoopConstMeta => const TaskConstMeta(
+            debugName: "stop_sync_loop",
+            argNames: ["svc"],
+        );
+        
+
+@override Future<List<NoteRow>> crateApiStoreList({required NoteStore store })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_note_row,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiStoreListConstMeta,
-        argValues: [store],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiStoreListConstMeta =>
-      const TaskConstMeta(debugName: "store_list", argNames: ["store"]);
-
-  @override
-  Future<List<NoteRow>> crateApiStoreSearch({
-    required NoteStore store,
-    required String query,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-            store,
-            serializer,
-          );
-          sse_encode_String(query, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 53,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiStoreListConstMeta,
+            argValues: [store],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStoreListConstMeta => const TaskConstMeta(
+            debugName: "store_list",
+            argNames: ["store"],
+        );
+        
+
+@override Future<List<NoteRow>> crateApiStoreSearch({required NoteStore store , required String query })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+sse_encode_String(query, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_note_row,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiStoreSearchConstMeta,
-        argValues: [store, query],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiStoreSearchConstMeta => const TaskConstMeta(
-    debugName: "store_search",
-    argNames: ["store", "query"],
-  );
-
-  @override
-  Future<List<NoteRow>> crateApiStoreTrashList({required NoteStore store}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-            store,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 54,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiStoreSearchConstMeta,
+            argValues: [store, query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStoreSearchConstMeta => const TaskConstMeta(
+            debugName: "store_search",
+            argNames: ["store", "query"],
+        );
+        
+
+@override Future<List<NoteRow>> crateApiStoreTrashList({required NoteStore store })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_note_row,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiStoreTrashListConstMeta,
-        argValues: [store],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiStoreTrashListConstMeta =>
-      const TaskConstMeta(debugName: "store_trash_list", argNames: ["store"]);
-
-  @override
-  Future<List<PeerInfo>> crateApiSyncDiscoverPeers({required SyncService svc}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 55,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiStoreTrashListConstMeta,
+            argValues: [store],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStoreTrashListConstMeta => const TaskConstMeta(
+            debugName: "store_trash_list",
+            argNames: ["store"],
+        );
+        
+
+@override Future<List<PeerInfo>> crateApiSyncDiscoverPeers({required SyncService svc })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_peer_info,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiSyncDiscoverPeersConstMeta,
-        argValues: [svc],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSyncDiscoverPeersConstMeta =>
-      const TaskConstMeta(debugName: "sync_discover_peers", argNames: ["svc"]);
-
-  @override
-  Future<void> crateApiSyncNotesToStore({
-    required SyncService svc,
-    required NoteStore store,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-            svc,
-            serializer,
-          );
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-            store,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 56,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSyncDiscoverPeersConstMeta,
+            argValues: [svc],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSyncDiscoverPeersConstMeta => const TaskConstMeta(
+            debugName: "sync_discover_peers",
+            argNames: ["svc"],
+        );
+        
+
+@override Stream<SyncEvent> crateApiSyncEvents({required SyncService svc })  { 
+            final sink = RustStreamSink<SyncEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_StreamSink_sync_event_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiSyncNotesToStoreConstMeta,
-        argValues: [svc, store],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSyncNotesToStoreConstMeta => const TaskConstMeta(
-    debugName: "sync_notes_to_store",
-    argNames: ["svc", "store"],
-  );
-
-  @override
-  Future<int> crateApiSyncPollIntervalSecs() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 57,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSyncEventsConstMeta,
+            argValues: [svc, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiSyncEventsConstMeta => const TaskConstMeta(
+            debugName: "sync_events",
+            argNames: ["svc", "sink"],
+        );
+        
+
+@override Future<void> crateApiSyncNotesToStore({required SyncService svc , required NoteStore store })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(svc, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(store, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSyncNotesToStoreConstMeta,
+            argValues: [svc, store],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSyncNotesToStoreConstMeta => const TaskConstMeta(
+            debugName: "sync_notes_to_store",
+            argNames: ["svc", "store"],
+        );
+        
+
+@override Future<int> crateApiSyncPollIntervalSecs()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_32,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSyncPollIntervalSecsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSyncPollIntervalSecsConstMeta =>
-      const TaskConstMeta(debugName: "sync_poll_interval_secs", argNames: []);
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_DiscoveryService => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_DiscoveryService => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_NoteStore => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_NoteStore => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_SyncService => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_SyncService => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService;
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
-  }
-
-  @protected
-  NoteStore
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return NoteStoreImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  SyncService
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SyncServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  DiscoveryService
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return DiscoveryServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  SyncService
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SyncServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  DiscoveryService
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return DiscoveryServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  NoteStore
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return NoteStoreImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  SyncService
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SyncServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Duration dco_decode_Chrono_Duration(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeDuration(dco_decode_i_64(raw).toInt());
-  }
-
-  @protected
-  DiscoveryService
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return DiscoveryServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  NoteStore
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return NoteStoreImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  SyncService
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SyncServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  PairingRequest dco_decode_box_autoadd_pairing_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_pairing_request(raw);
-  }
-
-  @protected
-  PairingTarget dco_decode_box_autoadd_pairing_target(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_pairing_target(raw);
-  }
-
-  @protected
-  DevicePushResult dco_decode_device_push_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return DevicePushResult(
-      peerId: dco_decode_String(arr[0]),
-      ok: dco_decode_bool(arr[1]),
-      message: dco_decode_String(arr[2]),
-    );
-  }
-
-  @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
-  }
-
-  @protected
-  LinkRow dco_decode_link_row(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return LinkRow(
-      id: dco_decode_String(arr[0]),
-      title: dco_decode_String(arr[1]),
-      alias: dco_decode_String(arr[2]),
-      exists: dco_decode_bool(arr[3]),
-    );
-  }
-
-  @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
-  }
-
-  @protected
-  List<DevicePushResult> dco_decode_list_device_push_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_device_push_result).toList();
-  }
-
-  @protected
-  List<LinkRow> dco_decode_list_link_row(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_link_row).toList();
-  }
-
-  @protected
-  List<NoteRow> dco_decode_list_note_row(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_note_row).toList();
-  }
-
-  @protected
-  List<PairedDeviceRow> dco_decode_list_paired_device_row(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_paired_device_row).toList();
-  }
-
-  @protected
-  List<PeerInfo> dco_decode_list_peer_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_peer_info).toList();
-  }
-
-  @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  List<(String, List<String>?)> dco_decode_list_record_string_opt_list_string(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_record_string_opt_list_string)
-        .toList();
-  }
-
-  @protected
-  NoteRow dco_decode_note_row(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return NoteRow(
-      id: dco_decode_String(arr[0]),
-      title: dco_decode_String(arr[1]),
-      contentPreview: dco_decode_String(arr[2]),
-      tags: dco_decode_String(arr[3]),
-      updatedAt: dco_decode_String(arr[4]),
-      deletedAt: dco_decode_opt_String(arr[5]),
-    );
-  }
-
-  @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
-  }
-
-  @protected
-  PairingRequest? dco_decode_opt_box_autoadd_pairing_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_pairing_request(raw);
-  }
-
-  @protected
-  List<String>? dco_decode_opt_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_String(raw);
-  }
-
-  @protected
-  PairedDeviceRow dco_decode_paired_device_row(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return PairedDeviceRow(
-      peerId: dco_decode_String(arr[0]),
-      name: dco_decode_String(arr[1]),
-      lastSeen: dco_decode_opt_String(arr[2]),
-      pairedAt: dco_decode_String(arr[3]),
-    );
-  }
-
-  @protected
-  PairingCredentialDisplay dco_decode_pairing_credential_display(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return PairingCredentialDisplay(
-      code: dco_decode_String(arr[0]),
-      credential: dco_decode_String(arr[1]),
-      expiresAt: dco_decode_String(arr[2]),
-    );
-  }
-
-  @protected
-  PairingCredentialError dco_decode_pairing_credential_error(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return PairingCredentialError(
-      kind: dco_decode_pairing_credential_error_kind(arr[0]),
-      message: dco_decode_String(arr[1]),
-    );
-  }
-
-  @protected
-  PairingCredentialErrorKind dco_decode_pairing_credential_error_kind(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return PairingCredentialErrorKind.values[raw as int];
-  }
-
-  @protected
-  PairingRequest dco_decode_pairing_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return PairingRequest(
-      code: dco_decode_String(arr[0]),
-      deviceId: dco_decode_String(arr[1]),
-      deviceName: dco_decode_String(arr[2]),
-      relayInfo: dco_decode_String(arr[3]),
-      ips: dco_decode_list_String(arr[4]),
-      nonce: dco_decode_String(arr[5]),
-    );
-  }
-
-  @protected
-  PairingResult dco_decode_pairing_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return PairingResult(
-      peerId: dco_decode_String(arr[0]),
-      peerName: dco_decode_String(arr[1]),
-    );
-  }
-
-  @protected
-  PairingTarget dco_decode_pairing_target(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return PairingTarget(
-      deviceId: dco_decode_String(arr[0]),
-      ips: dco_decode_list_String(arr[1]),
-      nonce: dco_decode_String(arr[2]),
-    );
-  }
-
-  @protected
-  ParsedPairingCredential dco_decode_parsed_pairing_credential(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return ParsedPairingCredential(
-      code: dco_decode_String(arr[0]),
-      deviceId: dco_decode_String(arr[1]),
-      expiresAt: dco_decode_String(arr[2]),
-      nonce: dco_decode_String(arr[3]),
-    );
-  }
-
-  @protected
-  PeerInfo dco_decode_peer_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return PeerInfo(
-      deviceId: dco_decode_String(arr[0]),
-      ip: dco_decode_String(arr[1]),
-      port: dco_decode_u_16(arr[2]),
-      nonce: dco_decode_String(arr[3]),
-    );
-  }
-
-  @protected
-  (String, List<String>?) dco_decode_record_string_opt_list_string(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_String(arr[0]), dco_decode_opt_list_String(arr[1]));
-  }
-
-  @protected
-  SyncCycleResult dco_decode_sync_cycle_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return SyncCycleResult(
-      pushedCount: dco_decode_u_32(arr[0]),
-      acceptedPush: dco_decode_bool(arr[1]),
-      disabled: dco_decode_bool(arr[2]),
-    );
-  }
-
-  @protected
-  int dco_decode_u_16(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
-  }
-
-  @protected
-  NoteStore
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return NoteStoreImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  SyncService
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return SyncServiceImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  DiscoveryService
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return DiscoveryServiceImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  SyncService
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return SyncServiceImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  DiscoveryService
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return DiscoveryServiceImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  NoteStore
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return NoteStoreImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  SyncService
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return SyncServiceImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  Duration sse_decode_Chrono_Duration(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_64(deserializer);
-    return Duration(microseconds: inner.toInt());
-  }
-
-  @protected
-  DiscoveryService
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return DiscoveryServiceImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  NoteStore
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return NoteStoreImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  SyncService
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return SyncServiceImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  PairingRequest sse_decode_box_autoadd_pairing_request(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_pairing_request(deserializer));
-  }
-
-  @protected
-  PairingTarget sse_decode_box_autoadd_pairing_target(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_pairing_target(deserializer));
-  }
-
-  @protected
-  DevicePushResult sse_decode_device_push_result(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_peerId = sse_decode_String(deserializer);
-    var var_ok = sse_decode_bool(deserializer);
-    var var_message = sse_decode_String(deserializer);
-    return DevicePushResult(
-      peerId: var_peerId,
-      ok: var_ok,
-      message: var_message,
-    );
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
-  }
-
-  @protected
-  LinkRow sse_decode_link_row(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_title = sse_decode_String(deserializer);
-    var var_alias = sse_decode_String(deserializer);
-    var var_exists = sse_decode_bool(deserializer);
-    return LinkRow(
-      id: var_id,
-      title: var_title,
-      alias: var_alias,
-      exists: var_exists,
-    );
-  }
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<DevicePushResult> sse_decode_list_device_push_result(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <DevicePushResult>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_device_push_result(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<LinkRow> sse_decode_list_link_row(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <LinkRow>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_link_row(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<NoteRow> sse_decode_list_note_row(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <NoteRow>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_note_row(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<PairedDeviceRow> sse_decode_list_paired_device_row(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <PairedDeviceRow>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_paired_device_row(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<PeerInfo> sse_decode_list_peer_info(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <PeerInfo>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_peer_info(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  List<(String, List<String>?)> sse_decode_list_record_string_opt_list_string(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, List<String>?)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_opt_list_string(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  NoteRow sse_decode_note_row(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_title = sse_decode_String(deserializer);
-    var var_contentPreview = sse_decode_String(deserializer);
-    var var_tags = sse_decode_String(deserializer);
-    var var_updatedAt = sse_decode_String(deserializer);
-    var var_deletedAt = sse_decode_opt_String(deserializer);
-    return NoteRow(
-      id: var_id,
-      title: var_title,
-      contentPreview: var_contentPreview,
-      tags: var_tags,
-      updatedAt: var_updatedAt,
-      deletedAt: var_deletedAt,
-    );
-  }
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  PairingRequest? sse_decode_opt_box_autoadd_pairing_request(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_pairing_request(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_String(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  PairedDeviceRow sse_decode_paired_device_row(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_peerId = sse_decode_String(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_lastSeen = sse_decode_opt_String(deserializer);
-    var var_pairedAt = sse_decode_String(deserializer);
-    return PairedDeviceRow(
-      peerId: var_peerId,
-      name: var_name,
-      lastSeen: var_lastSeen,
-      pairedAt: var_pairedAt,
-    );
-  }
-
-  @protected
-  PairingCredentialDisplay sse_decode_pairing_credential_display(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_code = sse_decode_String(deserializer);
-    var var_credential = sse_decode_String(deserializer);
-    var var_expiresAt = sse_decode_String(deserializer);
-    return PairingCredentialDisplay(
-      code: var_code,
-      credential: var_credential,
-      expiresAt: var_expiresAt,
-    );
-  }
-
-  @protected
-  PairingCredentialError sse_decode_pairing_credential_error(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_kind = sse_decode_pairing_credential_error_kind(deserializer);
-    var var_message = sse_decode_String(deserializer);
-    return PairingCredentialError(kind: var_kind, message: var_message);
-  }
-
-  @protected
-  PairingCredentialErrorKind sse_decode_pairing_credential_error_kind(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return PairingCredentialErrorKind.values[inner];
-  }
-
-  @protected
-  PairingRequest sse_decode_pairing_request(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_code = sse_decode_String(deserializer);
-    var var_deviceId = sse_decode_String(deserializer);
-    var var_deviceName = sse_decode_String(deserializer);
-    var var_relayInfo = sse_decode_String(deserializer);
-    var var_ips = sse_decode_list_String(deserializer);
-    var var_nonce = sse_decode_String(deserializer);
-    return PairingRequest(
-      code: var_code,
-      deviceId: var_deviceId,
-      deviceName: var_deviceName,
-      relayInfo: var_relayInfo,
-      ips: var_ips,
-      nonce: var_nonce,
-    );
-  }
-
-  @protected
-  PairingResult sse_decode_pairing_result(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_peerId = sse_decode_String(deserializer);
-    var var_peerName = sse_decode_String(deserializer);
-    return PairingResult(peerId: var_peerId, peerName: var_peerName);
-  }
-
-  @protected
-  PairingTarget sse_decode_pairing_target(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_deviceId = sse_decode_String(deserializer);
-    var var_ips = sse_decode_list_String(deserializer);
-    var var_nonce = sse_decode_String(deserializer);
-    return PairingTarget(
-      deviceId: var_deviceId,
-      ips: var_ips,
-      nonce: var_nonce,
-    );
-  }
-
-  @protected
-  ParsedPairingCredential sse_decode_parsed_pairing_credential(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_code = sse_decode_String(deserializer);
-    var var_deviceId = sse_decode_String(deserializer);
-    var var_expiresAt = sse_decode_String(deserializer);
-    var var_nonce = sse_decode_String(deserializer);
-    return ParsedPairingCredential(
-      code: var_code,
-      deviceId: var_deviceId,
-      expiresAt: var_expiresAt,
-      nonce: var_nonce,
-    );
-  }
-
-  @protected
-  PeerInfo sse_decode_peer_info(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_deviceId = sse_decode_String(deserializer);
-    var var_ip = sse_decode_String(deserializer);
-    var var_port = sse_decode_u_16(deserializer);
-    var var_nonce = sse_decode_String(deserializer);
-    return PeerInfo(
-      deviceId: var_deviceId,
-      ip: var_ip,
-      port: var_port,
-      nonce: var_nonce,
-    );
-  }
-
-  @protected
-  (String, List<String>?) sse_decode_record_string_opt_list_string(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_String(deserializer);
-    var var_field1 = sse_decode_opt_list_String(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  SyncCycleResult sse_decode_sync_cycle_result(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_pushedCount = sse_decode_u_32(deserializer);
-    var var_acceptedPush = sse_decode_bool(deserializer);
-    var var_disabled = sse_decode_bool(deserializer);
-    return SyncCycleResult(
-      pushedCount: var_pushedCount,
-      acceptedPush: var_acceptedPush,
-      disabled: var_disabled,
-    );
-  }
-
-  @protected
-  int sse_decode_u_16(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint16();
-  }
-
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
-  }
-
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-    NoteStore self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as NoteStoreImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    SyncService self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as SyncServiceImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(
-    DiscoveryService self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as DiscoveryServiceImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    SyncService self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as SyncServiceImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(
-    DiscoveryService self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as DiscoveryServiceImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-    NoteStore self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as NoteStoreImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    SyncService self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as SyncServiceImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_Chrono_Duration(Duration self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(PlatformInt64Util.from(self.inMicroseconds), serializer);
-  }
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(
-    DiscoveryService self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as DiscoveryServiceImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-    NoteStore self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as NoteStoreImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    SyncService self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as SyncServiceImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_pairing_request(
-    PairingRequest self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_pairing_request(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_pairing_target(
-    PairingTarget self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_pairing_target(self, serializer);
-  }
-
-  @protected
-  void sse_encode_device_push_result(
-    DevicePushResult self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.peerId, serializer);
-    sse_encode_bool(self.ok, serializer);
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
-  }
-
-  @protected
-  void sse_encode_link_row(LinkRow self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_String(self.title, serializer);
-    sse_encode_String(self.alias, serializer);
-    sse_encode_bool(self.exists, serializer);
-  }
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_device_push_result(
-    List<DevicePushResult> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_device_push_result(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_link_row(List<LinkRow> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_link_row(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_note_row(List<NoteRow> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_note_row(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_paired_device_row(
-    List<PairedDeviceRow> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_paired_device_row(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_peer_info(
-    List<PeerInfo> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_peer_info(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_loose(
-    List<int> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(
-      self is Uint8List ? self : Uint8List.fromList(self),
-    );
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_list_record_string_opt_list_string(
-    List<(String, List<String>?)> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_opt_list_string(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_note_row(NoteRow self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_String(self.title, serializer);
-    sse_encode_String(self.contentPreview, serializer);
-    sse_encode_String(self.tags, serializer);
-    sse_encode_String(self.updatedAt, serializer);
-    sse_encode_opt_String(self.deletedAt, serializer);
-  }
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_pairing_request(
-    PairingRequest? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_pairing_request(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_list_String(
-    List<String>? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_String(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_paired_device_row(
-    PairedDeviceRow self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.peerId, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_opt_String(self.lastSeen, serializer);
-    sse_encode_String(self.pairedAt, serializer);
-  }
-
-  @protected
-  void sse_encode_pairing_credential_display(
-    PairingCredentialDisplay self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.code, serializer);
-    sse_encode_String(self.credential, serializer);
-    sse_encode_String(self.expiresAt, serializer);
-  }
-
-  @protected
-  void sse_encode_pairing_credential_error(
-    PairingCredentialError self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_pairing_credential_error_kind(self.kind, serializer);
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void sse_encode_pairing_credential_error_kind(
-    PairingCredentialErrorKind self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_pairing_request(
-    PairingRequest self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.code, serializer);
-    sse_encode_String(self.deviceId, serializer);
-    sse_encode_String(self.deviceName, serializer);
-    sse_encode_String(self.relayInfo, serializer);
-    sse_encode_list_String(self.ips, serializer);
-    sse_encode_String(self.nonce, serializer);
-  }
-
-  @protected
-  void sse_encode_pairing_result(PairingResult self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.peerId, serializer);
-    sse_encode_String(self.peerName, serializer);
-  }
-
-  @protected
-  void sse_encode_pairing_target(PairingTarget self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.deviceId, serializer);
-    sse_encode_list_String(self.ips, serializer);
-    sse_encode_String(self.nonce, serializer);
-  }
-
-  @protected
-  void sse_encode_parsed_pairing_credential(
-    ParsedPairingCredential self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.code, serializer);
-    sse_encode_String(self.deviceId, serializer);
-    sse_encode_String(self.expiresAt, serializer);
-    sse_encode_String(self.nonce, serializer);
-  }
-
-  @protected
-  void sse_encode_peer_info(PeerInfo self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.deviceId, serializer);
-    sse_encode_String(self.ip, serializer);
-    sse_encode_u_16(self.port, serializer);
-    sse_encode_String(self.nonce, serializer);
-  }
-
-  @protected
-  void sse_encode_record_string_opt_list_string(
-    (String, List<String>?) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.$1, serializer);
-    sse_encode_opt_list_String(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_sync_cycle_result(
-    SyncCycleResult self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.pushedCount, serializer);
-    sse_encode_bool(self.acceptedPush, serializer);
-    sse_encode_bool(self.disabled, serializer);
-  }
-
-  @protected
-  void sse_encode_u_16(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint16(self);
-  }
-
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint32(self);
-  }
-
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-}
-
-@sealed
-class DiscoveryServiceImpl extends RustOpaque implements DiscoveryService {
-  // Not to be used by end users
-  DiscoveryServiceImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  DiscoveryServiceImpl.frbInternalSseDecode(
-    BigInt ptr,
-    int externalSizeOnNative,
-  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_DiscoveryService,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_DiscoveryService,
-    rustArcDecrementStrongCountPtr: RustLib
-        .instance
-        .api
-        .rust_arc_decrement_strong_count_DiscoveryServicePtr,
-  );
-}
-
-@sealed
-class NoteStoreImpl extends RustOpaque implements NoteStore {
-  // Not to be used by end users
-  NoteStoreImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  NoteStoreImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_NoteStore,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_NoteStore,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_NoteStorePtr,
-  );
-}
-
-@sealed
-class SyncServiceImpl extends RustOpaque implements SyncService {
-  // Not to be used by end users
-  SyncServiceImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  SyncServiceImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_SyncService,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_SyncService,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_SyncServicePtr,
-  );
-}
+        )
+        ,
+            constMeta: kCrateApiSyncPollIntervalSecsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSyncPollIntervalSecsConstMeta => const TaskConstMeta(
+            debugName: "sync_poll_interval_secs",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiVaultChangePassphrase({required String path , required String oldPassphrase , required String newPassphrase })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_String(oldPassphrase, serializer);
+sse_encode_String(newPassphrase, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiVaultChangePassphraseConstMeta,
+            argValues: [path, oldPassphrase, newPassphrase],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiVaultChangePassphraseConstMeta => const TaskConstMeta(
+            debugName: "vault_change_passphrase",
+            argNames: ["path", "oldPassphrase", "newPassphrase"],
+        );
+        
+
+@override Future<void> crateApiVaultLock({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiVaultLockConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiVaultLockConstMeta => const TaskConstMeta(
+            debugName: "vault_lock",
+            argNames: ["path"],
+        );
+        
+
+@override Future<VaultStatus> crateApiVaultStatus({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_vault_status,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiVaultStatusConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiVaultStatusConstMeta => const TaskConstMeta(
+            debugName: "vault_status",
+            argNames: ["path"],
+        );
+        
+
+@override Future<void> crateApiVaultUnlock({required String path , required String passphrase })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_String(passphrase, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiVaultUnlockConstMeta,
+            argValues: [path, passphrase],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiVaultUnlockConstMeta => const TaskConstMeta(
+            debugName: "vault_unlock",
+            argNames: ["path", "passphrase"],
+        );
+        
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_DiscoveryService => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_DiscoveryService => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_NoteStore => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_NoteStore => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SyncService => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SyncService => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected NoteStore dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return NoteStoreImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SyncService dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SyncServiceImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected DiscoveryService dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DiscoveryServiceImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SyncService dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SyncServiceImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected DiscoveryService dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DiscoveryServiceImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected NoteStore dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return NoteStoreImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SyncService dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SyncServiceImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Duration dco_decode_Chrono_Duration(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeDuration(dco_decode_i_64(raw).toInt()); }
+
+@protected DiscoveryService dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DiscoveryServiceImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected NoteStore dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return NoteStoreImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SyncService dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SyncServiceImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RustStreamSink<SyncEvent> dco_decode_StreamSink_sync_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected PairingRequest dco_decode_box_autoadd_pairing_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_pairing_request(raw); }
+
+@protected PairingTarget dco_decode_box_autoadd_pairing_target(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_pairing_target(raw); }
+
+@protected ConflictDetail dco_decode_conflict_detail(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return ConflictDetail(conflict: dco_decode_note_conflict(arr[0]),
+baseContent: dco_decode_String(arr[1]),
+localContent: dco_decode_String(arr[2]),
+remoteContent: dco_decode_String(arr[3]),
+mergedContent: dco_decode_String(arr[4]),); }
+
+@protected ConflictResolution dco_decode_conflict_resolution(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ConflictResolution.values[raw as int]; }
+
+@protected DevicePushResult dco_decode_device_push_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return DevicePushResult(peerId: dco_decode_String(arr[0]),
+ok: dco_decode_bool(arr[1]),
+message: dco_decode_String(arr[2]),
+deliveredCount: dco_decode_u_32(arr[3]),
+pendingCount: dco_decode_u_32(arr[4]),); }
+
+@protected GraphEdge dco_decode_graph_edge(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return GraphEdge(source: dco_decode_String(arr[0]),
+target: dco_decode_String(arr[1]),
+alias: dco_decode_String(arr[2]),); }
+
+@protected GraphNode dco_decode_graph_node(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return GraphNode(id: dco_decode_String(arr[0]),
+title: dco_decode_String(arr[1]),
+tags: dco_decode_list_String(arr[2]),
+degree: dco_decode_u_32(arr[3]),); }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected LinkRow dco_decode_link_row(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return LinkRow(id: dco_decode_String(arr[0]),
+title: dco_decode_String(arr[1]),
+alias: dco_decode_String(arr[2]),
+anchor: dco_decode_String(arr[3]),
+exists: dco_decode_bool(arr[4]),
+resolved: dco_decode_bool(arr[5]),
+ambiguous: dco_decode_bool(arr[6]),); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<ConflictDetail> dco_decode_list_conflict_detail(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_conflict_detail).toList(); }
+
+@protected List<DevicePushResult> dco_decode_list_device_push_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_device_push_result).toList(); }
+
+@protected List<GraphEdge> dco_decode_list_graph_edge(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_graph_edge).toList(); }
+
+@protected List<GraphNode> dco_decode_list_graph_node(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_graph_node).toList(); }
+
+@protected List<LinkRow> dco_decode_list_link_row(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_link_row).toList(); }
+
+@protected List<List<String>> dco_decode_list_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_list_String).toList(); }
+
+@protected List<MentionRow> dco_decode_list_mention_row(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_mention_row).toList(); }
+
+@protected List<NoteRow> dco_decode_list_note_row(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_note_row).toList(); }
+
+@protected List<NoteVersion> dco_decode_list_note_version(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_note_version).toList(); }
+
+@protected List<PairedDeviceRow> dco_decode_list_paired_device_row(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_paired_device_row).toList(); }
+
+@protected List<PeerInfo> dco_decode_list_peer_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_peer_info).toList(); }
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<(String,List<String>?)> dco_decode_list_record_string_opt_list_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_string_opt_list_string).toList(); }
+
+@protected List<RevokedDeviceRow> dco_decode_list_revoked_device_row(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_revoked_device_row).toList(); }
+
+@protected MentionRow dco_decode_mention_row(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return MentionRow(id: dco_decode_String(arr[0]),
+title: dco_decode_String(arr[1]),
+snippet: dco_decode_String(arr[2]),
+mentions: dco_decode_u_32(arr[3]),); }
+
+@protected NoteConflict dco_decode_note_conflict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return NoteConflict(noteId: dco_decode_String(arr[0]),
+detectedAt: dco_decode_String(arr[1]),
+localDevices: dco_decode_list_String(arr[2]),
+remoteDevices: dco_decode_list_String(arr[3]),
+localVersion: dco_decode_list_String(arr[4]),
+remoteVersion: dco_decode_list_String(arr[5]),
+baseVersion: dco_decode_list_String(arr[6]),); }
+
+@protected NoteGraph dco_decode_note_graph(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return NoteGraph(nodes: dco_decode_list_graph_node(arr[0]),
+edges: dco_decode_list_graph_edge(arr[1]),); }
+
+@protected NoteRow dco_decode_note_row(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return NoteRow(id: dco_decode_String(arr[0]),
+title: dco_decode_String(arr[1]),
+contentPreview: dco_decode_String(arr[2]),
+tags: dco_decode_String(arr[3]),
+updatedAt: dco_decode_String(arr[4]),
+deletedAt: dco_decode_opt_String(arr[5]),); }
+
+@protected NoteVersion dco_decode_note_version(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return NoteVersion(version: dco_decode_String(arr[0]),
+timestamp: dco_decode_String(arr[1]),
+deviceId: dco_decode_String(arr[2]),
+changeLen: dco_decode_u_32(arr[3]),); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected PairingRequest? dco_decode_opt_box_autoadd_pairing_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_pairing_request(raw); }
+
+@protected List<String>? dco_decode_opt_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_String(raw); }
+
+@protected PairedDeviceRow dco_decode_paired_device_row(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return PairedDeviceRow(peerId: dco_decode_String(arr[0]),
+name: dco_decode_String(arr[1]),
+lastSeen: dco_decode_opt_String(arr[2]),
+pairedAt: dco_decode_String(arr[3]),); }
+
+@protected PairingCredentialDisplay dco_decode_pairing_credential_display(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return PairingCredentialDisplay(code: dco_decode_String(arr[0]),
+credential: dco_decode_String(arr[1]),
+expiresAt: dco_decode_String(arr[2]),); }
+
+@protected PairingCredentialError dco_decode_pairing_credential_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return PairingCredentialError(kind: dco_decode_pairing_credential_error_kind(arr[0]),
+message: dco_decode_String(arr[1]),); }
+
+@protected PairingCredentialErrorKind dco_decode_pairing_credential_error_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PairingCredentialErrorKind.values[raw as int]; }
+
+@protected PairingRequest dco_decode_pairing_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return PairingRequest(code: dco_decode_String(arr[0]),
+deviceId: dco_decode_String(arr[1]),
+deviceName: dco_decode_String(arr[2]),
+relayInfo: dco_decode_String(arr[3]),
+ips: dco_decode_list_String(arr[4]),
+nonce: dco_decode_String(arr[5]),); }
+
+@protected PairingResult dco_decode_pairing_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return PairingResult(peerId: dco_decode_String(arr[0]),
+peerName: dco_decode_String(arr[1]),); }
+
+@protected PairingTarget dco_decode_pairing_target(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return PairingTarget(deviceId: dco_decode_String(arr[0]),
+ips: dco_decode_list_String(arr[1]),
+nonce: dco_decode_String(arr[2]),); }
+
+@protected ParsedPairingCredential dco_decode_parsed_pairing_credential(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ParsedPairingCredential(code: dco_decode_String(arr[0]),
+deviceId: dco_decode_String(arr[1]),
+expiresAt: dco_decode_String(arr[2]),
+nonce: dco_decode_String(arr[3]),); }
+
+@protected PeerInfo dco_decode_peer_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return PeerInfo(deviceId: dco_decode_String(arr[0]),
+ip: dco_decode_String(arr[1]),
+port: dco_decode_u_16(arr[2]),
+nonce: dco_decode_String(arr[3]),); }
+
+@protected (String,List<String>?) dco_decode_record_string_opt_list_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_String(arr[0]),dco_decode_opt_list_String(arr[1]),); }
+
+@protected RevokedDeviceRow dco_decode_revoked_device_row(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return RevokedDeviceRow(peerId: dco_decode_String(arr[0]),
+revokedBy: dco_decode_String(arr[1]),
+revokedAt: dco_decode_String(arr[2]),
+signature: dco_decode_String(arr[3]),); }
+
+@protected SearchQueryError dco_decode_search_query_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SearchQueryError(kind: dco_decode_search_query_error_kind(arr[0]),
+message: dco_decode_String(arr[1]),
+position: dco_decode_u_32(arr[2]),); }
+
+@protected SearchQueryErrorKind dco_decode_search_query_error_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SearchQueryErrorKind.values[raw as int]; }
+
+@protected SyncCycleResult dco_decode_sync_cycle_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SyncCycleResult(pushedCount: dco_decode_u_32(arr[0]),
+acceptedPush: dco_decode_bool(arr[1]),
+disabled: dco_decode_bool(arr[2]),); }
+
+@protected SyncEvent dco_decode_sync_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return SyncEvent_NotesChanged(noteIds: dco_decode_list_String(raw[1]),remote: dco_decode_bool(raw[2]),);
+case 1: return SyncEvent_NoteDeleted(noteId: dco_decode_String(raw[1]),remote: dco_decode_bool(raw[2]),);
+case 2: return SyncEvent_NoteRestored(noteId: dco_decode_String(raw[1]),remote: dco_decode_bool(raw[2]),);
+case 3: return SyncEvent_NotesPurged(noteIds: dco_decode_list_String(raw[1]),remote: dco_decode_bool(raw[2]),);
+case 4: return SyncEvent_PeerOnline(peerId: dco_decode_String(raw[1]),);
+case 5: return SyncEvent_PeerOffline(peerId: dco_decode_String(raw[1]),);
+case 6: return SyncEvent_SyncStarted();
+case 7: return SyncEvent_SyncFinished(okCount: dco_decode_u_32(raw[1]),failedCount: dco_decode_u_32(raw[2]),pulledCount: dco_decode_u_32(raw[3]),);
+case 8: return SyncEvent_SyncFailed(message: dco_decode_String(raw[1]),);
+case 9: return SyncEvent_PairingRequested(deviceId: dco_decode_String(raw[1]),deviceName: dco_decode_String(raw[2]),);
+case 10: return SyncEvent_Lagged(missed: dco_decode_u_64(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected int dco_decode_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected VaultStatus dco_decode_vault_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return VaultStatus.values[raw as int]; }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected NoteStore sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return NoteStoreImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SyncService sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SyncServiceImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected DiscoveryService sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return DiscoveryServiceImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SyncService sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SyncServiceImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected DiscoveryService sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return DiscoveryServiceImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected NoteStore sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return NoteStoreImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SyncService sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SyncServiceImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Duration sse_decode_Chrono_Duration(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_64(deserializer);
+        return Duration(microseconds: inner.toInt()); }
+
+@protected DiscoveryService sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return DiscoveryServiceImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected NoteStore sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return NoteStoreImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SyncService sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SyncServiceImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RustStreamSink<SyncEvent> sse_decode_StreamSink_sync_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected PairingRequest sse_decode_box_autoadd_pairing_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_pairing_request(deserializer)); }
+
+@protected PairingTarget sse_decode_box_autoadd_pairing_target(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_pairing_target(deserializer)); }
+
+@protected ConflictDetail sse_decode_conflict_detail(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_conflict = sse_decode_note_conflict(deserializer);
+var var_baseContent = sse_decode_String(deserializer);
+var var_localContent = sse_decode_String(deserializer);
+var var_remoteContent = sse_decode_String(deserializer);
+var var_mergedContent = sse_decode_String(deserializer);
+return ConflictDetail(conflict: var_conflict, baseContent: var_baseContent, localContent: var_localContent, remoteContent: var_remoteContent, mergedContent: var_mergedContent); }
+
+@protected ConflictResolution sse_decode_conflict_resolution(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ConflictResolution.values[inner]; }
+
+@protected DevicePushResult sse_decode_device_push_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_peerId = sse_decode_String(deserializer);
+var var_ok = sse_decode_bool(deserializer);
+var var_message = sse_decode_String(deserializer);
+var var_deliveredCount = sse_decode_u_32(deserializer);
+var var_pendingCount = sse_decode_u_32(deserializer);
+return DevicePushResult(peerId: var_peerId, ok: var_ok, message: var_message, deliveredCount: var_deliveredCount, pendingCount: var_pendingCount); }
+
+@protected GraphEdge sse_decode_graph_edge(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_source = sse_decode_String(deserializer);
+var var_target = sse_decode_String(deserializer);
+var var_alias = sse_decode_String(deserializer);
+return GraphEdge(source: var_source, target: var_target, alias: var_alias); }
+
+@protected GraphNode sse_decode_graph_node(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_title = sse_decode_String(deserializer);
+var var_tags = sse_decode_list_String(deserializer);
+var var_degree = sse_decode_u_32(deserializer);
+return GraphNode(id: var_id, title: var_title, tags: var_tags, degree: var_degree); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected LinkRow sse_decode_link_row(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_title = sse_decode_String(deserializer);
+var var_alias = sse_decode_String(deserializer);
+var var_anchor = sse_decode_String(deserializer);
+var var_exists = sse_decode_bool(deserializer);
+var var_resolved = sse_decode_bool(deserializer);
+var var_ambiguous = sse_decode_bool(deserializer);
+return LinkRow(id: var_id, title: var_title, alias: var_alias, anchor: var_anchor, exists: var_exists, resolved: var_resolved, ambiguous: var_ambiguous); }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ConflictDetail> sse_decode_list_conflict_detail(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ConflictDetail>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_conflict_detail(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DevicePushResult> sse_decode_list_device_push_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DevicePushResult>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_device_push_result(deserializer)); }
+        return ans_;
+         }
+
+@protected List<GraphEdge> sse_decode_list_graph_edge(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <GraphEdge>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_graph_edge(deserializer)); }
+        return ans_;
+         }
+
+@protected List<GraphNode> sse_decode_list_graph_node(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <GraphNode>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_graph_node(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LinkRow> sse_decode_list_link_row(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LinkRow>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_link_row(deserializer)); }
+        return ans_;
+         }
+
+@protected List<List<String>> sse_decode_list_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <List<String>>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_list_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<MentionRow> sse_decode_list_mention_row(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <MentionRow>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_mention_row(deserializer)); }
+        return ans_;
+         }
+
+@protected List<NoteRow> sse_decode_list_note_row(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <NoteRow>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_note_row(deserializer)); }
+        return ans_;
+         }
+
+@protected List<NoteVersion> sse_decode_list_note_version(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <NoteVersion>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_note_version(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PairedDeviceRow> sse_decode_list_paired_device_row(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PairedDeviceRow>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_paired_device_row(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PeerInfo> sse_decode_list_peer_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PeerInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_peer_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<(String,List<String>?)> sse_decode_list_record_string_opt_list_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(String,List<String>?)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_string_opt_list_string(deserializer)); }
+        return ans_;
+         }
+
+@protected List<RevokedDeviceRow> sse_decode_list_revoked_device_row(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RevokedDeviceRow>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_revoked_device_row(deserializer)); }
+        return ans_;
+         }
+
+@protected MentionRow sse_decode_mention_row(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_title = sse_decode_String(deserializer);
+var var_snippet = sse_decode_String(deserializer);
+var var_mentions = sse_decode_u_32(deserializer);
+return MentionRow(id: var_id, title: var_title, snippet: var_snippet, mentions: var_mentions); }
+
+@protected NoteConflict sse_decode_note_conflict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_noteId = sse_decode_String(deserializer);
+var var_detectedAt = sse_decode_String(deserializer);
+var var_localDevices = sse_decode_list_String(deserializer);
+var var_remoteDevices = sse_decode_list_String(deserializer);
+var var_localVersion = sse_decode_list_String(deserializer);
+var var_remoteVersion = sse_decode_list_String(deserializer);
+var var_baseVersion = sse_decode_list_String(deserializer);
+return NoteConflict(noteId: var_noteId, detectedAt: var_detectedAt, localDevices: var_localDevices, remoteDevices: var_remoteDevices, localVersion: var_localVersion, remoteVersion: var_remoteVersion, baseVersion: var_baseVersion); }
+
+@protected NoteGraph sse_decode_note_graph(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_nodes = sse_decode_list_graph_node(deserializer);
+var var_edges = sse_decode_list_graph_edge(deserializer);
+return NoteGraph(nodes: var_nodes, edges: var_edges); }
+
+@protected NoteRow sse_decode_note_row(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_title = sse_decode_String(deserializer);
+var var_contentPreview = sse_decode_String(deserializer);
+var var_tags = sse_decode_String(deserializer);
+var var_updatedAt = sse_decode_String(deserializer);
+var var_deletedAt = sse_decode_opt_String(deserializer);
+return NoteRow(id: var_id, title: var_title, contentPreview: var_contentPreview, tags: var_tags, updatedAt: var_updatedAt, deletedAt: var_deletedAt); }
+
+@protected NoteVersion sse_decode_note_version(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_version = sse_decode_String(deserializer);
+var var_timestamp = sse_decode_String(deserializer);
+var var_deviceId = sse_decode_String(deserializer);
+var var_changeLen = sse_decode_u_32(deserializer);
+return NoteVersion(version: var_version, timestamp: var_timestamp, deviceId: var_deviceId, changeLen: var_changeLen); }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PairingRequest? sse_decode_opt_box_autoadd_pairing_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_pairing_request(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected List<String>? sse_decode_opt_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PairedDeviceRow sse_decode_paired_device_row(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_peerId = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_lastSeen = sse_decode_opt_String(deserializer);
+var var_pairedAt = sse_decode_String(deserializer);
+return PairedDeviceRow(peerId: var_peerId, name: var_name, lastSeen: var_lastSeen, pairedAt: var_pairedAt); }
+
+@protected PairingCredentialDisplay sse_decode_pairing_credential_display(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_code = sse_decode_String(deserializer);
+var var_credential = sse_decode_String(deserializer);
+var var_expiresAt = sse_decode_String(deserializer);
+return PairingCredentialDisplay(code: var_code, credential: var_credential, expiresAt: var_expiresAt); }
+
+@protected PairingCredentialError sse_decode_pairing_credential_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_pairing_credential_error_kind(deserializer);
+var var_message = sse_decode_String(deserializer);
+return PairingCredentialError(kind: var_kind, message: var_message); }
+
+@protected PairingCredentialErrorKind sse_decode_pairing_credential_error_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PairingCredentialErrorKind.values[inner]; }
+
+@protected PairingRequest sse_decode_pairing_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_code = sse_decode_String(deserializer);
+var var_deviceId = sse_decode_String(deserializer);
+var var_deviceName = sse_decode_String(deserializer);
+var var_relayInfo = sse_decode_String(deserializer);
+var var_ips = sse_decode_list_String(deserializer);
+var var_nonce = sse_decode_String(deserializer);
+return PairingRequest(code: var_code, deviceId: var_deviceId, deviceName: var_deviceName, relayInfo: var_relayInfo, ips: var_ips, nonce: var_nonce); }
+
+@protected PairingResult sse_decode_pairing_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_peerId = sse_decode_String(deserializer);
+var var_peerName = sse_decode_String(deserializer);
+return PairingResult(peerId: var_peerId, peerName: var_peerName); }
+
+@protected PairingTarget sse_decode_pairing_target(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_deviceId = sse_decode_String(deserializer);
+var var_ips = sse_decode_list_String(deserializer);
+var var_nonce = sse_decode_String(deserializer);
+return PairingTarget(deviceId: var_deviceId, ips: var_ips, nonce: var_nonce); }
+
+@protected ParsedPairingCredential sse_decode_parsed_pairing_credential(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_code = sse_decode_String(deserializer);
+var var_deviceId = sse_decode_String(deserializer);
+var var_expiresAt = sse_decode_String(deserializer);
+var var_nonce = sse_decode_String(deserializer);
+return ParsedPairingCredential(code: var_code, deviceId: var_deviceId, expiresAt: var_expiresAt, nonce: var_nonce); }
+
+@protected PeerInfo sse_decode_peer_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_deviceId = sse_decode_String(deserializer);
+var var_ip = sse_decode_String(deserializer);
+var var_port = sse_decode_u_16(deserializer);
+var var_nonce = sse_decode_String(deserializer);
+return PeerInfo(deviceId: var_deviceId, ip: var_ip, port: var_port, nonce: var_nonce); }
+
+@protected (String,List<String>?) sse_decode_record_string_opt_list_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_String(deserializer);
+var var_field1 = sse_decode_opt_list_String(deserializer);
+return (var_field0, var_field1); }
+
+@protected RevokedDeviceRow sse_decode_revoked_device_row(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_peerId = sse_decode_String(deserializer);
+var var_revokedBy = sse_decode_String(deserializer);
+var var_revokedAt = sse_decode_String(deserializer);
+var var_signature = sse_decode_String(deserializer);
+return RevokedDeviceRow(peerId: var_peerId, revokedBy: var_revokedBy, revokedAt: var_revokedAt, signature: var_signature); }
+
+@protected SearchQueryError sse_decode_search_query_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_search_query_error_kind(deserializer);
+var var_message = sse_decode_String(deserializer);
+var var_position = sse_decode_u_32(deserializer);
+return SearchQueryError(kind: var_kind, message: var_message, position: var_position); }
+
+@protected SearchQueryErrorKind sse_decode_search_query_error_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SearchQueryErrorKind.values[inner]; }
+
+@protected SyncCycleResult sse_decode_sync_cycle_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_pushedCount = sse_decode_u_32(deserializer);
+var var_acceptedPush = sse_decode_bool(deserializer);
+var var_disabled = sse_decode_bool(deserializer);
+return SyncCycleResult(pushedCount: var_pushedCount, acceptedPush: var_acceptedPush, disabled: var_disabled); }
+
+@protected SyncEvent sse_decode_sync_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_noteIds = sse_decode_list_String(deserializer);
+var var_remote = sse_decode_bool(deserializer);
+return SyncEvent_NotesChanged(noteIds: var_noteIds, remote: var_remote);case 1: var var_noteId = sse_decode_String(deserializer);
+var var_remote = sse_decode_bool(deserializer);
+return SyncEvent_NoteDeleted(noteId: var_noteId, remote: var_remote);case 2: var var_noteId = sse_decode_String(deserializer);
+var var_remote = sse_decode_bool(deserializer);
+return SyncEvent_NoteRestored(noteId: var_noteId, remote: var_remote);case 3: var var_noteIds = sse_decode_list_String(deserializer);
+var var_remote = sse_decode_bool(deserializer);
+return SyncEvent_NotesPurged(noteIds: var_noteIds, remote: var_remote);case 4: var var_peerId = sse_decode_String(deserializer);
+return SyncEvent_PeerOnline(peerId: var_peerId);case 5: var var_peerId = sse_decode_String(deserializer);
+return SyncEvent_PeerOffline(peerId: var_peerId);case 6: return SyncEvent_SyncStarted();case 7: var var_okCount = sse_decode_u_32(deserializer);
+var var_failedCount = sse_decode_u_32(deserializer);
+var var_pulledCount = sse_decode_u_32(deserializer);
+return SyncEvent_SyncFinished(okCount: var_okCount, failedCount: var_failedCount, pulledCount: var_pulledCount);case 8: var var_message = sse_decode_String(deserializer);
+return SyncEvent_SyncFailed(message: var_message);case 9: var var_deviceId = sse_decode_String(deserializer);
+var var_deviceName = sse_decode_String(deserializer);
+return SyncEvent_PairingRequested(deviceId: var_deviceId, deviceName: var_deviceName);case 10: var var_missed = sse_decode_u_64(deserializer);
+return SyncEvent_Lagged(missed: var_missed); default: throw UnimplementedError(''); }
+             }
+
+@protected int sse_decode_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint16(); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected VaultStatus sse_decode_vault_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return VaultStatus.values[inner]; }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(NoteStore self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as NoteStoreImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(SyncService self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SyncServiceImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(DiscoveryService self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as DiscoveryServiceImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(SyncService self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SyncServiceImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(DiscoveryService self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as DiscoveryServiceImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(NoteStore self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as NoteStoreImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(SyncService self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SyncServiceImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Chrono_Duration(Duration self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(PlatformInt64Util.from(self.inMicroseconds), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(DiscoveryService self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as DiscoveryServiceImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(NoteStore self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as NoteStoreImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(SyncService self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SyncServiceImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_StreamSink_sync_event_Sse(RustStreamSink<SyncEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_sync_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_pairing_request(PairingRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_pairing_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_pairing_target(PairingTarget self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_pairing_target(self, serializer); }
+
+@protected void sse_encode_conflict_detail(ConflictDetail self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_note_conflict(self.conflict, serializer);
+sse_encode_String(self.baseContent, serializer);
+sse_encode_String(self.localContent, serializer);
+sse_encode_String(self.remoteContent, serializer);
+sse_encode_String(self.mergedContent, serializer);
+ }
+
+@protected void sse_encode_conflict_resolution(ConflictResolution self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_device_push_result(DevicePushResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.peerId, serializer);
+sse_encode_bool(self.ok, serializer);
+sse_encode_String(self.message, serializer);
+sse_encode_u_32(self.deliveredCount, serializer);
+sse_encode_u_32(self.pendingCount, serializer);
+ }
+
+@protected void sse_encode_graph_edge(GraphEdge self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.source, serializer);
+sse_encode_String(self.target, serializer);
+sse_encode_String(self.alias, serializer);
+ }
+
+@protected void sse_encode_graph_node(GraphNode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.title, serializer);
+sse_encode_list_String(self.tags, serializer);
+sse_encode_u_32(self.degree, serializer);
+ }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_link_row(LinkRow self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.title, serializer);
+sse_encode_String(self.alias, serializer);
+sse_encode_String(self.anchor, serializer);
+sse_encode_bool(self.exists, serializer);
+sse_encode_bool(self.resolved, serializer);
+sse_encode_bool(self.ambiguous, serializer);
+ }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_conflict_detail(List<ConflictDetail> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_conflict_detail(item, serializer); } }
+
+@protected void sse_encode_list_device_push_result(List<DevicePushResult> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_device_push_result(item, serializer); } }
+
+@protected void sse_encode_list_graph_edge(List<GraphEdge> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_graph_edge(item, serializer); } }
+
+@protected void sse_encode_list_graph_node(List<GraphNode> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_graph_node(item, serializer); } }
+
+@protected void sse_encode_list_link_row(List<LinkRow> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_link_row(item, serializer); } }
+
+@protected void sse_encode_list_list_String(List<List<String>> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_list_String(item, serializer); } }
+
+@protected void sse_encode_list_mention_row(List<MentionRow> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_mention_row(item, serializer); } }
+
+@protected void sse_encode_list_note_row(List<NoteRow> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_note_row(item, serializer); } }
+
+@protected void sse_encode_list_note_version(List<NoteVersion> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_note_version(item, serializer); } }
+
+@protected void sse_encode_list_paired_device_row(List<PairedDeviceRow> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_paired_device_row(item, serializer); } }
+
+@protected void sse_encode_list_peer_info(List<PeerInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_peer_info(item, serializer); } }
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_record_string_opt_list_string(List<(String,List<String>?)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_string_opt_list_string(item, serializer); } }
+
+@protected void sse_encode_list_revoked_device_row(List<RevokedDeviceRow> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_revoked_device_row(item, serializer); } }
+
+@protected void sse_encode_mention_row(MentionRow self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.title, serializer);
+sse_encode_String(self.snippet, serializer);
+sse_encode_u_32(self.mentions, serializer);
+ }
+
+@protected void sse_encode_note_conflict(NoteConflict self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.noteId, serializer);
+sse_encode_String(self.detectedAt, serializer);
+sse_encode_list_String(self.localDevices, serializer);
+sse_encode_list_String(self.remoteDevices, serializer);
+sse_encode_list_String(self.localVersion, serializer);
+sse_encode_list_String(self.remoteVersion, serializer);
+sse_encode_list_String(self.baseVersion, serializer);
+ }
+
+@protected void sse_encode_note_graph(NoteGraph self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_graph_node(self.nodes, serializer);
+sse_encode_list_graph_edge(self.edges, serializer);
+ }
+
+@protected void sse_encode_note_row(NoteRow self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.title, serializer);
+sse_encode_String(self.contentPreview, serializer);
+sse_encode_String(self.tags, serializer);
+sse_encode_String(self.updatedAt, serializer);
+sse_encode_opt_String(self.deletedAt, serializer);
+ }
+
+@protected void sse_encode_note_version(NoteVersion self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.version, serializer);
+sse_encode_String(self.timestamp, serializer);
+sse_encode_String(self.deviceId, serializer);
+sse_encode_u_32(self.changeLen, serializer);
+ }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_pairing_request(PairingRequest? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_pairing_request(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_paired_device_row(PairedDeviceRow self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.peerId, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_opt_String(self.lastSeen, serializer);
+sse_encode_String(self.pairedAt, serializer);
+ }
+
+@protected void sse_encode_pairing_credential_display(PairingCredentialDisplay self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.code, serializer);
+sse_encode_String(self.credential, serializer);
+sse_encode_String(self.expiresAt, serializer);
+ }
+
+@protected void sse_encode_pairing_credential_error(PairingCredentialError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_pairing_credential_error_kind(self.kind, serializer);
+sse_encode_String(self.message, serializer);
+ }
+
+@protected void sse_encode_pairing_credential_error_kind(PairingCredentialErrorKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_pairing_request(PairingRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.code, serializer);
+sse_encode_String(self.deviceId, serializer);
+sse_encode_String(self.deviceName, serializer);
+sse_encode_String(self.relayInfo, serializer);
+sse_encode_list_String(self.ips, serializer);
+sse_encode_String(self.nonce, serializer);
+ }
+
+@protected void sse_encode_pairing_result(PairingResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.peerId, serializer);
+sse_encode_String(self.peerName, serializer);
+ }
+
+@protected void sse_encode_pairing_target(PairingTarget self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.deviceId, serializer);
+sse_encode_list_String(self.ips, serializer);
+sse_encode_String(self.nonce, serializer);
+ }
+
+@protected void sse_encode_parsed_pairing_credential(ParsedPairingCredential self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.code, serializer);
+sse_encode_String(self.deviceId, serializer);
+sse_encode_String(self.expiresAt, serializer);
+sse_encode_String(self.nonce, serializer);
+ }
+
+@protected void sse_encode_peer_info(PeerInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.deviceId, serializer);
+sse_encode_String(self.ip, serializer);
+sse_encode_u_16(self.port, serializer);
+sse_encode_String(self.nonce, serializer);
+ }
+
+@protected void sse_encode_record_string_opt_list_string((String,List<String>?) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.$1, serializer);
+sse_encode_opt_list_String(self.$2, serializer);
+ }
+
+@protected void sse_encode_revoked_device_row(RevokedDeviceRow self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.peerId, serializer);
+sse_encode_String(self.revokedBy, serializer);
+sse_encode_String(self.revokedAt, serializer);
+sse_encode_String(self.signature, serializer);
+ }
+
+@protected void sse_encode_search_query_error(SearchQueryError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_search_query_error_kind(self.kind, serializer);
+sse_encode_String(self.message, serializer);
+sse_encode_u_32(self.position, serializer);
+ }
+
+@protected void sse_encode_search_query_error_kind(SearchQueryErrorKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_sync_cycle_result(SyncCycleResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.pushedCount, serializer);
+sse_encode_bool(self.acceptedPush, serializer);
+sse_encode_bool(self.disabled, serializer);
+ }
+
+@protected void sse_encode_sync_event(SyncEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case SyncEvent_NotesChanged(noteIds: final noteIds,remote: final remote): sse_encode_i_32(0, serializer); sse_encode_list_String(noteIds, serializer);
+sse_encode_bool(remote, serializer);
+case SyncEvent_NoteDeleted(noteId: final noteId,remote: final remote): sse_encode_i_32(1, serializer); sse_encode_String(noteId, serializer);
+sse_encode_bool(remote, serializer);
+case SyncEvent_NoteRestored(noteId: final noteId,remote: final remote): sse_encode_i_32(2, serializer); sse_encode_String(noteId, serializer);
+sse_encode_bool(remote, serializer);
+case SyncEvent_NotesPurged(noteIds: final noteIds,remote: final remote): sse_encode_i_32(3, serializer); sse_encode_list_String(noteIds, serializer);
+sse_encode_bool(remote, serializer);
+case SyncEvent_PeerOnline(peerId: final peerId): sse_encode_i_32(4, serializer); sse_encode_String(peerId, serializer);
+case SyncEvent_PeerOffline(peerId: final peerId): sse_encode_i_32(5, serializer); sse_encode_String(peerId, serializer);
+case SyncEvent_SyncStarted(): sse_encode_i_32(6, serializer); case SyncEvent_SyncFinished(okCount: final okCount,failedCount: final failedCount,pulledCount: final pulledCount): sse_encode_i_32(7, serializer); sse_encode_u_32(okCount, serializer);
+sse_encode_u_32(failedCount, serializer);
+sse_encode_u_32(pulledCount, serializer);
+case SyncEvent_SyncFailed(message: final message): sse_encode_i_32(8, serializer); sse_encode_String(message, serializer);
+case SyncEvent_PairingRequested(deviceId: final deviceId,deviceName: final deviceName): sse_encode_i_32(9, serializer); sse_encode_String(deviceId, serializer);
+sse_encode_String(deviceName, serializer);
+case SyncEvent_Lagged(missed: final missed): sse_encode_i_32(10, serializer); sse_encode_u_64(missed, serializer);
+  } }
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint16(self); }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_vault_status(VaultStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+                }
+                
+
+            @sealed class DiscoveryServiceImpl extends RustOpaque implements DiscoveryService {
+                // Not to be used by end users
+                DiscoveryServiceImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                DiscoveryServiceImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_DiscoveryService,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_DiscoveryService,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_DiscoveryServicePtr,
+                );
+
+                
+            }
+            @sealed class NoteStoreImpl extends RustOpaque implements NoteStore {
+                // Not to be used by end users
+                NoteStoreImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                NoteStoreImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_NoteStore,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_NoteStore,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_NoteStorePtr,
+                );
+
+                
+            }
+            @sealed class SyncServiceImpl extends RustOpaque implements SyncService {
+                // Not to be used by end users
+                SyncServiceImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                SyncServiceImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_SyncService,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_SyncService,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_SyncServicePtr,
+                );
+
+                
+            }
\ No newline at end of file
@@ -3,6 +3,7 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+
 // Static analysis wrongly picks the IO variant, thus ignore this
 // ignore_for_file: argument_type_not_assignable
 
@@ -10,746 +11,492 @@ import 'api.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'discovery.dart';
+import 'events.dart';
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
+import 'search.dart';
 import 'store.dart';
 import 'sync.dart';
+import 'vault.dart';
+
+
+
+
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_DiscoveryServicePtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_NoteStorePtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SyncServicePtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+@protected NoteStore dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(dynamic raw);
+
+@protected SyncService dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(dynamic raw);
+
+@protected DiscoveryService dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(dynamic raw);
+
+@protected SyncService dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(dynamic raw);
+
+@protected DiscoveryService dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(dynamic raw);
+
+@protected NoteStore dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(dynamic raw);
+
+@protected SyncService dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(dynamic raw);
+
+@protected Duration dco_decode_Chrono_Duration(dynamic raw);
+
+@protected DiscoveryService dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(dynamic raw);
+
+@protected NoteStore dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(dynamic raw);
+
+@protected SyncService dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(dynamic raw);
+
+@protected RustStreamSink<SyncEvent> dco_decode_StreamSink_sync_event_Sse(dynamic raw);
+
+@protected String dco_decode_String(dynamic raw);
+
+@protected bool dco_decode_bool(dynamic raw);
+
+@protected PairingRequest dco_decode_box_autoadd_pairing_request(dynamic raw);
+
+@protected PairingTarget dco_decode_box_autoadd_pairing_target(dynamic raw);
+
+@protected ConflictDetail dco_decode_conflict_detail(dynamic raw);
+
+@protected ConflictResolution dco_decode_conflict_resolution(dynamic raw);
+
+@protected DevicePushResult dco_decode_device_push_result(dynamic raw);
+
+@protected GraphEdge dco_decode_graph_edge(dynamic raw);
+
+@protected GraphNode dco_decode_graph_node(dynamic raw);
+
+@protected int dco_decode_i_32(dynamic raw);
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw);
+
+@protected LinkRow dco_decode_link_row(dynamic raw);
+
+@protected List<String> dco_decode_list_String(dynamic raw);
+
+@protected List<ConflictDetail> dco_decode_list_conflict_detail(dynamic raw);
+
+@protected List<DevicePushResult> dco_decode_list_device_push_result(dynamic raw);
+
+@protected List<GraphEdge> dco_decode_list_graph_edge(dynamic raw);
+
+@protected List<GraphNode> dco_decode_list_graph_node(dynamic raw);
+
+@protected List<LinkRow> dco_decode_list_link_row(dynamic raw);
+
+@protected List<List<String>> dco_decode_list_list_String(dynamic raw);
+
+@protected List<MentionRow> dco_decode_list_mention_row(dynamic raw);
+
+@protected List<NoteRow> dco_decode_list_note_row(dynamic raw);
+
+@protected List<NoteVersion> dco_decode_list_note_version(dynamic raw);
+
+@protected List<PairedDeviceRow> dco_decode_list_paired_device_row(dynamic raw);
+
+@protected List<PeerInfo> dco_decode_list_peer_info(dynamic raw);
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+@protected List<(String,List<String>?)> dco_decode_list_record_string_opt_list_string(dynamic raw);
+
+@protected List<RevokedDeviceRow> dco_decode_list_revoked_device_row(dynamic raw);
+
+@protected MentionRow dco_decode_mention_row(dynamic raw);
+
+@protected NoteConflict dco_decode_note_conflict(dynamic raw);
+
+@protected NoteGraph dco_decode_note_graph(dynamic raw);
+
+@protected NoteRow dco_decode_note_row(dynamic raw);
+
+@protected NoteVersion dco_decode_note_version(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected PairingRequest? dco_decode_opt_box_autoadd_pairing_request(dynamic raw);
+
+@protected List<String>? dco_decode_opt_list_String(dynamic raw);
+
+@protected PairedDeviceRow dco_decode_paired_device_row(dynamic raw);
+
+@protected PairingCredentialDisplay dco_decode_pairing_credential_display(dynamic raw);
+
+@protected PairingCredentialError dco_decode_pairing_credential_error(dynamic raw);
+
+@protected PairingCredentialErrorKind dco_decode_pairing_credential_error_kind(dynamic raw);
+
+@protected PairingRequest dco_decode_pairing_request(dynamic raw);
+
+@protected PairingResult dco_decode_pairing_result(dynamic raw);
+
+@protected PairingTarget dco_decode_pairing_target(dynamic raw);
+
+@protected ParsedPairingCredential dco_decode_parsed_pairing_credential(dynamic raw);
+
+@protected PeerInfo dco_decode_peer_info(dynamic raw);
+
+@protected (String,List<String>?) dco_decode_record_string_opt_list_string(dynamic raw);
+
+@protected RevokedDeviceRow dco_decode_revoked_device_row(dynamic raw);
+
+@protected SearchQueryError dco_decode_search_query_error(dynamic raw);
+
+@protected SearchQueryErrorKind dco_decode_search_query_error_kind(dynamic raw);
+
+@protected SyncCycleResult dco_decode_sync_cycle_result(dynamic raw);
+
+@protected SyncEvent dco_decode_sync_event(dynamic raw);
+
+@protected int dco_decode_u_16(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected BigInt dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected BigInt dco_decode_usize(dynamic raw);
+
+@protected VaultStatus dco_decode_vault_status(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected NoteStore sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(SseDeserializer deserializer);
+
+@protected SyncService sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(SseDeserializer deserializer);
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_DiscoveryServicePtr => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_NoteStorePtr => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_SyncServicePtr => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService;
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw);
-
-  @protected
-  NoteStore
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-    dynamic raw,
-  );
-
-  @protected
-  SyncService
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    dynamic raw,
-  );
-
-  @protected
-  DiscoveryService
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(
-    dynamic raw,
-  );
-
-  @protected
-  SyncService
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    dynamic raw,
-  );
-
-  @protected
-  DiscoveryService
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(
-    dynamic raw,
-  );
+@protected DiscoveryService sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(SseDeserializer deserializer);
 
-  @protected
-  NoteStore
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-    dynamic raw,
-  );
+@protected SyncService sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(SseDeserializer deserializer);
 
-  @protected
-  SyncService
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    dynamic raw,
-  );
+@protected DiscoveryService sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(SseDeserializer deserializer);
 
-  @protected
-  Duration dco_decode_Chrono_Duration(dynamic raw);
+@protected NoteStore sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(SseDeserializer deserializer);
 
-  @protected
-  DiscoveryService
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(
-    dynamic raw,
-  );
+@protected SyncService sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(SseDeserializer deserializer);
 
-  @protected
-  NoteStore
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-    dynamic raw,
-  );
+@protected Duration sse_decode_Chrono_Duration(SseDeserializer deserializer);
 
-  @protected
-  SyncService
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    dynamic raw,
-  );
+@protected DiscoveryService sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(SseDeserializer deserializer);
 
-  @protected
-  String dco_decode_String(dynamic raw);
+@protected NoteStore sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(SseDeserializer deserializer);
 
-  @protected
-  bool dco_decode_bool(dynamic raw);
+@protected SyncService sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(SseDeserializer deserializer);
 
-  @protected
-  PairingRequest dco_decode_box_autoadd_pairing_request(dynamic raw);
+@protected RustStreamSink<SyncEvent> sse_decode_StreamSink_sync_event_Sse(SseDeserializer deserializer);
 
-  @protected
-  PairingTarget dco_decode_box_autoadd_pairing_target(dynamic raw);
+@protected String sse_decode_String(SseDeserializer deserializer);
 
-  @protected
-  DevicePushResult dco_decode_device_push_result(dynamic raw);
+@protected bool sse_decode_bool(SseDeserializer deserializer);
 
-  @protected
-  int dco_decode_i_32(dynamic raw);
+@protected PairingRequest sse_decode_box_autoadd_pairing_request(SseDeserializer deserializer);
 
-  @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw);
+@protected PairingTarget sse_decode_box_autoadd_pairing_target(SseDeserializer deserializer);
 
-  @protected
-  LinkRow dco_decode_link_row(dynamic raw);
+@protected ConflictDetail sse_decode_conflict_detail(SseDeserializer deserializer);
 
-  @protected
-  List<String> dco_decode_list_String(dynamic raw);
+@protected ConflictResolution sse_decode_conflict_resolution(SseDeserializer deserializer);
 
-  @protected
-  List<DevicePushResult> dco_decode_list_device_push_result(dynamic raw);
+@protected DevicePushResult sse_decode_device_push_result(SseDeserializer deserializer);
 
-  @protected
-  List<LinkRow> dco_decode_list_link_row(dynamic raw);
+@protected GraphEdge sse_decode_graph_edge(SseDeserializer deserializer);
 
-  @protected
-  List<NoteRow> dco_decode_list_note_row(dynamic raw);
+@protected GraphNode sse_decode_graph_node(SseDeserializer deserializer);
 
-  @protected
-  List<PairedDeviceRow> dco_decode_list_paired_device_row(dynamic raw);
+@protected int sse_decode_i_32(SseDeserializer deserializer);
 
-  @protected
-  List<PeerInfo> dco_decode_list_peer_info(dynamic raw);
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
 
-  @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+@protected LinkRow sse_decode_link_row(SseDeserializer deserializer);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
 
-  @protected
-  List<(String, List<String>?)> dco_decode_list_record_string_opt_list_string(
-    dynamic raw,
-  );
+@protected List<ConflictDetail> sse_decode_list_conflict_detail(SseDeserializer deserializer);
 
-  @protected
-  NoteRow dco_decode_note_row(dynamic raw);
+@protected List<DevicePushResult> sse_decode_list_device_push_result(SseDeserializer deserializer);
 
-  @protected
-  String? dco_decode_opt_String(dynamic raw);
+@protected List<GraphEdge> sse_decode_list_graph_edge(SseDeserializer deserializer);
 
-  @protected
-  PairingRequest? dco_decode_opt_box_autoadd_pairing_request(dynamic raw);
+@protected List<GraphNode> sse_decode_list_graph_node(SseDeserializer deserializer);
 
-  @protected
-  List<String>? dco_decode_opt_list_String(dynamic raw);
+@protected List<LinkRow> sse_decode_list_link_row(SseDeserializer deserializer);
 
-  @protected
-  PairedDeviceRow dco_decode_paired_device_row(dynamic raw);
+@protected List<List<String>> sse_decode_list_list_String(SseDeserializer deserializer);
 
-  @protected
-  PairingCredentialDisplay dco_decode_pairing_credential_display(dynamic raw);
+@protected List<MentionRow> sse_decode_list_mention_row(SseDeserializer deserializer);
 
-  @protected
-  PairingCredentialError dco_decode_pairing_credential_error(dynamic raw);
+@protected List<NoteRow> sse_decode_list_note_row(SseDeserializer deserializer);
 
-  @protected
-  PairingCredentialErrorKind dco_decode_pairing_credential_error_kind(
-    dynamic raw,
-  );
+@protected List<NoteVersion> sse_decode_list_note_version(SseDeserializer deserializer);
 
-  @protected
-  PairingRequest dco_decode_pairing_request(dynamic raw);
+@protected List<PairedDeviceRow> sse_decode_list_paired_device_row(SseDeserializer deserializer);
 
-  @protected
-  PairingResult dco_decode_pairing_result(dynamic raw);
+@protected List<PeerInfo> sse_decode_list_peer_info(SseDeserializer deserializer);
 
-  @protected
-  PairingTarget dco_decode_pairing_target(dynamic raw);
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
 
-  @protected
-  ParsedPairingCredential dco_decode_parsed_pairing_credential(dynamic raw);
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
-  @protected
-  PeerInfo dco_decode_peer_info(dynamic raw);
+@protected List<(String,List<String>?)> sse_decode_list_record_string_opt_list_string(SseDeserializer deserializer);
 
-  @protected
-  (String, List<String>?) dco_decode_record_string_opt_list_string(dynamic raw);
+@protected List<RevokedDeviceRow> sse_decode_list_revoked_device_row(SseDeserializer deserializer);
 
-  @protected
-  SyncCycleResult dco_decode_sync_cycle_result(dynamic raw);
+@protected MentionRow sse_decode_mention_row(SseDeserializer deserializer);
 
-  @protected
-  int dco_decode_u_16(dynamic raw);
+@protected NoteConflict sse_decode_note_conflict(SseDeserializer deserializer);
 
-  @protected
-  int dco_decode_u_32(dynamic raw);
+@protected NoteGraph sse_decode_note_graph(SseDeserializer deserializer);
 
-  @protected
-  BigInt dco_decode_u_64(dynamic raw);
+@protected NoteRow sse_decode_note_row(SseDeserializer deserializer);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+@protected NoteVersion sse_decode_note_version(SseDeserializer deserializer);
 
-  @protected
-  void dco_decode_unit(dynamic raw);
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
 
-  @protected
-  BigInt dco_decode_usize(dynamic raw);
+@protected PairingRequest? sse_decode_opt_box_autoadd_pairing_request(SseDeserializer deserializer);
 
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+@protected List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
 
-  @protected
-  NoteStore
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-    SseDeserializer deserializer,
-  );
+@protected PairedDeviceRow sse_decode_paired_device_row(SseDeserializer deserializer);
 
-  @protected
-  SyncService
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    SseDeserializer deserializer,
-  );
+@protected PairingCredentialDisplay sse_decode_pairing_credential_display(SseDeserializer deserializer);
 
-  @protected
-  DiscoveryService
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(
-    SseDeserializer deserializer,
-  );
+@protected PairingCredentialError sse_decode_pairing_credential_error(SseDeserializer deserializer);
 
-  @protected
-  SyncService
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    SseDeserializer deserializer,
-  );
+@protected PairingCredentialErrorKind sse_decode_pairing_credential_error_kind(SseDeserializer deserializer);
 
-  @protected
-  DiscoveryService
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(
-    SseDeserializer deserializer,
-  );
+@protected PairingRequest sse_decode_pairing_request(SseDeserializer deserializer);
 
-  @protected
-  NoteStore
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-    SseDeserializer deserializer,
-  );
+@protected PairingResult sse_decode_pairing_result(SseDeserializer deserializer);
 
-  @protected
-  SyncService
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    SseDeserializer deserializer,
-  );
+@protected PairingTarget sse_decode_pairing_target(SseDeserializer deserializer);
 
-  @protected
-  Duration sse_decode_Chrono_Duration(SseDeserializer deserializer);
+@protected ParsedPairingCredential sse_decode_parsed_pairing_credential(SseDeserializer deserializer);
 
-  @protected
-  DiscoveryService
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(
-    SseDeserializer deserializer,
-  );
+@protected PeerInfo sse_decode_peer_info(SseDeserializer deserializer);
 
-  @protected
-  NoteStore
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-    SseDeserializer deserializer,
-  );
+@protected (String,List<String>?) sse_decode_record_string_opt_list_string(SseDeserializer deserializer);
 
-  @protected
-  SyncService
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    SseDeserializer deserializer,
-  );
+@protected RevokedDeviceRow sse_decode_revoked_device_row(SseDeserializer deserializer);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
+@protected SearchQueryError sse_decode_search_query_error(SseDeserializer deserializer);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected SearchQueryErrorKind sse_decode_search_query_error_kind(SseDeserializer deserializer);
 
-  @protected
-  PairingRequest sse_decode_box_autoadd_pairing_request(
-    SseDeserializer deserializer,
-  );
+@protected SyncCycleResult sse_decode_sync_cycle_result(SseDeserializer deserializer);
 
-  @protected
-  PairingTarget sse_decode_box_autoadd_pairing_target(
-    SseDeserializer deserializer,
-  );
+@protected SyncEvent sse_decode_sync_event(SseDeserializer deserializer);
 
-  @protected
-  DevicePushResult sse_decode_device_push_result(SseDeserializer deserializer);
+@protected int sse_decode_u_16(SseDeserializer deserializer);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+@protected int sse_decode_u_32(SseDeserializer deserializer);
 
-  @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
 
-  @protected
-  LinkRow sse_decode_link_row(SseDeserializer deserializer);
+@protected int sse_decode_u_8(SseDeserializer deserializer);
 
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+@protected void sse_decode_unit(SseDeserializer deserializer);
 
-  @protected
-  List<DevicePushResult> sse_decode_list_device_push_result(
-    SseDeserializer deserializer,
-  );
+@protected BigInt sse_decode_usize(SseDeserializer deserializer);
 
-  @protected
-  List<LinkRow> sse_decode_list_link_row(SseDeserializer deserializer);
-
-  @protected
-  List<NoteRow> sse_decode_list_note_row(SseDeserializer deserializer);
+@protected VaultStatus sse_decode_vault_status(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(NoteStore self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(SyncService self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(DiscoveryService self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(SyncService self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(DiscoveryService self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(NoteStore self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(SyncService self, SseSerializer serializer);
+
+@protected void sse_encode_Chrono_Duration(Duration self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(DiscoveryService self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(NoteStore self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(SyncService self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_sync_event_Sse(RustStreamSink<SyncEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_pairing_request(PairingRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_pairing_target(PairingTarget self, SseSerializer serializer);
+
+@protected void sse_encode_conflict_detail(ConflictDetail self, SseSerializer serializer);
+
+@protected void sse_encode_conflict_resolution(ConflictResolution self, SseSerializer serializer);
+
+@protected void sse_encode_device_push_result(DevicePushResult self, SseSerializer serializer);
+
+@protected void sse_encode_graph_edge(GraphEdge self, SseSerializer serializer);
+
+@protected void sse_encode_graph_node(GraphNode self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_link_row(LinkRow self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_conflict_detail(List<ConflictDetail> self, SseSerializer serializer);
+
+@protected void sse_encode_list_device_push_result(List<DevicePushResult> self, SseSerializer serializer);
+
+@protected void sse_encode_list_graph_edge(List<GraphEdge> self, SseSerializer serializer);
+
+@protected void sse_encode_list_graph_node(List<GraphNode> self, SseSerializer serializer);
+
+@protected void sse_encode_list_link_row(List<LinkRow> self, SseSerializer serializer);
+
+@protected void sse_encode_list_list_String(List<List<String>> self, SseSerializer serializer);
+
+@protected void sse_encode_list_mention_row(List<MentionRow> self, SseSerializer serializer);
+
+@protected void sse_encode_list_note_row(List<NoteRow> self, SseSerializer serializer);
+
+@protected void sse_encode_list_note_version(List<NoteVersion> self, SseSerializer serializer);
+
+@protected void sse_encode_list_paired_device_row(List<PairedDeviceRow> self, SseSerializer serializer);
+
+@protected void sse_encode_list_peer_info(List<PeerInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_string_opt_list_string(List<(String,List<String>?)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_revoked_device_row(List<RevokedDeviceRow> self, SseSerializer serializer);
+
+@protected void sse_encode_mention_row(MentionRow self, SseSerializer serializer);
+
+@protected void sse_encode_note_conflict(NoteConflict self, SseSerializer serializer);
+
+@protected void sse_encode_note_graph(NoteGraph self, SseSerializer serializer);
+
+@protected void sse_encode_note_row(NoteRow self, SseSerializer serializer);
+
+@protected void sse_encode_note_version(NoteVersion self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_pairing_request(PairingRequest? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
+
+@protected void sse_encode_paired_device_row(PairedDeviceRow self, SseSerializer serializer);
+
+@protected void sse_encode_pairing_credential_display(PairingCredentialDisplay self, SseSerializer serializer);
+
+@protected void sse_encode_pairing_credential_error(PairingCredentialError self, SseSerializer serializer);
+
+@protected void sse_encode_pairing_credential_error_kind(PairingCredentialErrorKind self, SseSerializer serializer);
+
+@protected void sse_encode_pairing_request(PairingRequest self, SseSerializer serializer);
+
+@protected void sse_encode_pairing_result(PairingResult self, SseSerializer serializer);
+
+@protected void sse_encode_pairing_target(PairingTarget self, SseSerializer serializer);
+
+@protected void sse_encode_parsed_pairing_credential(ParsedPairingCredential self, SseSerializer serializer);
+
+@protected void sse_encode_peer_info(PeerInfo self, SseSerializer serializer);
+
+@protected void sse_encode_record_string_opt_list_string((String,List<String>?) self, SseSerializer serializer);
+
+@protected void sse_encode_revoked_device_row(RevokedDeviceRow self, SseSerializer serializer);
+
+@protected void sse_encode_search_query_error(SearchQueryError self, SseSerializer serializer);
+
+@protected void sse_encode_search_query_error_kind(SearchQueryErrorKind self, SseSerializer serializer);
+
+@protected void sse_encode_sync_cycle_result(SyncCycleResult self, SseSerializer serializer);
+
+@protected void sse_encode_sync_event(SyncEvent self, SseSerializer serializer);
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_vault_status(VaultStatus self, SseSerializer serializer);
+                }
+                
 
-  @protected
-  List<PairedDeviceRow> sse_decode_list_paired_device_row(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  List<PeerInfo> sse_decode_list_peer_info(SseDeserializer deserializer);
-
-  @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
-
-  @protected
-  List<(String, List<String>?)> sse_decode_list_record_string_opt_list_string(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  NoteRow sse_decode_note_row(SseDeserializer deserializer);
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
-
-  @protected
-  PairingRequest? sse_decode_opt_box_autoadd_pairing_request(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
-
-  @protected
-  PairedDeviceRow sse_decode_paired_device_row(SseDeserializer deserializer);
-
-  @protected
-  PairingCredentialDisplay sse_decode_pairing_credential_display(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  PairingCredentialError sse_decode_pairing_credential_error(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  PairingCredentialErrorKind sse_decode_pairing_credential_error_kind(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  PairingRequest sse_decode_pairing_request(SseDeserializer deserializer);
-
-  @protected
-  PairingResult sse_decode_pairing_result(SseDeserializer deserializer);
-
-  @protected
-  PairingTarget sse_decode_pairing_target(SseDeserializer deserializer);
-
-  @protected
-  ParsedPairingCredential sse_decode_parsed_pairing_credential(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  PeerInfo sse_decode_peer_info(SseDeserializer deserializer);
-
-  @protected
-  (String, List<String>?) sse_decode_record_string_opt_list_string(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  SyncCycleResult sse_decode_sync_cycle_result(SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_u_16(SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
-
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
-
-  @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-    NoteStore self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    SyncService self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(
-    DiscoveryService self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    SyncService self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(
-    DiscoveryService self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-    NoteStore self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    SyncService self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_Chrono_Duration(Duration self, SseSerializer serializer);
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(
-    DiscoveryService self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-    NoteStore self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    SyncService self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_box_autoadd_pairing_request(
-    PairingRequest self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_pairing_target(
-    PairingTarget self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_device_push_result(
-    DevicePushResult self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_link_row(LinkRow self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_list_device_push_result(
-    List<DevicePushResult> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_link_row(List<LinkRow> self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_list_note_row(List<NoteRow> self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_list_paired_device_row(
-    List<PairedDeviceRow> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_peer_info(List<PeerInfo> self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_record_string_opt_list_string(
-    List<(String, List<String>?)> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_note_row(NoteRow self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_opt_box_autoadd_pairing_request(
-    PairingRequest? self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_paired_device_row(
-    PairedDeviceRow self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_pairing_credential_display(
-    PairingCredentialDisplay self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_pairing_credential_error(
-    PairingCredentialError self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_pairing_credential_error_kind(
-    PairingCredentialErrorKind self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_pairing_request(
-    PairingRequest self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_pairing_result(PairingResult self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_pairing_target(PairingTarget self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_parsed_pairing_credential(
-    ParsedPairingCredential self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_peer_info(PeerInfo self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_record_string_opt_list_string(
-    (String, List<String>?) self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_sync_cycle_result(
-    SyncCycleResult self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_u_16(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer);
-}
 
 // Section: wire_class
 
 class RustLibWire implements BaseWire {
-  RustLibWire.fromExternalLibrary(ExternalLibrary lib);
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(
-        ptr,
-      );
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(
-        ptr,
-      );
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-        ptr,
-      );
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-        ptr,
-      );
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-        ptr,
-      );
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-        ptr,
-      );
-}
-
-@JS('wasm_bindgen')
-external RustLibWasmModule get wasmModule;
-
-@JS()
-@anonymous
-extension type RustLibWasmModule._(JSObject _) implements JSObject {
-  external void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(
-    int ptr,
-  );
-
-  external void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(
-    int ptr,
-  );
-
-  external void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-    int ptr,
-  );
-
-  external void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(
-    int ptr,
-  );
-
-  external void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    int ptr,
-  );
-
-  external void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(
-    int ptr,
-  );
-}
+            RustLibWire.fromExternalLibrary(ExternalLibrary lib);
+
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(ptr);
+        }
+        @JS('wasm_bindgen') external RustLibWasmModule get wasmModule;
+
+        @JS() @anonymous extension type RustLibWasmModule._(JSObject _) implements JSObject {
+            external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDiscoveryService(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNoteStore(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSyncService(int ptr);
+        }
+        
\ No newline at end of file
@@ -0,0 +1,56 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// 搜索语法错误（过 FRB；携带稳定 kind、技术细节 message 与出错位置）。
+class SearchQueryError implements FrbException {
+                final SearchQueryErrorKind kind;
+final String message;
+/// 出错位置：查询字符串中的字符下标
+final int position;
+
+                const SearchQueryError({required this.kind ,required this.message ,required this.position ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^message.hashCode^position.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchQueryError &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& message == other.message&& position == other.position;
+        
+            }
+
+/// 搜索语法错误分类（过 FRB；Dart 侧按 kind 映射中文文案）。
+enum SearchQueryErrorKind {
+                    /// 引号未闭合
+unterminatedQuote,
+/// 过滤器或 `-` 后缺少值（如 `tag:`）
+missingValue,
+/// 日期无效（应为 `YYYY-MM-DD`）
+invalidDate,
+/// 不支持的过滤值或用法（如 `in:` 只支持 `trash`）
+unsupportedFilter,
+/// `OR` 两侧缺少搜索项
+danglingOr,
+                    ;
+                    
+                }
+            
\ No newline at end of file
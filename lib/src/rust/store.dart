@@ -6,119 +6,262 @@
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NoteStore>>
-abstract class NoteStore implements RustOpaqueInterface {}
+
+            
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NoteStore>>
+                abstract class NoteStore implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+/// 知识图谱边：source 正文链接到 target（同一对笔记的多条链接合并为一条；
+/// 悬空链接与指向回收站笔记的链接不计）
+class GraphEdge  {
+                final String source;
+final String target;
+/// 第一条链接的显示名
+final String alias;
+
+                const GraphEdge({required this.source ,required this.target ,required this.alias ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => source.hashCode^target.hashCode^alias.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is GraphEdge &&
+                runtimeType == other.runtimeType
+                && source == other.source&& target == other.target&& alias == other.alias;
+        
+            }
+
+/// 知识图谱节点（未删除的笔记，FRB 可序列化）
+class GraphNode  {
+                final String id;
+final String title;
+final List<String> tags;
+/// 度数：与之相连的边数（出边 + 入边，全图口径）
+final int degree;
+
+                const GraphNode({required this.id ,required this.title ,required this.tags ,required this.degree ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^title.hashCode^tags.hashCode^degree.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is GraphNode &&
+                runtimeType == other.runtimeType
+                && id == other.id&& title == other.title&& tags == other.tags&& degree == other.degree;
+        
+            }
 
 /// 链接行（outgoing/backlink 查询结果，FRB 可序列化）
-class LinkRow {
-  /// 对端笔记 id
-  final String id;
-
-  /// 对端实时标题（JOIN 得出，空串 = 悬空）
-  final String title;
-
-  /// 源正文里写的显示名
-  final String alias;
-
-  /// 对端笔记是否存在（false = 悬空链接）
-  final bool exists;
-
-  const LinkRow({
-    required this.id,
-    required this.title,
-    required this.alias,
-    required this.exists,
-  });
-
-  @override
-  int get hashCode =>
-      id.hashCode ^ title.hashCode ^ alias.hashCode ^ exists.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is LinkRow &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          title == other.title &&
-          alias == other.alias &&
-          exists == other.exists;
-}
+class LinkRow  {
+                /// 对端笔记 id
+final String id;
+/// 对端实时标题（JOIN 得出，空串 = 悬空）
+final String title;
+/// 源正文里写的显示名
+final String alias;
+/// 目标笔记内的锚点：`#标题` 或 `^块 id`（空 = 整篇笔记）
+final String anchor;
+/// 对端笔记是否存在（false = 悬空链接）
+final bool exists;
+/// 链接目标是否已解析为笔记 id（`[[标题]]` 尚无同名笔记 = false，
+/// 此时 `id` 为正文里写的目标原文）
+final bool resolved;
+/// 标题链接有多篇同名笔记（解析结果固定为先解析到的一篇，UI 可提示改用 id 链接）
+final bool ambiguous;
+
+                const LinkRow({required this.id ,required this.title ,required this.alias ,required this.anchor ,required this.exists ,required this.resolved ,required this.ambiguous ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^title.hashCode^alias.hashCode^anchor.hashCode^exists.hashCode^resolved.hashCode^ambiguous.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LinkRow &&
+                runtimeType == other.runtimeType
+                && id == other.id&& title == other.title&& alias == other.alias&& anchor == other.anchor&& exists == other.exists&& resolved == other.resolved&& ambiguous == other.ambiguous;
+        
+            }
+
+/// 未链接提及行（[`NoteStore::unlinked_mentions`] 结果，FRB 可序列化）
+class MentionRow  {
+                /// 提及所在笔记 id
+final String id;
+/// 提及所在笔记标题
+final String title;
+/// 第一处提及的上下文
+final String snippet;
+/// 该笔记中未加链接的提及次数
+final int mentions;
+
+                const MentionRow({required this.id ,required this.title ,required this.snippet ,required this.mentions ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^title.hashCode^snippet.hashCode^mentions.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MentionRow &&
+                runtimeType == other.runtimeType
+                && id == other.id&& title == other.title&& snippet == other.snippet&& mentions == other.mentions;
+        
+            }
+
+/// 知识图谱（全图或局部；节点按 id、边按 `(source, target)` 排序）
+class NoteGraph  {
+                final List<GraphNode> nodes;
+final List<GraphEdge> edges;
+
+                const NoteGraph({required this.nodes ,required this.edges ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => nodes.hashCode^edges.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NoteGraph &&
+                runtimeType == other.runtimeType
+                && nodes == other.nodes&& edges == other.edges;
+        
+            }
 
 /// 笔记的只读行（从 SQLite 反查）
-class NoteRow {
-  final String id;
-  final String title;
-  final String contentPreview;
-  final String tags;
-  final String updatedAt;
-
-  /// 软删时间（回收站条目展示"删除于"用；未删除 = None）
-  final String? deletedAt;
-
-  const NoteRow({
-    required this.id,
-    required this.title,
-    required this.contentPreview,
-    required this.tags,
-    required this.updatedAt,
-    this.deletedAt,
-  });
-
-  @override
-  int get hashCode =>
-      id.hashCode ^
-      title.hashCode ^
-      contentPreview.hashCode ^
-      tags.hashCode ^
-      updatedAt.hashCode ^
-      deletedAt.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is NoteRow &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          title == other.title &&
-          contentPreview == other.contentPreview &&
-          tags == other.tags &&
-          updatedAt == other.updatedAt &&
-          deletedAt == other.deletedAt;
-}
+class NoteRow  {
+                final String id;
+final String title;
+final String contentPreview;
+final String tags;
+final String updatedAt;
+/// 软删时间（回收站条目展示"删除于"用；未删除 = None）
+final String? deletedAt;
+
+                const NoteRow({required this.id ,required this.title ,required this.contentPreview ,required this.tags ,required this.updatedAt ,this.deletedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^title.hashCode^contentPreview.hashCode^tags.hashCode^updatedAt.hashCode^deletedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NoteRow &&
+                runtimeType == other.runtimeType
+                && id == other.id&& title == other.title&& contentPreview == other.contentPreview&& tags == other.tags&& updatedAt == other.updatedAt&& deletedAt == other.deletedAt;
+        
+            }
 
 /// 配对设备行（paired_devices 表，FRB 可序列化）
-class PairedDeviceRow {
-  /// 对端 iroh node id
-  final String peerId;
-
-  /// 对端设备名
-  final String name;
-
-  /// 最后成功连接/同步时间（ISO8601；尚未连接过 = None）
-  final String? lastSeen;
-
-  /// 配对时间（ISO8601）
-  final String pairedAt;
-
-  const PairedDeviceRow({
-    required this.peerId,
-    required this.name,
-    this.lastSeen,
-    required this.pairedAt,
-  });
-
-  @override
-  int get hashCode =>
-      peerId.hashCode ^ name.hashCode ^ lastSeen.hashCode ^ pairedAt.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is PairedDeviceRow &&
-          runtimeType == other.runtimeType &&
-          peerId == other.peerId &&
-          name == other.name &&
-          lastSeen == other.lastSeen &&
-          pairedAt == other.pairedAt;
-}
+class PairedDeviceRow  {
+                /// 对端 iroh node id
+final String peerId;
+/// 对端设备名
+final String name;
+/// 最后成功连接/同步时间（ISO8601；尚未连接过 = None）
+final String? lastSeen;
+/// 配对时间（ISO8601）
+final String pairedAt;
+
+                const PairedDeviceRow({required this.peerId ,required this.name ,this.lastSeen ,required this.pairedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => peerId.hashCode^name.hashCode^lastSeen.hashCode^pairedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PairedDeviceRow &&
+                runtimeType == other.runtimeType
+                && peerId == other.peerId&& name == other.name&& lastSeen == other.lastSeen&& pairedAt == other.pairedAt;
+        
+            }
+
+/// 设备撤销记录（revoked_devices 表，FRB 可序列化）。
+///
+/// 由撤销方设备用 iroh 身份密钥签名，随同步会话传播给其他设备；被撤销的
+/// node id 之后发起的同步一律拒绝。
+class RevokedDeviceRow  {
+                /// 被撤销设备的 iroh node id
+final String peerId;
+/// 撤销方设备的 iroh node id（签名公钥）
+final String revokedBy;
+/// 撤销时间（ISO8601）
+final String revokedAt;
+/// 撤销方对 `(peer_id, revoked_by, revoked_at)` 的 Ed25519 签名（hex）
+final String signature;
+
+                const RevokedDeviceRow({required this.peerId ,required this.revokedBy ,required this.revokedAt ,required this.signature ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => peerId.hashCode^revokedBy.hashCode^revokedAt.hashCode^signature.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RevokedDeviceRow &&
+                runtimeType == other.runtimeType
+                && peerId == other.peerId&& revokedBy == other.revokedBy&& revokedAt == other.revokedAt&& signature == other.signature;
+        
+            }
+            
\ No newline at end of file
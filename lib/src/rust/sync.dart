@@ -6,271 +6,386 @@
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>>
-abstract class SyncService implements RustOpaqueInterface {}
-
-/// 单设备推送结果：peer_id + 成功/失败信息
-class DevicePushResult {
-  final String peerId;
-  final bool ok;
-
-  /// 失败原因（成功时为空）
-  final String message;
-
-  const DevicePushResult({
-    required this.peerId,
-    required this.ok,
-    required this.message,
-  });
-
-  @override
-  int get hashCode => peerId.hashCode ^ ok.hashCode ^ message.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is DevicePushResult &&
-          runtimeType == other.runtimeType &&
-          peerId == other.peerId &&
-          ok == other.ok &&
-          message == other.message;
-}
+
+            
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>>
+                abstract class SyncService implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+/// 冲突详情（[`SyncService::list_conflicts`] 返回；UI 并排展示两侧正文）。
+class ConflictDetail  {
+                final NoteConflict conflict;
+/// 共同祖先版本的正文
+final String baseContent;
+/// 本端一侧的正文
+final String localContent;
+/// 对端一侧的正文
+final String remoteContent;
+/// 当前（自动合并后）的正文
+final String mergedContent;
+
+                const ConflictDetail({required this.conflict ,required this.baseContent ,required this.localContent ,required this.remoteContent ,required this.mergedContent ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => conflict.hashCode^baseContent.hashCode^localContent.hashCode^remoteContent.hashCode^mergedContent.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ConflictDetail &&
+                runtimeType == other.runtimeType
+                && conflict == other.conflict&& baseContent == other.baseContent&& localContent == other.localContent&& remoteContent == other.remoteContent&& mergedContent == other.mergedContent;
+        
+            }
+
+/// 冲突处理方式（见 [`SyncService::resolve_conflict`]）。
+enum ConflictResolution {
+                    /// 保留自动合并结果
+keepMerged,
+/// 以本端一侧的正文覆盖合并结果
+keepLocal,
+/// 以对端一侧的正文覆盖合并结果
+keepRemote,
+                    ;
+                    
+                }
+
+/// 单设备推送结果：peer_id + 成功/失败信息 + 送达/待同步笔记数
+class DevicePushResult  {
+                final String peerId;
+/// 对端已确认收到（读完整帧后以关闭码 0 关闭连接）
+final bool ok;
+/// 失败原因（成功时为空）
+final String message;
+/// 本次送达的笔记记录数（对端确认后才计；失败为 0）
+final int deliveredCount;
+/// 本次之后该对端仍未确认的笔记/墓碑数（失败时即下次需重试的数量）
+final int pendingCount;
+
+                const DevicePushResult({required this.peerId ,required this.ok ,required this.message ,required this.deliveredCount ,required this.pendingCount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => peerId.hashCode^ok.hashCode^message.hashCode^deliveredCount.hashCode^pendingCount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DevicePushResult &&
+                runtimeType == other.runtimeType
+                && peerId == other.peerId&& ok == other.ok&& message == other.message&& deliveredCount == other.deliveredCount&& pendingCount == other.pendingCount;
+        
+            }
+
+/// 并发编辑冲突：导入的对端变更与本端尚未同步给对方的变更并发，且两侧都改动
+/// 了正文（Loro 已自动合并，记录下来供用户复核）。
+class NoteConflict  {
+                final String noteId;
+/// 检测时间（RFC3339）
+final String detectedAt;
+/// 本端一侧并发变更的发起设备 device_id
+final List<String> localDevices;
+/// 对端一侧并发变更的发起设备 device_id
+final List<String> remoteDevices;
+/// 本端一侧版本（frontiers，`counter@peer` 列表，格式同 [`NoteVersion::version`]）
+final List<String> localVersion;
+/// 对端一侧版本
+final List<String> remoteVersion;
+/// 两侧的共同祖先版本（空 = 无共同历史）
+final List<String> baseVersion;
+
+                const NoteConflict({required this.noteId ,required this.detectedAt ,required this.localDevices ,required this.remoteDevices ,required this.localVersion ,required this.remoteVersion ,required this.baseVersion ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => noteId.hashCode^detectedAt.hashCode^localDevices.hashCode^remoteDevices.hashCode^localVersion.hashCode^remoteVersion.hashCode^baseVersion.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NoteConflict &&
+                runtimeType == other.runtimeType
+                && noteId == other.noteId&& detectedAt == other.detectedAt&& localDevices == other.localDevices&& remoteDevices == other.remoteDevices&& localVersion == other.localVersion&& remoteVersion == other.remoteVersion&& baseVersion == other.baseVersion;
+        
+            }
+
+/// 笔记的一个历史版本（Loro change；FRB 可序列化，供历史面板展示）
+class NoteVersion  {
+                /// 版本标识（`counter@peer`），传给按版本渲染/恢复接口
+final String version;
+/// 提交时间（RFC3339；未记录时间戳的旧变更为空）
+final String timestamp;
+/// 发起设备 device_id（旧数据或对端旧版本写入的变更为空）
+final String deviceId;
+/// 变更大小（Loro 原子操作数：插入/删除的字符数 + 元数据操作数）
+final int changeLen;
+
+                const NoteVersion({required this.version ,required this.timestamp ,required this.deviceId ,required this.changeLen ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => version.hashCode^timestamp.hashCode^deviceId.hashCode^changeLen.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NoteVersion &&
+                runtimeType == other.runtimeType
+                && version == other.version&& timestamp == other.timestamp&& deviceId == other.deviceId&& changeLen == other.changeLen;
+        
+            }
 
 /// 显示方生成的配对凭证展示对象（过 FRB）。
-class PairingCredentialDisplay {
-  /// 6 位数字配对码（局域网旧流程兼容）
-  final String code;
-
-  /// 完整凭证字符串（`cm1.` + base64url，二维码与复制文本逐字相同）
-  final String credential;
-
-  /// 过期时间 RFC3339 UTC（供 UI 倒计时）
-  final String expiresAt;
-
-  const PairingCredentialDisplay({
-    required this.code,
-    required this.credential,
-    required this.expiresAt,
-  });
-
-  @override
-  int get hashCode => code.hashCode ^ credential.hashCode ^ expiresAt.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is PairingCredentialDisplay &&
-          runtimeType == other.runtimeType &&
-          code == other.code &&
-          credential == other.credential &&
-          expiresAt == other.expiresAt;
-}
+class PairingCredentialDisplay  {
+                /// 6 位数字配对码（局域网旧流程兼容）
+final String code;
+/// 完整凭证字符串（`cm1.` + base64url，二维码与复制文本逐字相同）
+final String credential;
+/// 过期时间 RFC3339 UTC（供 UI 倒计时）
+final String expiresAt;
+
+                const PairingCredentialDisplay({required this.code ,required this.credential ,required this.expiresAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => code.hashCode^credential.hashCode^expiresAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PairingCredentialDisplay &&
+                runtimeType == other.runtimeType
+                && code == other.code&& credential == other.credential&& expiresAt == other.expiresAt;
+        
+            }
 
 /// 配对凭证错误（过 FRB；携带稳定 kind + 技术细节 message）。
 class PairingCredentialError implements FrbException {
-  final PairingCredentialErrorKind kind;
-  final String message;
+                final PairingCredentialErrorKind kind;
+final String message;
+
+                const PairingCredentialError({required this.kind ,required this.message ,});
 
-  const PairingCredentialError({required this.kind, required this.message});
+                
+                
 
-  @override
-  int get hashCode => kind.hashCode ^ message.hashCode;
+                
+        @override
+        int get hashCode => kind.hashCode^message.hashCode;
+        
 
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is PairingCredentialError &&
-          runtimeType == other.runtimeType &&
-          kind == other.kind &&
-          message == other.message;
-}
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PairingCredentialError &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& message == other.message;
+        
+            }
 
 /// 用户可读的配对凭证错误分类（过 FRB；Dart 侧 match，避免字符串匹配）。
 enum PairingCredentialErrorKind {
-  /// 凭证字符串格式无效（前缀/版本/长度/base64）
-  invalidFormat,
-
-  /// 签名无效 / 载荷被篡改
-  invalidSignature,
-
-  /// 凭证已过期
-  expired,
-
-  /// 凭证已使用或被新凭证替代（会话已更换/清除）
-  usedOrReplaced,
-
-  /// 目标设备不可达（连接失败/超时/无握手响应）
-  unreachable,
-
-  /// 其它内部错误
-  internal,
-}
+                    /// 凭证字符串格式无效（前缀/版本/长度/base64）
+invalidFormat,
+/// 签名无效 / 载荷被篡改
+invalidSignature,
+/// 凭证已过期
+expired,
+/// 凭证已使用或被新凭证替代（会话已更换/清除）
+usedOrReplaced,
+/// 目标设备不可达（连接失败/超时/无握手响应）
+unreachable,
+/// 其它内部错误
+internal,
+                    ;
+                    
+                }
 
 /// 发起方配对请求（含本机身份与配对码，经网络发送给确认方）
-class PairingRequest {
-  /// 配对码（发起方从确认方展示处获得；请求携带以便确认方校验匹配）
-  final String code;
-
-  /// 发起方 iroh 节点 ID（device_id）
-  final String deviceId;
-
-  /// 发起方设备名
-  final String deviceName;
-
-  /// 发起方 relay 信息（配置的 relay URL 列表，逗号分隔）。
-  ///
-  /// 说明：N0 preset 已通过 PkarrPublisher 自动发布本端点地址（含 relay）到
-  /// n0 DNS（iroh.link），此字段仅为协议完整性保留（信息性）。
-  final String relayInfo;
-
-  /// 发起方 IPv4 地址列表（"ip:port"，供确认方直连/推送加速）
-  final List<String> ips;
-
-  /// 一次性会话 nonce（hex 字符串；凭证路径来自凭证；6 位码路径来自 mDNS TXT）。
-  /// 确认方校验请求 nonce 必须与当前 PairingSession 一致。
-  final String nonce;
-
-  const PairingRequest({
-    required this.code,
-    required this.deviceId,
-    required this.deviceName,
-    required this.relayInfo,
-    required this.ips,
-    required this.nonce,
-  });
-
-  @override
-  int get hashCode =>
-      code.hashCode ^
-      deviceId.hashCode ^
-      deviceName.hashCode ^
-      relayInfo.hashCode ^
-      ips.hashCode ^
-      nonce.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is PairingRequest &&
-          runtimeType == other.runtimeType &&
-          code == other.code &&
-          deviceId == other.deviceId &&
-          deviceName == other.deviceName &&
-          relayInfo == other.relayInfo &&
-          ips == other.ips &&
-          nonce == other.nonce;
-}
+class PairingRequest  {
+                /// 配对码（发起方从确认方展示处获得；请求携带以便确认方校验匹配）
+final String code;
+/// 发起方 iroh 节点 ID（device_id）
+final String deviceId;
+/// 发起方设备名
+final String deviceName;
+/// 发起方 relay 信息（配置的 relay URL 列表，逗号分隔）。
+///
+/// 说明：N0 preset 已通过 PkarrPublisher 自动发布本端点地址（含 relay）到
+/// n0 DNS（iroh.link），此字段仅为协议完整性保留（信息性）。
+final String relayInfo;
+/// 发起方 IPv4 地址列表（"ip:port"，供确认方直连/推送加速）
+final List<String> ips;
+/// 一次性会话 nonce（hex 字符串；凭证路径来自凭证；6 位码路径来自 mDNS TXT）。
+/// 确认方校验请求 nonce 必须与当前 PairingSession 一致。
+final String nonce;
+
+                const PairingRequest({required this.code ,required this.deviceId ,required this.deviceName ,required this.relayInfo ,required this.ips ,required this.nonce ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => code.hashCode^deviceId.hashCode^deviceName.hashCode^relayInfo.hashCode^ips.hashCode^nonce.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PairingRequest &&
+                runtimeType == other.runtimeType
+                && code == other.code&& deviceId == other.deviceId&& deviceName == other.deviceName&& relayInfo == other.relayInfo&& ips == other.ips&& nonce == other.nonce;
+        
+            }
 
 /// 配对结果（对端身份）
-class PairingResult {
-  /// 对端 iroh 节点 ID
-  final String peerId;
-
-  /// 对端设备名
-  final String peerName;
-
-  const PairingResult({required this.peerId, required this.peerName});
-
-  @override
-  int get hashCode => peerId.hashCode ^ peerName.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is PairingResult &&
-          runtimeType == other.runtimeType &&
-          peerId == other.peerId &&
-          peerName == other.peerName;
-}
+class PairingResult  {
+                /// 对端 iroh 节点 ID
+final String peerId;
+/// 对端设备名
+final String peerName;
+
+                const PairingResult({required this.peerId ,required this.peerName ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => peerId.hashCode^peerName.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PairingResult &&
+                runtimeType == other.runtimeType
+                && peerId == other.peerId&& peerName == other.peerName;
+        
+            }
 
 /// 发起方要连接的确认方目标（同网段配对场景由 mDNS 发现提供 device_id + ip:port）
-class PairingTarget {
-  /// 确认方 iroh 节点 ID（device_id）
-  final String deviceId;
-
-  /// 确认方 IP 列表（"ip:port"）。空时经 n0 地址解析 + 公共 relay 连接
-  /// （iroh 1.x N0 preset 的 DnsAddressLookup 机制）；非空时直连优先。
-  final List<String> ips;
-
-  /// 确认方当前会话 nonce（hex 字符串；凭证路径直接内嵌；6 位码路径来自 mDNS TXT）。
-  /// 构造请求时写入 PairingRequest.nonce。
-  final String nonce;
-
-  const PairingTarget({
-    required this.deviceId,
-    required this.ips,
-    required this.nonce,
-  });
-
-  @override
-  int get hashCode => deviceId.hashCode ^ ips.hashCode ^ nonce.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is PairingTarget &&
-          runtimeType == other.runtimeType &&
-          deviceId == other.deviceId &&
-          ips == other.ips &&
-          nonce == other.nonce;
-}
+class PairingTarget  {
+                /// 确认方 iroh 节点 ID（device_id）
+final String deviceId;
+/// 确认方 IP 列表（"ip:port"）。空时经 n0 地址解析 + 公共 relay 连接
+/// （iroh 1.x N0 preset 的 DnsAddressLookup 机制）；非空时直连优先。
+final List<String> ips;
+/// 确认方当前会话 nonce（hex 字符串；凭证路径直接内嵌；6 位码路径来自 mDNS TXT）。
+/// 构造请求时写入 PairingRequest.nonce。
+final String nonce;
+
+                const PairingTarget({required this.deviceId ,required this.ips ,required this.nonce ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => deviceId.hashCode^ips.hashCode^nonce.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PairingTarget &&
+                runtimeType == other.runtimeType
+                && deviceId == other.deviceId&& ips == other.ips&& nonce == other.nonce;
+        
+            }
 
 /// 发起方解析后的配对凭证字段（过 FRB；nonce 仅内部 FRB→握手传递）。
-class ParsedPairingCredential {
-  final String code;
-  final String deviceId;
-  final String expiresAt;
-  final String nonce;
-
-  const ParsedPairingCredential({
-    required this.code,
-    required this.deviceId,
-    required this.expiresAt,
-    required this.nonce,
-  });
-
-  @override
-  int get hashCode =>
-      code.hashCode ^ deviceId.hashCode ^ expiresAt.hashCode ^ nonce.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is ParsedPairingCredential &&
-          runtimeType == other.runtimeType &&
-          code == other.code &&
-          deviceId == other.deviceId &&
-          expiresAt == other.expiresAt &&
-          nonce == other.nonce;
-}
+class ParsedPairingCredential  {
+                final String code;
+final String deviceId;
+final String expiresAt;
+final String nonce;
+
+                const ParsedPairingCredential({required this.code ,required this.deviceId ,required this.expiresAt ,required this.nonce ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => code.hashCode^deviceId.hashCode^expiresAt.hashCode^nonce.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ParsedPairingCredential &&
+                runtimeType == other.runtimeType
+                && code == other.code&& deviceId == other.deviceId&& expiresAt == other.expiresAt&& nonce == other.nonce;
+        
+            }
 
 /// 一次周期同步的结果（FRB 可序列化，供 Flutter 侧诊断/未来 UI 使用）
-class SyncCycleResult {
-  /// 成功推送的对端设备数（0 = 本轮无成功推送）
-  final int pushedCount;
-
-  /// 本轮是否 accept 到对端 push 并导入
-  final bool acceptedPush;
-
-  /// 同步开关关闭导致整轮跳过
-  final bool disabled;
-
-  const SyncCycleResult({
-    required this.pushedCount,
-    required this.acceptedPush,
-    required this.disabled,
-  });
-
-  @override
-  int get hashCode =>
-      pushedCount.hashCode ^ acceptedPush.hashCode ^ disabled.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is SyncCycleResult &&
-          runtimeType == other.runtimeType &&
-          pushedCount == other.pushedCount &&
-          acceptedPush == other.acceptedPush &&
-          disabled == other.disabled;
-}
+class SyncCycleResult  {
+                /// 成功完成同步会话的对端设备数（0 = 本轮无成功会话）
+final int pushedCount;
+/// 本轮是否从对端拉取到本端缺失的笔记并导入
+final bool acceptedPush;
+/// 同步开关关闭导致整轮跳过
+final bool disabled;
+
+                const SyncCycleResult({required this.pushedCount ,required this.acceptedPush ,required this.disabled ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => pushedCount.hashCode^acceptedPush.hashCode^disabled.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SyncCycleResult &&
+                runtimeType == other.runtimeType
+                && pushedCount == other.pushedCount&& acceptedPush == other.acceptedPush&& disabled == other.disabled;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,25 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// 数据目录的加密状态。
+enum VaultStatus {
+                    /// 未启用加密（明文数据目录）
+disabled,
+/// 已启用但本进程尚未解锁（加载前需 [`unlock`]）
+locked,
+/// 已解锁，可加载
+unlocked,
+                    ;
+                    
+                }
+            
\ No newline at end of file
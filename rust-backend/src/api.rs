@@ -1,10 +1,16 @@
 use crate::discovery::{DiscoveryService, PeerInfo};
-use crate::store::{LinkRow, NoteRow, NoteStore, PairedDeviceRow};
+use crate::events::SyncEvent;
+use crate::frb_generated::StreamSink;
+use crate::search::{self, SearchQueryError};
+use crate::store::{
+    LinkRow, MentionRow, NoteGraph, NoteRow, NoteStore, PairedDeviceRow, RevokedDeviceRow,
+};
 use crate::sync::{
-    DevicePushResult, NoteCrdt, PairingCredentialDisplay, PairingCredentialError, PairingRequest,
-    PairingResult, PairingTarget, ParsedPairingCredential, SyncCycleResult, SyncService,
-    SYNC_POLL_INTERVAL_SECS,
+    ConflictDetail, ConflictResolution, DevicePushResult, NoteCrdt, NoteVersion,
+    PairingCredentialDisplay, PairingCredentialError, PairingRequest, PairingResult, PairingTarget,
+    ParsedPairingCredential, SyncCycleResult, SyncService, TransferLimits, SYNC_POLL_INTERVAL_SECS,
 };
+use crate::vault::{self, VaultStatus};
 
 /// 创建同步服务
 pub async fn create_sync_service() -> anyhow::Result<SyncService> {
@@ -190,24 +196,56 @@ pub fn get_sync_allowed(svc: &SyncService) -> bool {
     svc.sync_allowed()
 }
 
-/// 待同步笔记计数（模块 5 基础）。
+/// 待同步笔记计数（模块 5 基础；至少一台对端尚未确认的笔记数，跨重启保持）。
 pub fn pending_sync_count(svc: &SyncService) -> u32 {
     svc.pending_sync_count()
 }
 
+/// 单台对端尚未确认的笔记数。
+pub fn pending_sync_count_for_peer(svc: &SyncService, peer_id: String) -> u32 {
+    svc.pending_sync_count_for_peer(&peer_id)
+}
+
+/// 清除全部对端的同步退避（用户手动"立即同步"时先调用，离线过的设备也立即重试）。
+pub fn reset_sync_backoff(svc: &SyncService) {
+    svc.reset_sync_backoff();
+}
+
+/// 设置入站同步数据上限（单条记录 / 单次传输 / 整帧缓冲字节数；超限的推送
+/// 被中止）。
+pub fn set_transfer_limits(
+    svc: &SyncService,
+    max_record_bytes: u32,
+    max_transfer_bytes: u64,
+    max_buffered_bytes: u64,
+) {
+    svc.set_transfer_limits(TransferLimits {
+        max_record_bytes,
+        max_transfer_bytes,
+        max_buffered_bytes,
+    });
+}
+
+/// 设置墓碑回收期限（天）：未被全部配对设备确认的墓碑超过该期限后回收。
+pub fn set_tombstone_horizon_days(svc: &SyncService, days: u32) -> anyhow::Result<()> {
+    svc.set_tombstone_horizon(std::time::Duration::from_secs(
+        u64::from(days) * 24 * 60 * 60,
+    ))
+}
+
 /// 周期拉取间隔（秒）——Flutter 侧 Timer 周期用。
 pub fn sync_poll_interval_secs() -> u32 {
     SYNC_POLL_INTERVAL_SECS as u32
 }
 
-/// 推送待办（编辑保存即推送）：向所有配对设备推全量快照。
+/// 推送待办（编辑保存即推送）：向所有配对设备推送对端缺失的增量。
 ///
 /// 失败静默（决策 18）：返回每台设备结果，不抛错；调用方 fire-and-forget 即可。
 pub async fn push_pending(svc: &SyncService, store: &NoteStore) -> Vec<DevicePushResult> {
     svc.push_pending(store).await
 }
 
-/// 周期同步任务体：push 给所有对端 + 短窗口 accept 对端 push + 刷新 SQLite 投影。
+/// 周期同步任务体：与每台对端一次双向同步会话（拉取本端缺失 + 推回对端缺失）+ 刷新 SQLite 投影。
 pub async fn run_sync_cycle(
     svc: &mut SyncService,
     store: &NoteStore,
@@ -246,6 +284,45 @@ pub fn receiver_content_revision(svc: &SyncService) -> u64 {
     svc.receiver_content_revision()
 }
 
+/// 启动后台同步任务（幂等）：编辑后防抖推送 + 每 `SYNC_POLL_INTERVAL_SECS`
+/// 一次同步会话，遵守同步开关。启用后 Flutter 侧无需再驱动周期计时器。
+pub async fn start_sync_loop(svc: &SyncService, store: &NoteStore) -> anyhow::Result<()> {
+    svc.start_sync_loop(
+        store.clone(),
+        std::time::Duration::from_secs(SYNC_POLL_INTERVAL_SECS),
+    )
+    .await
+}
+
+/// 停止后台同步任务（幂等；3 秒内返回）。
+pub async fn stop_sync_loop(svc: &SyncService) -> anyhow::Result<()> {
+    svc.stop_sync_loop().await
+}
+
+/// 订阅同步服务事件流：笔记变更（含 id）、回收站/彻底删除、设备上下线、
+/// 同步开始/结束/失败、配对请求。UI 按事件只刷新受影响的内容。
+///
+/// 转发任务在 Dart 侧取消订阅或服务释放后结束；处理过慢丢失事件时收到
+/// `SyncEvent::Lagged`，应整体重新加载。
+pub async fn sync_events(svc: &SyncService, sink: StreamSink<SyncEvent>) -> anyhow::Result<()> {
+    let mut events = svc.subscribe_events();
+    tokio::spawn(async move {
+        loop {
+            let event = match events.recv().await {
+                Ok(event) => event,
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(missed)) => {
+                    SyncEvent::Lagged { missed }
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+            };
+            if sink.add(event).is_err() {
+                break;
+            }
+        }
+    });
+    Ok(())
+}
+
 /// 创建笔记
 pub fn note_create(svc: &mut SyncService, id: String, content: String) -> anyhow::Result<()> {
     svc.create_note(id, &content)
@@ -280,7 +357,7 @@ pub async fn accept_push(svc: &SyncService) -> anyhow::Result<Vec<u8>> {
     svc.accept_push().await
 }
 
-/// 向多台设备逐个推送全量快照（含墓碑），返回每台设备的结果。
+/// 向多台设备逐个增量推送（含墓碑），返回每台设备的结果。
 ///
 /// `devices`: `(peer_id, Option<IP 列表>)`；IP 缺省（None/空）时经 relay/地址解析尝试连接。
 /// 单台失败不中断整体；单台超时 10 秒记为失败。
@@ -296,9 +373,27 @@ pub fn list_paired_devices(store: &NoteStore) -> anyhow::Result<Vec<PairedDevice
     store.list_paired_devices()
 }
 
-/// SQLite — 移除一台配对设备。
-pub fn remove_paired_device(store: &NoteStore, peer_id: String) -> anyhow::Result<()> {
-    store.remove_paired_device(&peer_id)
+/// 移除一台配对设备。
+///
+/// 签名的移除记录随后续同步传播，其他设备同样移除它，名册不会再把它加回
+/// （之后该设备的同步帧因不在配对表而被拒）；该设备仍可重新配对。要让整个
+/// 设备网永久拒绝它，使用 [`revoke_device`]。
+pub fn remove_paired_device(
+    svc: &SyncService,
+    store: &NoteStore,
+    peer_id: String,
+) -> anyhow::Result<()> {
+    svc.remove_paired_device(store, &peer_id)
+}
+
+/// 撤销一台设备：签名撤销记录并移除配对，记录随后续同步会话传播给其他设备。
+pub fn revoke_device(svc: &SyncService, store: &NoteStore, peer_id: String) -> anyhow::Result<()> {
+    svc.revoke_device(store, &peer_id)
+}
+
+/// SQLite — 列出已撤销的设备。
+pub fn list_revoked_devices(store: &NoteStore) -> anyhow::Result<Vec<RevokedDeviceRow>> {
+    store.list_revoked_devices()
 }
 
 /// 设备发现 — 广播本设备
@@ -316,9 +411,9 @@ pub async fn discover_peers(disc: &DiscoveryService) -> anyhow::Result<Vec<PeerI
     disc.discover_peers().await
 }
 
-/// 创建 SQLite 存储
+/// 创建 SQLite 存储（数据目录已启用静态加密时为内存投影，需先 [`vault_unlock`]）
 pub fn create_note_store(path: String) -> anyhow::Result<NoteStore> {
-    NoteStore::new(&path)
+    NoteStore::open(&path)
 }
 
 /// SQLite — 列出所有笔记
@@ -355,16 +450,56 @@ pub fn get_backlinks(store: &NoteStore, note_id: String) -> anyhow::Result<Vec<L
     store.backlinks(&note_id)
 }
 
-/// SQLite — 全文搜索（FTS5）
+/// SQLite — 全文搜索（FTS5，支持 `tag:`、`updated:`、`links-to:`、`in:trash`、`OR`、`-` 等语法）
 pub fn search_notes(store: &NoteStore, query: String) -> anyhow::Result<Vec<NoteRow>> {
     store.search_notes(&query)
 }
 
+/// 校验搜索语法（输入时实时提示）。
+///
+/// 错误为稳定的 [`SearchQueryError`]（kind + 出错位置），Dart 侧按 kind 映射文案。
+pub fn check_search_query(query: String) -> Result<(), SearchQueryError> {
+    search::parse_query(&query).map(|_| ())
+}
+
 /// SQLite — 链接自动补全（标题前缀，最近 20 条）
 pub fn auto_complete_links(store: &NoteStore, prefix: String) -> anyhow::Result<Vec<NoteRow>> {
     store.auto_complete_links(&prefix)
 }
 
+/// SQLite — 知识图谱全图（节点含标题/标签/度数，边含显示名）
+pub fn get_note_graph(store: &NoteStore) -> anyhow::Result<NoteGraph> {
+    store.graph()
+}
+
+/// SQLite — 局部图谱：note_id 周围 `depth` 跳内的笔记及其间的边
+pub fn get_note_neighborhood(
+    store: &NoteStore,
+    note_id: String,
+    depth: u32,
+) -> anyhow::Result<NoteGraph> {
+    store.neighborhood(&note_id, depth)
+}
+
+/// SQLite — 孤立笔记（无出链也无反链）
+pub fn get_orphan_notes(store: &NoteStore) -> anyhow::Result<Vec<NoteRow>> {
+    store.orphan_notes()
+}
+
+/// SQLite — 两篇笔记间的最短链接路径（含首尾；不连通 = 空）
+pub fn get_shortest_path(
+    store: &NoteStore,
+    from: String,
+    to: String,
+) -> anyhow::Result<Vec<String>> {
+    store.shortest_path(&from, &to)
+}
+
+/// SQLite — 连通分量（按大小降序）
+pub fn get_connected_components(store: &NoteStore) -> anyhow::Result<Vec<Vec<String>>> {
+    store.connected_components()
+}
+
 /// SQLite — 全部标签（去重排序）
 pub fn get_all_tags(store: &NoteStore) -> anyhow::Result<Vec<String>> {
     store.get_all_tags()
@@ -402,3 +537,129 @@ pub fn note_purge(svc: &mut SyncService, id: String) -> anyhow::Result<()> {
 pub fn purge_expired_trash(svc: &mut SyncService, cutoff: String) -> anyhow::Result<usize> {
     svc.purge_expired(&cutoff)
 }
+
+// ━━━ 链接维护 ━━━
+
+/// 改标题：改写笔记首行，并把指向它的 `[[旧标题]]` 链接改为新标题；
+/// `rewrite_aliases` 时显示名等于旧标题的链接一并改写。返回被改写的其他笔记数。
+/// 改动已写入 `store` 投影（含 links）。
+pub fn rename_note(
+    svc: &mut SyncService,
+    store: &NoteStore,
+    id: String,
+    new_title: String,
+    rewrite_aliases: bool,
+) -> anyhow::Result<usize> {
+    svc.rename_note(store, &id, &new_title, rewrite_aliases)
+}
+
+/// 合并笔记：`source_id` 正文并入 `into_id` 后移入回收站，指向它的链接改指向
+/// `into_id`。返回被改写的其他笔记数。改动已写入 `store` 投影（含 links）。
+pub fn merge_notes(
+    svc: &mut SyncService,
+    store: &NoteStore,
+    source_id: String,
+    into_id: String,
+) -> anyhow::Result<usize> {
+    svc.merge_notes(store, &source_id, &into_id)
+}
+
+/// SQLite — 未链接提及：正文提到该笔记标题但尚未链接它的其他笔记，附上下文。
+pub fn get_unlinked_mentions(
+    store: &NoteStore,
+    note_id: String,
+) -> anyhow::Result<Vec<MentionRow>> {
+    store.unlinked_mentions(&note_id)
+}
+
+/// 把 `source_id` 中第一处未链接的提及改为指向 `target_id` 的链接。
+/// 改动已写入 `store` 投影（含 links）。
+pub fn link_mention(
+    svc: &mut SyncService,
+    store: &NoteStore,
+    source_id: String,
+    target_id: String,
+) -> anyhow::Result<()> {
+    svc.link_mention(store, &source_id, &target_id)
+}
+
+// ━━━ 编辑历史 ━━━
+
+/// 笔记历史版本列表（新 → 旧）：提交时间、发起设备、变更大小。
+pub fn note_versions(svc: &SyncService, id: String) -> anyhow::Result<Vec<NoteVersion>> {
+    svc.note_versions(&id)
+}
+
+/// 渲染笔记在指定历史版本时的正文（只读预览，不改变当前内容）。
+pub fn note_content_at_version(
+    svc: &SyncService,
+    id: String,
+    version: String,
+) -> anyhow::Result<String> {
+    svc.note_content_at_version(&id, &version)
+}
+
+/// 恢复到指定历史版本：以一次新编辑写入，随后像普通编辑一样同步到其他设备。
+/// 调用后需由 repository 跟随 `sync_notes_to_store` 刷新投影。
+pub fn note_restore_version(
+    svc: &mut SyncService,
+    id: String,
+    version: String,
+) -> anyhow::Result<()> {
+    svc.restore_note_version(&id, &version)
+}
+
+// ━━━ 并发编辑冲突 ━━━
+
+/// 未处理的并发编辑冲突（两台设备离线编辑同一笔记，合并后供用户复核）。
+/// 收到 `NotesChanged { remote: true }` 事件后刷新。
+pub fn list_conflicts(svc: &SyncService) -> Vec<ConflictDetail> {
+    svc.list_conflicts()
+}
+
+/// 处理冲突：保留合并结果，或以本端/对端一侧的正文覆盖。
+/// 覆盖时需由 repository 跟随 `sync_notes_to_store` 刷新投影。
+pub fn resolve_conflict(
+    svc: &mut SyncService,
+    id: String,
+    resolution: ConflictResolution,
+) -> anyhow::Result<()> {
+    svc.resolve_conflict(&id, resolution)
+}
+
+// ━━━ 静态加密 ━━━
+
+/// 数据目录的加密状态（启动时先查询：`Locked` → 先请求口令 [`vault_unlock`]，
+/// 再 `create_persistent_sync_service`）。
+pub fn vault_status(path: String) -> VaultStatus {
+    vault::status(&path)
+}
+
+/// 用口令解锁数据目录（口令错误返回 Err）。解锁后本进程才能加载该目录。
+pub fn vault_unlock(path: String, passphrase: String) -> anyhow::Result<()> {
+    vault::unlock(&path, &passphrase)
+}
+
+/// 撤销本进程的解锁（已打开的服务不受影响；下次打开前需重新解锁）。
+pub fn vault_lock(path: String) {
+    vault::lock(&path)
+}
+
+/// 为当前数据目录启用静态加密：笔记快照/日志、device.key、配对设备表改为
+/// 密文，SQLite 投影改为内存。之后每次启动需先 [`vault_unlock`]。
+pub fn enable_encryption(
+    svc: &SyncService,
+    store: &NoteStore,
+    passphrase: String,
+) -> anyhow::Result<()> {
+    svc.enable_encryption(store, &passphrase)
+}
+
+/// 修改加密口令（只重新包裹数据密钥，不重写数据文件）。
+pub fn vault_change_passphrase(
+    path: String,
+    old_passphrase: String,
+    new_passphrase: String,
+) -> anyhow::Result<()> {
+    vault::change_passphrase(&path, &old_passphrase, &new_passphrase)
+}
@@ -0,0 +1,70 @@
+//! 长度前缀二进制编码的共用读写函数。
+//!
+//! 同步帧、同步状态文件（`sync.rs`）与加密设备表文件（`store.rs`）都使用同一种
+//! 布局：`(len: u32 LE, bytes)`。读取越界统一报 `truncated data: missing {field}`。
+
+use anyhow::{Context, Result};
+
+/// 追加 `(len: u32 LE, bytes)`（与 [`take_bytes`] 对称）。
+pub(crate) fn push_bytes(buf: &mut Vec<u8>, bytes: &[u8]) {
+    buf.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+    buf.extend_from_slice(bytes);
+}
+
+/// 写入 u32 长度前缀 + UTF-8 字符串（与 [`take_str`] 对称）。
+pub(crate) fn push_str(buf: &mut Vec<u8>, s: &str) {
+    push_bytes(buf, s.as_bytes());
+}
+
+/// 写入可选字符串：None 以长度 `u32::MAX` 表示（与 [`take_opt_str`] 对称）。
+pub(crate) fn push_opt_str(buf: &mut Vec<u8>, s: Option<&str>) {
+    match s {
+        Some(s) => push_str(buf, s),
+        None => buf.extend_from_slice(&u32::MAX.to_le_bytes()),
+    }
+}
+
+/// 读取一个 u32 LE（越界报 truncated）。
+pub(crate) fn take_u32(data: &[u8], offset: &mut usize, field: &str) -> Result<u32> {
+    let bytes = data
+        .get(*offset..*offset + 4)
+        .ok_or_else(|| anyhow::anyhow!("truncated data: missing {field}"))?;
+    *offset += 4;
+    Ok(u32::from_le_bytes(bytes.try_into().unwrap()))
+}
+
+/// 读取 u32 长度前缀 + 原始字节（越界报 truncated）。
+pub(crate) fn take_bytes<'a>(data: &'a [u8], offset: &mut usize, field: &str) -> Result<&'a [u8]> {
+    if *offset + 4 > data.len() {
+        anyhow::bail!("truncated data: missing {field} length");
+    }
+    let len = u32::from_le_bytes(data[*offset..*offset + 4].try_into().unwrap()) as usize;
+    *offset += 4;
+    take_raw(data, offset, len, field)
+}
+
+/// 读取 u32 长度前缀 + UTF-8 字符串。
+pub(crate) fn take_str(data: &[u8], offset: &mut usize, field: &str) -> Result<String> {
+    let bytes = take_bytes(data, offset, field)?;
+    String::from_utf8(bytes.to_vec()).with_context(|| format!("invalid UTF-8 in {field}"))
+}
+
+/// 读取可选字符串（长度 `u32::MAX` = None，格式见 [`push_opt_str`]）。
+pub(crate) fn take_opt_str(data: &[u8], offset: &mut usize, field: &str) -> Result<Option<String>> {
+    let len = take_u32(data, offset, &format!("{field} length"))?;
+    if len == u32::MAX {
+        return Ok(None);
+    }
+    let bytes = take_raw(data, offset, len as usize, field)?;
+    let s =
+        String::from_utf8(bytes.to_vec()).with_context(|| format!("invalid UTF-8 in {field}"))?;
+    Ok(Some(s))
+}
+
+fn take_raw<'a>(data: &'a [u8], offset: &mut usize, len: usize, field: &str) -> Result<&'a [u8]> {
+    let bytes = data
+        .get(*offset..*offset + len)
+        .ok_or_else(|| anyhow::anyhow!("truncated data: missing {field}"))?;
+    *offset += len;
+    Ok(bytes)
+}
@@ -3,7 +3,7 @@ use std::net::IpAddr;
 use std::time::Duration;
 
 use anyhow::{Context, Result};
-use mdns_sd::{ServiceDaemon, ServiceEvent, ServiceInfo};
+use mdns_sd::{ResolvedService, ServiceDaemon, ServiceEvent, ServiceInfo};
 
 /// mDNS 服务类型
 const SERVICE_TYPE: &str = "_cardmind._tcp.local.";
@@ -43,21 +43,42 @@ impl DiscoveryService {
     /// `port`: iroh 监听端口
     /// `nonce`: 当前配对会话 nonce（hex，随 TXT 广播）
     pub fn start_advertising(&mut self, device_id: &str, port: u16, nonce: &str) -> Result<()> {
+        let instance_name = format!("cardmind-{}", &device_id[..device_id.len().min(8)]);
+        self.advertise(&instance_name, device_id, port, &[("nonce", nonce)])
+    }
+
+    /// 广播在线状态（后台同步任务运行期间）：已配对设备经 [`Self::watch_peers`]
+    /// 看到本机即结束对本机的退避。实例名与配对广播不同，TXT 带 `presence=1`，
+    /// 配对扫描（[`Self::discover_peers`]）忽略此类记录。
+    pub fn start_presence(&mut self, device_id: &str, port: u16) -> Result<()> {
+        let instance_name = format!("cardmind-sync-{}", &device_id[..device_id.len().min(8)]);
+        self.advertise(&instance_name, device_id, port, &[("presence", "1")])
+    }
+
+    /// 注册 mDNS 实例（先停旧广播）；TXT 固定含 device_id 与 port，另附 `extra`。
+    fn advertise(
+        &mut self,
+        instance_name: &str,
+        device_id: &str,
+        port: u16,
+        extra: &[(&str, &str)],
+    ) -> Result<()> {
         // 停止之前的广播（如有）
         self.stop_advertising()?;
 
-        let instance_name = format!("cardmind-{}", &device_id[..device_id.len().min(8)]);
         let hostname = format!("{}.local.", instance_name);
 
         // TXT 记录
         let mut properties = HashMap::new();
         properties.insert("device_id".to_string(), device_id.to_string());
         properties.insert("port".to_string(), port.to_string());
-        properties.insert("nonce".to_string(), nonce.to_string());
+        for (key, value) in extra {
+            properties.insert(key.to_string(), value.to_string());
+        }
 
         let service_info = ServiceInfo::new(
             SERVICE_TYPE,
-            &instance_name,
+            instance_name,
             &hostname,
             (), // 不放固定 IP，让 daemon 自动检测
             port,
@@ -86,7 +107,8 @@ impl DiscoveryService {
 
     /// 扫描局域网内的 CardMind 设备（阻塞，超时 3 秒）
     ///
-    /// 使用 `recv_async()` + `tokio::time::timeout` 实现超时扫描。
+    /// 使用 `recv_async()` + `tokio::time::timeout` 实现超时扫描。只返回配对
+    /// 广播；在线状态广播（`presence=1`）不是配对候选。
     pub async fn discover_peers(&self) -> Result<Vec<PeerInfo>> {
         let receiver = self
             .daemon
@@ -98,46 +120,12 @@ impl DiscoveryService {
         loop {
             match tokio::time::timeout(Duration::from_secs(3), receiver.recv_async()).await {
                 Ok(Ok(ServiceEvent::ServiceResolved(service))) => {
-                    // 从 TXT 记录提取 device_id
-                    let device_id = service
-                        .get_property_val_str("device_id")
-                        .unwrap_or_default()
-                        .to_string();
-
-                    // 没有 device_id 的忽略
-                    if device_id.is_empty() {
+                    if service.get_property_val_str("presence") == Some("1") {
                         continue;
                     }
-
-                    // 提取 IPv4 地址
-                    let ip = service
-                        .get_addresses_v4()
-                        .iter()
-                        .next()
-                        .map(|a| IpAddr::V4(*a))
-                        .or_else(|| {
-                            // fallback: 取第一个可用地址
-                            service
-                                .get_addresses()
-                                .iter()
-                                .next()
-                                .map(|scoped| scoped.to_ip_addr())
-                        })
-                        .map(|addr| addr.to_string())
-                        .unwrap_or_default();
-
-                    let port = service.get_port();
-                    let nonce = service
-                        .get_property_val_str("nonce")
-                        .unwrap_or_default()
-                        .to_string();
-
-                    peers.push(PeerInfo {
-                        device_id,
-                        ip,
-                        port,
-                        nonce,
-                    });
+                    if let Some(peer) = peer_info(&service) {
+                        peers.push(peer);
+                    }
                 }
                 Ok(Ok(
                     ServiceEvent::SearchStarted(_)
@@ -158,11 +146,90 @@ impl DiscoveryService {
 
         Ok(peers)
     }
+
+    /// 持续监听局域网内的 CardMind 设备（配对广播与在线状态广播都算）。
+    ///
+    /// 与 [`Self::discover_peers`] 不同，不设超时：后台同步任务据此在对端重新
+    /// 出现时立即结束其退避。
+    pub fn watch_peers(&self) -> Result<PeerWatch> {
+        let receiver = self
+            .daemon
+            .browse(SERVICE_TYPE)
+            .context("Failed to browse mDNS service")?;
+        Ok(PeerWatch { receiver })
+    }
+}
+
+/// 持续 mDNS 监听（见 [`DiscoveryService::watch_peers`]）。
+pub struct PeerWatch {
+    receiver: mdns_sd::Receiver<ServiceEvent>,
+}
+
+impl PeerWatch {
+    /// 下一个被解析到的对端；通道关闭（daemon 停止）时返回 None。
+    pub async fn next(&self) -> Option<PeerInfo> {
+        loop {
+            match self.receiver.recv_async().await {
+                Ok(ServiceEvent::ServiceResolved(service)) => {
+                    if let Some(peer) = peer_info(&service) {
+                        return Some(peer);
+                    }
+                }
+                Ok(_) => continue,
+                Err(_) => return None,
+            }
+        }
+    }
+}
+
+/// 从已解析的 mDNS 服务提取对端信息（TXT 无 device_id 的忽略）。
+fn peer_info(service: &ResolvedService) -> Option<PeerInfo> {
+    // 从 TXT 记录提取 device_id
+    let device_id = service
+        .get_property_val_str("device_id")
+        .unwrap_or_default()
+        .to_string();
+
+    // 没有 device_id 的忽略
+    if device_id.is_empty() {
+        return None;
+    }
+
+    // 提取 IPv4 地址
+    let ip = service
+        .get_addresses_v4()
+        .iter()
+        .next()
+        .map(|a| IpAddr::V4(*a))
+        .or_else(|| {
+            // fallback: 取第一个可用地址
+            service
+                .get_addresses()
+                .iter()
+                .next()
+                .map(|scoped| scoped.to_ip_addr())
+        })
+        .map(|addr| addr.to_string())
+        .unwrap_or_default();
+
+    let port = service.get_port();
+    let nonce = service
+        .get_property_val_str("nonce")
+        .unwrap_or_default()
+        .to_string();
+
+    Some(PeerInfo {
+        device_id,
+        ip,
+        port,
+        nonce,
+    })
 }
 
 impl Drop for DiscoveryService {
     fn drop(&mut self) {
-        // 析构时停止广播
+        // 析构时停止广播并结束 daemon 线程（监听通道随之关闭）
         let _ = self.stop_advertising();
+        let _ = self.daemon.shutdown();
     }
 }
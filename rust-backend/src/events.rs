@@ -0,0 +1,104 @@
+//! 同步服务事件流（UI 精确刷新）。
+//!
+//! 设计原则：
+//! - **类型化事件**：`SyncEvent` 区分笔记变更/回收站/彻底删除、设备在线状态、
+//!   同步进度与配对请求，UI 只刷新受影响的笔记，不再因 `receiver_content_revision`
+//!   变化整体重载列表。
+//! - **多方共享**：`EventHub` 以 Arc 在主服务、后台接收任务与后台同步任务之间共享，
+//!   经 tokio broadcast 通道分发；无订阅方时事件直接丢弃，不影响主流程。
+//! - **在线状态去重**：`PeerOnline` / `PeerOffline` 只在状态变化时发出。
+//! - **不含正文**：事件只携带 note id / device id / 计数，与调试日志同样不泄露内容。
+
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+use tokio::sync::broadcast;
+
+/// 事件通道容量：订阅方处理过慢、积压超出容量时收到 [`SyncEvent::Lagged`]。
+const EVENT_CHANNEL_CAPACITY: usize = 256;
+
+/// 同步服务事件（经 `api::sync_events` 推送给 UI）。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyncEvent {
+    /// 笔记新建或内容/元数据变更；`remote` = 来自对端同步。
+    NotesChanged { note_ids: Vec<String>, remote: bool },
+    /// 笔记移入回收站（软删除）。
+    NoteDeleted { note_id: String, remote: bool },
+    /// 笔记从回收站恢复。
+    NoteRestored { note_id: String, remote: bool },
+    /// 笔记彻底删除（入墓碑）。
+    NotesPurged { note_ids: Vec<String>, remote: bool },
+    /// 对端设备变为可达（同步成功、入站连接或 mDNS 发现）。
+    PeerOnline { peer_id: String },
+    /// 此前可达的对端同步失败。
+    PeerOffline { peer_id: String },
+    /// 一轮同步（周期同步或推送待办）开始。
+    SyncStarted,
+    /// 一轮同步结束（至少一台对端成功）。
+    SyncFinished {
+        ok_count: u32,
+        failed_count: u32,
+        pulled_count: u32,
+    },
+    /// 一轮同步失败（全部对端失败或刷新投影出错）。
+    SyncFailed { message: String },
+    /// 收到配对请求，等待用户确认。
+    PairingRequested {
+        device_id: String,
+        device_name: String,
+    },
+    /// 订阅方处理过慢，丢失了 `missed` 条事件：应整体重新加载。
+    Lagged { missed: u64 },
+}
+
+/// 事件分发中心（主服务、后台接收任务与后台同步任务共享）。
+pub struct EventHub {
+    sender: broadcast::Sender<SyncEvent>,
+    /// 当前视为在线的对端（在线/离线事件只在状态变化时发出）
+    online: Mutex<HashSet<String>>,
+}
+
+impl Default for EventHub {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventHub {
+    /// 创建事件中心（尚无订阅方）。
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        Self {
+            sender,
+            online: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// 订阅此后发出的事件。
+    pub fn subscribe(&self) -> broadcast::Receiver<SyncEvent> {
+        self.sender.subscribe()
+    }
+
+    /// 发出事件（无订阅方时丢弃）。
+    pub fn emit(&self, event: SyncEvent) {
+        let _ = self.sender.send(event);
+    }
+
+    /// 对端可达：此前不在线时发出 [`SyncEvent::PeerOnline`]。
+    pub fn peer_online(&self, peer_id: &str) {
+        if self.online.lock().unwrap().insert(peer_id.to_string()) {
+            self.emit(SyncEvent::PeerOnline {
+                peer_id: peer_id.to_string(),
+            });
+        }
+    }
+
+    /// 对端同步失败：此前在线时发出 [`SyncEvent::PeerOffline`]。
+    pub fn peer_offline(&self, peer_id: &str) {
+        if self.online.lock().unwrap().remove(peer_id) {
+            self.emit(SyncEvent::PeerOffline {
+                peer_id: peer_id.to_string(),
+            });
+        }
+    }
+}
@@ -41,7 +41,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.12.0";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1493639640;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -414880187;
 
 // Section: executor
 
@@ -692,6 +692,39 @@ fn wire__crate__api__begin_pairing_credential_with_advertising_impl(
         },
     )
 }
+fn wire__crate__api__check_search_query_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "check_search_query",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::search::SearchQueryError>((move || {
+                    let output_ok = crate::api::check_search_query(api_query)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__confirm_pairing_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -930,6 +963,73 @@ fn wire__crate__api__discover_peers_impl(
         },
     )
 }
+fn wire__crate__api__enable_encryption_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "enable_encryption",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_svc = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            >>::sse_decode(&mut deserializer);
+            let api_store = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NoteStore>,
+            >>::sse_decode(&mut deserializer);
+            let api_passphrase = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let mut api_svc_guard = None;
+                        let mut api_store_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![
+                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                        &api_svc, 0, false,
+                                    ),
+                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                        &api_store, 1, false,
+                                    ),
+                                ],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref()),
+                                1 => api_store_guard = Some(api_store.lockable_decode_sync_ref()),
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_svc_guard = api_svc_guard.unwrap();
+                        let api_store_guard = api_store_guard.unwrap();
+                        let output_ok = crate::api::enable_encryption(
+                            &*api_svc_guard,
+                            &*api_store_guard,
+                            api_passphrase,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__generate_note_id_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -1065,6 +1165,57 @@ fn wire__crate__api__get_backlinks_impl(
         },
     )
 }
+fn wire__crate__api__get_connected_components_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_connected_components",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_store = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NoteStore>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let mut api_store_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_store, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => api_store_guard = Some(api_store.lockable_decode_sync_ref()),
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_store_guard = api_store_guard.unwrap();
+                        let output_ok = crate::api::get_connected_components(&*api_store_guard)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__get_device_id_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -1165,7 +1316,7 @@ fn wire__crate__api__get_device_name_impl(
         },
     )
 }
-fn wire__crate__api__get_outgoing_links_impl(
+fn wire__crate__api__get_note_graph_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1173,7 +1324,7 @@ fn wire__crate__api__get_outgoing_links_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_outgoing_links",
+            debug_name: "get_note_graph",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1190,7 +1341,6 @@ fn wire__crate__api__get_outgoing_links_impl(
             let api_store = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NoteStore>,
             >>::sse_decode(&mut deserializer);
-            let api_note_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
@@ -1209,8 +1359,7 @@ fn wire__crate__api__get_outgoing_links_impl(
                             }
                         }
                         let api_store_guard = api_store_guard.unwrap();
-                        let output_ok =
-                            crate::api::get_outgoing_links(&*api_store_guard, api_note_id)?;
+                        let output_ok = crate::api::get_note_graph(&*api_store_guard)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1218,7 +1367,7 @@ fn wire__crate__api__get_outgoing_links_impl(
         },
     )
 }
-fn wire__crate__api__get_sync_allowed_impl(
+fn wire__crate__api__get_note_neighborhood_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1226,7 +1375,7 @@ fn wire__crate__api__get_sync_allowed_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_sync_allowed",
+            debug_name: "get_note_neighborhood",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1240,35 +1389,42 @@ fn wire__crate__api__get_sync_allowed_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_svc = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            let api_store = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NoteStore>,
             >>::sse_decode(&mut deserializer);
+            let api_note_id = <String>::sse_decode(&mut deserializer);
+            let api_depth = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let mut api_svc_guard = None;
-                    let decode_indices_ =
-                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                &api_svc, 0, false,
-                            ),
-                        ]);
-                    for i in decode_indices_ {
-                        match i {
-                            0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref()),
-                            _ => unreachable!(),
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let mut api_store_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_store, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => api_store_guard = Some(api_store.lockable_decode_sync_ref()),
+                                _ => unreachable!(),
+                            }
                         }
-                    }
-                    let api_svc_guard = api_svc_guard.unwrap();
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::get_sync_allowed(&*api_svc_guard))?;
-                    Ok(output_ok)
-                })())
+                        let api_store_guard = api_store_guard.unwrap();
+                        let output_ok = crate::api::get_note_neighborhood(
+                            &*api_store_guard,
+                            api_note_id,
+                            api_depth,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
             }
         },
     )
 }
-fn wire__crate__api__list_paired_devices_impl(
+fn wire__crate__api__get_orphan_notes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1276,7 +1432,7 @@ fn wire__crate__api__list_paired_devices_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "list_paired_devices",
+            debug_name: "get_orphan_notes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1311,7 +1467,7 @@ fn wire__crate__api__list_paired_devices_impl(
                             }
                         }
                         let api_store_guard = api_store_guard.unwrap();
-                        let output_ok = crate::api::list_paired_devices(&*api_store_guard)?;
+                        let output_ok = crate::api::get_orphan_notes(&*api_store_guard)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1319,7 +1475,7 @@ fn wire__crate__api__list_paired_devices_impl(
         },
     )
 }
-fn wire__crate__api__local_addrs_impl(
+fn wire__crate__api__get_outgoing_links_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1327,7 +1483,7 @@ fn wire__crate__api__local_addrs_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "local_addrs",
+            debug_name: "get_outgoing_links",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1341,34 +1497,38 @@ fn wire__crate__api__local_addrs_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_svc = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            let api_store = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NoteStore>,
             >>::sse_decode(&mut deserializer);
+            let api_note_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let mut api_svc_guard = None;
-                    let decode_indices_ =
-                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                &api_svc, 0, false,
-                            ),
-                        ]);
-                    for i in decode_indices_ {
-                        match i {
-                            0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref()),
-                            _ => unreachable!(),
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let mut api_store_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_store, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => api_store_guard = Some(api_store.lockable_decode_sync_ref()),
+                                _ => unreachable!(),
+                            }
                         }
-                    }
-                    let api_svc_guard = api_svc_guard.unwrap();
-                    let output_ok = Result::<_, ()>::Ok(crate::api::local_addrs(&*api_svc_guard))?;
-                    Ok(output_ok)
-                })())
+                        let api_store_guard = api_store_guard.unwrap();
+                        let output_ok =
+                            crate::api::get_outgoing_links(&*api_store_guard, api_note_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
             }
         },
     )
 }
-fn wire__crate__api__note_create_impl(
+fn wire__crate__api__get_shortest_path_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1376,7 +1536,7 @@ fn wire__crate__api__note_create_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "note_create",
+            debug_name: "get_shortest_path",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1390,31 +1550,31 @@ fn wire__crate__api__note_create_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_svc = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            let api_store = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NoteStore>,
             >>::sse_decode(&mut deserializer);
-            let api_id = <String>::sse_decode(&mut deserializer);
-            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_from = <String>::sse_decode(&mut deserializer);
+            let api_to = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let mut api_svc_guard = None;
+                        let mut api_store_guard = None;
                         let decode_indices_ =
                             flutter_rust_bridge::for_generated::lockable_compute_decode_order(
                                 vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_svc, 0, true,
+                                    &api_store, 0, false,
                                 )],
                             );
                         for i in decode_indices_ {
                             match i {
-                                0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref_mut()),
+                                0 => api_store_guard = Some(api_store.lockable_decode_sync_ref()),
                                 _ => unreachable!(),
                             }
                         }
-                        let mut api_svc_guard = api_svc_guard.unwrap();
+                        let api_store_guard = api_store_guard.unwrap();
                         let output_ok =
-                            crate::api::note_create(&mut *api_svc_guard, api_id, api_content)?;
+                            crate::api::get_shortest_path(&*api_store_guard, api_from, api_to)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1422,58 +1582,7 @@ fn wire__crate__api__note_create_impl(
         },
     )
 }
-fn wire__crate__api__note_export_all_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "note_export_all",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_svc = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
-            >>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let mut api_svc_guard = None;
-                        let decode_indices_ =
-                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_svc, 0, false,
-                                )],
-                            );
-                        for i in decode_indices_ {
-                            match i {
-                                0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref()),
-                                _ => unreachable!(),
-                            }
-                        }
-                        let api_svc_guard = api_svc_guard.unwrap();
-                        let output_ok = crate::api::note_export_all(&*api_svc_guard)?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
-        },
-    )
-}
-fn wire__crate__api__note_get_impl(
+fn wire__crate__api__get_sync_allowed_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1481,7 +1590,7 @@ fn wire__crate__api__note_get_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "note_get",
+            debug_name: "get_sync_allowed",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1498,7 +1607,6 @@ fn wire__crate__api__note_get_impl(
             let api_svc = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
             >>::sse_decode(&mut deserializer);
-            let api_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
@@ -1517,14 +1625,14 @@ fn wire__crate__api__note_get_impl(
                     }
                     let api_svc_guard = api_svc_guard.unwrap();
                     let output_ok =
-                        Result::<_, ()>::Ok(crate::api::note_get(&*api_svc_guard, api_id))?;
+                        Result::<_, ()>::Ok(crate::api::get_sync_allowed(&*api_svc_guard))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__note_import_all_impl(
+fn wire__crate__api__get_unlinked_mentions_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1532,7 +1640,7 @@ fn wire__crate__api__note_import_all_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "note_import_all",
+            debug_name: "get_unlinked_mentions",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1546,29 +1654,30 @@ fn wire__crate__api__note_import_all_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_svc = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            let api_store = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NoteStore>,
             >>::sse_decode(&mut deserializer);
-            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_note_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let mut api_svc_guard = None;
+                        let mut api_store_guard = None;
                         let decode_indices_ =
                             flutter_rust_bridge::for_generated::lockable_compute_decode_order(
                                 vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_svc, 0, true,
+                                    &api_store, 0, false,
                                 )],
                             );
                         for i in decode_indices_ {
                             match i {
-                                0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref_mut()),
+                                0 => api_store_guard = Some(api_store.lockable_decode_sync_ref()),
                                 _ => unreachable!(),
                             }
                         }
-                        let mut api_svc_guard = api_svc_guard.unwrap();
-                        let output_ok = crate::api::note_import_all(&mut *api_svc_guard, api_data)?;
+                        let api_store_guard = api_store_guard.unwrap();
+                        let output_ok =
+                            crate::api::get_unlinked_mentions(&*api_store_guard, api_note_id)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1576,7 +1685,7 @@ fn wire__crate__api__note_import_all_impl(
         },
     )
 }
-fn wire__crate__api__note_purge_impl(
+fn wire__crate__api__link_mention_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1584,7 +1693,7 @@ fn wire__crate__api__note_purge_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "note_purge",
+            debug_name: "link_mention",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1601,26 +1710,43 @@ fn wire__crate__api__note_purge_impl(
             let api_svc = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
             >>::sse_decode(&mut deserializer);
-            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_store = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NoteStore>,
+            >>::sse_decode(&mut deserializer);
+            let api_source_id = <String>::sse_decode(&mut deserializer);
+            let api_target_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
                         let mut api_svc_guard = None;
+                        let mut api_store_guard = None;
                         let decode_indices_ =
                             flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_svc, 0, true,
-                                )],
+                                vec![
+                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                        &api_svc, 0, true,
+                                    ),
+                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                        &api_store, 1, false,
+                                    ),
+                                ],
                             );
                         for i in decode_indices_ {
                             match i {
                                 0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref_mut()),
+                                1 => api_store_guard = Some(api_store.lockable_decode_sync_ref()),
                                 _ => unreachable!(),
                             }
                         }
                         let mut api_svc_guard = api_svc_guard.unwrap();
-                        let output_ok = crate::api::note_purge(&mut *api_svc_guard, api_id)?;
+                        let api_store_guard = api_store_guard.unwrap();
+                        let output_ok = crate::api::link_mention(
+                            &mut *api_svc_guard,
+                            &*api_store_guard,
+                            api_source_id,
+                            api_target_id,
+                        )?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1628,7 +1754,7 @@ fn wire__crate__api__note_purge_impl(
         },
     )
 }
-fn wire__crate__api__note_restore_impl(
+fn wire__crate__api__list_conflicts_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1636,7 +1762,7 @@ fn wire__crate__api__note_restore_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "note_restore",
+            debug_name: "list_conflicts",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1653,34 +1779,32 @@ fn wire__crate__api__note_restore_impl(
             let api_svc = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
             >>::sse_decode(&mut deserializer);
-            let api_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let mut api_svc_guard = None;
-                        let decode_indices_ =
-                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_svc, 0, true,
-                                )],
-                            );
-                        for i in decode_indices_ {
-                            match i {
-                                0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref_mut()),
-                                _ => unreachable!(),
-                            }
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_svc_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_svc, 0, false,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref()),
+                            _ => unreachable!(),
                         }
-                        let mut api_svc_guard = api_svc_guard.unwrap();
-                        let output_ok = crate::api::note_restore(&mut *api_svc_guard, api_id)?;
-                        Ok(output_ok)
-                    })(),
-                )
+                    }
+                    let api_svc_guard = api_svc_guard.unwrap();
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::list_conflicts(&*api_svc_guard))?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__note_soft_delete_impl(
+fn wire__crate__api__list_paired_devices_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1688,7 +1812,7 @@ fn wire__crate__api__note_soft_delete_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "note_soft_delete",
+            debug_name: "list_paired_devices",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1702,29 +1826,28 @@ fn wire__crate__api__note_soft_delete_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_svc = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            let api_store = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NoteStore>,
             >>::sse_decode(&mut deserializer);
-            let api_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let mut api_svc_guard = None;
+                        let mut api_store_guard = None;
                         let decode_indices_ =
                             flutter_rust_bridge::for_generated::lockable_compute_decode_order(
                                 vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_svc, 0, true,
+                                    &api_store, 0, false,
                                 )],
                             );
                         for i in decode_indices_ {
                             match i {
-                                0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref_mut()),
+                                0 => api_store_guard = Some(api_store.lockable_decode_sync_ref()),
                                 _ => unreachable!(),
                             }
                         }
-                        let mut api_svc_guard = api_svc_guard.unwrap();
-                        let output_ok = crate::api::note_soft_delete(&mut *api_svc_guard, api_id)?;
+                        let api_store_guard = api_store_guard.unwrap();
+                        let output_ok = crate::api::list_paired_devices(&*api_store_guard)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1732,7 +1855,7 @@ fn wire__crate__api__note_soft_delete_impl(
         },
     )
 }
-fn wire__crate__api__note_update_metadata_impl(
+fn wire__crate__api__list_revoked_devices_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1740,7 +1863,7 @@ fn wire__crate__api__note_update_metadata_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "note_update_metadata",
+            debug_name: "list_revoked_devices",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1754,34 +1877,28 @@ fn wire__crate__api__note_update_metadata_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_svc = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            let api_store = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NoteStore>,
             >>::sse_decode(&mut deserializer);
-            let api_note_id = <String>::sse_decode(&mut deserializer);
-            let api_tags = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let mut api_svc_guard = None;
+                        let mut api_store_guard = None;
                         let decode_indices_ =
                             flutter_rust_bridge::for_generated::lockable_compute_decode_order(
                                 vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_svc, 0, true,
+                                    &api_store, 0, false,
                                 )],
                             );
                         for i in decode_indices_ {
                             match i {
-                                0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref_mut()),
+                                0 => api_store_guard = Some(api_store.lockable_decode_sync_ref()),
                                 _ => unreachable!(),
                             }
                         }
-                        let mut api_svc_guard = api_svc_guard.unwrap();
-                        let output_ok = crate::api::note_update_metadata(
-                            &mut *api_svc_guard,
-                            api_note_id,
-                            api_tags,
-                        )?;
+                        let api_store_guard = api_store_guard.unwrap();
+                        let output_ok = crate::api::list_revoked_devices(&*api_store_guard)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1789,7 +1906,7 @@ fn wire__crate__api__note_update_metadata_impl(
         },
     )
 }
-fn wire__crate__api__parse_pairing_credential_impl(
+fn wire__crate__api__local_addrs_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1797,7 +1914,7 @@ fn wire__crate__api__parse_pairing_credential_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_pairing_credential",
+            debug_name: "local_addrs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1814,10 +1931,9 @@ fn wire__crate__api__parse_pairing_credential_impl(
             let api_svc = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
             >>::sse_decode(&mut deserializer);
-            let api_credential = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, crate::sync::PairingCredentialError>((move || {
+                transform_result_sse::<_, ()>((move || {
                     let mut api_svc_guard = None;
                     let decode_indices_ =
                         flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -1832,15 +1948,14 @@ fn wire__crate__api__parse_pairing_credential_impl(
                         }
                     }
                     let api_svc_guard = api_svc_guard.unwrap();
-                    let output_ok =
-                        crate::api::parse_pairing_credential(&*api_svc_guard, api_credential)?;
+                    let output_ok = Result::<_, ()>::Ok(crate::api::local_addrs(&*api_svc_guard))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__pending_sync_count_impl(
+fn wire__crate__api__merge_notes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1848,7 +1963,7 @@ fn wire__crate__api__pending_sync_count_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "pending_sync_count",
+            debug_name: "merge_notes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1865,32 +1980,51 @@ fn wire__crate__api__pending_sync_count_impl(
             let api_svc = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
             >>::sse_decode(&mut deserializer);
+            let api_store = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NoteStore>,
+            >>::sse_decode(&mut deserializer);
+            let api_source_id = <String>::sse_decode(&mut deserializer);
+            let api_into_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let mut api_svc_guard = None;
-                    let decode_indices_ =
-                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                &api_svc, 0, false,
-                            ),
-                        ]);
-                    for i in decode_indices_ {
-                        match i {
-                            0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref()),
-                            _ => unreachable!(),
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let mut api_svc_guard = None;
+                        let mut api_store_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![
+                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                        &api_svc, 0, true,
+                                    ),
+                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                        &api_store, 1, false,
+                                    ),
+                                ],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref_mut()),
+                                1 => api_store_guard = Some(api_store.lockable_decode_sync_ref()),
+                                _ => unreachable!(),
+                            }
                         }
-                    }
-                    let api_svc_guard = api_svc_guard.unwrap();
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::pending_sync_count(&*api_svc_guard))?;
-                    Ok(output_ok)
-                })())
+                        let mut api_svc_guard = api_svc_guard.unwrap();
+                        let api_store_guard = api_store_guard.unwrap();
+                        let output_ok = crate::api::merge_notes(
+                            &mut *api_svc_guard,
+                            &*api_store_guard,
+                            api_source_id,
+                            api_into_id,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
             }
         },
     )
 }
-fn wire__crate__api__purge_expired_trash_impl(
+fn wire__crate__api__note_content_at_version_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1898,7 +2032,7 @@ fn wire__crate__api__purge_expired_trash_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "purge_expired_trash",
+            debug_name: "note_content_at_version",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1915,7 +2049,8 @@ fn wire__crate__api__purge_expired_trash_impl(
             let api_svc = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
             >>::sse_decode(&mut deserializer);
-            let api_cutoff = <String>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_version = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
@@ -1924,18 +2059,21 @@ fn wire__crate__api__purge_expired_trash_impl(
                         let decode_indices_ =
                             flutter_rust_bridge::for_generated::lockable_compute_decode_order(
                                 vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_svc, 0, true,
+                                    &api_svc, 0, false,
                                 )],
                             );
                         for i in decode_indices_ {
                             match i {
-                                0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref_mut()),
+                                0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref()),
                                 _ => unreachable!(),
                             }
                         }
-                        let mut api_svc_guard = api_svc_guard.unwrap();
-                        let output_ok =
-                            crate::api::purge_expired_trash(&mut *api_svc_guard, api_cutoff)?;
+                        let api_svc_guard = api_svc_guard.unwrap();
+                        let output_ok = crate::api::note_content_at_version(
+                            &*api_svc_guard,
+                            api_id,
+                            api_version,
+                        )?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1943,7 +2081,744 @@ fn wire__crate__api__purge_expired_trash_impl(
         },
     )
 }
-fn wire__crate__api__push_pending_impl(
+fn wire__crate__api__note_create_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_create",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_svc = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            >>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let mut api_svc_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_svc, 0, true,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref_mut()),
+                                _ => unreachable!(),
+                            }
+                        }
+                        let mut api_svc_guard = api_svc_guard.unwrap();
+                        let output_ok =
+                            crate::api::note_create(&mut *api_svc_guard, api_id, api_content)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__note_export_all_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_export_all",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_svc = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let mut api_svc_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_svc, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref()),
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_svc_guard = api_svc_guard.unwrap();
+                        let output_ok = crate::api::note_export_all(&*api_svc_guard)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__note_get_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_get",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_svc = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            >>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_svc_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_svc, 0, false,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref()),
+                            _ => unreachable!(),
+                        }
+                    }
+                    let api_svc_guard = api_svc_guard.unwrap();
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::note_get(&*api_svc_guard, api_id))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__note_import_all_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_import_all",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_svc = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            >>::sse_decode(&mut deserializer);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let mut api_svc_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_svc, 0, true,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref_mut()),
+                                _ => unreachable!(),
+                            }
+                        }
+                        let mut api_svc_guard = api_svc_guard.unwrap();
+                        let output_ok = crate::api::note_import_all(&mut *api_svc_guard, api_data)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__note_purge_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_purge",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_svc = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            >>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let mut api_svc_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_svc, 0, true,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref_mut()),
+                                _ => unreachable!(),
+                            }
+                        }
+                        let mut api_svc_guard = api_svc_guard.unwrap();
+                        let output_ok = crate::api::note_purge(&mut *api_svc_guard, api_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__note_restore_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_restore",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_svc = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            >>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let mut api_svc_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_svc, 0, true,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref_mut()),
+                                _ => unreachable!(),
+                            }
+                        }
+                        let mut api_svc_guard = api_svc_guard.unwrap();
+                        let output_ok = crate::api::note_restore(&mut *api_svc_guard, api_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__note_restore_version_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_restore_version",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_svc = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            >>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_version = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let mut api_svc_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_svc, 0, true,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref_mut()),
+                                _ => unreachable!(),
+                            }
+                        }
+                        let mut api_svc_guard = api_svc_guard.unwrap();
+                        let output_ok = crate::api::note_restore_version(
+                            &mut *api_svc_guard,
+                            api_id,
+                            api_version,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__note_soft_delete_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_soft_delete",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_svc = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            >>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let mut api_svc_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_svc, 0, true,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref_mut()),
+                                _ => unreachable!(),
+                            }
+                        }
+                        let mut api_svc_guard = api_svc_guard.unwrap();
+                        let output_ok = crate::api::note_soft_delete(&mut *api_svc_guard, api_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__note_update_metadata_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_update_metadata",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_svc = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            >>::sse_decode(&mut deserializer);
+            let api_note_id = <String>::sse_decode(&mut deserializer);
+            let api_tags = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let mut api_svc_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_svc, 0, true,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref_mut()),
+                                _ => unreachable!(),
+                            }
+                        }
+                        let mut api_svc_guard = api_svc_guard.unwrap();
+                        let output_ok = crate::api::note_update_metadata(
+                            &mut *api_svc_guard,
+                            api_note_id,
+                            api_tags,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__note_versions_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_versions",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_svc = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            >>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let mut api_svc_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_svc, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref()),
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_svc_guard = api_svc_guard.unwrap();
+                        let output_ok = crate::api::note_versions(&*api_svc_guard, api_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__parse_pairing_credential_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_pairing_credential",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_svc = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            >>::sse_decode(&mut deserializer);
+            let api_credential = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::sync::PairingCredentialError>((move || {
+                    let mut api_svc_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_svc, 0, false,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref()),
+                            _ => unreachable!(),
+                        }
+                    }
+                    let api_svc_guard = api_svc_guard.unwrap();
+                    let output_ok =
+                        crate::api::parse_pairing_credential(&*api_svc_guard, api_credential)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__pending_sync_count_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pending_sync_count",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_svc = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_svc_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_svc, 0, false,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref()),
+                            _ => unreachable!(),
+                        }
+                    }
+                    let api_svc_guard = api_svc_guard.unwrap();
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::pending_sync_count(&*api_svc_guard))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__pending_sync_count_for_peer_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pending_sync_count_for_peer",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_svc = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            >>::sse_decode(&mut deserializer);
+            let api_peer_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_svc_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_svc, 0, false,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref()),
+                            _ => unreachable!(),
+                        }
+                    }
+                    let api_svc_guard = api_svc_guard.unwrap();
+                    let output_ok = Result::<_, ()>::Ok(crate::api::pending_sync_count_for_peer(
+                        &*api_svc_guard,
+                        api_peer_id,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__purge_expired_trash_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "purge_expired_trash",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_svc = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            >>::sse_decode(&mut deserializer);
+            let api_cutoff = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let mut api_svc_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_svc, 0, true,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref_mut()),
+                                _ => unreachable!(),
+                            }
+                        }
+                        let mut api_svc_guard = api_svc_guard.unwrap();
+                        let output_ok =
+                            crate::api::purge_expired_trash(&mut *api_svc_guard, api_cutoff)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__push_pending_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2110,24 +2985,263 @@ fn wire__crate__api__push_to_peer_impl(
                             );
                         for i in decode_indices_ {
                             match i {
-                                0 => {
-                                    api_svc_guard = Some(api_svc.lockable_decode_async_ref().await)
-                                }
+                                0 => {
+                                    api_svc_guard = Some(api_svc.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_svc_guard = api_svc_guard.unwrap();
+                        let output_ok =
+                            crate::api::push_to_peer(&*api_svc_guard, api_peer_id, api_ips).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__receiver_content_revision_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "receiver_content_revision",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_svc = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_svc_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_svc, 0, false,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref()),
+                            _ => unreachable!(),
+                        }
+                    }
+                    let api_svc_guard = api_svc_guard.unwrap();
+                    let output_ok = Result::<_, ()>::Ok(crate::api::receiver_content_revision(
+                        &*api_svc_guard,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__receiver_running_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "receiver_running",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_svc = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_svc_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_svc, 0, false,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref()),
+                            _ => unreachable!(),
+                        }
+                    }
+                    let api_svc_guard = api_svc_guard.unwrap();
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::receiver_running(&*api_svc_guard))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__remove_paired_device_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_paired_device",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_svc = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            >>::sse_decode(&mut deserializer);
+            let api_store = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NoteStore>,
+            >>::sse_decode(&mut deserializer);
+            let api_peer_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let mut api_svc_guard = None;
+                        let mut api_store_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![
+                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                        &api_svc, 0, false,
+                                    ),
+                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                        &api_store, 1, false,
+                                    ),
+                                ],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref()),
+                                1 => api_store_guard = Some(api_store.lockable_decode_sync_ref()),
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_svc_guard = api_svc_guard.unwrap();
+                        let api_store_guard = api_store_guard.unwrap();
+                        let output_ok = crate::api::remove_paired_device(
+                            &*api_svc_guard,
+                            &*api_store_guard,
+                            api_peer_id,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__rename_note_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rename_note",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_svc = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            >>::sse_decode(&mut deserializer);
+            let api_store = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NoteStore>,
+            >>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_new_title = <String>::sse_decode(&mut deserializer);
+            let api_rewrite_aliases = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let mut api_svc_guard = None;
+                        let mut api_store_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![
+                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                        &api_svc, 0, true,
+                                    ),
+                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                        &api_store, 1, false,
+                                    ),
+                                ],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref_mut()),
+                                1 => api_store_guard = Some(api_store.lockable_decode_sync_ref()),
                                 _ => unreachable!(),
                             }
                         }
-                        let api_svc_guard = api_svc_guard.unwrap();
-                        let output_ok =
-                            crate::api::push_to_peer(&*api_svc_guard, api_peer_id, api_ips).await?;
+                        let mut api_svc_guard = api_svc_guard.unwrap();
+                        let api_store_guard = api_store_guard.unwrap();
+                        let output_ok = crate::api::rename_note(
+                            &mut *api_svc_guard,
+                            &*api_store_guard,
+                            api_id,
+                            api_new_title,
+                            api_rewrite_aliases,
+                        )?;
                         Ok(output_ok)
-                    })()
-                    .await,
+                    })(),
                 )
             }
         },
     )
 }
-fn wire__crate__api__receiver_content_revision_impl(
+fn wire__crate__api__reset_sync_backoff_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2135,7 +3249,7 @@ fn wire__crate__api__receiver_content_revision_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "receiver_content_revision",
+            debug_name: "reset_sync_backoff",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2169,16 +3283,16 @@ fn wire__crate__api__receiver_content_revision_impl(
                         }
                     }
                     let api_svc_guard = api_svc_guard.unwrap();
-                    let output_ok = Result::<_, ()>::Ok(crate::api::receiver_content_revision(
-                        &*api_svc_guard,
-                    ))?;
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::reset_sync_backoff(&*api_svc_guard);
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__receiver_running_impl(
+fn wire__crate__api__resolve_conflict_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2186,7 +3300,7 @@ fn wire__crate__api__receiver_running_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "receiver_running",
+            debug_name: "resolve_conflict",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2203,32 +3317,39 @@ fn wire__crate__api__receiver_running_impl(
             let api_svc = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
             >>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_resolution = <crate::sync::ConflictResolution>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let mut api_svc_guard = None;
-                    let decode_indices_ =
-                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                &api_svc, 0, false,
-                            ),
-                        ]);
-                    for i in decode_indices_ {
-                        match i {
-                            0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref()),
-                            _ => unreachable!(),
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let mut api_svc_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_svc, 0, true,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref_mut()),
+                                _ => unreachable!(),
+                            }
                         }
-                    }
-                    let api_svc_guard = api_svc_guard.unwrap();
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::receiver_running(&*api_svc_guard))?;
-                    Ok(output_ok)
-                })())
+                        let mut api_svc_guard = api_svc_guard.unwrap();
+                        let output_ok = crate::api::resolve_conflict(
+                            &mut *api_svc_guard,
+                            api_id,
+                            api_resolution,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
             }
         },
     )
 }
-fn wire__crate__api__remove_paired_device_impl(
+fn wire__crate__api__revoke_device_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2236,7 +3357,7 @@ fn wire__crate__api__remove_paired_device_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_paired_device",
+            debug_name: "revoke_device",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2250,6 +3371,9 @@ fn wire__crate__api__remove_paired_device_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_svc = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            >>::sse_decode(&mut deserializer);
             let api_store = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NoteStore>,
             >>::sse_decode(&mut deserializer);
@@ -2258,22 +3382,33 @@ fn wire__crate__api__remove_paired_device_impl(
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
+                        let mut api_svc_guard = None;
                         let mut api_store_guard = None;
                         let decode_indices_ =
                             flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_store, 0, false,
-                                )],
+                                vec![
+                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                        &api_svc, 0, false,
+                                    ),
+                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                        &api_store, 1, false,
+                                    ),
+                                ],
                             );
                         for i in decode_indices_ {
                             match i {
-                                0 => api_store_guard = Some(api_store.lockable_decode_sync_ref()),
+                                0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref()),
+                                1 => api_store_guard = Some(api_store.lockable_decode_sync_ref()),
                                 _ => unreachable!(),
                             }
                         }
+                        let api_svc_guard = api_svc_guard.unwrap();
                         let api_store_guard = api_store_guard.unwrap();
-                        let output_ok =
-                            crate::api::remove_paired_device(&*api_store_guard, api_peer_id)?;
+                        let output_ok = crate::api::revoke_device(
+                            &*api_svc_guard,
+                            &*api_store_guard,
+                            api_peer_id,
+                        )?;
                         Ok(output_ok)
                     })(),
                 )
@@ -2560,6 +3695,118 @@ fn wire__crate__api__set_sync_allowed_impl(
         },
     )
 }
+fn wire__crate__api__set_tombstone_horizon_days_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_tombstone_horizon_days",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_svc = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            >>::sse_decode(&mut deserializer);
+            let api_days = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let mut api_svc_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_svc, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref()),
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_svc_guard = api_svc_guard.unwrap();
+                        let output_ok =
+                            crate::api::set_tombstone_horizon_days(&*api_svc_guard, api_days)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__set_transfer_limits_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_transfer_limits",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_svc = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            >>::sse_decode(&mut deserializer);
+            let api_max_record_bytes = <u32>::sse_decode(&mut deserializer);
+            let api_max_transfer_bytes = <u64>::sse_decode(&mut deserializer);
+            let api_max_buffered_bytes = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_svc_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_svc, 0, false,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => api_svc_guard = Some(api_svc.lockable_decode_sync_ref()),
+                            _ => unreachable!(),
+                        }
+                    }
+                    let api_svc_guard = api_svc_guard.unwrap();
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::set_transfer_limits(
+                            &*api_svc_guard,
+                            api_max_record_bytes,
+                            api_max_transfer_bytes,
+                            api_max_buffered_bytes,
+                        );
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__start_advertising_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -2613,13 +3860,82 @@ fn wire__crate__api__start_advertising_impl(
                             api_nonce,
                         )?;
                         Ok(output_ok)
-                    })(),
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__start_receiver_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_receiver",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_svc = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            >>::sse_decode(&mut deserializer);
+            let api_store = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NoteStore>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let mut api_svc_guard = None;
+                        let mut api_store_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![
+                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                        &api_svc, 0, false,
+                                    ),
+                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                        &api_store, 1, false,
+                                    ),
+                                ],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_svc_guard = Some(api_svc.lockable_decode_async_ref().await)
+                                }
+                                1 => {
+                                    api_store_guard =
+                                        Some(api_store.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_svc_guard = api_svc_guard.unwrap();
+                        let api_store_guard = api_store_guard.unwrap();
+                        let output_ok =
+                            crate::api::start_receiver(&*api_svc_guard, &*api_store_guard).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
                 )
             }
         },
     )
 }
-fn wire__crate__api__start_receiver_impl(
+fn wire__crate__api__start_sync_loop_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2627,7 +3943,7 @@ fn wire__crate__api__start_receiver_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "start_receiver",
+            debug_name: "start_sync_loop",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2679,7 +3995,7 @@ fn wire__crate__api__start_receiver_impl(
                         let api_svc_guard = api_svc_guard.unwrap();
                         let api_store_guard = api_store_guard.unwrap();
                         let output_ok =
-                            crate::api::start_receiver(&*api_svc_guard, &*api_store_guard).await?;
+                            crate::api::start_sync_loop(&*api_svc_guard, &*api_store_guard).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2797,6 +4113,60 @@ fn wire__crate__api__stop_receiver_impl(
         },
     )
 }
+fn wire__crate__api__stop_sync_loop_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_sync_loop",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_svc = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let mut api_svc_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_svc, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_svc_guard = Some(api_svc.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_svc_guard = api_svc_guard.unwrap();
+                        let output_ok = crate::api::stop_sync_loop(&*api_svc_guard).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__store_list_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -3005,6 +4375,64 @@ fn wire__crate__api__sync_discover_peers_impl(
         },
     )
 }
+fn wire__crate__api__sync_events_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_events",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_svc = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SyncService>,
+            >>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::events::SyncEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let mut api_svc_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_svc, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_svc_guard = Some(api_svc.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_svc_guard = api_svc_guard.unwrap();
+                        let output_ok = crate::api::sync_events(&*api_svc_guard, api_sink).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__sync_notes_to_store_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -3100,6 +4528,151 @@ fn wire__crate__api__sync_poll_interval_secs_impl(
         },
     )
 }
+fn wire__crate__api__vault_change_passphrase_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "vault_change_passphrase",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_old_passphrase = <String>::sse_decode(&mut deserializer);
+            let api_new_passphrase = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::vault_change_passphrase(
+                            api_path,
+                            api_old_passphrase,
+                            api_new_passphrase,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__vault_lock_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "vault_lock",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::vault_lock(api_path);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__vault_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "vault_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::vault_status(api_path))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__vault_unlock_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "vault_unlock",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_passphrase = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::vault_unlock(api_path, api_passphrase)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
 
 // Section: related_funcs
 
@@ -3181,6 +4754,16 @@ impl SseDecode
     }
 }
 
+impl SseDecode
+    for StreamSink<crate::events::SyncEvent, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
 impl SseDecode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3189,10 +4772,41 @@ impl SseDecode for String {
     }
 }
 
-impl SseDecode for bool {
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::sync::ConflictDetail {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_conflict = <crate::sync::NoteConflict>::sse_decode(deserializer);
+        let mut var_baseContent = <String>::sse_decode(deserializer);
+        let mut var_localContent = <String>::sse_decode(deserializer);
+        let mut var_remoteContent = <String>::sse_decode(deserializer);
+        let mut var_mergedContent = <String>::sse_decode(deserializer);
+        return crate::sync::ConflictDetail {
+            conflict: var_conflict,
+            base_content: var_baseContent,
+            local_content: var_localContent,
+            remote_content: var_remoteContent,
+            merged_content: var_mergedContent,
+        };
+    }
+}
+
+impl SseDecode for crate::sync::ConflictResolution {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::sync::ConflictResolution::KeepMerged,
+            1 => crate::sync::ConflictResolution::KeepLocal,
+            2 => crate::sync::ConflictResolution::KeepRemote,
+            _ => unreachable!("Invalid variant for ConflictResolution: {}", inner),
+        };
     }
 }
 
@@ -3202,10 +4816,44 @@ impl SseDecode for crate::sync::DevicePushResult {
         let mut var_peerId = <String>::sse_decode(deserializer);
         let mut var_ok = <bool>::sse_decode(deserializer);
         let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_deliveredCount = <u32>::sse_decode(deserializer);
+        let mut var_pendingCount = <u32>::sse_decode(deserializer);
         return crate::sync::DevicePushResult {
             peer_id: var_peerId,
             ok: var_ok,
             message: var_message,
+            delivered_count: var_deliveredCount,
+            pending_count: var_pendingCount,
+        };
+    }
+}
+
+impl SseDecode for crate::store::GraphEdge {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_source = <String>::sse_decode(deserializer);
+        let mut var_target = <String>::sse_decode(deserializer);
+        let mut var_alias = <String>::sse_decode(deserializer);
+        return crate::store::GraphEdge {
+            source: var_source,
+            target: var_target,
+            alias: var_alias,
+        };
+    }
+}
+
+impl SseDecode for crate::store::GraphNode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
+        let mut var_degree = <u32>::sse_decode(deserializer);
+        return crate::store::GraphNode {
+            id: var_id,
+            title: var_title,
+            tags: var_tags,
+            degree: var_degree,
         };
     }
 }
@@ -3230,12 +4878,18 @@ impl SseDecode for crate::store::LinkRow {
         let mut var_id = <String>::sse_decode(deserializer);
         let mut var_title = <String>::sse_decode(deserializer);
         let mut var_alias = <String>::sse_decode(deserializer);
+        let mut var_anchor = <String>::sse_decode(deserializer);
         let mut var_exists = <bool>::sse_decode(deserializer);
+        let mut var_resolved = <bool>::sse_decode(deserializer);
+        let mut var_ambiguous = <bool>::sse_decode(deserializer);
         return crate::store::LinkRow {
             id: var_id,
             title: var_title,
             alias: var_alias,
+            anchor: var_anchor,
             exists: var_exists,
+            resolved: var_resolved,
+            ambiguous: var_ambiguous,
         };
     }
 }
@@ -3252,6 +4906,18 @@ impl SseDecode for Vec<String> {
     }
 }
 
+impl SseDecode for Vec<crate::sync::ConflictDetail> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::sync::ConflictDetail>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::sync::DevicePushResult> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3264,6 +4930,30 @@ impl SseDecode for Vec<crate::sync::DevicePushResult> {
     }
 }
 
+impl SseDecode for Vec<crate::store::GraphEdge> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::store::GraphEdge>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::store::GraphNode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::store::GraphNode>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::store::LinkRow> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3276,6 +4966,30 @@ impl SseDecode for Vec<crate::store::LinkRow> {
     }
 }
 
+impl SseDecode for Vec<Vec<String>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<Vec<String>>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::store::MentionRow> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::store::MentionRow>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::store::NoteRow> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3288,6 +5002,18 @@ impl SseDecode for Vec<crate::store::NoteRow> {
     }
 }
 
+impl SseDecode for Vec<crate::sync::NoteVersion> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::sync::NoteVersion>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::store::PairedDeviceRow> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3336,6 +5062,68 @@ impl SseDecode for Vec<(String, Option<Vec<String>>)> {
     }
 }
 
+impl SseDecode for Vec<crate::store::RevokedDeviceRow> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::store::RevokedDeviceRow>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::store::MentionRow {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_snippet = <String>::sse_decode(deserializer);
+        let mut var_mentions = <u32>::sse_decode(deserializer);
+        return crate::store::MentionRow {
+            id: var_id,
+            title: var_title,
+            snippet: var_snippet,
+            mentions: var_mentions,
+        };
+    }
+}
+
+impl SseDecode for crate::sync::NoteConflict {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_noteId = <String>::sse_decode(deserializer);
+        let mut var_detectedAt = <String>::sse_decode(deserializer);
+        let mut var_localDevices = <Vec<String>>::sse_decode(deserializer);
+        let mut var_remoteDevices = <Vec<String>>::sse_decode(deserializer);
+        let mut var_localVersion = <Vec<String>>::sse_decode(deserializer);
+        let mut var_remoteVersion = <Vec<String>>::sse_decode(deserializer);
+        let mut var_baseVersion = <Vec<String>>::sse_decode(deserializer);
+        return crate::sync::NoteConflict {
+            note_id: var_noteId,
+            detected_at: var_detectedAt,
+            local_devices: var_localDevices,
+            remote_devices: var_remoteDevices,
+            local_version: var_localVersion,
+            remote_version: var_remoteVersion,
+            base_version: var_baseVersion,
+        };
+    }
+}
+
+impl SseDecode for crate::store::NoteGraph {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_nodes = <Vec<crate::store::GraphNode>>::sse_decode(deserializer);
+        let mut var_edges = <Vec<crate::store::GraphEdge>>::sse_decode(deserializer);
+        return crate::store::NoteGraph {
+            nodes: var_nodes,
+            edges: var_edges,
+        };
+    }
+}
+
 impl SseDecode for crate::store::NoteRow {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3356,6 +5144,22 @@ impl SseDecode for crate::store::NoteRow {
     }
 }
 
+impl SseDecode for crate::sync::NoteVersion {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_version = <String>::sse_decode(deserializer);
+        let mut var_timestamp = <String>::sse_decode(deserializer);
+        let mut var_deviceId = <String>::sse_decode(deserializer);
+        let mut var_changeLen = <u32>::sse_decode(deserializer);
+        return crate::sync::NoteVersion {
+            version: var_version,
+            timestamp: var_timestamp,
+            device_id: var_deviceId,
+            change_len: var_changeLen,
+        };
+    }
+}
+
 impl SseDecode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3534,6 +5338,51 @@ impl SseDecode for (String, Option<Vec<String>>) {
     }
 }
 
+impl SseDecode for crate::store::RevokedDeviceRow {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_peerId = <String>::sse_decode(deserializer);
+        let mut var_revokedBy = <String>::sse_decode(deserializer);
+        let mut var_revokedAt = <String>::sse_decode(deserializer);
+        let mut var_signature = <String>::sse_decode(deserializer);
+        return crate::store::RevokedDeviceRow {
+            peer_id: var_peerId,
+            revoked_by: var_revokedBy,
+            revoked_at: var_revokedAt,
+            signature: var_signature,
+        };
+    }
+}
+
+impl SseDecode for crate::search::SearchQueryError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::search::SearchQueryErrorKind>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_position = <u32>::sse_decode(deserializer);
+        return crate::search::SearchQueryError {
+            kind: var_kind,
+            message: var_message,
+            position: var_position,
+        };
+    }
+}
+
+impl SseDecode for crate::search::SearchQueryErrorKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::search::SearchQueryErrorKind::UnterminatedQuote,
+            1 => crate::search::SearchQueryErrorKind::MissingValue,
+            2 => crate::search::SearchQueryErrorKind::InvalidDate,
+            3 => crate::search::SearchQueryErrorKind::UnsupportedFilter,
+            4 => crate::search::SearchQueryErrorKind::DanglingOr,
+            _ => unreachable!("Invalid variant for SearchQueryErrorKind: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for crate::sync::SyncCycleResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3548,6 +5397,93 @@ impl SseDecode for crate::sync::SyncCycleResult {
     }
 }
 
+impl SseDecode for crate::events::SyncEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_noteIds = <Vec<String>>::sse_decode(deserializer);
+                let mut var_remote = <bool>::sse_decode(deserializer);
+                return crate::events::SyncEvent::NotesChanged {
+                    note_ids: var_noteIds,
+                    remote: var_remote,
+                };
+            }
+            1 => {
+                let mut var_noteId = <String>::sse_decode(deserializer);
+                let mut var_remote = <bool>::sse_decode(deserializer);
+                return crate::events::SyncEvent::NoteDeleted {
+                    note_id: var_noteId,
+                    remote: var_remote,
+                };
+            }
+            2 => {
+                let mut var_noteId = <String>::sse_decode(deserializer);
+                let mut var_remote = <bool>::sse_decode(deserializer);
+                return crate::events::SyncEvent::NoteRestored {
+                    note_id: var_noteId,
+                    remote: var_remote,
+                };
+            }
+            3 => {
+                let mut var_noteIds = <Vec<String>>::sse_decode(deserializer);
+                let mut var_remote = <bool>::sse_decode(deserializer);
+                return crate::events::SyncEvent::NotesPurged {
+                    note_ids: var_noteIds,
+                    remote: var_remote,
+                };
+            }
+            4 => {
+                let mut var_peerId = <String>::sse_decode(deserializer);
+                return crate::events::SyncEvent::PeerOnline {
+                    peer_id: var_peerId,
+                };
+            }
+            5 => {
+                let mut var_peerId = <String>::sse_decode(deserializer);
+                return crate::events::SyncEvent::PeerOffline {
+                    peer_id: var_peerId,
+                };
+            }
+            6 => {
+                return crate::events::SyncEvent::SyncStarted;
+            }
+            7 => {
+                let mut var_okCount = <u32>::sse_decode(deserializer);
+                let mut var_failedCount = <u32>::sse_decode(deserializer);
+                let mut var_pulledCount = <u32>::sse_decode(deserializer);
+                return crate::events::SyncEvent::SyncFinished {
+                    ok_count: var_okCount,
+                    failed_count: var_failedCount,
+                    pulled_count: var_pulledCount,
+                };
+            }
+            8 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::events::SyncEvent::SyncFailed {
+                    message: var_message,
+                };
+            }
+            9 => {
+                let mut var_deviceId = <String>::sse_decode(deserializer);
+                let mut var_deviceName = <String>::sse_decode(deserializer);
+                return crate::events::SyncEvent::PairingRequested {
+                    device_id: var_deviceId,
+                    device_name: var_deviceName,
+                };
+            }
+            10 => {
+                let mut var_missed = <u64>::sse_decode(deserializer);
+                return crate::events::SyncEvent::Lagged { missed: var_missed };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 impl SseDecode for u16 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3588,6 +5524,19 @@ impl SseDecode for usize {
     }
 }
 
+impl SseDecode for crate::vault::VaultStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::vault::VaultStatus::Disabled,
+            1 => crate::vault::VaultStatus::Locked,
+            2 => crate::vault::VaultStatus::Unlocked,
+            _ => unreachable!("Invalid variant for VaultStatus: {}", inner),
+        };
+    }
+}
+
 fn pde_ffi_dispatcher_primary_impl(
     func_id: i32,
     port: flutter_rust_bridge::for_generated::MessagePort,
@@ -3628,54 +5577,83 @@ fn pde_ffi_dispatcher_primary_impl(
             rust_vec_len,
             data_len,
         ),
-        12 => wire__crate__api__confirm_pairing_impl(port, ptr, rust_vec_len, data_len),
-        13 => wire__crate__api__create_note_store_impl(port, ptr, rust_vec_len, data_len),
-        14 => {
+        12 => wire__crate__api__check_search_query_impl(port, ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__confirm_pairing_impl(port, ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__create_note_store_impl(port, ptr, rust_vec_len, data_len),
+        15 => {
             wire__crate__api__create_persistent_sync_service_impl(port, ptr, rust_vec_len, data_len)
         }
-        15 => wire__crate__api__create_sync_service_impl(port, ptr, rust_vec_len, data_len),
-        16 => wire__crate__api__discover_peers_impl(port, ptr, rust_vec_len, data_len),
-        17 => wire__crate__api__generate_note_id_impl(port, ptr, rust_vec_len, data_len),
-        18 => wire__crate__api__get_all_tags_impl(port, ptr, rust_vec_len, data_len),
-        19 => wire__crate__api__get_backlinks_impl(port, ptr, rust_vec_len, data_len),
-        20 => wire__crate__api__get_device_id_impl(port, ptr, rust_vec_len, data_len),
-        21 => wire__crate__api__get_device_name_impl(port, ptr, rust_vec_len, data_len),
-        22 => wire__crate__api__get_outgoing_links_impl(port, ptr, rust_vec_len, data_len),
-        23 => wire__crate__api__get_sync_allowed_impl(port, ptr, rust_vec_len, data_len),
-        24 => wire__crate__api__list_paired_devices_impl(port, ptr, rust_vec_len, data_len),
-        25 => wire__crate__api__local_addrs_impl(port, ptr, rust_vec_len, data_len),
-        26 => wire__crate__api__note_create_impl(port, ptr, rust_vec_len, data_len),
-        27 => wire__crate__api__note_export_all_impl(port, ptr, rust_vec_len, data_len),
-        28 => wire__crate__api__note_get_impl(port, ptr, rust_vec_len, data_len),
-        29 => wire__crate__api__note_import_all_impl(port, ptr, rust_vec_len, data_len),
-        30 => wire__crate__api__note_purge_impl(port, ptr, rust_vec_len, data_len),
-        31 => wire__crate__api__note_restore_impl(port, ptr, rust_vec_len, data_len),
-        32 => wire__crate__api__note_soft_delete_impl(port, ptr, rust_vec_len, data_len),
-        33 => wire__crate__api__note_update_metadata_impl(port, ptr, rust_vec_len, data_len),
-        34 => wire__crate__api__parse_pairing_credential_impl(port, ptr, rust_vec_len, data_len),
-        35 => wire__crate__api__pending_sync_count_impl(port, ptr, rust_vec_len, data_len),
-        36 => wire__crate__api__purge_expired_trash_impl(port, ptr, rust_vec_len, data_len),
-        37 => wire__crate__api__push_pending_impl(port, ptr, rust_vec_len, data_len),
-        38 => wire__crate__api__push_to_devices_impl(port, ptr, rust_vec_len, data_len),
-        39 => wire__crate__api__push_to_peer_impl(port, ptr, rust_vec_len, data_len),
-        40 => wire__crate__api__receiver_content_revision_impl(port, ptr, rust_vec_len, data_len),
-        41 => wire__crate__api__receiver_running_impl(port, ptr, rust_vec_len, data_len),
-        42 => wire__crate__api__remove_paired_device_impl(port, ptr, rust_vec_len, data_len),
-        43 => wire__crate__api__run_sync_cycle_impl(port, ptr, rust_vec_len, data_len),
-        44 => wire__crate__api__search_by_tag_impl(port, ptr, rust_vec_len, data_len),
-        45 => wire__crate__api__search_notes_impl(port, ptr, rust_vec_len, data_len),
-        46 => wire__crate__api__set_device_name_impl(port, ptr, rust_vec_len, data_len),
-        47 => wire__crate__api__set_sync_allowed_impl(port, ptr, rust_vec_len, data_len),
-        48 => wire__crate__api__start_advertising_impl(port, ptr, rust_vec_len, data_len),
-        49 => wire__crate__api__start_receiver_impl(port, ptr, rust_vec_len, data_len),
-        50 => wire__crate__api__stop_pairing_advertising_impl(port, ptr, rust_vec_len, data_len),
-        51 => wire__crate__api__stop_receiver_impl(port, ptr, rust_vec_len, data_len),
-        52 => wire__crate__api__store_list_impl(port, ptr, rust_vec_len, data_len),
-        53 => wire__crate__api__store_search_impl(port, ptr, rust_vec_len, data_len),
-        54 => wire__crate__api__store_trash_list_impl(port, ptr, rust_vec_len, data_len),
-        55 => wire__crate__api__sync_discover_peers_impl(port, ptr, rust_vec_len, data_len),
-        56 => wire__crate__api__sync_notes_to_store_impl(port, ptr, rust_vec_len, data_len),
-        57 => wire__crate__api__sync_poll_interval_secs_impl(port, ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__create_sync_service_impl(port, ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__discover_peers_impl(port, ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__enable_encryption_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__generate_note_id_impl(port, ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__get_all_tags_impl(port, ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__get_backlinks_impl(port, ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__get_connected_components_impl(port, ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__get_device_id_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__get_device_name_impl(port, ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__get_note_graph_impl(port, ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__get_note_neighborhood_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__get_orphan_notes_impl(port, ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__get_outgoing_links_impl(port, ptr, rust_vec_len, data_len),
+        29 => wire__crate__api__get_shortest_path_impl(port, ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__get_sync_allowed_impl(port, ptr, rust_vec_len, data_len),
+        31 => wire__crate__api__get_unlinked_mentions_impl(port, ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__link_mention_impl(port, ptr, rust_vec_len, data_len),
+        33 => wire__crate__api__list_conflicts_impl(port, ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__list_paired_devices_impl(port, ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__list_revoked_devices_impl(port, ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__local_addrs_impl(port, ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__merge_notes_impl(port, ptr, rust_vec_len, data_len),
+        38 => wire__crate__api__note_content_at_version_impl(port, ptr, rust_vec_len, data_len),
+        39 => wire__crate__api__note_create_impl(port, ptr, rust_vec_len, data_len),
+        40 => wire__crate__api__note_export_all_impl(port, ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__note_get_impl(port, ptr, rust_vec_len, data_len),
+        42 => wire__crate__api__note_import_all_impl(port, ptr, rust_vec_len, data_len),
+        43 => wire__crate__api__note_purge_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__note_restore_impl(port, ptr, rust_vec_len, data_len),
+        45 => wire__crate__api__note_restore_version_impl(port, ptr, rust_vec_len, data_len),
+        46 => wire__crate__api__note_soft_delete_impl(port, ptr, rust_vec_len, data_len),
+        47 => wire__crate__api__note_update_metadata_impl(port, ptr, rust_vec_len, data_len),
+        48 => wire__crate__api__note_versions_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire__crate__api__parse_pairing_credential_impl(port, ptr, rust_vec_len, data_len),
+        50 => wire__crate__api__pending_sync_count_impl(port, ptr, rust_vec_len, data_len),
+        51 => wire__crate__api__pending_sync_count_for_peer_impl(port, ptr, rust_vec_len, data_len),
+        52 => wire__crate__api__purge_expired_trash_impl(port, ptr, rust_vec_len, data_len),
+        53 => wire__crate__api__push_pending_impl(port, ptr, rust_vec_len, data_len),
+        54 => wire__crate__api__push_to_devices_impl(port, ptr, rust_vec_len, data_len),
+        55 => wire__crate__api__push_to_peer_impl(port, ptr, rust_vec_len, data_len),
+        56 => wire__crate__api__receiver_content_revision_impl(port, ptr, rust_vec_len, data_len),
+        57 => wire__crate__api__receiver_running_impl(port, ptr, rust_vec_len, data_len),
+        58 => wire__crate__api__remove_paired_device_impl(port, ptr, rust_vec_len, data_len),
+        59 => wire__crate__api__rename_note_impl(port, ptr, rust_vec_len, data_len),
+        60 => wire__crate__api__reset_sync_backoff_impl(port, ptr, rust_vec_len, data_len),
+        61 => wire__crate__api__resolve_conflict_impl(port, ptr, rust_vec_len, data_len),
+        62 => wire__crate__api__revoke_device_impl(port, ptr, rust_vec_len, data_len),
+        63 => wire__crate__api__run_sync_cycle_impl(port, ptr, rust_vec_len, data_len),
+        64 => wire__crate__api__search_by_tag_impl(port, ptr, rust_vec_len, data_len),
+        65 => wire__crate__api__search_notes_impl(port, ptr, rust_vec_len, data_len),
+        66 => wire__crate__api__set_device_name_impl(port, ptr, rust_vec_len, data_len),
+        67 => wire__crate__api__set_sync_allowed_impl(port, ptr, rust_vec_len, data_len),
+        68 => wire__crate__api__set_tombstone_horizon_days_impl(port, ptr, rust_vec_len, data_len),
+        69 => wire__crate__api__set_transfer_limits_impl(port, ptr, rust_vec_len, data_len),
+        70 => wire__crate__api__start_advertising_impl(port, ptr, rust_vec_len, data_len),
+        71 => wire__crate__api__start_receiver_impl(port, ptr, rust_vec_len, data_len),
+        72 => wire__crate__api__start_sync_loop_impl(port, ptr, rust_vec_len, data_len),
+        73 => wire__crate__api__stop_pairing_advertising_impl(port, ptr, rust_vec_len, data_len),
+        74 => wire__crate__api__stop_receiver_impl(port, ptr, rust_vec_len, data_len),
+        75 => wire__crate__api__stop_sync_loop_impl(port, ptr, rust_vec_len, data_len),
+        76 => wire__crate__api__store_list_impl(port, ptr, rust_vec_len, data_len),
+        77 => wire__crate__api__store_search_impl(port, ptr, rust_vec_len, data_len),
+        78 => wire__crate__api__store_trash_list_impl(port, ptr, rust_vec_len, data_len),
+        79 => wire__crate__api__sync_discover_peers_impl(port, ptr, rust_vec_len, data_len),
+        80 => wire__crate__api__sync_events_impl(port, ptr, rust_vec_len, data_len),
+        81 => wire__crate__api__sync_notes_to_store_impl(port, ptr, rust_vec_len, data_len),
+        82 => wire__crate__api__sync_poll_interval_secs_impl(port, ptr, rust_vec_len, data_len),
+        83 => wire__crate__api__vault_change_passphrase_impl(port, ptr, rust_vec_len, data_len),
+        84 => wire__crate__api__vault_lock_impl(port, ptr, rust_vec_len, data_len),
+        85 => wire__crate__api__vault_status_impl(port, ptr, rust_vec_len, data_len),
+        86 => wire__crate__api__vault_unlock_impl(port, ptr, rust_vec_len, data_len),
         _ => unreachable!(),
     }
 }
@@ -3724,6 +5702,49 @@ impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<SyncService>> for SyncService
     }
 }
 
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::sync::ConflictDetail {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.conflict.into_into_dart().into_dart(),
+            self.base_content.into_into_dart().into_dart(),
+            self.local_content.into_into_dart().into_dart(),
+            self.remote_content.into_into_dart().into_dart(),
+            self.merged_content.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::sync::ConflictDetail {}
+impl flutter_rust_bridge::IntoIntoDart<crate::sync::ConflictDetail>
+    for crate::sync::ConflictDetail
+{
+    fn into_into_dart(self) -> crate::sync::ConflictDetail {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::sync::ConflictResolution {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::KeepMerged => 0.into_dart(),
+            Self::KeepLocal => 1.into_dart(),
+            Self::KeepRemote => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::sync::ConflictResolution
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::sync::ConflictResolution>
+    for crate::sync::ConflictResolution
+{
+    fn into_into_dart(self) -> crate::sync::ConflictResolution {
+        self
+    }
+}
 // Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::sync::DevicePushResult {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
@@ -3731,6 +5752,8 @@ impl flutter_rust_bridge::IntoDart for crate::sync::DevicePushResult {
             self.peer_id.into_into_dart().into_dart(),
             self.ok.into_into_dart().into_dart(),
             self.message.into_into_dart().into_dart(),
+            self.delivered_count.into_into_dart().into_dart(),
+            self.pending_count.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -3744,13 +5767,51 @@ impl flutter_rust_bridge::IntoIntoDart<crate::sync::DevicePushResult>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::store::GraphEdge {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.source.into_into_dart().into_dart(),
+            self.target.into_into_dart().into_dart(),
+            self.alias.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::store::GraphEdge {}
+impl flutter_rust_bridge::IntoIntoDart<crate::store::GraphEdge> for crate::store::GraphEdge {
+    fn into_into_dart(self) -> crate::store::GraphEdge {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::store::GraphNode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.degree.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::store::GraphNode {}
+impl flutter_rust_bridge::IntoIntoDart<crate::store::GraphNode> for crate::store::GraphNode {
+    fn into_into_dart(self) -> crate::store::GraphNode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::store::LinkRow {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
             self.id.into_into_dart().into_dart(),
             self.title.into_into_dart().into_dart(),
             self.alias.into_into_dart().into_dart(),
+            self.anchor.into_into_dart().into_dart(),
             self.exists.into_into_dart().into_dart(),
+            self.resolved.into_into_dart().into_dart(),
+            self.ambiguous.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -3762,6 +5823,61 @@ impl flutter_rust_bridge::IntoIntoDart<crate::store::LinkRow> for crate::store::
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::store::MentionRow {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.snippet.into_into_dart().into_dart(),
+            self.mentions.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::store::MentionRow {}
+impl flutter_rust_bridge::IntoIntoDart<crate::store::MentionRow> for crate::store::MentionRow {
+    fn into_into_dart(self) -> crate::store::MentionRow {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::sync::NoteConflict {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.note_id.into_into_dart().into_dart(),
+            self.detected_at.into_into_dart().into_dart(),
+            self.local_devices.into_into_dart().into_dart(),
+            self.remote_devices.into_into_dart().into_dart(),
+            self.local_version.into_into_dart().into_dart(),
+            self.remote_version.into_into_dart().into_dart(),
+            self.base_version.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::sync::NoteConflict {}
+impl flutter_rust_bridge::IntoIntoDart<crate::sync::NoteConflict> for crate::sync::NoteConflict {
+    fn into_into_dart(self) -> crate::sync::NoteConflict {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::store::NoteGraph {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.nodes.into_into_dart().into_dart(),
+            self.edges.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::store::NoteGraph {}
+impl flutter_rust_bridge::IntoIntoDart<crate::store::NoteGraph> for crate::store::NoteGraph {
+    fn into_into_dart(self) -> crate::store::NoteGraph {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::store::NoteRow {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -3782,6 +5898,24 @@ impl flutter_rust_bridge::IntoIntoDart<crate::store::NoteRow> for crate::store::
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::sync::NoteVersion {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.version.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+            self.device_id.into_into_dart().into_dart(),
+            self.change_len.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::sync::NoteVersion {}
+impl flutter_rust_bridge::IntoIntoDart<crate::sync::NoteVersion> for crate::sync::NoteVersion {
+    fn into_into_dart(self) -> crate::sync::NoteVersion {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::store::PairedDeviceRow {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -3966,6 +6100,75 @@ impl flutter_rust_bridge::IntoIntoDart<crate::discovery::PeerInfo> for crate::di
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::store::RevokedDeviceRow {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.peer_id.into_into_dart().into_dart(),
+            self.revoked_by.into_into_dart().into_dart(),
+            self.revoked_at.into_into_dart().into_dart(),
+            self.signature.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::store::RevokedDeviceRow
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::store::RevokedDeviceRow>
+    for crate::store::RevokedDeviceRow
+{
+    fn into_into_dart(self) -> crate::store::RevokedDeviceRow {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::search::SearchQueryError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.position.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::search::SearchQueryError
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::search::SearchQueryError>
+    for crate::search::SearchQueryError
+{
+    fn into_into_dart(self) -> crate::search::SearchQueryError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::search::SearchQueryErrorKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::UnterminatedQuote => 0.into_dart(),
+            Self::MissingValue => 1.into_dart(),
+            Self::InvalidDate => 2.into_dart(),
+            Self::UnsupportedFilter => 3.into_dart(),
+            Self::DanglingOr => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::search::SearchQueryErrorKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::search::SearchQueryErrorKind>
+    for crate::search::SearchQueryErrorKind
+{
+    fn into_into_dart(self) -> crate::search::SearchQueryErrorKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::sync::SyncCycleResult {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -3984,6 +6187,96 @@ impl flutter_rust_bridge::IntoIntoDart<crate::sync::SyncCycleResult>
         self
     }
 }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::events::SyncEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::events::SyncEvent::NotesChanged { note_ids, remote } => [
+                0.into_dart(),
+                note_ids.into_into_dart().into_dart(),
+                remote.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::events::SyncEvent::NoteDeleted { note_id, remote } => [
+                1.into_dart(),
+                note_id.into_into_dart().into_dart(),
+                remote.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::events::SyncEvent::NoteRestored { note_id, remote } => [
+                2.into_dart(),
+                note_id.into_into_dart().into_dart(),
+                remote.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::events::SyncEvent::NotesPurged { note_ids, remote } => [
+                3.into_dart(),
+                note_ids.into_into_dart().into_dart(),
+                remote.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::events::SyncEvent::PeerOnline { peer_id } => {
+                [4.into_dart(), peer_id.into_into_dart().into_dart()].into_dart()
+            }
+            crate::events::SyncEvent::PeerOffline { peer_id } => {
+                [5.into_dart(), peer_id.into_into_dart().into_dart()].into_dart()
+            }
+            crate::events::SyncEvent::SyncStarted => [6.into_dart()].into_dart(),
+            crate::events::SyncEvent::SyncFinished {
+                ok_count,
+                failed_count,
+                pulled_count,
+            } => [
+                7.into_dart(),
+                ok_count.into_into_dart().into_dart(),
+                failed_count.into_into_dart().into_dart(),
+                pulled_count.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::events::SyncEvent::SyncFailed { message } => {
+                [8.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::events::SyncEvent::PairingRequested {
+                device_id,
+                device_name,
+            } => [
+                9.into_dart(),
+                device_id.into_into_dart().into_dart(),
+                device_name.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::events::SyncEvent::Lagged { missed } => {
+                [10.into_dart(), missed.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::events::SyncEvent {}
+impl flutter_rust_bridge::IntoIntoDart<crate::events::SyncEvent> for crate::events::SyncEvent {
+    fn into_into_dart(self) -> crate::events::SyncEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::vault::VaultStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Disabled => 0.into_dart(),
+            Self::Locked => 1.into_dart(),
+            Self::Unlocked => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::vault::VaultStatus {}
+impl flutter_rust_bridge::IntoIntoDart<crate::vault::VaultStatus> for crate::vault::VaultStatus {
+    fn into_into_dart(self) -> crate::vault::VaultStatus {
+        self
+    }
+}
 
 impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4050,6 +6343,15 @@ impl SseEncode
     }
 }
 
+impl SseEncode
+    for StreamSink<crate::events::SyncEvent, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
 impl SseEncode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4064,12 +6366,61 @@ impl SseEncode for bool {
     }
 }
 
+impl SseEncode for crate::sync::ConflictDetail {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::sync::NoteConflict>::sse_encode(self.conflict, serializer);
+        <String>::sse_encode(self.base_content, serializer);
+        <String>::sse_encode(self.local_content, serializer);
+        <String>::sse_encode(self.remote_content, serializer);
+        <String>::sse_encode(self.merged_content, serializer);
+    }
+}
+
+impl SseEncode for crate::sync::ConflictResolution {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::sync::ConflictResolution::KeepMerged => 0,
+                crate::sync::ConflictResolution::KeepLocal => 1,
+                crate::sync::ConflictResolution::KeepRemote => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for crate::sync::DevicePushResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.peer_id, serializer);
         <bool>::sse_encode(self.ok, serializer);
         <String>::sse_encode(self.message, serializer);
+        <u32>::sse_encode(self.delivered_count, serializer);
+        <u32>::sse_encode(self.pending_count, serializer);
+    }
+}
+
+impl SseEncode for crate::store::GraphEdge {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.source, serializer);
+        <String>::sse_encode(self.target, serializer);
+        <String>::sse_encode(self.alias, serializer);
+    }
+}
+
+impl SseEncode for crate::store::GraphNode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <Vec<String>>::sse_encode(self.tags, serializer);
+        <u32>::sse_encode(self.degree, serializer);
     }
 }
 
@@ -4093,7 +6444,10 @@ impl SseEncode for crate::store::LinkRow {
         <String>::sse_encode(self.id, serializer);
         <String>::sse_encode(self.title, serializer);
         <String>::sse_encode(self.alias, serializer);
+        <String>::sse_encode(self.anchor, serializer);
         <bool>::sse_encode(self.exists, serializer);
+        <bool>::sse_encode(self.resolved, serializer);
+        <bool>::sse_encode(self.ambiguous, serializer);
     }
 }
 
@@ -4107,6 +6461,16 @@ impl SseEncode for Vec<String> {
     }
 }
 
+impl SseEncode for Vec<crate::sync::ConflictDetail> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::sync::ConflictDetail>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::sync::DevicePushResult> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4117,6 +6481,26 @@ impl SseEncode for Vec<crate::sync::DevicePushResult> {
     }
 }
 
+impl SseEncode for Vec<crate::store::GraphEdge> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::store::GraphEdge>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::store::GraphNode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::store::GraphNode>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::store::LinkRow> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4127,6 +6511,26 @@ impl SseEncode for Vec<crate::store::LinkRow> {
     }
 }
 
+impl SseEncode for Vec<Vec<String>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <Vec<String>>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::store::MentionRow> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::store::MentionRow>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::store::NoteRow> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4137,6 +6541,16 @@ impl SseEncode for Vec<crate::store::NoteRow> {
     }
 }
 
+impl SseEncode for Vec<crate::sync::NoteVersion> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::sync::NoteVersion>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::store::PairedDeviceRow> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4177,6 +6591,47 @@ impl SseEncode for Vec<(String, Option<Vec<String>>)> {
     }
 }
 
+impl SseEncode for Vec<crate::store::RevokedDeviceRow> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::store::RevokedDeviceRow>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::store::MentionRow {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <String>::sse_encode(self.snippet, serializer);
+        <u32>::sse_encode(self.mentions, serializer);
+    }
+}
+
+impl SseEncode for crate::sync::NoteConflict {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.note_id, serializer);
+        <String>::sse_encode(self.detected_at, serializer);
+        <Vec<String>>::sse_encode(self.local_devices, serializer);
+        <Vec<String>>::sse_encode(self.remote_devices, serializer);
+        <Vec<String>>::sse_encode(self.local_version, serializer);
+        <Vec<String>>::sse_encode(self.remote_version, serializer);
+        <Vec<String>>::sse_encode(self.base_version, serializer);
+    }
+}
+
+impl SseEncode for crate::store::NoteGraph {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::store::GraphNode>>::sse_encode(self.nodes, serializer);
+        <Vec<crate::store::GraphEdge>>::sse_encode(self.edges, serializer);
+    }
+}
+
 impl SseEncode for crate::store::NoteRow {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4189,6 +6644,16 @@ impl SseEncode for crate::store::NoteRow {
     }
 }
 
+impl SseEncode for crate::sync::NoteVersion {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.version, serializer);
+        <String>::sse_encode(self.timestamp, serializer);
+        <String>::sse_encode(self.device_id, serializer);
+        <u32>::sse_encode(self.change_len, serializer);
+    }
+}
+
 impl SseEncode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4323,6 +6788,44 @@ impl SseEncode for (String, Option<Vec<String>>) {
     }
 }
 
+impl SseEncode for crate::store::RevokedDeviceRow {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.peer_id, serializer);
+        <String>::sse_encode(self.revoked_by, serializer);
+        <String>::sse_encode(self.revoked_at, serializer);
+        <String>::sse_encode(self.signature, serializer);
+    }
+}
+
+impl SseEncode for crate::search::SearchQueryError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::search::SearchQueryErrorKind>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <u32>::sse_encode(self.position, serializer);
+    }
+}
+
+impl SseEncode for crate::search::SearchQueryErrorKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::search::SearchQueryErrorKind::UnterminatedQuote => 0,
+                crate::search::SearchQueryErrorKind::MissingValue => 1,
+                crate::search::SearchQueryErrorKind::InvalidDate => 2,
+                crate::search::SearchQueryErrorKind::UnsupportedFilter => 3,
+                crate::search::SearchQueryErrorKind::DanglingOr => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for crate::sync::SyncCycleResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4332,6 +6835,74 @@ impl SseEncode for crate::sync::SyncCycleResult {
     }
 }
 
+impl SseEncode for crate::events::SyncEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::events::SyncEvent::NotesChanged { note_ids, remote } => {
+                <i32>::sse_encode(0, serializer);
+                <Vec<String>>::sse_encode(note_ids, serializer);
+                <bool>::sse_encode(remote, serializer);
+            }
+            crate::events::SyncEvent::NoteDeleted { note_id, remote } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(note_id, serializer);
+                <bool>::sse_encode(remote, serializer);
+            }
+            crate::events::SyncEvent::NoteRestored { note_id, remote } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(note_id, serializer);
+                <bool>::sse_encode(remote, serializer);
+            }
+            crate::events::SyncEvent::NotesPurged { note_ids, remote } => {
+                <i32>::sse_encode(3, serializer);
+                <Vec<String>>::sse_encode(note_ids, serializer);
+                <bool>::sse_encode(remote, serializer);
+            }
+            crate::events::SyncEvent::PeerOnline { peer_id } => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(peer_id, serializer);
+            }
+            crate::events::SyncEvent::PeerOffline { peer_id } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(peer_id, serializer);
+            }
+            crate::events::SyncEvent::SyncStarted => {
+                <i32>::sse_encode(6, serializer);
+            }
+            crate::events::SyncEvent::SyncFinished {
+                ok_count,
+                failed_count,
+                pulled_count,
+            } => {
+                <i32>::sse_encode(7, serializer);
+                <u32>::sse_encode(ok_count, serializer);
+                <u32>::sse_encode(failed_count, serializer);
+                <u32>::sse_encode(pulled_count, serializer);
+            }
+            crate::events::SyncEvent::SyncFailed { message } => {
+                <i32>::sse_encode(8, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::events::SyncEvent::PairingRequested {
+                device_id,
+                device_name,
+            } => {
+                <i32>::sse_encode(9, serializer);
+                <String>::sse_encode(device_id, serializer);
+                <String>::sse_encode(device_name, serializer);
+            }
+            crate::events::SyncEvent::Lagged { missed } => {
+                <i32>::sse_encode(10, serializer);
+                <u64>::sse_encode(missed, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 impl SseEncode for u16 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4375,6 +6946,23 @@ impl SseEncode for usize {
     }
 }
 
+impl SseEncode for crate::vault::VaultStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::vault::VaultStatus::Disabled => 0,
+                crate::vault::VaultStatus::Locked => 1,
+                crate::vault::VaultStatus::Unlocked => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 #[cfg(not(target_family = "wasm"))]
 mod io {
     // This file is automatically generated, so please do not edit it.
@@ -1,6 +1,11 @@
 pub mod api;
+mod codec;
 pub mod debug_log;
 pub mod discovery;
+pub mod events;
 mod frb_generated; /* AUTO INJECTED BY flutter_rust_bridge. This line may not be accurate, and you can change it according to your needs. */
+pub mod search;
 pub mod store;
 pub mod sync;
+mod tokenizer;
+pub mod vault;
@@ -0,0 +1,330 @@
+//! 搜索查询语法（`NoteStore::search_notes` 的输入）。
+//!
+//! 语法：
+//! - `词`、`"精确短语"`：标题/正文/标签全文匹配（不带引号的词按前缀匹配，
+//!   带引号的短语精确匹配）；`-词` / `-"短语"` 排除
+//! - `tag:rust` / `-tag:draft`：按标签过滤（值可加引号：`tag:"两个 词"`）
+//! - `updated:>2026-01-01`（也支持 `>=`、`<`、`<=`，不带比较符 = 当天）：按更新日期
+//!   过滤，日期按本地时区划分（与界面显示一致）
+//! - `links-to:<id>`：链接到指定笔记；`in:trash`：只搜回收站
+//! - `a OR b`：任一满足；相邻各项之间为 AND（`x a OR b` = `x AND (a OR b)`）
+//!
+//! 解析只产出结构化查询（[`SearchQuery`]），SQL 编译在 `store.rs`。语法错误为
+//! 稳定的 [`SearchQueryError`]（kind + 出错位置），UI 可直接提示。
+
+use chrono::{Local, NaiveDate, TimeZone, Utc};
+
+/// 解析后的搜索查询：各组之间为 AND，组内各项为 OR。
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SearchQuery {
+    pub groups: Vec<Vec<SearchTerm>>,
+    /// 只搜回收站（`in:trash`）；默认只搜未删除的笔记
+    pub in_trash: bool,
+}
+
+/// 查询中的一项（`negated` = 以 `-` 排除）。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchTerm {
+    pub negated: bool,
+    pub filter: SearchFilter,
+}
+
+/// 单项条件。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchFilter {
+    /// 全文匹配（不带引号的词，按前缀匹配）
+    Text(String),
+    /// 全文精确匹配（带引号的短语）
+    Phrase(String),
+    /// 带有该标签
+    Tag(String),
+    /// 更新时间落在 `[from, until)`（RFC 3339 UTC 时刻，由本地日期的零点换算；
+    /// None = 不限）
+    Updated {
+        from: Option<String>,
+        until: Option<String>,
+    },
+    /// 正文链接到该笔记 id
+    LinksTo(String),
+}
+
+/// 搜索语法错误分类（过 FRB；Dart 侧按 kind 映射中文文案）。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchQueryErrorKind {
+    /// 引号未闭合
+    UnterminatedQuote,
+    /// 过滤器或 `-` 后缺少值（如 `tag:`）
+    MissingValue,
+    /// 日期无效（应为 `YYYY-MM-DD`）
+    InvalidDate,
+    /// 不支持的过滤值或用法（如 `in:` 只支持 `trash`）
+    UnsupportedFilter,
+    /// `OR` 两侧缺少搜索项
+    DanglingOr,
+}
+
+/// 搜索语法错误（过 FRB；携带稳定 kind、技术细节 message 与出错位置）。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchQueryError {
+    pub kind: SearchQueryErrorKind,
+    pub message: String,
+    /// 出错位置：查询字符串中的字符下标
+    pub position: u32,
+}
+
+impl std::fmt::Display for SearchQueryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} at {}: {}",
+            kind_label(&self.kind),
+            self.position,
+            self.message
+        )
+    }
+}
+
+impl std::error::Error for SearchQueryError {}
+
+/// 错误 kind 的稳定标签（Display 用）。
+fn kind_label(kind: &SearchQueryErrorKind) -> &'static str {
+    match kind {
+        SearchQueryErrorKind::UnterminatedQuote => "unterminatedQuote",
+        SearchQueryErrorKind::MissingValue => "missingValue",
+        SearchQueryErrorKind::InvalidDate => "invalidDate",
+        SearchQueryErrorKind::UnsupportedFilter => "unsupportedFilter",
+        SearchQueryErrorKind::DanglingOr => "danglingOr",
+    }
+}
+
+fn error(kind: SearchQueryErrorKind, position: usize, message: String) -> SearchQueryError {
+    SearchQueryError {
+        kind,
+        message,
+        position: position as u32,
+    }
+}
+
+/// 词法单元：`OR` 运算符，或一项（原文、是否带引号、是否排除）。
+enum Token {
+    Or(usize),
+    Term {
+        position: usize,
+        negated: bool,
+        text: String,
+        quoted: bool,
+    },
+}
+
+/// 解析搜索查询。空白查询 = 无条件（列出全部笔记）。
+pub fn parse_query(input: &str) -> Result<SearchQuery, SearchQueryError> {
+    let mut query = SearchQuery::default();
+    let mut pending_or: Option<usize> = None;
+    for token in tokenize(input)? {
+        let (position, negated, text, quoted) = match token {
+            Token::Or(position) => {
+                if query.groups.is_empty() || pending_or.is_some() {
+                    return Err(error(
+                        SearchQueryErrorKind::DanglingOr,
+                        position,
+                        "OR must join two terms".to_string(),
+                    ));
+                }
+                pending_or = Some(position);
+                continue;
+            }
+            Token::Term {
+                position,
+                negated,
+                text,
+                quoted,
+            } => (position, negated, text, quoted),
+        };
+        let or_operand = pending_or.take().is_some();
+        let filter = if quoted {
+            SearchFilter::Phrase(text)
+        } else {
+            match parse_filter(position, &text)? {
+                Some(filter) => filter,
+                None => {
+                    // in:trash：作用于整条查询
+                    if or_operand {
+                        return Err(error(
+                            SearchQueryErrorKind::UnsupportedFilter,
+                            position,
+                            "in:trash cannot be combined with OR".to_string(),
+                        ));
+                    }
+                    query.in_trash = !negated;
+                    continue;
+                }
+            }
+        };
+        let term = SearchTerm { negated, filter };
+        match query.groups.last_mut() {
+            Some(group) if or_operand => group.push(term),
+            _ => query.groups.push(vec![term]),
+        }
+    }
+    if let Some(position) = pending_or {
+        return Err(error(
+            SearchQueryErrorKind::DanglingOr,
+            position,
+            "OR must join two terms".to_string(),
+        ));
+    }
+    Ok(query)
+}
+
+/// 未加引号的一项：识别 `tag:` / `updated:` / `links-to:` / `in:` 前缀，其余为
+/// 全文词。`in:trash` 返回 None（由调用方设置全局标志）。
+fn parse_filter(position: usize, text: &str) -> Result<Option<SearchFilter>, SearchQueryError> {
+    let Some((prefix, value)) = text.split_once(':') else {
+        return Ok(Some(SearchFilter::Text(text.to_string())));
+    };
+    let prefix = prefix.to_lowercase();
+    if !matches!(prefix.as_str(), "tag" | "updated" | "links-to" | "in") {
+        return Ok(Some(SearchFilter::Text(text.to_string())));
+    }
+    if value.is_empty() {
+        return Err(error(
+            SearchQueryErrorKind::MissingValue,
+            position,
+            format!("missing value after {prefix}:"),
+        ));
+    }
+    let filter = match prefix.as_str() {
+        "tag" => SearchFilter::Tag(value.to_string()),
+        "links-to" => SearchFilter::LinksTo(value.to_string()),
+        "updated" => parse_updated(position, value)?,
+        _ if value.eq_ignore_ascii_case("trash") => return Ok(None),
+        _ => {
+            return Err(error(
+                SearchQueryErrorKind::UnsupportedFilter,
+                position,
+                format!("unsupported in: value: {value}"),
+            ))
+        }
+    };
+    Ok(Some(filter))
+}
+
+/// `updated:` 的值：可选比较符 + `YYYY-MM-DD`，换算为半开时间区间（本地日期
+/// 零点对应的 UTC 时刻，与投影中的 `updated_at` 直接比较）。
+fn parse_updated(position: usize, value: &str) -> Result<SearchFilter, SearchQueryError> {
+    let (op, date) = ["<=", ">=", "<", ">", "="]
+        .iter()
+        .find_map(|op| value.strip_prefix(op).map(|rest| (*op, rest)))
+        .unwrap_or(("=", value));
+    let invalid = || {
+        error(
+            SearchQueryErrorKind::InvalidDate,
+            position,
+            format!("invalid date: {date} (expected YYYY-MM-DD)"),
+        )
+    };
+    let day = NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| invalid())?;
+    let next = day.succ_opt().ok_or_else(invalid)?;
+    let (day, next) = (local_day_start(day), local_day_start(next));
+    let (from, until) = match op {
+        ">" => (Some(next), None),
+        ">=" => (Some(day), None),
+        "<" => (None, Some(day)),
+        "<=" => (None, Some(next)),
+        _ => (Some(day), Some(next)),
+    };
+    Ok(SearchFilter::Updated { from, until })
+}
+
+/// 本地日期零点的 UTC 时刻（零点落在夏令时跳变中时顺延一小时）。
+fn local_day_start(day: NaiveDate) -> String {
+    let midnight = day.and_time(chrono::NaiveTime::MIN);
+    let start = Local
+        .from_local_datetime(&midnight)
+        .earliest()
+        .or_else(|| {
+            Local
+                .from_local_datetime(&(midnight + chrono::Duration::hours(1)))
+                .earliest()
+        })
+        .unwrap_or_else(|| Local.from_utc_datetime(&midnight));
+    start.with_timezone(&Utc).to_rfc3339()
+}
+
+/// 按空白切分：引号内为一个整体；`-` 前缀表示排除；未加引号的 `OR` 为运算符。
+fn tokenize(input: &str) -> Result<Vec<Token>, SearchQueryError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i].is_whitespace() {
+            i += 1;
+            continue;
+        }
+        let position = i;
+        let negated = chars[i] == '-';
+        if negated {
+            i += 1;
+            if i == chars.len() || chars[i].is_whitespace() {
+                return Err(error(
+                    SearchQueryErrorKind::MissingValue,
+                    position,
+                    "missing term after -".to_string(),
+                ));
+            }
+        }
+        let mut text = String::new();
+        let mut quoted = false;
+        while i < chars.len() && !chars[i].is_whitespace() {
+            if chars[i] == '"' {
+                // 只支持整项加引号（`"短语"`）或过滤值加引号（`tag:"两个 词"`）：
+                // 引号不能从词中间开始，闭合后也不能紧跟其他字符
+                if !text.is_empty() && !text.ends_with(':') {
+                    return Err(error(
+                        SearchQueryErrorKind::UnsupportedFilter,
+                        i,
+                        "quote must start a term or a filter value".to_string(),
+                    ));
+                }
+                let Some(close) = chars[i + 1..].iter().position(|&c| c == '"') else {
+                    return Err(error(
+                        SearchQueryErrorKind::UnterminatedQuote,
+                        i,
+                        "unterminated quote".to_string(),
+                    ));
+                };
+                quoted = text.is_empty();
+                text.extend(&chars[i + 1..i + 1 + close]);
+                i += close + 2;
+                if i < chars.len() && !chars[i].is_whitespace() {
+                    return Err(error(
+                        SearchQueryErrorKind::UnsupportedFilter,
+                        i,
+                        "unexpected text after closing quote".to_string(),
+                    ));
+                }
+                continue;
+            }
+            text.push(chars[i]);
+            i += 1;
+        }
+        if text.is_empty() {
+            return Err(error(
+                SearchQueryErrorKind::MissingValue,
+                position,
+                "empty phrase".to_string(),
+            ));
+        }
+        if !negated && !quoted && text == "OR" {
+            tokens.push(Token::Or(position));
+        } else {
+            tokens.push(Token::Term {
+                position,
+                negated,
+                text,
+                quoted,
+            });
+        }
+    }
+    Ok(tokens)
+}
@@ -1,18 +1,31 @@
-use anyhow::Result;
-use chrono::Utc;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
 use rusqlite::Connection;
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
-use crate::sync::NoteCrdt;
+use crate::codec::{push_opt_str, push_str, take_opt_str, take_str, take_u32};
+use crate::search::{self, SearchFilter, SearchQuery};
+use crate::sync::{parse_link_refs, split_link_anchor, NoteCrdt};
+use crate::tokenizer;
+use crate::vault::{self, VaultKey};
 
 /// SQLite 读投影 — 缓存 NoteCrdt 的扁平化视图
 ///
 /// 连接以 `Arc<Mutex<Connection>>` 共享：`Clone` 复制同一连接句柄（同一数据库），
 /// 供后台接收任务（任务 O continuous receiver）与主服务并发写入投影。
+///
+/// 静态加密模式（数据目录启用 vault）：投影放在内存中（启动时由
+/// `sync_notes_to_store` 从 CRDT 重建），唯一无法重建的 `paired_devices` 表
+/// 与 `revoked_devices` 表每次变更后整体加密写入旁路文件（[`sealed_devices_path`]）。
 #[derive(Clone)]
 pub struct NoteStore {
     conn: Arc<Mutex<Connection>>,
+    /// 数据库文件路径（`:memory:` = None）
+    path: Option<PathBuf>,
+    /// 静态加密数据密钥（Some = 内存投影 + 加密配对设备表）
+    sealed: Arc<Mutex<Option<VaultKey>>>,
 }
 
 /// 笔记的只读行（从 SQLite 反查）
@@ -36,8 +49,55 @@ pub struct LinkRow {
     pub title: String,
     /// 源正文里写的显示名
     pub alias: String,
+    /// 目标笔记内的锚点：`#标题` 或 `^块 id`（空 = 整篇笔记）
+    pub anchor: String,
     /// 对端笔记是否存在（false = 悬空链接）
     pub exists: bool,
+    /// 链接目标是否已解析为笔记 id（`[[标题]]` 尚无同名笔记 = false，
+    /// 此时 `id` 为正文里写的目标原文）
+    pub resolved: bool,
+    /// 标题链接有多篇同名笔记（解析结果固定为先解析到的一篇，UI 可提示改用 id 链接）
+    pub ambiguous: bool,
+}
+
+/// 知识图谱节点（未删除的笔记，FRB 可序列化）
+#[derive(Debug, Clone)]
+pub struct GraphNode {
+    pub id: String,
+    pub title: String,
+    pub tags: Vec<String>,
+    /// 度数：与之相连的边数（出边 + 入边，全图口径）
+    pub degree: u32,
+}
+
+/// 知识图谱边：source 正文链接到 target（同一对笔记的多条链接合并为一条；
+/// 悬空链接与指向回收站笔记的链接不计）
+#[derive(Debug, Clone)]
+pub struct GraphEdge {
+    pub source: String,
+    pub target: String,
+    /// 第一条链接的显示名
+    pub alias: String,
+}
+
+/// 知识图谱（全图或局部；节点按 id、边按 `(source, target)` 排序）
+#[derive(Debug, Clone, Default)]
+pub struct NoteGraph {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+}
+
+/// 未链接提及行（[`NoteStore::unlinked_mentions`] 结果，FRB 可序列化）
+#[derive(Debug)]
+pub struct MentionRow {
+    /// 提及所在笔记 id
+    pub id: String,
+    /// 提及所在笔记标题
+    pub title: String,
+    /// 第一处提及的上下文
+    pub snippet: String,
+    /// 该笔记中未加链接的提及次数
+    pub mentions: u32,
 }
 
 /// 配对设备行（paired_devices 表，FRB 可序列化）
@@ -53,72 +113,141 @@ pub struct PairedDeviceRow {
     pub paired_at: String,
 }
 
+/// 设备撤销记录（revoked_devices 表，FRB 可序列化）。
+///
+/// 由撤销方设备用 iroh 身份密钥签名，随同步会话传播给其他设备；被撤销的
+/// node id 之后发起的同步一律拒绝。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevokedDeviceRow {
+    /// 被撤销设备的 iroh node id
+    pub peer_id: String,
+    /// 撤销方设备的 iroh node id（签名公钥）
+    pub revoked_by: String,
+    /// 撤销时间（ISO8601）
+    pub revoked_at: String,
+    /// 撤销方对 `(peer_id, revoked_by, revoked_at)` 的 Ed25519 签名（hex）
+    pub signature: String,
+}
+
 impl NoteStore {
     /// 创建/打开 SQLite 数据库，自动建表
     pub fn new(path: &str) -> Result<Self> {
-        let conn = Connection::open(path)?;
-        conn.execute_batch(
-            "CREATE TABLE IF NOT EXISTS notes (
-                id TEXT PRIMARY KEY,
-                title TEXT NOT NULL,
-                content TEXT NOT NULL,
-                tags TEXT NOT NULL DEFAULT '',
-                created_at TEXT NOT NULL,
-                updated_at TEXT NOT NULL,
-                deleted_at TEXT NULL
-            );
-            CREATE TABLE IF NOT EXISTS links (
-                source_id TEXT NOT NULL,
-                target_id TEXT NOT NULL,
-                alias     TEXT NOT NULL DEFAULT '',
-                PRIMARY KEY (source_id, target_id)
-            );
-            CREATE TABLE IF NOT EXISTS paired_devices (
-                peer_id   TEXT PRIMARY KEY,
-                name      TEXT NOT NULL,
-                last_seen TEXT NULL,
-                paired_at TEXT NOT NULL
-            );
-            CREATE VIRTUAL TABLE IF NOT EXISTS notes_fts USING fts5(
-                title, content, tags,
-                content='notes', content_rowid='rowid',
-                tokenize='trigram'
-            );
-            CREATE TRIGGER IF NOT EXISTS notes_fts_ai AFTER INSERT ON notes BEGIN
-                INSERT INTO notes_fts(rowid, title, content, tags)
-                VALUES (new.rowid, new.title, new.content, new.tags);
-            END;
-            CREATE TRIGGER IF NOT EXISTS notes_fts_ad AFTER DELETE ON notes BEGIN
-                INSERT INTO notes_fts(notes_fts, rowid, title, content, tags)
-                VALUES ('delete', old.rowid, old.title, old.content, old.tags);
-            END;
-            CREATE TRIGGER IF NOT EXISTS notes_fts_au AFTER UPDATE ON notes BEGIN
-                INSERT INTO notes_fts(notes_fts, rowid, title, content, tags)
-                VALUES ('delete', old.rowid, old.title, old.content, old.tags);
-                INSERT INTO notes_fts(rowid, title, content, tags)
-                VALUES (new.rowid, new.title, new.content, new.tags);
-            END;",
-        )?;
-        // 迁移已有库：旧 notes 表没有 deleted_at 列时补列（SQLite 无 IF NOT EXISTS for column）。
-        let has_deleted_at = {
-            let mut stmt = conn.prepare("PRAGMA table_info(notes)")?;
-            let columns: Vec<String> = stmt
-                .query_map([], |row| row.get(1))?
-                .collect::<std::result::Result<Vec<_>, _>>()?;
-            columns.iter().any(|name| name == "deleted_at")
-        };
-        if !has_deleted_at {
-            conn.execute_batch("ALTER TABLE notes ADD COLUMN deleted_at TEXT NULL;")?;
-            // 旧库的既有行不在刚创建的 notes_fts 索引中；若不重建，之后任何
-            // UPDATE notes（如软删除的 deleted_at 标记）都会触发 FTS 触发器报
-            // "Content in the virtual table is corrupt"。重建使索引与 notes 一致。
-            conn.execute_batch("INSERT INTO notes_fts(notes_fts) VALUES('rebuild');")?;
-        }
+        let conn = open_connection(path)?;
         Ok(Self {
             conn: Arc::new(Mutex::new(conn)),
+            path: (path != ":memory:").then(|| PathBuf::from(path)),
+            sealed: Arc::new(Mutex::new(None)),
         })
     }
 
+    /// 按数据目录的加密状态打开投影（FRB `create_note_store` 入口）。
+    ///
+    /// - 未启用加密 → 同 [`new`](Self::new)
+    /// - 已启用且已解锁 → 内存投影 + 从加密旁路文件载入配对设备
+    /// - 启用中断（`vault.pending`）→ 同上，还留有明文数据库时迁入其配对设备后
+    ///   删除；vault 保持未提交，由改写快照与旁路文件的 `SyncService` 加载时提交
+    /// - 已启用但留有明文数据库 / 未解锁 → Err
+    pub fn open(path: &str) -> Result<Self> {
+        let db_path = Path::new(path);
+        let data_dir = db_path.parent().unwrap_or(Path::new("."));
+        let Some(key) = vault::key_for_data_dir(data_dir)? else {
+            return Self::new(path);
+        };
+        let store = Self {
+            conn: Arc::new(Mutex::new(open_connection(":memory:")?)),
+            path: Some(db_path.to_path_buf()),
+            sealed: Arc::new(Mutex::new(Some(key.clone()))),
+        };
+        let sidecar = sealed_devices_path(db_path);
+        if sidecar.exists() {
+            let bytes = std::fs::read(&sidecar)?;
+            let (paired, revoked) = decode_device_tables(&key.open(&bytes)?)?;
+            for row in paired {
+                store.insert_paired_row(&row)?;
+            }
+            for row in revoked {
+                store.insert_revoked_row(&row)?;
+            }
+        }
+        if key.is_pending() {
+            if db_path.exists() {
+                store.seal_in_place(&key)?;
+            }
+        } else if db_path.exists() {
+            key.check_plaintext()
+                .with_context(|| format!("open note store {}", db_path.display()))?;
+        }
+        Ok(store)
+    }
+
+    /// 切换为静态加密模式：明文库中的配对设备迁入内存投影并加密写出旁路文件，
+    /// 然后删除明文数据库文件。笔记投影由调用方随后 `sync_notes_to_store` 重建。
+    pub fn seal_in_place(&self, key: &VaultKey) -> Result<()> {
+        let Some(path) = self.path.clone() else {
+            anyhow::bail!("in-memory note store cannot be encrypted");
+        };
+        let already_sealed = self.sealed.lock().unwrap().is_some();
+        let (devices, revoked) = if already_sealed {
+            // 已是内存投影（open 时发现残留明文库）：读取明文库中的设备后合并
+            let plain = Self::new(&path.to_string_lossy())?;
+            (plain.list_paired_devices()?, plain.list_revoked_devices()?)
+        } else {
+            let tables = (self.list_paired_devices()?, self.list_revoked_devices()?);
+            *self.conn.lock().unwrap() = open_connection(":memory:")?;
+            tables
+        };
+        for row in &devices {
+            self.insert_paired_row(row)?;
+        }
+        for row in &revoked {
+            self.insert_revoked_row(row)?;
+        }
+        *self.sealed.lock().unwrap() = Some(key.clone());
+        self.flush_sealed_devices()?;
+        for suffix in ["", "-wal", "-shm", "-journal"] {
+            let file = PathBuf::from(format!("{}{suffix}", path.display()));
+            if file.exists() {
+                std::fs::remove_file(&file)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 插入完整配对设备行（迁移/载入用；已存在的 peer_id 保持不变）。
+    fn insert_paired_row(&self, row: &PairedDeviceRow) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT OR IGNORE INTO paired_devices (peer_id, name, last_seen, paired_at)
+             VALUES (?1, ?2, ?3, ?4)",
+            rusqlite::params![row.peer_id, row.name, row.last_seen, row.paired_at],
+        )?;
+        Ok(())
+    }
+
+    /// 插入撤销记录（迁移/载入用；已存在的 peer_id 保持不变）。返回是否新插入。
+    fn insert_revoked_row(&self, row: &RevokedDeviceRow) -> Result<bool> {
+        let conn = self.conn.lock().unwrap();
+        let inserted = conn.execute(
+            "INSERT OR IGNORE INTO revoked_devices (peer_id, revoked_by, revoked_at, signature)
+             VALUES (?1, ?2, ?3, ?4)",
+            rusqlite::params![row.peer_id, row.revoked_by, row.revoked_at, row.signature],
+        )?;
+        Ok(inserted > 0)
+    }
+
+    /// 加密模式下把配对设备表与撤销表整体加密写出旁路文件（明文模式 no-op）。
+    fn flush_sealed_devices(&self) -> Result<()> {
+        let Some(key) = self.sealed.lock().unwrap().clone() else {
+            return Ok(());
+        };
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let bytes =
+            encode_device_tables(&self.list_paired_devices()?, &self.list_revoked_devices()?);
+        vault::write_file_atomic(&sealed_devices_path(path), &key.seal(&bytes)?)
+    }
+
     /// 读取笔记的 deleted_at 标记（无删除 = None，有删除 = ISO8601 时间）。
     pub fn deleted_at(&self, note_id: &str) -> Result<Option<String>> {
         let conn = self.conn.lock().unwrap();
@@ -137,8 +266,11 @@ impl NoteStore {
     /// store 不再独立决定删除，删除状态全部来自 Loro。
     pub fn purge_note(&self, note_id: &str) -> Result<()> {
         let conn = self.conn.lock().unwrap();
+        let old_key = stored_title_key(&conn, note_id);
         conn.execute("DELETE FROM notes WHERE id = ?1", [note_id])?;
         conn.execute("DELETE FROM links WHERE source_id = ?1", [note_id])?;
+        // 按标题指向它的链接改由同名的其他笔记承接，或回到未解析
+        reresolve_links(&conn, note_id, old_key.as_deref(), None)?;
         Ok(())
     }
 
@@ -173,11 +305,16 @@ impl NoteStore {
     ///
     /// 从 LoroDoc 中读取当前内容 + 标题 + meta tags + meta.deleted_at，
     /// 写入 notes 表（deleted_at 为读投影：软删/恢复状态来自 Loro，store 不
-    /// 独立决定删除）。创建时间首次持久化后不再覆盖。末尾重建该笔记的 links 索引。
+    /// 独立决定删除）。更新时间取 meta.updated_at（本地编辑时写入；缺失时取
+    /// 最近一次变更的提交时间），重复投影不会改变；创建时间首次持久化后不再
+    /// 覆盖。末尾重建该笔记的 links 索引，
+    /// 并重新解析按 id 或新旧标题指向本笔记的链接（新建/改标题后悬空的
+    /// `[[标题]]` 链接自动接上）。
     pub fn sync_note(&self, note_id: &str, crdt: &NoteCrdt) -> Result<()> {
         let conn = self.conn.lock().unwrap();
         let content = crdt.get_content();
         let title = crdt.get_title();
+        let title_key = link_key(&title);
         let now = Utc::now().to_rfc3339();
         // 删除状态来自 Loro meta：软删 = Some(时间)，恢复 = None
         let deleted_at = crdt.get_deleted_at();
@@ -185,30 +322,62 @@ impl NoteStore {
         // 标签来自 NoteCrdt 的 meta tags（不再从正文提取）
         let tags = crdt.get_tags().join(",");
 
-        // 读取已有 created_at，若不存在则使用当前时间
-        let created_at: String = conn
+        // 读取已有 created_at / updated_at，若不存在则使用当前时间
+        let (created_at, stored_updated_at): (String, String) = conn
             .query_row(
-                "SELECT created_at FROM notes WHERE id = ?1",
+                "SELECT created_at, updated_at FROM notes WHERE id = ?1",
                 [note_id],
-                |row| row.get(0),
+                |row| Ok((row.get(0)?, row.get(1)?)),
             )
-            .unwrap_or_else(|_| now.clone());
+            .unwrap_or_else(|_| (now.clone(), now.clone()));
+        let updated_at = DateTime::parse_from_rfc3339(&crdt.get_updated_at())
+            .map(|at| at.with_timezone(&Utc))
+            .ok()
+            .or_else(|| crdt.last_changed_at())
+            .map_or(stored_updated_at, |at| at.to_rfc3339());
+        let old_key = stored_title_key(&conn, note_id);
 
         conn.execute(
-            "INSERT OR REPLACE INTO notes (id, title, content, tags, created_at, updated_at, deleted_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
-            rusqlite::params![note_id, title, content, tags, created_at, now, deleted_at],
+            "INSERT OR REPLACE INTO notes (id, title, content, tags, created_at, updated_at, deleted_at, title_key)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            rusqlite::params![note_id, title, content, tags, created_at, updated_at, deleted_at, title_key],
         )?;
 
-        // 重建链接索引：先删旧链接，再插入当前解析结果
+        // 重建链接索引：先删旧链接，再插入当前解析结果（目标按 id 或标题解析；
+        // 已解析的标题链接固定在原目标上）
+        let pinned: HashMap<String, String> = {
+            let mut stmt = conn.prepare(
+                "SELECT target_key, target_id FROM links
+                 WHERE source_id = ?1 AND resolved = 1 AND target_ref != target_id",
+            )?;
+            let rows = stmt
+                .query_map([note_id], |row| Ok((row.get(0)?, row.get(1)?)))?
+                .collect::<std::result::Result<HashMap<_, _>, _>>()?;
+            rows
+        };
         conn.execute("DELETE FROM links WHERE source_id = ?1", [note_id])?;
-        for (target_id, alias) in crdt.parse_links() {
+        // 含 `#`/`^` 的目标原文先整体匹配笔记（标题 `C# 入门`），不匹配再拆锚点
+        let is_note = |dest: &str| resolve_link_target(&conn, dest).is_ok_and(|(_, ok)| ok);
+        for (dest, link) in parse_link_refs(&content, is_note) {
+            let pin = pinned.get(&link_key(&link.target)).map(String::as_str);
+            let (target_id, resolved) = resolve_pinned_link_target(&conn, &link.target, pin)?;
             conn.execute(
-                "INSERT OR REPLACE INTO links (source_id, target_id, alias)
-                 VALUES (?1, ?2, ?3)",
-                rusqlite::params![note_id, target_id, alias],
+                "INSERT OR REPLACE INTO links (source_id, target_id, anchor, alias, target_ref, target_key, resolved, dest_ref, dest_key)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                rusqlite::params![
+                    note_id,
+                    target_id,
+                    link.anchor,
+                    link.alias,
+                    link.target,
+                    link_key(&link.target),
+                    resolved,
+                    dest,
+                    link_key(&dest)
+                ],
             )?;
         }
+        reresolve_links(&conn, note_id, old_key.as_deref(), Some(&title_key))?;
 
         Ok(())
     }
@@ -281,30 +450,86 @@ impl NoteStore {
         Ok(rows)
     }
 
-    /// 全文搜索（FTS5，trigram tokenizer）
+    /// 全文搜索（FTS5，`cjk_bigram` 分词），支持搜索语法（见 `search` 模块）
     ///
-    /// - 查询少于 3 个字符时回退 LIKE 搜索
-    /// - ≥3 字符走 FTS5 MATCH + ORDER BY bm25，preview 用 snippet 取匹配上下文
-    /// - 特殊字符（双引号等）转义后作为短语查询
+    /// - 语法错误返回 [`SearchQueryError`]（可经 `downcast_ref` 取出）
+    /// - 有必须命中的全文词时 ORDER BY bm25，preview 用 snippet 取匹配上下文；
+    ///   否则按更新时间倒序
+    /// - 单字、双字中文词同样走 FTS；不含文字的词（纯符号）回退 LIKE 匹配
     pub fn search_notes(&self, query: &str) -> Result<Vec<NoteRow>> {
-        if query.chars().count() < 3 {
-            return self.search(query);
+        let query = search::parse_query(query)?;
+        self.query_notes(&query)
+    }
+
+    /// 执行已解析的搜索查询：每组编译为一个 SQL 条件（组内 OR，组间 AND），
+    /// 全文词走 `notes_fts MATCH` 子查询，过滤器走 notes/links 列。
+    pub fn query_notes(&self, query: &SearchQuery) -> Result<Vec<NoteRow>> {
+        let mut clauses = vec![if query.in_trash {
+            "n.deleted_at IS NOT NULL".to_string()
+        } else {
+            "n.deleted_at IS NULL".to_string()
+        }];
+        let mut params: Vec<String> = Vec::new();
+        for group in &query.groups {
+            let alternatives: Vec<String> = group
+                .iter()
+                .map(|term| {
+                    let condition = search_condition(&term.filter, &mut params);
+                    if term.negated {
+                        format!("NOT {condition}")
+                    } else {
+                        condition
+                    }
+                })
+                .collect();
+            clauses.push(format!("({})", alternatives.join(" OR ")));
         }
-        let escaped = query.replace('"', "\"\"");
-        let match_expr = format!("\"{}\"", escaped);
-        let conn = self.conn.lock().unwrap();
-        let mut stmt = conn.prepare(
-            "SELECT n.id, n.title, n.content, n.tags, n.updated_at, n.deleted_at,
-                    snippet(notes_fts, 1, '', '', '…', 12)
-             FROM notes_fts
-             JOIN notes n ON n.rowid = notes_fts.rowid
-             WHERE notes_fts MATCH ?1
-               AND n.deleted_at IS NULL
-             ORDER BY bm25(notes_fts)",
-        )?;
 
+        // 存在“每条结果都必须命中全文词”的组时才能 JOIN FTS 排序；
+        // 排序表达式为所有正向全文词的 OR，保证 JOIN 不丢结果
+        let ranked = query.groups.iter().any(|group| {
+            group
+                .iter()
+                .all(|term| !term.negated && fts_term(&term.filter).is_some())
+        });
+        let (sql, params) = if ranked {
+            let rank_expr = query
+                .groups
+                .iter()
+                .flatten()
+                .filter(|term| !term.negated)
+                .filter_map(|term| fts_term(&term.filter))
+                .collect::<Vec<_>>()
+                .join(" OR ");
+            (
+                format!(
+                    "SELECT n.id, n.title, n.content, n.tags, n.updated_at, n.deleted_at,
+                            snippet(notes_fts, 1, '', '', '…', 12)
+                     FROM notes_fts
+                     JOIN notes n ON n.rowid = notes_fts.rowid
+                     WHERE notes_fts MATCH ? AND {}
+                     ORDER BY bm25(notes_fts)",
+                    clauses.join(" AND ")
+                ),
+                std::iter::once(rank_expr).chain(params).collect(),
+            )
+        } else {
+            (
+                format!(
+                    "SELECT n.id, n.title, n.content, n.tags, n.updated_at, n.deleted_at, ''
+                     FROM notes n
+                     WHERE {}
+                     ORDER BY n.updated_at DESC",
+                    clauses.join(" AND ")
+                ),
+                params,
+            )
+        };
+
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(&sql)?;
         let rows = stmt
-            .query_map([&match_expr], |row| {
+            .query_map(rusqlite::params_from_iter(params), |row| {
                 let content: String = row.get(2)?;
                 let fallback = Self::content_preview(&content);
                 let snippet: String = row.get(6)?;
@@ -327,16 +552,24 @@ impl NoteStore {
         Ok(rows)
     }
 
+    /// 按链接解析规则查找目标笔记：先按 id 精确匹配，再按标题大小写不敏感匹配
+    /// （同名取 id 最小者）。未解析 = None。
+    pub fn resolve_link(&self, target: &str) -> Result<Option<String>> {
+        let conn = self.conn.lock().unwrap();
+        let (id, resolved) = resolve_link_target(&conn, target)?;
+        Ok(resolved.then_some(id))
+    }
+
     /// 出链查询：note_id 指向的所有链接
     pub fn outgoing_links(&self, note_id: &str) -> Result<Vec<LinkRow>> {
         let conn = self.conn.lock().unwrap();
-        let mut stmt = conn.prepare(
-            "SELECT l.target_id, n.title, l.alias
+        let mut stmt = conn.prepare(&format!(
+            "SELECT l.target_id, n.title, l.alias, l.resolved, l.anchor, {LINK_AMBIGUOUS}
              FROM links l
              LEFT JOIN notes n ON n.id = l.target_id
              WHERE l.source_id = ?1
-             ORDER BY l.target_id",
-        )?;
+             ORDER BY l.target_id, l.anchor"
+        ))?;
 
         let rows = stmt
             .query_map([note_id], |row| {
@@ -345,7 +578,10 @@ impl NoteStore {
                     id: row.get(0)?,
                     title: title.clone().unwrap_or_default(),
                     alias: row.get(2)?,
+                    anchor: row.get(4)?,
                     exists: title.is_some(),
+                    resolved: row.get(3)?,
+                    ambiguous: row.get(5)?,
                 })
             })?
             .collect::<std::result::Result<Vec<_>, _>>()?;
@@ -356,13 +592,13 @@ impl NoteStore {
     /// 反链查询：指向 note_id 的所有链接
     pub fn backlinks(&self, note_id: &str) -> Result<Vec<LinkRow>> {
         let conn = self.conn.lock().unwrap();
-        let mut stmt = conn.prepare(
-            "SELECT l.source_id, n.title, l.alias
+        let mut stmt = conn.prepare(&format!(
+            "SELECT l.source_id, n.title, l.alias, l.resolved, l.anchor, {LINK_AMBIGUOUS}
              FROM links l
              LEFT JOIN notes n ON n.id = l.source_id
              WHERE l.target_id = ?1
-             ORDER BY l.source_id",
-        )?;
+             ORDER BY l.source_id, l.anchor"
+        ))?;
 
         let rows = stmt
             .query_map([note_id], |row| {
@@ -371,7 +607,10 @@ impl NoteStore {
                     id: row.get(0)?,
                     title: title.clone().unwrap_or_default(),
                     alias: row.get(2)?,
+                    anchor: row.get(4)?,
                     exists: title.is_some(),
+                    resolved: row.get(3)?,
+                    ambiguous: row.get(5)?,
                 })
             })?
             .collect::<std::result::Result<Vec<_>, _>>()?;
@@ -379,6 +618,101 @@ impl NoteStore {
         Ok(rows)
     }
 
+    /// 未链接提及：正文提到 note_id 的标题、但尚未链接到它的其他笔记（回收站除外）。
+    ///
+    /// 候选笔记由 FTS5 短语查询 content 列得出（按 bm25 排序；标题不含文字时
+    /// 回退 LIKE），再按 Markdown 逐处核对：代码与已有链接里的提及不算。
+    pub fn unlinked_mentions(&self, note_id: &str) -> Result<Vec<MentionRow>> {
+        let conn = self.conn.lock().unwrap();
+        let title: String =
+            match conn.query_row("SELECT title FROM notes WHERE id = ?1", [note_id], |row| {
+                row.get(0)
+            }) {
+                Ok(title) => title,
+                Err(rusqlite::Error::QueryReturnedNoRows) => return Ok(Vec::new()),
+                Err(err) => return Err(err.into()),
+            };
+        if title.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+        let title = title.trim();
+        let not_linked = "n.id != ?2 AND n.deleted_at IS NULL
+             AND NOT EXISTS (SELECT 1 FROM links l WHERE l.source_id = n.id AND l.target_id = ?2)";
+        let (sql, pattern) = if title.chars().any(char::is_alphanumeric) {
+            (
+                format!(
+                    "SELECT n.id, n.title, n.content FROM notes_fts
+                     JOIN notes n ON n.rowid = notes_fts.rowid
+                     WHERE notes_fts MATCH ?1 AND {not_linked}
+                     ORDER BY bm25(notes_fts)"
+                ),
+                format!("content : \"{}\"", title.replace('"', "\"\"")),
+            )
+        } else {
+            (
+                format!(
+                    "SELECT n.id, n.title, n.content FROM notes n
+                     WHERE n.content LIKE ?1 ESCAPE '\\' AND {not_linked}
+                     ORDER BY n.updated_at DESC"
+                ),
+                format!(
+                    "%{}%",
+                    title
+                        .replace('\\', "\\\\")
+                        .replace('%', "\\%")
+                        .replace('_', "\\_")
+                ),
+            )
+        };
+        let mut stmt = conn.prepare(&sql)?;
+        let candidates = stmt
+            .query_map(rusqlite::params![pattern, note_id], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                ))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let mut rows = Vec::new();
+        for (id, source_title, content) in candidates {
+            let mentions = crate::sync::find_mentions(&content, title);
+            let Some(first) = mentions.first() else {
+                continue;
+            };
+            rows.push(MentionRow {
+                id,
+                title: source_title,
+                snippet: Self::mention_snippet(&content, first.clone()),
+                mentions: mentions.len() as u32,
+            });
+        }
+        Ok(rows)
+    }
+
+    /// 提及上下文：前后各取至多 16 个字符（不跨行），截断处补 `…`。
+    fn mention_snippet(content: &str, hit: std::ops::Range<usize>) -> String {
+        const CONTEXT: usize = 16;
+        let line_start = content[..hit.start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = content[hit.end..]
+            .find('\n')
+            .map_or(content.len(), |i| hit.end + i);
+        let before: Vec<char> = content[line_start..hit.start].chars().collect();
+        let after: Vec<char> = content[hit.end..line_end].chars().collect();
+        let mut snippet = String::new();
+        if before.len() > CONTEXT {
+            snippet.push('…');
+        }
+        snippet.extend(&before[before.len().saturating_sub(CONTEXT)..]);
+        snippet.push_str(&content[hit]);
+        snippet.extend(after.iter().take(CONTEXT));
+        if after.len() > CONTEXT {
+            snippet.push('…');
+        }
+        snippet
+    }
+
     /// 链接自动补全：按标题前缀匹配，取最近更新的 20 条
     pub fn auto_complete_links(&self, prefix: &str) -> Result<Vec<NoteRow>> {
         let conn = self.conn.lock().unwrap();
@@ -462,6 +796,199 @@ impl NoteStore {
         Ok(rows)
     }
 
+    /// 知识图谱全图：全部未删除笔记为节点，解析到笔记的链接为边。
+    pub fn graph(&self) -> Result<NoteGraph> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt =
+            conn.prepare("SELECT id, title, tags FROM notes WHERE deleted_at IS NULL ORDER BY id")?;
+        let mut nodes = stmt
+            .query_map([], |row| {
+                let tags: String = row.get(2)?;
+                Ok(GraphNode {
+                    id: row.get(0)?,
+                    title: row.get(1)?,
+                    tags: tags
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|tag| !tag.is_empty())
+                        .map(str::to_string)
+                        .collect(),
+                    degree: 0,
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let mut stmt = conn.prepare(
+            "SELECT l.source_id, l.target_id, l.alias
+             FROM links l
+             JOIN notes s ON s.id = l.source_id AND s.deleted_at IS NULL
+             JOIN notes t ON t.id = l.target_id AND t.deleted_at IS NULL
+             WHERE l.source_id != l.target_id
+             ORDER BY l.source_id, l.target_id, l.anchor",
+        )?;
+        let links = stmt
+            .query_map([], |row| {
+                Ok(GraphEdge {
+                    source: row.get(0)?,
+                    target: row.get(1)?,
+                    alias: row.get(2)?,
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let mut edges: Vec<GraphEdge> = Vec::with_capacity(links.len());
+        for link in links {
+            let duplicate = edges
+                .last()
+                .is_some_and(|e| e.source == link.source && e.target == link.target);
+            if !duplicate {
+                edges.push(link);
+            }
+        }
+
+        let mut degrees: BTreeMap<&str, u32> = BTreeMap::new();
+        for edge in &edges {
+            *degrees.entry(&edge.source).or_default() += 1;
+            *degrees.entry(&edge.target).or_default() += 1;
+        }
+        for node in &mut nodes {
+            node.degree = degrees.get(node.id.as_str()).copied().unwrap_or(0);
+        }
+        Ok(NoteGraph { nodes, edges })
+    }
+
+    /// 局部图谱：从 note_id 出发沿链接（不分方向）至多 `depth` 跳可达的笔记，
+    /// 及它们之间的边。节点度数仍为全图口径。笔记不存在或已删除 = 空图。
+    pub fn neighborhood(&self, note_id: &str, depth: u32) -> Result<NoteGraph> {
+        let graph = self.graph()?;
+        let adjacency = graph_adjacency(&graph);
+        if !adjacency.contains_key(note_id) {
+            return Ok(NoteGraph::default());
+        }
+        let mut reached: BTreeSet<&str> = BTreeSet::from([note_id]);
+        let mut frontier = vec![note_id];
+        for _ in 0..depth {
+            let mut next = Vec::new();
+            for id in frontier {
+                for &neighbor in &adjacency[id] {
+                    if reached.insert(neighbor) {
+                        next.push(neighbor);
+                    }
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            frontier = next;
+        }
+        let reached: BTreeSet<String> = reached.into_iter().map(str::to_string).collect();
+        Ok(NoteGraph {
+            nodes: graph
+                .nodes
+                .into_iter()
+                .filter(|n| reached.contains(&n.id))
+                .collect(),
+            edges: graph
+                .edges
+                .into_iter()
+                .filter(|e| reached.contains(&e.source) && reached.contains(&e.target))
+                .collect(),
+        })
+    }
+
+    /// 孤立笔记：没有任何出链或反链（只计解析到未删除笔记的链接，自链接不计），
+    /// 按更新时间倒序。
+    pub fn orphan_notes(&self) -> Result<Vec<NoteRow>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT id, title, content, tags, updated_at, deleted_at FROM notes n
+             WHERE deleted_at IS NULL
+               AND NOT EXISTS (
+                   SELECT 1 FROM links l JOIN notes t ON t.id = l.target_id
+                   WHERE l.source_id = n.id AND l.target_id != n.id AND t.deleted_at IS NULL)
+               AND NOT EXISTS (
+                   SELECT 1 FROM links l JOIN notes s ON s.id = l.source_id
+                   WHERE l.target_id = n.id AND l.source_id != n.id AND s.deleted_at IS NULL)
+             ORDER BY updated_at DESC",
+        )?;
+
+        let rows = stmt
+            .query_map([], |row| {
+                let content: String = row.get(2)?;
+                let preview = Self::content_preview(&content);
+                Ok(NoteRow {
+                    id: row.get(0)?,
+                    title: row.get(1)?,
+                    content_preview: preview,
+                    tags: row.get(3)?,
+                    updated_at: row.get(4)?,
+                    deleted_at: row.get(5)?,
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(rows)
+    }
+
+    /// 两篇笔记间的最短路径（沿链接，不分方向；含首尾 id）。不连通或任一端不存在
+    /// = 空；同一篇 = 只含它自己。多条等长路径时按 id 序取第一条，结果稳定。
+    pub fn shortest_path(&self, from: &str, to: &str) -> Result<Vec<String>> {
+        let graph = self.graph()?;
+        let adjacency = graph_adjacency(&graph);
+        if !adjacency.contains_key(from) || !adjacency.contains_key(to) {
+            return Ok(Vec::new());
+        }
+        let mut parent: BTreeMap<&str, &str> = BTreeMap::new();
+        let mut queue = VecDeque::from([from]);
+        parent.insert(from, from);
+        while let Some(id) = queue.pop_front() {
+            if id == to {
+                let mut path = vec![to.to_string()];
+                let mut cur = to;
+                while cur != from {
+                    cur = parent[cur];
+                    path.push(cur.to_string());
+                }
+                path.reverse();
+                return Ok(path);
+            }
+            for &neighbor in &adjacency[id] {
+                if !parent.contains_key(neighbor) {
+                    parent.insert(neighbor, id);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        Ok(Vec::new())
+    }
+
+    /// 连通分量（链接不分方向）：每个分量内 id 升序，分量按大小降序（同大小按
+    /// 首个 id）。孤立笔记各自成一个分量。
+    pub fn connected_components(&self) -> Result<Vec<Vec<String>>> {
+        let graph = self.graph()?;
+        let adjacency = graph_adjacency(&graph);
+        let mut seen: BTreeSet<&str> = BTreeSet::new();
+        let mut components = Vec::new();
+        for &start in adjacency.keys() {
+            if !seen.insert(start) {
+                continue;
+            }
+            let mut component = vec![start.to_string()];
+            let mut stack = vec![start];
+            while let Some(id) = stack.pop() {
+                for &neighbor in &adjacency[id] {
+                    if seen.insert(neighbor) {
+                        component.push(neighbor.to_string());
+                        stack.push(neighbor);
+                    }
+                }
+            }
+            component.sort();
+            components.push(component);
+        }
+        components.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a[0].cmp(&b[0])));
+        Ok(components)
+    }
+
     /// 列出所有配对设备，最近连接优先（last_seen DESC，从未连接的最后；同名按 peer_id 稳定排序）。
     pub fn list_paired_devices(&self) -> Result<Vec<PairedDeviceRow>> {
         let conn = self.conn.lock().unwrap();
@@ -484,33 +1011,98 @@ impl NoteStore {
 
     /// 添加/更新一台配对设备（重复 peer_id 覆盖 name；paired_at 保持不变）。
     pub fn upsert_paired_device(&self, peer_id: &str, name: &str) -> Result<()> {
-        let conn = self.conn.lock().unwrap();
-        let now = Utc::now().to_rfc3339();
-        conn.execute(
-            "INSERT INTO paired_devices (peer_id, name, last_seen, paired_at)
-             VALUES (?1, ?2, NULL, ?3)
-             ON CONFLICT(peer_id) DO UPDATE SET name = excluded.name",
-            rusqlite::params![peer_id, name, now],
-        )?;
-        Ok(())
+        {
+            let conn = self.conn.lock().unwrap();
+            let now = Utc::now().to_rfc3339();
+            conn.execute(
+                "INSERT INTO paired_devices (peer_id, name, last_seen, paired_at)
+                 VALUES (?1, ?2, NULL, ?3)
+                 ON CONFLICT(peer_id) DO UPDATE SET name = excluded.name",
+                rusqlite::params![peer_id, name, now],
+            )?;
+        }
+        self.flush_sealed_devices()
     }
 
     /// 更新配对设备的最后连接/同步时间（ISO8601 now）。
     pub fn update_last_seen(&self, peer_id: &str) -> Result<()> {
-        let conn = self.conn.lock().unwrap();
-        let now = Utc::now().to_rfc3339();
-        conn.execute(
-            "UPDATE paired_devices SET last_seen = ?2 WHERE peer_id = ?1",
-            rusqlite::params![peer_id, now],
-        )?;
-        Ok(())
+        {
+            let conn = self.conn.lock().unwrap();
+            let now = Utc::now().to_rfc3339();
+            conn.execute(
+                "UPDATE paired_devices SET last_seen = ?2 WHERE peer_id = ?1",
+                rusqlite::params![peer_id, now],
+            )?;
+        }
+        self.flush_sealed_devices()
     }
 
     /// 移除一台配对设备。
     pub fn remove_paired_device(&self, peer_id: &str) -> Result<()> {
+        {
+            let conn = self.conn.lock().unwrap();
+            conn.execute("DELETE FROM paired_devices WHERE peer_id = ?1", [peer_id])?;
+        }
+        self.flush_sealed_devices()
+    }
+
+    /// 是否为（仍在表中的）配对设备。
+    pub fn is_paired_device(&self, peer_id: &str) -> Result<bool> {
         let conn = self.conn.lock().unwrap();
-        conn.execute("DELETE FROM paired_devices WHERE peer_id = ?1", [peer_id])?;
-        Ok(())
+        let count: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM paired_devices WHERE peer_id = ?1",
+            [peer_id],
+            |row| row.get(0),
+        )?;
+        Ok(count > 0)
+    }
+
+    /// 设备是否已被撤销。
+    pub fn is_revoked_device(&self, peer_id: &str) -> Result<bool> {
+        let conn = self.conn.lock().unwrap();
+        let count: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM revoked_devices WHERE peer_id = ?1",
+            [peer_id],
+            |row| row.get(0),
+        )?;
+        Ok(count > 0)
+    }
+
+    /// 列出全部撤销记录（按撤销时间，同步会话中整体发送给对端）。
+    pub fn list_revoked_devices(&self) -> Result<Vec<RevokedDeviceRow>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT peer_id, revoked_by, revoked_at, signature FROM revoked_devices
+             ORDER BY revoked_at ASC, peer_id ASC",
+        )?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok(RevokedDeviceRow {
+                    peer_id: row.get(0)?,
+                    revoked_by: row.get(1)?,
+                    revoked_at: row.get(2)?,
+                    signature: row.get(3)?,
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(rows)
+    }
+
+    /// 记录撤销（调用方已验签）：写入撤销表并移除对应配对设备。
+    /// 返回是否为新撤销（已撤销过的 peer_id 不变，返回 false）。
+    pub fn insert_revocation(&self, row: &RevokedDeviceRow) -> Result<bool> {
+        if !self.insert_revoked_row(row)? {
+            return Ok(false);
+        }
+        {
+            let conn = self.conn.lock().unwrap();
+            conn.execute(
+                "DELETE FROM paired_devices WHERE peer_id = ?1",
+                [&row.peer_id],
+            )?;
+        }
+        self.flush_sealed_devices()?;
+        Ok(true)
     }
 
     /// 预览只包含正文：移除标题首行及标签 marker，避免列表重复显示标题。
@@ -527,3 +1119,390 @@ impl NoteStore {
             .collect()
     }
 }
+
+/// links 表：每行一条 `[[target#锚点|alias]]`。`target_ref` 为正文里写的目标
+/// 原文，`target_key` 为其比较键；`target_id` 为解析出的笔记 id（未解析 = 原文）。
+const LINKS_SCHEMA: &str = "CREATE TABLE IF NOT EXISTS links (
+    source_id  TEXT NOT NULL,
+    target_id  TEXT NOT NULL,
+    anchor     TEXT NOT NULL DEFAULT '',
+    alias      TEXT NOT NULL DEFAULT '',
+    target_ref TEXT NOT NULL DEFAULT '',
+    target_key TEXT NOT NULL DEFAULT '',
+    resolved   INTEGER NOT NULL DEFAULT 0,
+    dest_ref   TEXT NOT NULL DEFAULT '',
+    dest_key   TEXT NOT NULL DEFAULT '',
+    PRIMARY KEY (source_id, target_id, anchor)
+);";
+
+/// 打开连接并建表/迁移（文件库与加密模式的内存库共用）。
+fn open_connection(path: &str) -> Result<Connection> {
+    let conn = Connection::open(path)?;
+    tokenizer::register(&conn)?;
+    // 迁移已有库：旧 notes_fts 为 trigram 分词，删表后按新分词器重建索引。
+    let fts_sql: Option<String> = conn
+        .query_row(
+            "SELECT sql FROM sqlite_master WHERE name = 'notes_fts'",
+            [],
+            |row| row.get(0),
+        )
+        .ok();
+    let rebuild_fts = fts_sql.is_some_and(|sql| !sql.contains(tokenizer::TOKENIZER_NAME));
+    if rebuild_fts {
+        conn.execute_batch("DROP TABLE notes_fts;")?;
+    }
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS notes (
+            id TEXT PRIMARY KEY,
+            title TEXT NOT NULL,
+            content TEXT NOT NULL,
+            tags TEXT NOT NULL DEFAULT '',
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL,
+            deleted_at TEXT NULL,
+            title_key TEXT NOT NULL DEFAULT ''
+        );
+        CREATE TABLE IF NOT EXISTS paired_devices (
+            peer_id   TEXT PRIMARY KEY,
+            name      TEXT NOT NULL,
+            last_seen TEXT NULL,
+            paired_at TEXT NOT NULL
+        );
+        CREATE TABLE IF NOT EXISTS revoked_devices (
+            peer_id    TEXT PRIMARY KEY,
+            revoked_by TEXT NOT NULL,
+            revoked_at TEXT NOT NULL,
+            signature  TEXT NOT NULL
+        );
+        CREATE VIRTUAL TABLE IF NOT EXISTS notes_fts USING fts5(
+            title, content, tags,
+            content='notes', content_rowid='rowid',
+            tokenize='cjk_bigram'
+        );
+        CREATE TRIGGER IF NOT EXISTS notes_fts_ai AFTER INSERT ON notes BEGIN
+            INSERT INTO notes_fts(rowid, title, content, tags)
+            VALUES (new.rowid, new.title, new.content, new.tags);
+        END;
+        CREATE TRIGGER IF NOT EXISTS notes_fts_ad AFTER DELETE ON notes BEGIN
+            INSERT INTO notes_fts(notes_fts, rowid, title, content, tags)
+            VALUES ('delete', old.rowid, old.title, old.content, old.tags);
+        END;
+        CREATE TRIGGER IF NOT EXISTS notes_fts_au AFTER UPDATE ON notes BEGIN
+            INSERT INTO notes_fts(notes_fts, rowid, title, content, tags)
+            VALUES ('delete', old.rowid, old.title, old.content, old.tags);
+            INSERT INTO notes_fts(rowid, title, content, tags)
+            VALUES (new.rowid, new.title, new.content, new.tags);
+        END;",
+    )?;
+    conn.execute_batch(LINKS_SCHEMA)?;
+    if rebuild_fts {
+        conn.execute_batch("INSERT INTO notes_fts(notes_fts) VALUES('rebuild');")?;
+    }
+    // 迁移已有库：旧 notes 表没有 deleted_at 列时补列（SQLite 无 IF NOT EXISTS for column）。
+    if !has_column(&conn, "notes", "deleted_at")? {
+        conn.execute_batch("ALTER TABLE notes ADD COLUMN deleted_at TEXT NULL;")?;
+        // 旧库的既有行不在刚创建的 notes_fts 索引中；若不重建，之后任何
+        // UPDATE notes（如软删除的 deleted_at 标记）都会触发 FTS 触发器报
+        // "Content in the virtual table is corrupt"。重建使索引与 notes 一致。
+        conn.execute_batch("INSERT INTO notes_fts(notes_fts) VALUES('rebuild');")?;
+    }
+    // 标题链接解析用的小写标题键：SQLite 的 lower() 只处理 ASCII，由 Rust 侧回填。
+    if !has_column(&conn, "notes", "title_key")? {
+        conn.execute_batch("ALTER TABLE notes ADD COLUMN title_key TEXT NOT NULL DEFAULT '';")?;
+        let titles: Vec<(String, String)> = {
+            let mut stmt = conn.prepare("SELECT id, title FROM notes")?;
+            let rows = stmt
+                .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            rows
+        };
+        for (id, title) in titles {
+            conn.execute(
+                "UPDATE notes SET title_key = ?2 WHERE id = ?1",
+                rusqlite::params![id, link_key(&title)],
+            )?;
+        }
+    }
+    // 旧 links 表缺锚点列且主键不含锚点（或缺目标原文列），无法原地 ALTER：
+    // links 只是投影，删表重建即可（打开后 `sync_notes_to_store` 重新填充）。
+    if !has_column(&conn, "links", "anchor")? || !has_column(&conn, "links", "dest_ref")? {
+        conn.execute_batch("DROP TABLE links;")?;
+        conn.execute_batch(LINKS_SCHEMA)?;
+    }
+    conn.execute_batch(
+        "CREATE INDEX IF NOT EXISTS notes_title_key ON notes(title_key);
+         CREATE INDEX IF NOT EXISTS links_target_key ON links(target_key);
+         CREATE INDEX IF NOT EXISTS links_dest_key ON links(dest_key);",
+    )?;
+    Ok(conn)
+}
+
+/// 表是否已有某列（迁移判断用）。
+fn has_column(conn: &Connection, table: &str, column: &str) -> Result<bool> {
+    let mut stmt = conn.prepare(&format!("PRAGMA table_info({table})"))?;
+    let columns: Vec<String> = stmt
+        .query_map([], |row| row.get(1))?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(columns.iter().any(|name| name == column))
+}
+
+/// LIKE 模式转义（配合 `ESCAPE '\\'`）。
+fn escape_like(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+/// 可走 FTS 的全文项（含文字，分词后非空）转为 FTS5 短语：不带引号的词为前缀
+/// 短语（`"词"*`，英文词可匹配词形变化），带引号的短语精确匹配；纯符号 = None。
+fn fts_term(filter: &SearchFilter) -> Option<String> {
+    let (text, prefix) = match filter {
+        SearchFilter::Text(text) => (text, "*"),
+        SearchFilter::Phrase(text) => (text, ""),
+        _ => return None,
+    };
+    text.chars()
+        .any(char::is_alphanumeric)
+        .then(|| format!("\"{}\"{prefix}", text.replace('"', "\"\"")))
+}
+
+/// 单项搜索条件编译为 SQL（别名 `n` = notes），参数按出现顺序追加到 `params`。
+fn search_condition(filter: &SearchFilter, params: &mut Vec<String>) -> String {
+    if let Some(phrase) = fts_term(filter) {
+        params.push(phrase);
+        return "n.rowid IN (SELECT rowid FROM notes_fts WHERE notes_fts MATCH ?)".to_string();
+    }
+    match filter {
+        SearchFilter::Text(text) | SearchFilter::Phrase(text) => {
+            let pattern = format!("%{}%", escape_like(text));
+            params.extend([pattern.clone(), pattern.clone(), pattern]);
+            "(n.title LIKE ? ESCAPE '\\' OR n.content LIKE ? ESCAPE '\\' \
+             OR n.tags LIKE ? ESCAPE '\\')"
+                .to_string()
+        }
+        SearchFilter::Tag(tag) => {
+            params.push(format!("%,{},%", escape_like(tag)));
+            "(',' || n.tags || ',') LIKE ? ESCAPE '\\'".to_string()
+        }
+        SearchFilter::Updated { from, until } => {
+            let mut bounds = Vec::new();
+            if let Some(from) = from {
+                params.push(from.clone());
+                bounds.push("n.updated_at >= ?");
+            }
+            if let Some(until) = until {
+                params.push(until.clone());
+                bounds.push("n.updated_at < ?");
+            }
+            format!("({})", bounds.join(" AND "))
+        }
+        SearchFilter::LinksTo(target) => {
+            params.push(target.clone());
+            "EXISTS (SELECT 1 FROM links l WHERE l.source_id = n.id AND l.target_id = ?)"
+                .to_string()
+        }
+    }
+}
+
+/// 链接目标/标题的比较键：去首尾空白后转小写（大小写不敏感匹配）。
+pub(crate) fn link_key(text: &str) -> String {
+    text.trim().to_lowercase()
+}
+
+/// 投影中已存的标题键（笔记尚未入库 = None）。
+fn stored_title_key(conn: &Connection, note_id: &str) -> Option<String> {
+    conn.query_row(
+        "SELECT title_key FROM notes WHERE id = ?1",
+        [note_id],
+        |row| row.get(0),
+    )
+    .ok()
+}
+
+/// 链接行的同名歧义列（别名 `l` = links）：标题链接的标题键对应多篇未删除笔记。
+const LINK_AMBIGUOUS: &str = "(l.target_ref != l.target_id AND (SELECT COUNT(*) FROM notes m
+     WHERE m.title_key = l.target_key AND m.title_key != '' AND m.deleted_at IS NULL) > 1)";
+
+/// 解析链接目标：先按笔记 id 精确匹配，再按标题大小写不敏感匹配未删除的笔记；
+/// 多篇同名时取 id 最小者（UUID v7 即最早创建）。已解析的链接此后固定在该笔记
+/// 上（见 [`resolve_pinned_link_target`]），歧义经 [`LinkRow::ambiguous`] 暴露。
+///
+/// 返回 `(target_id, resolved)`；未解析时 target_id 为目标原文。
+fn resolve_link_target(conn: &Connection, target: &str) -> Result<(String, bool)> {
+    let by_id = conn
+        .query_row("SELECT id FROM notes WHERE id = ?1", [target], |row| {
+            row.get::<_, String>(0)
+        })
+        .ok();
+    if let Some(id) = by_id {
+        return Ok((id, true));
+    }
+    let by_title = conn
+        .query_row(
+            "SELECT id FROM notes
+             WHERE title_key = ?1 AND title_key != '' AND deleted_at IS NULL
+             ORDER BY id LIMIT 1",
+            [link_key(target)],
+            |row| row.get::<_, String>(0),
+        )
+        .ok();
+    Ok(match by_title {
+        Some(id) => (id, true),
+        None => (target.to_string(), false),
+    })
+}
+
+/// 同 [`resolve_link_target`]，但标题链接此前解析到的笔记（`pinned`）仍是该标题的
+/// 未删除笔记时沿用它：后来出现的同名笔记不会改变已解析链接的指向。
+fn resolve_pinned_link_target(
+    conn: &Connection,
+    target: &str,
+    pinned: Option<&str>,
+) -> Result<(String, bool)> {
+    let (id, resolved) = resolve_link_target(conn, target)?;
+    let by_id = resolved && id == target;
+    let Some(pinned) = pinned.filter(|pinned| !by_id && *pinned != id) else {
+        return Ok((id, resolved));
+    };
+    let still_matches = conn
+        .query_row(
+            "SELECT 1 FROM notes
+             WHERE id = ?1 AND title_key = ?2 AND title_key != '' AND deleted_at IS NULL",
+            rusqlite::params![pinned, link_key(target)],
+            |_| Ok(()),
+        )
+        .is_ok();
+    Ok(if still_matches {
+        (pinned.to_string(), true)
+    } else {
+        (id, resolved)
+    })
+}
+
+/// 笔记新建/改标题/删除后，重新解析可能受影响的链接：目标（拆锚点前后）原文
+/// 等于该笔记 id，目标键等于其新旧标题键，或目标原文以新旧标题键开头（标题含
+/// `#`/`^`）的全部链接行。锚点拆分位置随之重新判断（见 [`split_link_anchor`]）。
+fn reresolve_links(
+    conn: &Connection,
+    note_id: &str,
+    old_key: Option<&str>,
+    new_key: Option<&str>,
+) -> Result<()> {
+    let affected: Vec<(String, String, String, String, bool)> = {
+        let mut stmt = conn.prepare(
+            "SELECT source_id, target_id, anchor, dest_ref, resolved FROM links
+             WHERE target_ref = ?1 OR dest_ref = ?1 OR target_key IN (?2, ?3)
+                OR (?2 != '' AND substr(dest_key, 1, length(?2)) = ?2)
+                OR (?3 != '' AND substr(dest_key, 1, length(?3)) = ?3)",
+        )?;
+        let rows = stmt
+            .query_map(
+                rusqlite::params![note_id, old_key.unwrap_or(""), new_key.unwrap_or("")],
+                |row| {
+                    Ok((
+                        row.get(0)?,
+                        row.get(1)?,
+                        row.get(2)?,
+                        row.get(3)?,
+                        row.get(4)?,
+                    ))
+                },
+            )?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        rows
+    };
+    let is_note = |dest: &str| resolve_link_target(conn, dest).is_ok_and(|(_, ok)| ok);
+    for (source_id, target_id, anchor, dest_ref, was_resolved) in affected {
+        let (target, new_anchor) = split_link_anchor(&dest_ref, is_note);
+        let pinned = was_resolved.then_some(target_id.as_str());
+        let (resolved_id, resolved) = resolve_pinned_link_target(conn, target, pinned)?;
+        // 同一源笔记的两个写法可能解析到同一目标：UPDATE OR REPLACE 合并为一行
+        conn.execute(
+            "UPDATE OR REPLACE links
+             SET target_id = ?4, anchor = ?5, target_ref = ?6, target_key = ?7, resolved = ?8
+             WHERE source_id = ?1 AND target_id = ?2 AND anchor = ?3",
+            rusqlite::params![
+                source_id,
+                target_id,
+                anchor,
+                resolved_id,
+                new_anchor,
+                target,
+                link_key(target),
+                resolved
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+/// 无向邻接表（节点 id → 相邻节点 id，均按 id 排序，遍历结果稳定）。
+fn graph_adjacency(graph: &NoteGraph) -> BTreeMap<&str, BTreeSet<&str>> {
+    let mut adjacency: BTreeMap<&str, BTreeSet<&str>> = graph
+        .nodes
+        .iter()
+        .map(|n| (n.id.as_str(), BTreeSet::new()))
+        .collect();
+    for edge in &graph.edges {
+        adjacency
+            .entry(&edge.source)
+            .or_default()
+            .insert(&edge.target);
+        adjacency
+            .entry(&edge.target)
+            .or_default()
+            .insert(&edge.source);
+    }
+    adjacency
+}
+
+/// 加密模式下配对设备表/撤销表的旁路文件：`<数据库文件名>.devices`（如 `cardmind.devices`）。
+fn sealed_devices_path(db_path: &Path) -> PathBuf {
+    db_path.with_extension("devices")
+}
+
+/// 加密旁路文件编码：配对设备 section 与撤销记录 section，各为 `count: u32 LE`
+/// 加每行 length-prefixed 字符串（配对设备 `peer_id, name, last_seen, paired_at`，
+/// last_seen 为空 = 长度 `u32::MAX`；撤销记录 `peer_id, revoked_by, revoked_at,
+/// signature`）。
+fn encode_device_tables(paired: &[PairedDeviceRow], revoked: &[RevokedDeviceRow]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&(paired.len() as u32).to_le_bytes());
+    for row in paired {
+        push_str(&mut buf, &row.peer_id);
+        push_str(&mut buf, &row.name);
+        push_opt_str(&mut buf, row.last_seen.as_deref());
+        push_str(&mut buf, &row.paired_at);
+    }
+    buf.extend_from_slice(&(revoked.len() as u32).to_le_bytes());
+    for row in revoked {
+        push_str(&mut buf, &row.peer_id);
+        push_str(&mut buf, &row.revoked_by);
+        push_str(&mut buf, &row.revoked_at);
+        push_str(&mut buf, &row.signature);
+    }
+    buf
+}
+
+fn decode_device_tables(data: &[u8]) -> Result<(Vec<PairedDeviceRow>, Vec<RevokedDeviceRow>)> {
+    let mut pos = 0;
+    let mut paired = Vec::new();
+    for _ in 0..take_u32(data, &mut pos, "paired device count")? {
+        paired.push(PairedDeviceRow {
+            peer_id: take_str(data, &mut pos, "paired peer_id")?,
+            name: take_str(data, &mut pos, "paired name")?,
+            last_seen: take_opt_str(data, &mut pos, "paired last_seen")?,
+            paired_at: take_str(data, &mut pos, "paired paired_at")?,
+        });
+    }
+    let mut revoked = Vec::new();
+    for _ in 0..take_u32(data, &mut pos, "revoked device count")? {
+        revoked.push(RevokedDeviceRow {
+            peer_id: take_str(data, &mut pos, "revoked peer_id")?,
+            revoked_by: take_str(data, &mut pos, "revoked revoked_by")?,
+            revoked_at: take_str(data, &mut pos, "revoked revoked_at")?,
+            signature: take_str(data, &mut pos, "revoked signature")?,
+        });
+    }
+    Ok((paired, revoked))
+}
@@ -11,16 +11,45 @@ use iroh::{
     endpoint::presets, Endpoint, EndpointAddr, PublicKey, RelayMode, SecretKey, Signature,
     TransportAddr,
 };
-use loro::{Container, ExportMode, LoroDoc, LoroValue, ValueOrContainer};
+use loro::{
+    Container, ExportMode, Frontiers, LoroDoc, LoroValue, UpdateOptions, ValueOrContainer,
+    VersionVector, ID,
+};
 use rand::Rng;
 use uuid::Uuid;
 
+use crate::codec::{push_bytes, push_str, take_bytes, take_str, take_u32};
 use crate::debug_log::{self, LogEvent, LogSink, PlatformSink};
-use crate::discovery::{DiscoveryService, PeerInfo};
-use crate::store::NoteStore;
+use crate::discovery::{DiscoveryService, PeerInfo, PeerWatch};
+use crate::events::{EventHub, SyncEvent};
+use crate::store::{NoteStore, RevokedDeviceRow};
+use crate::vault::{self, VaultKey};
 
 /// 同步服务 — 管理笔记集合并通过 iroh 与对端同步
 pub struct SyncService {
+    /// 与后台同步任务共享的服务状态（见 [`SyncContext`]）。
+    ctx: Arc<SyncContext>,
+    /// 当前配对码会话（内存态；10 分钟有效，重启失效可接受——用户重新发起）
+    pairing_session: Mutex<Option<PairingSession>>,
+    /// `accept_push` 已读完、等待 `import_all` 导入后再确认的入站连接。
+    pending_ack: Mutex<Option<PendingAck>>,
+    /// mDNS 发现服务（任务 J 惰性创建）：配对期间广播 + 发起方扫描。
+    ///
+    /// 用 tokio Mutex：`discover_peers` 需跨 await 持锁，FRB async 要求
+    /// Send future（std MutexGuard 非 Send，跨 await 编译不过）。
+    discovery: tokio::sync::Mutex<Option<DiscoveryService>>,
+    /// 后台接收任务状态（任务 O：持续 accept 对端 push；start/stop 幂等）。
+    receiver: Mutex<ReceiverHandle>,
+    /// 后台同步任务状态（防抖推送 + 周期同步会话；start/stop 幂等）。
+    sync_loop: Mutex<SyncLoopHandle>,
+}
+
+/// 主服务与后台同步任务（见 [`SyncService::start_sync_loop`]）共享的服务状态。
+///
+/// 以 `Arc` 整体共享：同步任务持有同一份状态运行推送与同步会话，设备名、
+/// 同步开关、退避等的变更对双方立即可见。配对会话、mDNS 与任务句柄只属于
+/// 主服务，不在此处。
+struct SyncContext {
     /// 可变核心状态（notes/tombstones/持久化路径）。
     ///
     /// 以 `Arc<Mutex<...>>` 共享：后台接收任务（任务 O）与主服务都能安全访问，
@@ -32,8 +61,6 @@ pub struct SyncService {
     relay_mode: RelayMode,
     /// 本设备持久化 SecretKey（构造时克隆保留，供凭证签名；不暴露、不落库）
     secret_key: SecretKey,
-    /// 当前配对码会话（内存态；10 分钟有效，重启失效可接受——用户重新发起）
-    pairing_session: Mutex<Option<PairingSession>>,
     /// 确认方已接收、等待用户确认的配对请求及其连接（确认时回复握手响应）。
     /// Arc 共享：后台接收任务（任务 O）与主服务路由到同一 pending_pairing。
     pending_pairing: Arc<Mutex<Option<PendingPairing>>>,
@@ -42,34 +69,66 @@ pub struct SyncService {
     /// 同步开关（决策 6 能力）：false 时调度器暂停推送与拉取。
     /// 移动端由 Flutter 侧按网络类型（WiFi vs 蜂窝）设置；桌面端恒 true。
     sync_allowed: AtomicBool,
-    /// 待同步笔记 id 集合（本地编辑成功后标记；成功推送后清空）。
-    /// 内存态：不持久化（保守正确——重启后经持久化加载重标全部待同步）。
-    pending_dirty: Mutex<HashSet<String>>,
-    /// note_id → 最后成功推送时间（模块 5 待同步计数基础；内存态不持久化）
-    last_pushed_at: Mutex<HashMap<String, DateTime<Utc>>>,
     /// peer_id → 最近已知直连 IP 列表（配对请求/配对目标时记录；供周期推送直连优先）
     peer_ips: Mutex<HashMap<String, Vec<String>>>,
-    /// mDNS 发现服务（任务 J 惰性创建）：配对期间广播 + 发起方扫描。
-    ///
-    /// 用 tokio Mutex：`discover_peers` 需跨 await 持锁，FRB async 要求
-    /// Send future（std MutexGuard 非 Send，跨 await 编译不过）。
-    discovery: tokio::sync::Mutex<Option<DiscoveryService>>,
-    /// 后台接收任务状态（任务 O：持续 accept 对端 push；start/stop 幂等）。
-    receiver: Mutex<ReceiverHandle>,
+    /// peer_id → 连续同步失败的退避状态（调度器跳过未到重试时间的对端）。
+    /// Arc 共享：后台接收任务收到对端入站连接即清除其退避。
+    peer_backoff: Arc<Mutex<HashMap<String, PeerBackoff>>>,
+    /// 最近一次传入的投影（配对/同步/投影刷新时绑定）：主服务 accept 路径据其
+    /// `paired_devices` / `revoked_devices` 鉴权入站同步帧。未绑定时拒绝全部
+    /// 入站同步帧（无从鉴权即不信任）。
+    trust_store: Mutex<Option<NoteStore>>,
+    /// 本地编辑通知：每次本地变更落盘后触发，后台同步任务据此防抖推送。
+    local_edits: tokio::sync::Notify,
     /// 调试日志 sink（实例级；测试注入收集/异常 sink 断言事件）。
     log: Arc<dyn LogSink>,
     /// verbose 日志开关（debug 提高详细程度；默认 false 只输出常规事件）。
     log_verbose: AtomicBool,
     content_revision: Arc<AtomicU64>,
+    /// UI 事件流（笔记变更/设备在线状态/同步进度/配对请求；见 [`crate::events`]）。
+    /// Arc 共享：后台接收任务与后台同步任务发出同一事件流。
+    events: Arc<EventHub>,
 }
 
 /// 可被主服务与后台接收任务共享的可变核心状态。
 struct CoreState {
     notes: HashMap<String, NoteCrdt>,
-    /// 已彻底删除的笔记 id 集合（墓碑）。删除信息随快照传播，防止
-    /// `sync_notes_to_store` 从 Loro 快照重建被删笔记（复活）。
-    tombstones: HashSet<String>,
+    /// 已彻底删除的笔记 id → 本端记录删除的时间（墓碑）。删除信息随快照传播，
+    /// 防止 `sync_notes_to_store` 从 Loro 快照重建被删笔记（复活）；全部配对设备
+    /// 确认或超过回收期限后回收（见 [`SyncService::collect_tombstones`]）。
+    tombstones: HashMap<String, DateTime<Utc>>,
     persistent_path: Option<PathBuf>,
+    /// 已落盘（基线快照 + 追加日志）的各笔记版本：persist 只追加超出部分。
+    persisted_versions: HashMap<String, VersionVector>,
+    /// 已落盘的墓碑（新增墓碑追加为墓碑记录）。
+    persisted_tombstones: HashSet<String>,
+    /// 自上次落盘以来有变更的笔记（本地编辑与导入时登记）：persist 只检查这些
+    /// 笔记，不随笔记总数线性增长。
+    dirty_notes: HashSet<String>,
+    /// 追加日志自上次压缩以来的记录数（超过阈值触发压缩）。
+    log_records: usize,
+    /// 追加日志当前字节数（超过阈值触发压缩；写失败时按此截断回滚）。
+    log_bytes: u64,
+    /// 静态加密数据密钥（数据目录启用加密时；快照与日志记录落盘前加密）。
+    vault_key: Option<VaultKey>,
+    /// peer_id → 对端已确认的同步水位（待同步计数按对端计算的依据；持久化为
+    /// `cardmind.sync`，重启后从上次确认处继续）。
+    peer_acks: HashMap<String, PeerWatermark>,
+    /// 设备名册（复制 CRDT）：`members` Map，peer_id → 签名成员条目（hex）。
+    /// 随同步会话交换，单独落盘为 `cardmind.roster`（见 [`roster_path`]）。
+    roster: LoroDoc,
+    /// 入站同步数据上限（主服务与后台接收任务共用）。
+    transfer_limits: TransferLimits,
+    /// 墓碑回收期限（见 [`DEFAULT_TOMBSTONE_HORIZON`]）。
+    tombstone_horizon: Duration,
+    /// 已回收墓碑：note_id → 原删除时间（持久化于 `cardmind.sync`）。对端带回
+    /// 这些 id 的记录或墓碑时不再导入（见 [`resurrected_tombstone`]）。
+    collected_tombstones: HashMap<String, DateTime<Utc>>,
+    /// note_id → 未处理的并发编辑冲突（导入时检测；持久化为 `cardmind.conflicts`）。
+    conflicts: HashMap<String, NoteConflict>,
+    /// 同步状态（水位、导入时间）有未落盘的变更：确认与导入只改内存，一轮同步
+    /// 或一次入站连接结束时整体写一次（见 [`flush_sync_state`]）。
+    sync_state_dirty: bool,
 }
 
 /// 后台接收任务句柄（start/stop 幂等管理）。
@@ -81,6 +140,15 @@ struct ReceiverHandle {
     join: Option<tokio::task::JoinHandle<()>>,
 }
 
+/// 后台同步任务句柄（start/stop 幂等管理）。
+#[derive(Default)]
+struct SyncLoopHandle {
+    /// 停止信号（任务在当前推送/会话结束后退出）
+    stop: Option<Arc<tokio::sync::Notify>>,
+    /// 同步任务句柄（stop 时有界等待，超时中止）
+    join: Option<tokio::task::JoinHandle<()>>,
+}
+
 /// 后台接收任务的独立上下文（endpoint clone + 共享 core + store clone + 日志）。
 ///
 /// 不持有 `&SyncService`：接收任务生命周期独立于 FRB opaque 锁，start 时拷贝所需
@@ -95,9 +163,13 @@ struct ReceiverContext {
     device_id: String,
     log_verbose: bool,
     cancel: Arc<AtomicBool>,
+    /// 与主服务共享的对端退避状态（入站连接证明对端在线 → 清除退避）
+    peer_backoff: Arc<Mutex<HashMap<String, PeerBackoff>>>,
     /// 连续空闲窗口计数（健康检查/诊断日志用）
     idle_windows: u64,
     content_revision: Arc<AtomicU64>,
+    /// 与主服务共享的事件流（入站变更投影后发出）
+    events: Arc<EventHub>,
 }
 
 /// 接收器单次 accept 窗口（任务 O：短窗口循环，与配对 accept 轮询同粒度）。
@@ -106,6 +178,8 @@ const RECEIVER_ACCEPT_WINDOW: Duration = Duration::from_millis(300);
 const RECEIVER_PROCESS_TIMEOUT: Duration = Duration::from_secs(10);
 /// stop_receiver 等待接收任务结束的硬上限（验收：3 秒内返回）。
 const RECEIVER_STOP_TIMEOUT: Duration = Duration::from_secs(3);
+/// stop_sync_loop 等待同步任务结束的上限：进行中的会话超过该时长则中止任务。
+const SYNC_LOOP_STOP_TIMEOUT: Duration = Duration::from_secs(3);
 
 /// NoteCrdt — LoroDoc 笔记模型
 ///
@@ -116,24 +190,202 @@ pub struct NoteCrdt {
     doc: LoroDoc,
 }
 
+/// 同一设备连续编辑合并为一个历史版本的时间窗口（秒）。编辑页约 0.7 秒
+/// 自动保存一次，不合并会让历史列表被逐键保存淹没。
+const HISTORY_MERGE_INTERVAL_SECS: i64 = 60;
+
+/// 正文超过该长度（字符/字节取大）时 `set_content` 改用按行 diff：
+/// 字符级 diff 在超长文本上耗时陡增，按行 diff 对 Markdown 编辑已足够精细。
+const CONTENT_LINE_DIFF_THRESHOLD: usize = 50_000;
+
 const ALPN: &[u8] = b"cardmind-v2";
+/// 全量推送帧标记：标记之后逐条发送全部笔记快照（墓碑 section + 记录流）。
+///
+/// 早于 [`HELLO_MAGIC`] 的版本经单向流发送且不压缩，即 v3 快照格式
+/// （`export_all` 原始字节），接收方仍按此格式接受。
 const LORO_MAGIC: &[u8; 8] = b"CARDMIND";
+/// 增量推送帧标记（双向流）：接收方回复各笔记版本向量摘要，发送方再只发送
+/// 对端缺失的 `ExportMode::updates` 增量。
+const DELTA_MAGIC: &[u8; 8] = b"CARDDELT";
+/// 版本摘要读取上限（防恶意对端回复超大摘要拖垮发送方内存）。
+const DELTA_DIGEST_MAX_LEN: usize = 64 * 1024 * 1024;
+/// 同步会话中撤销记录与设备名册 section 的读取上限（每台设备一条签名记录，
+/// 1 MiB 足够数千台设备）。
+const SESSION_META_MAX_LEN: usize = 1024 * 1024;
+/// 双向同步会话帧标记（双向流，一次连接双方收敛）：
+/// 1. 发起方 → `SESSION_MAGIC + (摘要长度: u32 LE, 本端版本摘要)
+///    + (长度: u32 LE, 本端已知撤销记录) + (长度: u32 LE, 本端设备名册快照)`
+/// 2. 响应方（先按配对设备表鉴权发起方）→ `(摘要长度: u32 LE, 响应方摘要)
+///    + (长度: u32 LE, 响应方已知撤销记录)
+///    + (长度: u32 LE, 合并后的设备名册快照)`
+///    + 发起方缺失的增量（逐条写出的墓碑 section + 记录流，至本方向流结束）
+/// 3. 发起方边读边导入增量后 → 响应方缺失的增量（同样逐条写出，至流结束）
+///
+/// 撤销记录随每次会话双向交换，经验签后各自落库（见 [`apply_revocations`]）；
+/// 设备名册双方 CRDT 合并，经可信成员签发的条目投影进配对设备表
+/// （见 [`apply_roster`]）。
+const SESSION_MAGIC: &[u8; 8] = b"CARDSESS";
+/// 握手帧标记（双向流，每个同步连接的第一帧）：
+/// 1. 发起方 → `HELLO_MAGIC + (协议版本: u16 LE) + (能力位: u8)`
+/// 2. 响应方 → `(协议版本: u16 LE) + (双方共同支持的能力位: u8)`
+/// 3. 发起方在同一双向流上继续发送同步帧（[`LORO_MAGIC`] / [`DELTA_MAGIC`] /
+///    [`SESSION_MAGIC`]），双方按共同能力编码记录流
+///
+/// 对端未应答（早期版本只接受单向流，或按未知帧标记关闭连接）→ 回退为 v3
+/// 快照格式的全量推送（见 [`SyncService::push_legacy_snapshot`]）。
+const HELLO_MAGIC: &[u8; 8] = b"CARDHELO";
+/// 同步协议版本（随 hello 交换）。线格式不兼容的改动须递增。
+const SYNC_PROTOCOL_VERSION: u16 = 2;
+/// hello 之前的协议版本（单向流 v3 快照全量推送）：早期版本对端按此版本计。
+const LEGACY_PROTOCOL_VERSION: u16 = 1;
+/// 对端协议版本下限：低于该版本的对端在握手时即拒绝（双方输出明确的错误
+/// 事件，而不是在记录流中途解析失败）。不高于 [`LEGACY_PROTOCOL_VERSION`] 时
+/// 早期版本对端回退为 v3 快照推送。
+const MIN_PEER_PROTOCOL_VERSION: u16 = 1;
+/// 能力位：增量推送与双向同步会话（[`DELTA_MAGIC`] / [`SESSION_MAGIC`]）。
+const CAP_DELTA: u8 = 0x01;
+/// 能力位：记录体 LZ4 压缩（格式见 [`compress_record_body`]）。
+const CAP_LZ4: u8 = 0x02;
+/// 能力位：分块流式导入（按 [`TransferLimits`] 逐条读取、分批落盘，中断后
+/// 按版本摘要续传）。增量与会话依赖此能力续传，缺失时回退为全量推送。
+const CAP_CHUNKED: u8 = 0x04;
+/// 本端支持的全部能力位。
+const SYNC_CAPABILITIES: u8 = CAP_DELTA | CAP_LZ4 | CAP_CHUNKED;
+/// 等待 hello 应答的时长：超时后发送单向流探测帧（见 [`SyncService::handshake`]），
+/// 再等待同样时长；仍无应答即报错（不当作早期版本）。
+const HELLO_TIMEOUT: Duration = Duration::from_secs(3);
 /// envelope 版本：
 /// - v1：旧纯文本格式（迁移路径）
 /// - v2：记录流（无墓碑 section）
 /// - v3：墓碑 section + 记录流
-const LORO_VERSION: u32 = 3;
+/// - v4：`(原始长度: u64 LE, LZ4 块)`，解压后同 v3
+/// - v5：压缩方式同 v4，墓碑 section 每项附带彻底删除时间（见
+///   [`write_timed_tombstone_section`]）
+const LORO_VERSION: u32 = 5;
+/// 同步线路 payload（推送/增量/会话记录流，`accept_push` 返回的数据）的格式
+/// 版本：墓碑 section 不带删除时间，同 envelope v3。
+const SYNC_PAYLOAD_VERSION: u32 = 3;
 const LORO_HEADER_LEN: usize = 8 + 4 + 8;
+/// LZ4 块格式的最大压缩比（每字节输入最多展开约 255 字节）：压缩 envelope 声明的
+/// 原始长度超过 `压缩体长度 × 该值` 必为损坏/伪造，分配内存前拒绝。
+const LZ4_MAX_RATIO: usize = 255;
+/// 更新日志记录类型：笔记 Loro 更新（首次落盘为完整快照）。
+const LOG_RECORD_NOTE: u8 = 0x01;
+/// 更新日志记录类型：墓碑（彻底删除；记录体为删除时间 i64 LE 毫秒，早期
+/// 版本写入的空记录体按重放时间计）。
+const LOG_RECORD_TOMBSTONE: u8 = 0x02;
+/// 更新日志记录类型：加密记录（启用静态加密时；解密后为完整的
+/// `kind + id + 记录体`）。
+const LOG_RECORD_SEALED: u8 = 0x80;
+/// 设备撤销签名的域分隔前缀（与配对凭证等其他签名载荷区分）。
+const REVOCATION_SIGN_CONTEXT: &[u8] = b"cardmind-revoke-v1";
+/// 设备名册成员条目签名的域分隔前缀。
+const ROSTER_SIGN_CONTEXT: &[u8] = b"cardmind-member-v1";
+/// 设备名册移除记录签名的域分隔前缀。
+const ROSTER_REMOVE_SIGN_CONTEXT: &[u8] = b"cardmind-unpair-v1";
+/// 入站同步帧被拒时的 QUIC 关闭码（对端读到连接关闭即失败）。
+const REJECT_CLOSE_CODE: u32 = 403;
+/// 日志累计记录数达到该值即压缩为新的基线快照。
+const LOG_COMPACT_RECORDS: usize = 512;
+/// 日志累计字节数达到该值即压缩（大笔记频繁编辑时先于记录数触发）。
+const LOG_COMPACT_BYTES: u64 = 8 * 1024 * 1024;
+/// 入站 hello 的协议版本低于 [`MIN_PEER_PROTOCOL_VERSION`] 时的 QUIC 关闭码
+/// （发起方据此报告本端版本过旧）。
+const UPGRADE_CLOSE_CODE: u32 = 426;
+/// 入站同步数据超出本端 [`TransferLimits`] 或导入中断时的 QUIC 关闭码
+/// （发送方据此判定未送达、不记水位）。
+const ABORT_CLOSE_CODE: u32 = 413;
+/// 配对握手帧读取上限（请求/响应只含身份、设备名与地址列表）。
+const PAIRING_FRAME_MAX_LEN: usize = 64 * 1024;
+/// 流式导入每累计该记录数即落盘一次（连接中断时已落盘部分无需重传）。
+const IMPORT_BATCH_RECORDS: usize = 64;
+/// 流式导入每累计该字节数即落盘一次（大笔记先于记录数触发）。
+const IMPORT_BATCH_BYTES: usize = 4 * 1024 * 1024;
+/// 墓碑回收期限默认值：未被全部配对设备确认的墓碑最多保留这么久。
+pub const DEFAULT_TOMBSTONE_HORIZON: Duration = Duration::from_secs(90 * 24 * 60 * 60);
 
 // ━━━ SyncService ━━━
 
-/// 单设备推送结果：peer_id + 成功/失败信息
+/// 单设备推送结果：peer_id + 成功/失败信息 + 送达/待同步笔记数
 #[derive(Debug, Clone)]
 pub struct DevicePushResult {
     pub peer_id: String,
+    /// 对端已确认收到（读完整帧后以关闭码 0 关闭连接）
     pub ok: bool,
     /// 失败原因（成功时为空）
     pub message: String,
+    /// 本次送达的笔记记录数（对端确认后才计；失败为 0）
+    pub delivered_count: u32,
+    /// 本次之后该对端仍未确认的笔记/墓碑数（失败时即下次需重试的数量）
+    pub pending_count: u32,
+}
+
+/// 笔记的一个历史版本（Loro change；FRB 可序列化，供历史面板展示）
+#[derive(Debug, Clone)]
+pub struct NoteVersion {
+    /// 版本标识（`counter@peer`），传给按版本渲染/恢复接口
+    pub version: String,
+    /// 提交时间（RFC3339；未记录时间戳的旧变更为空）
+    pub timestamp: String,
+    /// 发起设备 device_id（旧数据或对端旧版本写入的变更为空）
+    pub device_id: String,
+    /// 变更大小（Loro 原子操作数：插入/删除的字符数 + 元数据操作数）
+    pub change_len: u32,
+}
+
+/// 并发编辑冲突：导入的对端变更与本端尚未同步给对方的变更并发，且两侧都改动
+/// 了正文（Loro 已自动合并，记录下来供用户复核）。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteConflict {
+    pub note_id: String,
+    /// 检测时间（RFC3339）
+    pub detected_at: String,
+    /// 本端一侧并发变更的发起设备 device_id
+    pub local_devices: Vec<String>,
+    /// 对端一侧并发变更的发起设备 device_id
+    pub remote_devices: Vec<String>,
+    /// 本端一侧版本（frontiers，`counter@peer` 列表，格式同 [`NoteVersion::version`]）
+    pub local_version: Vec<String>,
+    /// 对端一侧版本
+    pub remote_version: Vec<String>,
+    /// 两侧的共同祖先版本（空 = 无共同历史）
+    pub base_version: Vec<String>,
+}
+
+/// 冲突详情（[`SyncService::list_conflicts`] 返回；UI 并排展示两侧正文）。
+#[derive(Debug, Clone)]
+pub struct ConflictDetail {
+    pub conflict: NoteConflict,
+    /// 共同祖先版本的正文
+    pub base_content: String,
+    /// 本端一侧的正文
+    pub local_content: String,
+    /// 对端一侧的正文
+    pub remote_content: String,
+    /// 当前（自动合并后）的正文
+    pub merged_content: String,
+}
+
+/// 冲突处理方式（见 [`SyncService::resolve_conflict`]）。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictResolution {
+    /// 保留自动合并结果
+    KeepMerged,
+    /// 以本端一侧的正文覆盖合并结果
+    KeepLocal,
+    /// 以对端一侧的正文覆盖合并结果
+    KeepRemote,
+}
+
+/// 正文中的一条 wiki 链接（[`NoteCrdt::parse_links`] 返回）。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteLink {
+    /// 链接目标：笔记 id 或标题
+    pub target: String,
+    /// 目标笔记内的锚点：`#标题` 或 `^块 id`（空 = 整篇笔记）
+    pub anchor: String,
+    /// 显示名（缺省取链接原文）
+    pub alias: String,
 }
 
 // ━━━ 自动同步调度（任务 H）━━━
@@ -142,16 +394,69 @@ pub struct DevicePushResult {
 /// 本实现为可调常量，默认 60 秒（任务单定稿）。
 pub const SYNC_POLL_INTERVAL_SECS: u64 = 60;
 
-/// 周期 accept 对端 push 的等待窗口（非阻塞拉取；到点返回 None，不长期占用
-/// accept 通道——避免与配对 accept 争用）。
-pub const SYNC_ACCEPT_WINDOW: Duration = Duration::from_secs(2);
+/// 本地编辑后的推送防抖（毫秒）：连续编辑合并为一次推送，最后一次编辑
+/// 约 2 秒后推出。
+pub const SYNC_EDIT_DEBOUNCE_MS: u64 = 2000;
+
+/// 对端首次同步失败后的重试间隔（秒）；此后每次连续失败翻倍。
+pub const SYNC_BACKOFF_BASE_SECS: u64 = 30;
+
+/// 退避间隔上限（秒）：长期离线的设备最多每 30 分钟尝试一次。
+pub const SYNC_BACKOFF_MAX_SECS: u64 = 30 * 60;
+
+// ━━━ 传输上限 ━━━
+
+/// 单条记录（笔记 id 或记录体）默认上限：64 MiB。
+pub const DEFAULT_MAX_RECORD_BYTES: u32 = 64 * 1024 * 1024;
+
+/// 单次传输（一个推送帧或同步会话的一个方向）默认累计上限：256 MiB（移动端
+/// 可承受的量级；流式导入边读边落盘，超出部分下一轮续传）。
+pub const DEFAULT_MAX_TRANSFER_BYTES: u64 = 256 * 1024 * 1024;
+
+/// 整帧读入内存再导入（`accept_push`）时的默认累计上限：64 MiB。
+pub const DEFAULT_MAX_BUFFERED_BYTES: u64 = 64 * 1024 * 1024;
+
+/// 读取单条记录时每次分配/读取的块大小：内存随实际到达的数据增长，而不是按
+/// 对端声明的长度一次分配。
+const RECORD_READ_CHUNK: usize = 64 * 1024;
+
+/// 入站同步数据的大小上限：记录按长度前缀逐条读取，任一记录或累计字节数超限
+/// 即中止读取并断开，异常或恶意对端无法耗尽本端内存。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransferLimits {
+    /// 单条记录上限（字节）
+    pub max_record_bytes: u32,
+    /// 单次传输累计上限（字节，含长度前缀）
+    pub max_transfer_bytes: u64,
+    /// 整帧读入内存再导入时的累计上限（字节；与 `max_transfer_bytes` 取较小者）
+    pub max_buffered_bytes: u64,
+}
+
+impl Default for TransferLimits {
+    fn default() -> Self {
+        Self {
+            max_record_bytes: DEFAULT_MAX_RECORD_BYTES,
+            max_transfer_bytes: DEFAULT_MAX_TRANSFER_BYTES,
+            max_buffered_bytes: DEFAULT_MAX_BUFFERED_BYTES,
+        }
+    }
+}
+
+/// 单台对端的退避状态（内存态；重启后全部对端立即重试）。
+#[derive(Debug, Clone, Copy)]
+struct PeerBackoff {
+    /// 连续失败次数（成功或对端重新出现时清零）
+    failures: u32,
+    /// 最早的下次尝试时间
+    next_attempt: std::time::Instant,
+}
 
 /// 一次周期同步的结果（FRB 可序列化，供 Flutter 侧诊断/未来 UI 使用）
 #[derive(Debug, Clone)]
 pub struct SyncCycleResult {
-    /// 成功推送的对端设备数（0 = 本轮无成功推送）
+    /// 成功完成同步会话的对端设备数（0 = 本轮无成功会话）
     pub pushed_count: u32,
-    /// 本轮是否 accept 到对端 push 并导入
+    /// 本轮是否从对端拉取到本端缺失的笔记并导入
     pub accepted_push: bool,
     /// 同步开关关闭导致整轮跳过
     pub disabled: bool,
@@ -282,7 +587,15 @@ impl SyncService {
             std::fs::create_dir_all(parent)
                 .with_context(|| format!("create data directory {}", parent.display()))?;
         }
-        let key = load_or_create_secret_key(data_dir.as_deref())?;
+        // 启用静态加密的数据目录必须先解锁（vault::unlock），否则拒绝加载
+        let vault_key = match &data_dir {
+            Some(dir) => vault::key_for_data_dir(dir)?,
+            None => None,
+        };
+        let key = load_or_create_secret_key(data_dir.as_deref(), vault_key.as_ref())?;
+        let roster = load_roster(path.as_deref(), vault_key.as_ref())?;
+        let sync_state = load_sync_state(path.as_deref(), vault_key.as_ref())?;
+        let conflicts = load_conflicts(path.as_deref(), vault_key.as_ref())?;
         let secret_key_for_signing = key.clone();
         let relay_mode = load_relay_mode(data_dir.as_deref())?;
         let endpoint = Endpoint::builder(presets::N0)
@@ -293,35 +606,75 @@ impl SyncService {
             .await
             .context("bind iroh endpoint")?;
         let mut service = Self {
-            core: Arc::new(Mutex::new(CoreState {
-                notes: HashMap::new(),
-                tombstones: HashSet::new(),
-                persistent_path: path.clone(),
-            })),
-            endpoint,
-            relay_mode,
-            secret_key: secret_key_for_signing,
+            ctx: Arc::new(SyncContext {
+                core: Arc::new(Mutex::new(CoreState {
+                    notes: HashMap::new(),
+                    tombstones: HashMap::new(),
+                    persistent_path: path.clone(),
+                    persisted_versions: HashMap::new(),
+                    persisted_tombstones: HashSet::new(),
+                    dirty_notes: HashSet::new(),
+                    log_records: 0,
+                    log_bytes: 0,
+                    vault_key: vault_key.clone(),
+                    peer_acks: sync_state.peer_acks,
+                    roster,
+                    transfer_limits: TransferLimits::default(),
+                    tombstone_horizon: sync_state
+                        .tombstone_horizon
+                        .unwrap_or(DEFAULT_TOMBSTONE_HORIZON),
+                    collected_tombstones: sync_state.collected_tombstones,
+                    conflicts,
+                    sync_state_dirty: false,
+                })),
+                endpoint,
+                relay_mode,
+                secret_key: secret_key_for_signing,
+                pending_pairing: Arc::new(Mutex::new(None)),
+                device_name: Mutex::new(default_device_name()),
+                sync_allowed: AtomicBool::new(true),
+                peer_ips: Mutex::new(HashMap::new()),
+                peer_backoff: Arc::new(Mutex::new(HashMap::new())),
+                trust_store: Mutex::new(None),
+                local_edits: tokio::sync::Notify::new(),
+                log,
+                log_verbose: AtomicBool::new(false),
+                content_revision: Arc::new(AtomicU64::new(0)),
+                events: Arc::new(EventHub::new()),
+            }),
             pairing_session: Mutex::new(None),
-            pending_pairing: Arc::new(Mutex::new(None)),
-            device_name: Mutex::new(default_device_name()),
-            sync_allowed: AtomicBool::new(true),
-            pending_dirty: Mutex::new(HashSet::new()),
-            last_pushed_at: Mutex::new(HashMap::new()),
-            peer_ips: Mutex::new(HashMap::new()),
+            pending_ack: Mutex::new(None),
             discovery: tokio::sync::Mutex::new(None),
             receiver: Mutex::new(ReceiverHandle::default()),
-            log,
-            log_verbose: AtomicBool::new(false),
-            content_revision: Arc::new(AtomicU64::new(0)),
+            sync_loop: Mutex::new(SyncLoopHandle::default()),
         };
         if let Some(path) = &path {
             if path.exists() {
                 let bytes = std::fs::read(path)
                     .with_context(|| format!("read Loro file {}", path.display()))?;
+                let snapshot_sealed = vault::is_sealed(&bytes);
+                let bytes = vault::open_at_rest(vault_key.as_ref(), bytes)
+                    .with_context(|| format!("decrypt Loro file {}", path.display()))?;
                 let (version, payload) = decode_envelope(&bytes)?;
                 service.import_raw(version, &payload)?;
-                // 重启后全部视为待同步（last_pushed_at 不持久化，保守正确——对端状态未知）
-                service.mark_all_pending();
+                // 崩溃恢复：基线快照之上重放追加日志（残缺尾部截断丢弃）
+                let (replayed, truncated, plaintext_records) = {
+                    let mut core = service.ctx.core.lock().unwrap();
+                    replay_update_log(&mut core)?
+                };
+                // 已启用加密但仍有明文（启用过程中中断）→ 立即压缩为密文快照
+                if vault_key.is_some() && (!snapshot_sealed || plaintext_records > 0) {
+                    service.compact()?;
+                }
+                if replayed > 0 || truncated > 0 {
+                    service.emit_log(
+                        LogEvent::new("storage.replay", "sync.init")
+                            .with_id(&service.device_id())
+                            .with_field("action", "success")
+                            .with_field("record_count", replayed.to_string())
+                            .with_field("truncated_bytes", truncated.to_string()),
+                    );
+                }
                 if version == 1 {
                     // ━━━ v1 → v2 迁移 ━━━
                     // 先备份原始 v1 文件，再逐 note 迁移：
@@ -333,11 +686,11 @@ impl SyncService {
                         .with_context(|| format!("backup v1 file to {}", backup.display()))?;
                     let now = chrono::Utc::now().to_rfc3339();
                     let note_ids: Vec<String> = {
-                        let core = service.core.lock().unwrap();
+                        let core = service.ctx.core.lock().unwrap();
                         core.notes.keys().cloned().collect()
                     };
                     for note_id in note_ids {
-                        let core = service.core.lock().unwrap();
+                        let core = service.ctx.core.lock().unwrap();
                         let Some(note) = core.notes.get(&note_id) else {
                             continue;
                         };
@@ -357,9 +710,10 @@ impl SyncService {
                         }
                         note.set_created_at(&now);
                         note.set_updated_at(&now);
+                        note.commit();
                     }
                     // 迁移全部完成后再以 v3 写回
-                    service.persist()?;
+                    service.compact()?;
                 }
             } else if let Some(parent) = path.parent() {
                 let legacy_db = parent.join("cardmind.db");
@@ -368,10 +722,22 @@ impl SyncService {
                     for (id, content) in store.legacy_notes()? {
                         let note = NoteCrdt::new();
                         note.set_content(&content);
-                        service.core.lock().unwrap().notes.insert(id, note);
+                        note.commit();
+                        service.ctx.core.lock().unwrap().notes.insert(id, note);
                     }
-                    service.persist()?;
+                    service.compact()?;
+                }
+            }
+        }
+        // 启用中断（vault.pending）：快照、日志与旁路文件已在上面改写为密文，
+        // 残留的明文投影数据库同样改写后才提交 vault
+        if let (Some(key), Some(dir)) = (&vault_key, &data_dir) {
+            if key.is_pending() {
+                let db = dir.join("cardmind.db");
+                if db.exists() {
+                    NoteStore::open(&db.to_string_lossy())?;
                 }
+                vault::commit(dir, key)?;
             }
         }
         service.emit_startup_events();
@@ -381,12 +747,12 @@ impl SyncService {
     /// 启动事件：初始化成功、relay 配置、本机身份（全部脱敏）。
     fn emit_startup_events(&self) {
         let device_id = self.device_id();
-        let notes_loaded = self.core.lock().unwrap().notes.len();
+        let notes_loaded = self.ctx.core.lock().unwrap().notes.len();
         let mut startup = LogEvent::new("startup.sync_service", "sync.init")
             .with_id(&device_id)
             .with_field("action", "success")
             .with_field("notes_loaded", notes_loaded.to_string());
-        let (relay_enabled, relay_host, relay_port) = relay_endpoint(&self.relay_mode);
+        let (relay_enabled, relay_host, relay_port) = relay_endpoint(&self.ctx.relay_mode);
         if relay_enabled {
             startup = startup
                 .with_field("relay_enabled", "true")
@@ -419,33 +785,31 @@ impl SyncService {
         self.emit_log(LogEvent::new("identity.device_id", "identity").with_id(&device_id));
     }
 
-    /// 输出日志事件：verbose 开关过滤 + sink 异常兜底（绝不打断主流程）。
+    /// 见 [`SyncContext::emit_log`]。
     fn emit_log(&self, event: LogEvent) {
-        if event.verbose && !self.log_verbose.load(Ordering::Relaxed) {
-            return;
-        }
-        debug_log::emit_to(&self.log, event);
+        self.ctx.emit_log(event)
     }
 
     /// 设置 verbose 日志开关（debug 提高详细程度；默认 false）。
     pub fn set_log_verbose(&self, verbose: bool) {
-        self.log_verbose.store(verbose, Ordering::Relaxed);
+        self.ctx.log_verbose.store(verbose, Ordering::Relaxed);
     }
 
     /// 获取本设备 iroh 身份 ID
     pub fn device_id(&self) -> String {
-        self.endpoint.id().to_string()
+        self.ctx.device_id()
     }
 
     /// 构造时使用的 relay 模式（任务 K：默认 `Disabled` 仅局域网；
     /// 持久化版经 `relay.txt` 可配置 `Custom`）
     pub fn relay_mode(&self) -> &RelayMode {
-        &self.relay_mode
+        &self.ctx.relay_mode
     }
 
     /// 本端点当前绑定的 IPv4 地址（`"ip:port"` 格式，用于直连/mDNS 广播）
     pub fn local_addrs(&self) -> Vec<String> {
-        self.endpoint
+        self.ctx
+            .endpoint
             .addr()
             .ip_addrs()
             .filter(|a| a.is_ipv4())
@@ -455,12 +819,12 @@ impl SyncService {
 
     /// 本设备名（配对握手时发送给对端；默认取主机名）
     pub fn device_name(&self) -> String {
-        self.device_name.lock().unwrap().clone()
+        self.ctx.device_name()
     }
 
     /// 设置本设备名
     pub fn set_device_name(&self, name: &str) {
-        *self.device_name.lock().unwrap() = name.to_string();
+        *self.ctx.device_name.lock().unwrap() = name.to_string();
     }
 
     // ━━━ 配对码（任务 G）━━━
@@ -482,7 +846,7 @@ impl SyncService {
         };
         *self.pairing_session.lock().unwrap() = Some(session);
         // 新码产生时清除上一次未完成的待确认请求（避免旧连接回复错码）
-        *self.pending_pairing.lock().unwrap() = None;
+        *self.ctx.pending_pairing.lock().unwrap() = None;
         // 显示配对码：开始/成功（**绝不记录码本身**）
         self.emit_log(
             LogEvent::new("pairing.show_code", "pairing.show_code")
@@ -494,16 +858,6 @@ impl SyncService {
 
     // ━━━ mDNS 自动发现接线（任务 J）━━━
 
-    /// 本端点当前监听端口（mDNS 广播用；与 `local_addrs` 同源）。
-    fn endpoint_listen_port(&self) -> u16 {
-        self.endpoint
-            .addr()
-            .ip_addrs()
-            .next()
-            .map(|a| a.port())
-            .unwrap_or(0)
-    }
-
     /// 确认方：生成 6 位配对码并启动 mDNS 广播（组合 API，任务 J）。
     ///
     /// 码与广播在同一调用内完成——配对期间广播一定在，Flutter 侧无需自行
@@ -521,7 +875,7 @@ impl SyncService {
             .as_ref()
             .map(|s| nonce_to_hex(&s.nonce))
             .unwrap_or_default();
-        let port = self.endpoint_listen_port();
+        let port = self.ctx.endpoint_listen_port();
         let mut guard = self.discovery.lock().await;
         if guard.is_none() {
             *guard = Some(DiscoveryService::new()?);
@@ -601,7 +955,8 @@ impl SyncService {
     ///
     /// 复用共享 DiscoveryService（惰性创建）；返回对端 device_id + ip:port，
     /// 供 UI 在设备 ID 留空时自动填充配对目标。扫描超时或通道断开返回
-    /// 已收集的结果（可能为空），不报错。
+    /// 已收集的结果（可能为空），不报错。被发现的已配对对端同时结束退避
+    /// （下一轮同步立即尝试）。
     pub async fn discover_peers(&self) -> Result<Vec<PeerInfo>> {
         let started = std::time::Instant::now();
         // 事件 #4：设备发现开始
@@ -626,12 +981,16 @@ impl SyncService {
         // 事件 #4：发现数量 + 耗时；verbose 时附候选 id（脱敏）
         match &result {
             Ok(peers) => {
+                // 局域网内重新出现的对端立即结束退避
+                for peer in peers {
+                    self.ctx.peer_reappeared(&peer.device_id, "mdns");
+                }
                 let mut ev = LogEvent::new("discovery.mdns", "discovery.mdns")
                     .with_id(&self.device_id())
                     .with_field("action", "result")
                     .with_field("count", peers.len().to_string())
                     .with_duration(duration);
-                if self.log_verbose.load(Ordering::Relaxed) && !peers.is_empty() {
+                if self.ctx.log_verbose.load(Ordering::Relaxed) && !peers.is_empty() {
                     let candidates: Vec<String> = peers
                         .iter()
                         .map(|p| debug_log::redact_device_id(&p.device_id))
@@ -813,7 +1172,7 @@ impl SyncService {
         let deadline = tokio::time::Instant::now() + timeout;
         loop {
             // 统一路由可能已把配对请求存入 pending_pairing（被周期 accept 抢到）
-            if let Some(pending) = self.pending_pairing.lock().unwrap().as_ref() {
+            if let Some(pending) = self.ctx.pending_pairing.lock().unwrap().as_ref() {
                 return Ok(Some(pending.request.clone()));
             }
             let now = tokio::time::Instant::now();
@@ -825,7 +1184,7 @@ impl SyncService {
             // 500ms 的较小值，保证每次网络等待都有界。
             let remaining = deadline.saturating_duration_since(now);
             let window = remaining.min(Duration::from_millis(500));
-            let incoming = match tokio::time::timeout(window, self.endpoint.accept()).await {
+            let incoming = match tokio::time::timeout(window, self.ctx.endpoint.accept()).await {
                 Ok(Some(incoming)) => incoming,
                 _ => continue,
             };
@@ -851,7 +1210,7 @@ impl SyncService {
                     );
                 }
             }
-            if let Some(pending) = self.pending_pairing.lock().unwrap().as_ref() {
+            if let Some(pending) = self.ctx.pending_pairing.lock().unwrap().as_ref() {
                 return Ok(Some(pending.request.clone()));
             }
         }
@@ -953,7 +1312,7 @@ impl SyncService {
 
         // 若存在待确认请求，校验其身份与本次确认的发起方一致（防错配连接/响应）
         {
-            let guard = self.pending_pairing.lock().unwrap();
+            let guard = self.ctx.pending_pairing.lock().unwrap();
             if let Some(pending) = guard.as_ref() {
                 if pending.request.device_id != requester.device_id {
                     anyhow::bail!("pairing requester mismatch with pending request");
@@ -964,23 +1323,31 @@ impl SyncService {
         // 配对码单次使用：成功后即清除会话
         *self.pairing_session.lock().unwrap() = None;
 
+        // 已撤销的设备不能重新配对（撤销对该 node id 永久有效）
+        if store.is_revoked_device(&requester.device_id)? {
+            anyhow::bail!("requester device has been revoked");
+        }
         // 确认方持久化发起方
         store.upsert_paired_device(&requester.device_id, &requester.device_name)?;
+        self.add_roster_member(&requester.device_id, &requester.device_name, &requester.ips)?;
+        self.ctx.bind_trust_store(store);
         // 配对握手成功 → 发起方立即进入"近期在线"（任务 O 验收 11：不能等下一次同步）
-        self.touch_last_seen(store, &requester.device_id, "pairing");
+        self.ctx
+            .touch_last_seen(store, &requester.device_id, "pairing");
         // 记录发起方直连 IP（供后续周期推送直连优先）
-        self.peer_ips
+        self.ctx
+            .peer_ips
             .lock()
             .unwrap()
             .insert(requester.device_id.clone(), requester.ips.clone());
 
         // 有真实握手（待确认连接）时：回复本机身份
-        let pending = self.pending_pairing.lock().unwrap().take();
+        let pending = self.ctx.pending_pairing.lock().unwrap().take();
         let had_handshake = pending.is_some();
         if let Some(pending) = pending {
             let response = encode_pairing_response(&PairingResponse {
                 device_id: self.device_id(),
-                device_name: self.device_name(),
+                device_name: self.ctx.device_name(),
             });
             let mut send = pending
                 .conn
@@ -1036,24 +1403,7 @@ impl SyncService {
         node_id: iroh::EndpointId,
         ips: &[String],
     ) -> Result<EndpointAddr> {
-        if ips.is_empty() {
-            let mut addr = EndpointAddr::new(node_id);
-            let urls: Vec<iroh::RelayUrl> = self.relay_mode.relay_map().urls();
-            if let Some(u) = urls.into_iter().next() {
-                addr = addr.with_relay_url(u);
-            }
-            Ok(addr)
-        } else {
-            let ips: Vec<TransportAddr> = ips
-                .iter()
-                .filter_map(|ip| ip.parse::<std::net::SocketAddr>().ok())
-                .map(TransportAddr::Ip)
-                .collect();
-            if ips.is_empty() {
-                anyhow::bail!("no valid IPs provided");
-            }
-            Ok(EndpointAddr::from_parts(node_id, ips))
-        }
+        self.ctx.build_connect_addr(node_id, ips)
     }
 
     /// 发起方：连接确认方，发送配对请求，等待握手响应；成功后 upsert 确认方。
@@ -1078,7 +1428,7 @@ impl SyncService {
                         .with_id(&self.device_id())
                         .with_id(&r.peer_id)
                         .with_field("action", "success")
-                        .with_field("transport", self.transport_label(&r.peer_id))
+                        .with_field("transport", self.ctx.transport_label(&r.peer_id))
                         .with_field("peer_name", r.peer_name.clone())
                         .with_duration(duration),
                 );
@@ -1109,7 +1459,7 @@ impl SyncService {
             .device_id
             .parse()
             .context("invalid target endpoint id")?;
-        let addr = self.build_connect_addr(node_id, &target.ips)?;
+        let addr = self.ctx.build_connect_addr(node_id, &target.ips)?;
 
         // 事件 #7：连接开始（transport 区分 direct / relay / dns）
         let transport = if !target.ips.is_empty() {
@@ -1128,11 +1478,11 @@ impl SyncService {
         );
 
         // 发起方请求：本机身份 + relay 信息（N0 preset 已自动发布地址到 n0 DNS）
-        let relay_urls: Vec<iroh::RelayUrl> = self.relay_mode.relay_map().urls();
+        let relay_urls: Vec<iroh::RelayUrl> = self.ctx.relay_mode.relay_map().urls();
         let request = PairingRequest {
             code: code.to_string(),
             device_id: self.device_id(),
-            device_name: self.device_name(),
+            device_name: self.ctx.device_name(),
             relay_info: relay_urls
                 .iter()
                 .map(|u| u.to_string())
@@ -1143,6 +1493,7 @@ impl SyncService {
         };
 
         let conn = self
+            .ctx
             .endpoint
             .connect(addr, ALPN)
             .await
@@ -1165,19 +1516,26 @@ impl SyncService {
             .await
             .context("accept pairing response stream")?;
         let data = recv
-            .read_to_end(usize::MAX)
+            .read_to_end(PAIRING_FRAME_MAX_LEN)
             .await
             .context("read pairing response")?;
         let response = decode_pairing_response(&data)?;
         // 数据已读入内存，主动关闭连接，通知确认方可释放（与 accept_push 同模式）
         conn.close(0u32.into(), b"done");
 
+        if store.is_revoked_device(&response.device_id)? {
+            anyhow::bail!("confirmer device has been revoked");
+        }
         // 握手响应 → 发起方持久化确认方
         store.upsert_paired_device(&response.device_id, &response.device_name)?;
+        self.add_roster_member(&response.device_id, &response.device_name, &target.ips)?;
+        self.ctx.bind_trust_store(store);
         // 配对握手成功 → 确认方立即进入"近期在线"（任务 O 验收 11）
-        self.touch_last_seen(store, &response.device_id, "pairing");
+        self.ctx
+            .touch_last_seen(store, &response.device_id, "pairing");
         // 记录确认方直连 IP（供后续周期推送直连优先）
-        self.peer_ips
+        self.ctx
+            .peer_ips
             .lock()
             .unwrap()
             .insert(response.device_id.clone(), target.ips.clone());
@@ -1188,185 +1546,574 @@ impl SyncService {
         })
     }
 
-    /// 传输方式标签（direct/relay/dns；对端已有直连 IP 记录 → direct）。
-    fn transport_label(&self, peer_id: &str) -> String {
-        if self
-            .peer_ips
-            .lock()
-            .unwrap()
-            .get(peer_id)
-            .map(|ips| !ips.is_empty())
-            .unwrap_or(false)
-        {
-            return "direct".to_string();
-        }
-        if self
-            .relay_mode
-            .relay_map()
-            .urls::<Vec<iroh::RelayUrl>>()
-            .into_iter()
-            .next()
-            .is_some()
-        {
-            return "relay".to_string();
-        }
-        "dns".to_string()
-    }
-
     /// 添加/创建一条笔记
     pub fn create_note(&mut self, note_id: String, content: &str) -> Result<()> {
         let note = NoteCrdt::new();
         note.set_content(content);
-        let mut core = self.core.lock().unwrap();
+        note.commit_edit(&self.device_id());
+        let mut core = self.ctx.core.lock().unwrap();
         let previous = core.notes.remove(&note_id);
         core.notes.insert(note_id.clone(), note);
-        if let Err(err) = self.persist_locked(&core) {
+        core.dirty_notes.insert(note_id.clone());
+        if let Err(err) = self.persist_locked(&mut core) {
             core.notes.remove(&note_id);
             if let Some(previous) = previous {
                 core.notes.insert(note_id, previous);
             }
             return Err(err);
         }
-        drop(core);
-        // 编辑保存即推送：标记待同步（推送由调度器异步执行，不阻塞编辑）
-        self.mark_sync_pending(&note_id);
+        self.note_local_edit(SyncEvent::NotesChanged {
+            note_ids: vec![note_id],
+            remote: false,
+        });
         Ok(())
     }
 
-    /// 遍历所有笔记（用于同步到 SQLite；任务 O 后返回 owned 快照，避免持锁借用）
-    pub fn iter_notes(&self) -> Vec<(String, NoteCrdt)> {
-        let core = self.core.lock().unwrap();
-        core.notes.clone().into_iter().collect()
+    /// 笔记历史版本列表（新 → 旧）：提交时间、发起设备、变更大小。
+    pub fn note_versions(&self, note_id: &str) -> Result<Vec<NoteVersion>> {
+        let core = self.ctx.core.lock().unwrap();
+        let note = core
+            .notes
+            .get(note_id)
+            .ok_or_else(|| anyhow::anyhow!("note not found: {}", note_id))?;
+        Ok(note.versions())
     }
 
-    /// 更新笔记内容
-    pub fn update_note(&mut self, note_id: &str, content: &str) -> Result<()> {
+    /// 渲染笔记在指定历史版本时的正文（只读：在 fork 上 checkout，不影响当前文档）。
+    pub fn note_content_at_version(&self, note_id: &str, version: &str) -> Result<String> {
+        let core = self.ctx.core.lock().unwrap();
+        let note = core
+            .notes
+            .get(note_id)
+            .ok_or_else(|| anyhow::anyhow!("note not found: {}", note_id))?;
+        Ok(note.at_version(version)?.get_content())
+    }
+
+    /// 恢复到指定历史版本：把该版本的正文与标签作为一次新编辑写入（不回退
+    /// 历史），随后像普通编辑一样持久化并标记待同步，传播到所有设备。
+    ///
+    /// persist 失败时回滚内存态。
+    pub fn restore_note_version(&mut self, note_id: &str, version: &str) -> Result<()> {
         {
-            let core = self.core.lock().unwrap();
+            let mut core = self.ctx.core.lock().unwrap();
             let note = core
                 .notes
                 .get(note_id)
                 .ok_or_else(|| anyhow::anyhow!("note not found: {}", note_id))?;
-            let previous = note.get_content();
-            note.set_content(content);
-            if let Err(err) = self.persist_locked(&core) {
-                if let Some(note) = core.notes.get(note_id) {
-                    note.set_content(&previous);
-                }
-                return Err(err);
+            let past = note.at_version(version)?;
+            let checkpoint = note.checkpoint();
+            note.set_content(&past.get_content());
+            let past_tags = past.get_tags();
+            if past_tags != note.get_tags() {
+                note.set_tags(&past_tags);
             }
+            note.commit_edit(&self.device_id());
+            self.persist_edit_locked(&mut core, vec![(note_id.to_string(), checkpoint)])?;
         }
-        self.mark_sync_pending(note_id);
+        self.note_local_edit(SyncEvent::NotesChanged {
+            note_ids: vec![note_id.to_string()],
+            remote: false,
+        });
         Ok(())
     }
 
-    /// 更新笔记元数据（meta tags）
+    /// 未处理的并发编辑冲突（按检测时间排序），附两侧与共同祖先的正文。
     ///
-    /// 更新 NoteCrdt 的 meta.tags list 并 persist；persist 失败时回滚内存态。
-    pub fn update_metadata(&mut self, note_id: &str, tags: &[String]) -> Result<()> {
-        {
-            let core = self.core.lock().unwrap();
-            let note = core
-                .notes
+    /// 笔记已彻底删除，或记录的版本已不在文档中（导入失败回滚）的冲突不再返回。
+    pub fn list_conflicts(&self) -> Vec<ConflictDetail> {
+        let core = self.ctx.core.lock().unwrap();
+        let mut details: Vec<ConflictDetail> = core
+            .conflicts
+            .values()
+            .filter_map(|conflict| {
+                let note = core.notes.get(&conflict.note_id)?;
+                let content_at = |versions: &[String]| {
+                    frontiers_from_strings(versions).and_then(|f| note.content_at(&f))
+                };
+                Some(ConflictDetail {
+                    base_content: content_at(&conflict.base_version).ok()?,
+                    local_content: content_at(&conflict.local_version).ok()?,
+                    remote_content: content_at(&conflict.remote_version).ok()?,
+                    merged_content: note.get_content(),
+                    conflict: conflict.clone(),
+                })
+            })
+            .collect();
+        details.sort_by(|a, b| {
+            (&a.conflict.detected_at, &a.conflict.note_id)
+                .cmp(&(&b.conflict.detected_at, &b.conflict.note_id))
+        });
+        details
+    }
+
+    /// 处理笔记的并发编辑冲突：保留合并结果，或以一侧正文覆盖（作为一次新编辑
+    /// 写入并同步到其他设备）。处理后冲突记录删除。
+    ///
+    /// 笔记没有未处理的冲突时报错；persist 失败时回滚内存态。
+    pub fn resolve_conflict(
+        &mut self,
+        note_id: &str,
+        resolution: ConflictResolution,
+    ) -> Result<()> {
+        let edited = {
+            let mut core = self.ctx.core.lock().unwrap();
+            let conflict = core
+                .conflicts
                 .get(note_id)
-                .ok_or_else(|| anyhow::anyhow!("note not found: {}", note_id))?;
-            let previous = note.get_tags();
-            note.set_tags(tags);
-            if let Err(err) = self.persist_locked(&core) {
-                if let Some(note) = core.notes.get(note_id) {
-                    note.set_tags(&previous);
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("no conflict for note: {}", note_id))?;
+            let chosen = match resolution {
+                ConflictResolution::KeepMerged => None,
+                ConflictResolution::KeepLocal => Some(conflict.local_version),
+                ConflictResolution::KeepRemote => Some(conflict.remote_version),
+            };
+            let edited = match chosen {
+                Some(versions) => {
+                    let note = core
+                        .notes
+                        .get(note_id)
+                        .ok_or_else(|| anyhow::anyhow!("note not found: {}", note_id))?;
+                    let content = note.content_at(&frontiers_from_strings(&versions)?)?;
+                    let checkpoint = note.checkpoint();
+                    note.set_content(&content);
+                    note.commit_edit(&self.device_id());
+                    self.persist_edit_locked(&mut core, vec![(note_id.to_string(), checkpoint)])?;
+                    true
                 }
-                return Err(err);
-            }
+                None => false,
+            };
+            core.conflicts.remove(note_id);
+            persist_conflicts(&core)?;
+            edited
+        };
+        self.emit_log(
+            LogEvent::new("sync.conflict", "sync.conflict")
+                .with_id(&self.device_id())
+                .with_field("action", "resolved")
+                .with_field(
+                    "resolution",
+                    match resolution {
+                        ConflictResolution::KeepMerged => "merged",
+                        ConflictResolution::KeepLocal => "local",
+                        ConflictResolution::KeepRemote => "remote",
+                    },
+                ),
+        );
+        if edited {
+            self.note_local_edit(SyncEvent::NotesChanged {
+                note_ids: vec![note_id.to_string()],
+                remote: false,
+            });
         }
-        self.mark_sync_pending(note_id);
         Ok(())
     }
 
-    /// 获取笔记内容
-    pub fn get_note(&self, note_id: &str) -> Option<String> {
-        let core = self.core.lock().unwrap();
-        core.notes.get(note_id).map(|n| n.get_content())
+    /// 遍历所有笔记（用于同步到 SQLite；任务 O 后返回 owned 快照，避免持锁借用）
+    pub fn iter_notes(&self) -> Vec<(String, NoteCrdt)> {
+        self.ctx.iter_notes()
     }
 
-    /// 软删除：给笔记 meta 打 deleted_at 标记（进回收站），随快照传播。
-    ///
-    /// 笔记仍在 notes HashMap 中，仅 meta 标记；persist 失败时回滚内存态。
-    pub fn soft_delete_note(&mut self, note_id: &str) -> Result<()> {
+    /// 更新笔记内容
+    pub fn update_note(&mut self, note_id: &str, content: &str) -> Result<()> {
         {
-            let core = self.core.lock().unwrap();
+            let mut core = self.ctx.core.lock().unwrap();
             let note = core
                 .notes
                 .get(note_id)
                 .ok_or_else(|| anyhow::anyhow!("note not found: {}", note_id))?;
-            let previous = note.get_deleted_at();
-            note.set_deleted_at(Some(Utc::now().to_rfc3339()));
-            if let Err(err) = self.persist_locked(&core) {
-                if let Some(note) = core.notes.get(note_id) {
-                    note.set_deleted_at(previous);
-                }
-                return Err(err);
-            }
+            let checkpoint = note.checkpoint();
+            note.set_content(content);
+            note.commit_edit(&self.device_id());
+            self.persist_edit_locked(&mut core, vec![(note_id.to_string(), checkpoint)])?;
         }
-        self.mark_sync_pending(note_id);
+        self.note_local_edit(SyncEvent::NotesChanged {
+            note_ids: vec![note_id.to_string()],
+            remote: false,
+        });
         Ok(())
     }
 
-    /// 恢复：清除笔记 meta 的 deleted_at 标记，随快照传播。
-    pub fn restore_note(&mut self, note_id: &str) -> Result<()> {
+    /// 更新笔记元数据（meta tags）
+    ///
+    /// 更新 NoteCrdt 的 meta.tags list 并 persist；persist 失败时回滚内存态。
+    pub fn update_metadata(&mut self, note_id: &str, tags: &[String]) -> Result<()> {
         {
-            let core = self.core.lock().unwrap();
+            let mut core = self.ctx.core.lock().unwrap();
             let note = core
                 .notes
                 .get(note_id)
                 .ok_or_else(|| anyhow::anyhow!("note not found: {}", note_id))?;
-            let previous = note.get_deleted_at();
-            note.set_deleted_at(None);
-            if let Err(err) = self.persist_locked(&core) {
-                if let Some(note) = core.notes.get(note_id) {
-                    note.set_deleted_at(previous);
-                }
-                return Err(err);
-            }
+            let checkpoint = note.checkpoint();
+            note.set_tags(tags);
+            note.commit_edit(&self.device_id());
+            self.persist_edit_locked(&mut core, vec![(note_id.to_string(), checkpoint)])?;
         }
-        self.mark_sync_pending(note_id);
+        self.note_local_edit(SyncEvent::NotesChanged {
+            note_ids: vec![note_id.to_string()],
+            remote: false,
+        });
         Ok(())
     }
 
-    /// 彻底删除：从 notes 移除并记入墓碑（删除信息随快照传播，防复活）。
+    /// 改标题：改写笔记首行，并把所有指向它的链接一并改写（同一次 persist 的
+    /// CRDT 编辑，随后同步到其他设备）：
     ///
-    /// 已彻底删除的 id 幂等成功；persist 失败时回滚内存态。
-    pub fn purge_note(&mut self, note_id: &str) -> Result<()> {
-        let mut core = self.core.lock().unwrap();
-        let removed = core.notes.remove(note_id);
-        if removed.is_none() && !core.tombstones.contains(note_id) {
-            anyhow::bail!("note not found: {}", note_id);
+    /// - `[[旧标题]]` 标题链接改为 `[[新标题]]`（新标题含链接语法字符时改为
+    ///   `[[id|新标题]]`），锚点与显示名保留
+    /// - `rewrite_aliases = true` 时，显示名等于旧标题的链接（如 `[[id|旧标题]]`）
+    ///   显示名一并改为新标题；其他自定义显示名不动
+    ///
+    /// 改写后的笔记随即写入 `store` 投影（links 同步更新）。返回被改写链接的其他
+    /// 笔记数。persist 失败时回滚全部内存态。
+    pub fn rename_note(
+        &mut self,
+        store: &NoteStore,
+        note_id: &str,
+        new_title: &str,
+        rewrite_aliases: bool,
+    ) -> Result<usize> {
+        let new_title = new_title.trim();
+        if new_title.is_empty() || new_title.contains('\n') {
+            anyhow::bail!("invalid note title");
         }
-        core.tombstones.insert(note_id.to_string());
-        if let Err(err) = self.persist_locked(&core) {
-            if let Some(note) = removed {
-                core.notes.insert(note_id.to_string(), note);
+        let old_title = self
+            .ctx
+            .core
+            .lock()
+            .unwrap()
+            .notes
+            .get(note_id)
+            .map(|note| note.get_title())
+            .ok_or_else(|| anyhow::anyhow!("note not found: {}", note_id))?;
+        let old_key = crate::store::link_key(&old_title);
+        let old_key = old_key.as_str();
+        // 同名笔记的标题链接只解析到其中一篇：只改写投影中解析到本笔记的源笔记
+        let title_sources = link_sources(store, note_id)?;
+        let names_note = |owns_title: bool| {
+            move |dest: &str| {
+                dest == note_id || (owns_title && crate::store::link_key(dest) == old_key)
             }
-            core.tombstones.remove(note_id);
-            return Err(err);
-        }
-        drop(core);
-        self.mark_sync_pending(note_id);
-        Ok(())
+        };
+        let rewrite = |owns_title: bool| {
+            move |link: &NoteLink, raw_alias: Option<&str>| {
+                let by_title = link.target != note_id;
+                if by_title && !(owns_title && crate::store::link_key(&link.target) == old_key) {
+                    return None;
+                }
+                let alias = match raw_alias {
+                    Some(alias) if rewrite_aliases && crate::store::link_key(alias) == old_key => {
+                        Some(new_title)
+                    }
+                    other => other,
+                };
+                let inner = match (by_title, alias) {
+                    (true, alias) if is_link_safe_title(new_title) => {
+                        format_link_inner(new_title, &link.anchor, alias)
+                    }
+                    (true, alias) => {
+                        format_link_inner(note_id, &link.anchor, Some(alias.unwrap_or(new_title)))
+                    }
+                    (false, alias) => format_link_inner(note_id, &link.anchor, alias),
+                };
+                Some(inner)
+            }
+        };
+
+        let edited = {
+            let mut core = self.ctx.core.lock().unwrap();
+            let mut edits: Vec<(String, String)> = Vec::new();
+            for (id, note) in &core.notes {
+                let content = note.get_content();
+                let content = if id == note_id {
+                    replace_title_line(&content, new_title)
+                } else {
+                    content
+                };
+                let owns_title = title_sources.contains(id);
+                let rewritten =
+                    rewrite_wiki_links(&content, &names_note(owns_title), rewrite(owns_title));
+                if id == note_id || rewritten.is_some() {
+                    edits.push((id.clone(), rewritten.unwrap_or(content)));
+                }
+            }
+            self.apply_content_edits(&mut core, &edits, Vec::new())?;
+            edits.into_iter().map(|(id, _)| id).collect::<Vec<_>>()
+        };
+        let rewritten = edited.len() - 1;
+        // 先刷新改名的笔记（重新解析按新旧标题指向它的链接），再刷新改写过的源笔记
+        let mut ordered = edited;
+        ordered.sort_by_key(|id| id.as_str() != note_id);
+        self.sync_edited_to_store(store, &ordered)?;
+        self.note_local_edit(SyncEvent::NotesChanged {
+            note_ids: ordered,
+            remote: false,
+        });
+        Ok(rewritten)
     }
 
-    /// 过期清理：遍历 meta.deleted_at < cutoff 的软删笔记并 purge（入墓碑），
-    /// 返回清理数。cutoff 为 RFC3339 时间字符串。
-    pub fn purge_expired(&mut self, cutoff: &str) -> Result<usize> {
-        let cutoff_dt = chrono::DateTime::parse_from_rfc3339(cutoff)
-            .with_context(|| format!("invalid cutoff timestamp: {}", cutoff))?;
-        let mut core = self.core.lock().unwrap();
-        let expired: Vec<String> = core
-            .notes
-            .iter()
-            .filter_map(|(id, note)| {
-                let deleted_at = note.get_deleted_at()?;
-                let dt = chrono::DateTime::parse_from_rfc3339(&deleted_at).ok()?;
+    /// 合并笔记：`source_id` 的正文追加到 `into_id` 末尾、标签取并集，`source_id`
+    /// 移入回收站；所有指向 `source_id` 的链接（id 链接与解析到它的标题链接）
+    /// 改指向 `into_id`，锚点与显示名保留（原先无显示名的标题链接以原标题为显示名）。
+    ///
+    /// 全部改动为同一次 persist 的 CRDT 编辑，随后写入 `store` 投影。返回被改写
+    /// 链接的其他笔记数。persist 失败时回滚全部内存态。
+    pub fn merge_notes(
+        &mut self,
+        store: &NoteStore,
+        source_id: &str,
+        into_id: &str,
+    ) -> Result<usize> {
+        if source_id == into_id {
+            anyhow::bail!("cannot merge a note into itself");
+        }
+        let source_title = {
+            let core = self.ctx.core.lock().unwrap();
+            if !core.notes.contains_key(into_id) {
+                anyhow::bail!("note not found: {}", into_id);
+            }
+            core.notes
+                .get(source_id)
+                .map(|note| note.get_title())
+                .ok_or_else(|| anyhow::anyhow!("note not found: {}", source_id))?
+        };
+        let source_key = crate::store::link_key(&source_title);
+        let source_key = source_key.as_str();
+        let title_sources = link_sources(store, source_id)?;
+        let names_note = |owns_title: bool| {
+            move |dest: &str| {
+                dest == source_id || (owns_title && crate::store::link_key(dest) == source_key)
+            }
+        };
+        let retarget = |owns_title: bool| {
+            move |link: &NoteLink, raw_alias: Option<&str>| {
+                let by_title = link.target != source_id;
+                if by_title && !(owns_title && crate::store::link_key(&link.target) == source_key) {
+                    return None;
+                }
+                let alias = raw_alias.or(by_title.then_some(link.target.as_str()));
+                Some(format_link_inner(into_id, &link.anchor, alias))
+            }
+        };
+
+        let edited = {
+            let mut core = self.ctx.core.lock().unwrap();
+            let source_content = core.notes[source_id].get_content();
+            let mut edits: Vec<(String, String)> = Vec::new();
+            for (id, note) in &core.notes {
+                if id == source_id {
+                    continue;
+                }
+                let content = note.get_content();
+                let content = if id == into_id {
+                    format!("{}\n\n{}", content.trim_end(), source_content.trim())
+                } else {
+                    content
+                };
+                // 目标笔记追加了源笔记正文：其中的标题链接按源笔记的解析结果判断
+                let owns_title = title_sources.contains(id)
+                    || (id == into_id && title_sources.contains(source_id));
+                let rewritten =
+                    rewrite_wiki_links(&content, &names_note(owns_title), retarget(owns_title));
+                if id == into_id || rewritten.is_some() {
+                    edits.push((id.clone(), rewritten.unwrap_or(content)));
+                }
+            }
+            let source = &core.notes[source_id];
+            let into = &core.notes[into_id];
+            let checkpoints = vec![
+                (into_id.to_string(), into.checkpoint()),
+                (source_id.to_string(), source.checkpoint()),
+            ];
+            let mut tags = into.get_tags();
+            for tag in source.get_tags() {
+                if !tags.contains(&tag) {
+                    tags.push(tag);
+                }
+            }
+            into.set_tags(&tags);
+            source.set_deleted_at(Some(Utc::now().to_rfc3339()));
+            source.commit_as(&self.device_id());
+            self.apply_content_edits(&mut core, &edits, checkpoints)?;
+            edits.into_iter().map(|(id, _)| id).collect::<Vec<_>>()
+        };
+        let rewritten = edited.len() - 1;
+        let mut ordered = edited;
+        ordered.sort_by_key(|id| id.as_str() != into_id);
+        ordered.insert(1, source_id.to_string());
+        self.sync_edited_to_store(store, &ordered)?;
+        self.note_local_edit(SyncEvent::NotesChanged {
+            note_ids: ordered.into_iter().filter(|id| id != source_id).collect(),
+            remote: false,
+        });
+        self.note_local_edit(SyncEvent::NoteDeleted {
+            note_id: source_id.to_string(),
+            remote: false,
+        });
+        Ok(rewritten)
+    }
+
+    /// 把 `source_id` 中第一处未加链接的提及（见 [`NoteStore::unlinked_mentions`]）
+    /// 改为 `[[target_id|原文]]` 链接（CRDT 编辑），并写入 `store` 投影。
+    ///
+    /// 多处提及时重复调用逐处链接；已无提及时报错。
+    pub fn link_mention(
+        &mut self,
+        store: &NoteStore,
+        source_id: &str,
+        target_id: &str,
+    ) -> Result<()> {
+        {
+            let mut core = self.ctx.core.lock().unwrap();
+            let title = core
+                .notes
+                .get(target_id)
+                .map(|note| note.get_title())
+                .ok_or_else(|| anyhow::anyhow!("note not found: {}", target_id))?;
+            let content = core
+                .notes
+                .get(source_id)
+                .map(|note| note.get_content())
+                .ok_or_else(|| anyhow::anyhow!("note not found: {}", source_id))?;
+            let range = find_mentions(&content, &title)
+                .into_iter()
+                .next()
+                .ok_or_else(|| anyhow::anyhow!("no unlinked mention in note: {}", source_id))?;
+            let linked = format!(
+                "{}[[{}]]{}",
+                &content[..range.start],
+                format_link_inner(target_id, "", Some(&content[range.clone()])),
+                &content[range.end..]
+            );
+            self.apply_content_edits(&mut core, &[(source_id.to_string(), linked)], Vec::new())?;
+        }
+        self.sync_edited_to_store(store, &[source_id.to_string()])?;
+        self.note_local_edit(SyncEvent::NotesChanged {
+            note_ids: vec![source_id.to_string()],
+            remote: false,
+        });
+        Ok(())
+    }
+
+    /// 批量写入正文改动（各自提交一次 CRDT 编辑）并一次 persist；失败时全部回滚。
+    ///
+    /// `checkpoints` 为调用方已先行改动的笔记的编辑前版本，与正文改动一起回滚。
+    fn apply_content_edits(
+        &self,
+        core: &mut CoreState,
+        edits: &[(String, String)],
+        mut checkpoints: Vec<(String, Frontiers)>,
+    ) -> Result<()> {
+        let device_id = self.device_id();
+        for (id, content) in edits {
+            if let Some(note) = core.notes.get(id) {
+                if !checkpoints.iter().any(|(checked, _)| checked == id) {
+                    checkpoints.push((id.clone(), note.checkpoint()));
+                }
+                note.set_content(content);
+                note.commit_edit(&device_id);
+            }
+        }
+        self.persist_edit_locked(core, checkpoints)
+    }
+
+    /// 按给定顺序把笔记刷新到 SQLite 投影。
+    fn sync_edited_to_store(&self, store: &NoteStore, note_ids: &[String]) -> Result<()> {
+        for id in note_ids {
+            let note = self.ctx.core.lock().unwrap().notes.get(id).cloned();
+            if let Some(note) = note {
+                store.sync_note(id, &note)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 获取笔记内容
+    pub fn get_note(&self, note_id: &str) -> Option<String> {
+        let core = self.ctx.core.lock().unwrap();
+        core.notes.get(note_id).map(|n| n.get_content())
+    }
+
+    /// 软删除：给笔记 meta 打 deleted_at 标记（进回收站），随快照传播。
+    ///
+    /// 笔记仍在 notes HashMap 中，仅 meta 标记；persist 失败时回滚内存态。
+    pub fn soft_delete_note(&mut self, note_id: &str) -> Result<()> {
+        {
+            let mut core = self.ctx.core.lock().unwrap();
+            let note = core
+                .notes
+                .get(note_id)
+                .ok_or_else(|| anyhow::anyhow!("note not found: {}", note_id))?;
+            let checkpoint = note.checkpoint();
+            note.set_deleted_at(Some(Utc::now().to_rfc3339()));
+            note.commit_as(&self.device_id());
+            self.persist_edit_locked(&mut core, vec![(note_id.to_string(), checkpoint)])?;
+        }
+        self.note_local_edit(SyncEvent::NoteDeleted {
+            note_id: note_id.to_string(),
+            remote: false,
+        });
+        Ok(())
+    }
+
+    /// 恢复：清除笔记 meta 的 deleted_at 标记，随快照传播。
+    pub fn restore_note(&mut self, note_id: &str) -> Result<()> {
+        {
+            let mut core = self.ctx.core.lock().unwrap();
+            let note = core
+                .notes
+                .get(note_id)
+                .ok_or_else(|| anyhow::anyhow!("note not found: {}", note_id))?;
+            let checkpoint = note.checkpoint();
+            note.set_deleted_at(None);
+            note.commit_as(&self.device_id());
+            self.persist_edit_locked(&mut core, vec![(note_id.to_string(), checkpoint)])?;
+        }
+        self.note_local_edit(SyncEvent::NoteRestored {
+            note_id: note_id.to_string(),
+            remote: false,
+        });
+        Ok(())
+    }
+
+    /// 彻底删除：从 notes 移除并记入墓碑（删除信息随快照传播，防复活）。
+    ///
+    /// 已彻底删除的 id 幂等成功；persist 失败时回滚内存态。
+    pub fn purge_note(&mut self, note_id: &str) -> Result<()> {
+        let mut core = self.ctx.core.lock().unwrap();
+        let removed = core.notes.remove(note_id);
+        if removed.is_none() && !core.tombstones.contains_key(note_id) {
+            anyhow::bail!("note not found: {}", note_id);
+        }
+        let previous = core.tombstones.insert(note_id.to_string(), Utc::now());
+        if let Err(err) = self.persist_locked(&mut core) {
+            if let Some(note) = removed {
+                core.notes.insert(note_id.to_string(), note);
+            }
+            match previous {
+                Some(purged_at) => core.tombstones.insert(note_id.to_string(), purged_at),
+                None => core.tombstones.remove(note_id),
+            };
+            return Err(err);
+        }
+        self.note_local_edit(SyncEvent::NotesPurged {
+            note_ids: vec![note_id.to_string()],
+            remote: false,
+        });
+        Ok(())
+    }
+
+    /// 过期清理：遍历 meta.deleted_at < cutoff 的软删笔记并 purge（入墓碑），
+    /// 返回清理数。cutoff 为 RFC3339 时间字符串。
+    pub fn purge_expired(&mut self, cutoff: &str) -> Result<usize> {
+        let cutoff_dt = chrono::DateTime::parse_from_rfc3339(cutoff)
+            .with_context(|| format!("invalid cutoff timestamp: {}", cutoff))?;
+        let mut core = self.ctx.core.lock().unwrap();
+        let expired: Vec<String> = core
+            .notes
+            .iter()
+            .filter_map(|(id, note)| {
+                let deleted_at = note.get_deleted_at()?;
+                let dt = chrono::DateTime::parse_from_rfc3339(&deleted_at).ok()?;
                 (dt < cutoff_dt).then(|| id.clone())
             })
             .collect();
@@ -1375,13 +2122,14 @@ impl SyncService {
         }
         // 一次 persist：全部移除 + 入墓碑，失败则整体回滚
         let mut removed_notes = Vec::with_capacity(expired.len());
+        let now = Utc::now();
         for id in &expired {
             if let Some(note) = core.notes.remove(id) {
                 removed_notes.push((id.clone(), note));
             }
-            core.tombstones.insert(id.clone());
+            core.tombstones.insert(id.clone(), now);
         }
-        if let Err(err) = self.persist_locked(&core) {
+        if let Err(err) = self.persist_locked(&mut core) {
             for (id, note) in removed_notes {
                 core.notes.insert(id, note);
             }
@@ -1390,18 +2138,16 @@ impl SyncService {
             }
             return Err(err);
         }
-        drop(core);
-        // 与 purge_note 一致：清理的墓碑标记待同步，随下一次全量推送传播给对端
-        // （push 是全量快照含墓碑；不标记则墓碑只在有其他变更触发的推送中捎带）。
-        for id in &expired {
-            self.mark_sync_pending(id);
-        }
+        self.note_local_edit(SyncEvent::NotesPurged {
+            note_ids: expired.clone(),
+            remote: false,
+        });
         Ok(expired.len())
     }
 
     /// 墓碑集合快照（已彻底删除的 note id；任务 O 后改为 clone 快照，避免借用锁）
     pub fn tombstones(&self) -> HashSet<String> {
-        self.core.lock().unwrap().tombstones.clone()
+        self.ctx.tombstones()
     }
 
     /// 导出所有笔记的全量快照（用于首次同步）
@@ -1411,22 +2157,52 @@ impl SyncService {
     /// 记录流：每条笔记连续拼接为
     ///   `(note_id_len: u32 LE, note_id, snapshot_len: u32 LE, snapshot)`
     pub fn export_all(&self) -> Result<Vec<u8>> {
-        let core = self.core.lock().unwrap();
+        let core = self.ctx.core.lock().unwrap();
         export_core_all(&core)
     }
 
-    /// 导入全量快照（v3 语义：墓碑 section + 记录流）
+    /// 本端版本摘要（增量推送握手时接收方回复的内容；诊断/测试用）。
+    pub fn version_digest(&self) -> Vec<u8> {
+        self.ctx.version_digest()
+    }
+
+    /// 按对端版本摘要导出增量（与 `export_all` 同格式，`import_all` 直接消费）。
+    pub fn export_delta(&self, digest: &[u8]) -> Result<Vec<u8>> {
+        let remote = decode_version_digest(digest)?;
+        let core = self.ctx.core.lock().unwrap();
+        Ok(export_core_delta(&core, &remote)?.0)
+    }
+
+    /// 导入全量快照或增量（v3 语义：墓碑 section + 记录流；已有笔记合并导入）。
+    ///
+    /// 导入带来的变更随即作为对端事件发出（调用方随后刷新投影）。
     pub fn import_all(&mut self, data: &[u8]) -> Result<()> {
         let started = std::time::Instant::now();
+        // accept_push 读入的数据：导入（或失败）后才应答发送方
+        let ack = self.pending_ack.lock().unwrap().take();
         let result = {
-            let mut core = self.core.lock().unwrap();
-            import_core_all(&mut core, data)
+            let mut core = self.ctx.core.lock().unwrap();
+            import_core_tracked(&mut core, data)
         };
+        if let Some(ack) = ack {
+            ack.settle(result.is_ok());
+        }
         let duration = started.elapsed();
         // 事件 #9/#10：导入只记录数量/方向/耗时，绝不记录正文
         match &result {
-            Ok(()) => {
-                let core = self.core.lock().unwrap();
+            Ok((events, resurrected)) => {
+                for event in events {
+                    self.ctx.events.emit(event.clone());
+                }
+                if !resurrected.is_empty() {
+                    self.emit_log(
+                        LogEvent::new("sync.tombstone", "sync.import")
+                            .with_id(&self.device_id())
+                            .with_field("action", "resurrection_blocked")
+                            .with_field("note_count", resurrected.len().to_string()),
+                    );
+                }
+                let core = self.ctx.core.lock().unwrap();
                 let note_count = core.notes.len() + core.tombstones.len();
                 drop(core);
                 self.emit_log(
@@ -1451,27 +2227,96 @@ impl SyncService {
                 );
             }
         }
-        result
+        result.map(|_| ())
     }
 
     /// 持锁 persist（任务 O：persist 直接消费已持锁的 core，避免锁内重入）。
-    fn persist_locked(&self, core: &CoreState) -> Result<()> {
+    ///
+    /// 只追加本次变更到更新日志（见 [`persist_core`]），不重写整个快照。
+    fn persist_locked(&self, core: &mut CoreState) -> Result<()> {
         persist_core(core)
     }
 
-    /// 全量快照导出（已持锁 core 的纯函数，接收任务/主服务共用）。
-    fn persist(&self) -> Result<()> {
-        let core = self.core.lock().unwrap();
-        persist_core(&core)
+    /// 持锁 persist 一次本地编辑：`checkpoints` 为被编辑笔记编辑前的版本（见
+    /// [`NoteCrdt::checkpoint`]）。失败时把这些笔记回滚到检查点，丢弃本次变更
+    /// 而不产生新操作。
+    fn persist_edit_locked(
+        &self,
+        core: &mut CoreState,
+        checkpoints: Vec<(String, Frontiers)>,
+    ) -> Result<()> {
+        core.dirty_notes
+            .extend(checkpoints.iter().map(|(id, _)| id.clone()));
+        if let Err(err) = self.persist_locked(core) {
+            for (id, checkpoint) in checkpoints {
+                let restored = core
+                    .notes
+                    .get(&id)
+                    .map(|note| note.rollback_to(&checkpoint));
+                if let Some(Ok(note)) = restored {
+                    core.notes.insert(id, note);
+                }
+            }
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    /// 全量压缩：重写基线快照并清空追加日志（迁移写回/启动恢复后调用）。
+    fn compact(&self) -> Result<()> {
+        let mut core = self.ctx.core.lock().unwrap();
+        compact_core(&mut core)
+    }
+
+    /// 为持久化数据目录启用静态加密（口令派生密钥，见 [`vault`] 模块）。
+    ///
+    /// 依次：写出 `vault.pending` 并登记为已解锁 → 基线快照压缩为密文（明文日志
+    /// 随之清空）、设备名册、同步水位与冲突记录改写为密文 → `device.key` 改写为密文 → `store`
+    /// 切换为内存投影 + 加密配对设备表并删除明文 SQLite 文件 → 重建投影 → 提交为
+    /// `vault.bin`。此后每次启动须先 [`vault::unlock`] 才能加载，明文文件一律拒绝。
+    pub fn enable_encryption(&self, store: &NoteStore, passphrase: &str) -> Result<()> {
+        let data_dir = {
+            let core = self.ctx.core.lock().unwrap();
+            core.persistent_path
+                .as_ref()
+                .and_then(|p| p.parent().map(Path::to_path_buf))
+        };
+        let Some(data_dir) = data_dir else {
+            anyhow::bail!("encryption at rest requires a persistent sync service");
+        };
+        let key = vault::create(&data_dir, passphrase)?;
+        {
+            let mut core = self.ctx.core.lock().unwrap();
+            core.vault_key = Some(key.clone());
+            compact_core(&mut core)?;
+            persist_roster(&core)?;
+            persist_peer_acks(&core)?;
+            persist_conflicts(&core)?;
+        }
+        write_secret_key(
+            &data_dir.join("device.key"),
+            &self.ctx.secret_key,
+            Some(&key),
+        )?;
+        store.seal_in_place(&key)?;
+        self.ctx.sync_notes_to_store(store)?;
+        vault::commit(&data_dir, &key)?;
+        self.emit_log(
+            LogEvent::new("storage.encryption", "storage")
+                .with_id(&self.device_id())
+                .with_field("action", "enabled"),
+        );
+        Ok(())
     }
 
-    /// 导入 payload。`version` 决定是否含墓碑 section：
-    /// - v3：`墓碑 section + 记录流`（导入的墓碑与本地 tombstones union 合并；
+    /// 导入 payload。`version` 决定墓碑 section 格式（见 [`import_core_raw`]）：
+    /// - v5：`带删除时间的墓碑 section + 记录流`
+    /// - v3/v4：`墓碑 section + 记录流`（导入的墓碑与本地 tombstones union 合并；
     ///   记录流中遇到墓碑中的 id 跳过，不复活）
     /// - v1/v2：纯记录流（无墓碑 section，tombstones 为空，无损升级）
     fn import_raw(&mut self, version: u32, data: &[u8]) -> Result<()> {
-        let mut core = self.core.lock().unwrap();
-        import_core_raw(&mut core, version, data)
+        let mut core = self.ctx.core.lock().unwrap();
+        import_core_raw(&mut core, &mut ImportBatch::default(), version, data).map(|_| ())
     }
 
     /// 向指定对端推送所有笔记的快照
@@ -1483,7 +2328,7 @@ impl SyncService {
     pub async fn push_to_peer(&self, peer_id: &str, peer_ips: Vec<String>) -> Result<()> {
         let started = std::time::Instant::now();
         let note_count = {
-            let core = self.core.lock().unwrap();
+            let core = self.ctx.core.lock().unwrap();
             core.notes.len() + core.tombstones.len()
         };
         // 事件 #9：首次全量同步 push 开始（只记录数量，不记录正文）
@@ -1496,6 +2341,7 @@ impl SyncService {
                 .with_field("note_count", note_count.to_string()),
         );
         let result = self.push_to_peer_inner(peer_id, &peer_ips).await;
+        self.ctx.flush_sync_state();
         let duration = started.elapsed();
         match &result {
             Ok(()) => {
@@ -1525,176 +2371,50 @@ impl SyncService {
         result
     }
 
-    /// push_to_peer 核心逻辑。
+    /// push_to_peer 核心逻辑：握手后发送全量快照（早期版本对端回退为 v3 快照格式）。
     async fn push_to_peer_inner(&self, peer_id: &str, peer_ips: &[String]) -> Result<()> {
-        let node_id: iroh::EndpointId = peer_id.parse().context("invalid peer endpoint id")?;
-
-        let addr = self.build_connect_addr(node_id, peer_ips)?;
-
-        let data = self.export_all()?;
-        // 网络线格式：8 字节 CARDMIND magic + export_all 输出（M2：识别推送帧，
-        // 防止墓碑数=1 时 export_all 首字节 0x01 与配对帧标记冲突）
-        let wire = encode_push_wire(&data);
-
-        let conn = self
-            .endpoint
-            .connect(addr, ALPN)
-            .await
-            .context("connect to peer")?;
-        let mut send = conn.open_uni().await.context("open uni stream")?;
-        send.write_all(&wire).await.context("write snapshot data")?;
-        // finish() 显式发送流结束（EOF），接收端 read_to_end 据此结束
-        send.finish().context("finish uni stream")?;
-        // 保持连接存活直到对端读完数据并关闭连接；避免对端未读完时本端
-        // drop conn 导致连接被提前关闭（数据丢失）。超时保护防止对端不关闭。
-        tokio::time::timeout(std::time::Duration::from_secs(10), conn.closed())
-            .await
-            .ok();
-
+        let conn = self.ctx.connect_peer(peer_id, peer_ips).await?;
+        match self.ctx.handshake(&conn, peer_id).await? {
+            Handshake::Negotiated { send, caps, .. } => {
+                self.ctx
+                    .send_snapshot(&conn, send, peer_id, caps & CAP_LZ4 != 0)
+                    .await?;
+            }
+            Handshake::Legacy => {
+                self.ctx
+                    .push_legacy_snapshot(conn, peer_id, peer_ips)
+                    .await?;
+            }
+        }
         Ok(())
     }
 
-    /// 逐个向多台设备推送全量快照（含墓碑）。
+    /// 并发向多台设备增量推送（含墓碑）。
     ///
-    /// - 每台设备独立尝试，单台失败不中断整体
-    /// - 单台连接/推送超时 10 秒，超时记为失败并继续下一台
+    /// - 每台设备独立握手：对端先回复各笔记版本向量，本端只发送对端缺失的
+    ///   增量（对端已是最新的笔记不发送，见 [`send_records`]）
+    /// - 各台并发尝试，单台失败不中断整体；单台连接/推送超时 10 秒，离线设备
+    ///   不拖慢其他设备
+    /// - 每台结果计入该对端的退避状态（成功清零，失败按指数退避推迟下次尝试）
     /// - `devices`: `(peer_id, Option<IP 列表>)`；IP 缺省（None/空）时经 relay/地址解析连接
     pub async fn push_to_paired_devices(
         &self,
         devices: &[(String, Option<Vec<String>>)],
     ) -> Vec<DevicePushResult> {
-        let started = std::time::Instant::now();
-        let note_count = {
-            let core = self.core.lock().unwrap();
-            core.notes.len() + core.tombstones.len()
-        };
-        let data = match self.export_all() {
-            Ok(d) => d,
-            Err(e) => {
-                // 快照导出失败：所有设备都记为失败
-                self.emit_log(
-                    LogEvent::new("sync.push", "sync.push")
-                        .with_id(&self.device_id())
-                        .with_field("direction", "push")
-                        .with_field("action", "failed")
-                        .with_error(&e.to_string())
-                        .with_chain(&format!("{e:#}")),
-                );
-                return devices
-                    .iter()
-                    .map(|(peer_id, _)| DevicePushResult {
-                        peer_id: peer_id.clone(),
-                        ok: false,
-                        message: format!("export_all failed: {e}"),
-                    })
-                    .collect();
-            }
-        };
-
-        let mut results = Vec::with_capacity(devices.len());
-        for (peer_id, ips) in devices {
-            let peer_id = peer_id.clone();
-            let ips = ips.clone();
-            let outcome = tokio::time::timeout(
-                std::time::Duration::from_secs(10),
-                self.push_to_peer_once(&peer_id, ips.as_deref(), &data),
-            )
-            .await;
-            match outcome {
-                Ok(Ok(())) => {
-                    // 事件 #10：后续同步单台成功（只记录数量）
-                    self.emit_log(
-                        LogEvent::new("sync.push", "sync.push")
-                            .with_id(&self.device_id())
-                            .with_id(&peer_id)
-                            .with_field("direction", "push")
-                            .with_field("action", "success")
-                            .with_field("note_count", note_count.to_string())
-                            .with_field("transport", self.transport_label(&peer_id)),
-                    );
-                    results.push(DevicePushResult {
-                        peer_id,
-                        ok: true,
-                        message: String::new(),
-                    });
-                }
-                Ok(Err(e)) => {
-                    // 事件 #10：后续同步单台失败（错误链 + 耗时；**不再打印完整 peer_id**）
-                    self.emit_log(
-                        LogEvent::new("sync.push", "sync.push")
-                            .with_id(&self.device_id())
-                            .with_id(&peer_id)
-                            .with_field("direction", "push")
-                            .with_field("action", "failed")
-                            .with_error(&e.to_string())
-                            .with_chain(&format!("{e:#}"))
-                            .with_duration(started.elapsed()),
-                    );
-                    results.push(DevicePushResult {
-                        peer_id,
-                        ok: false,
-                        message: format!("{e:#}"),
-                    });
-                }
-                Err(_) => {
-                    // 单台超时：包含耗时与"超时"错误
-                    self.emit_log(
-                        LogEvent::new("sync.push", "sync.push")
-                            .with_id(&self.device_id())
-                            .with_id(&peer_id)
-                            .with_field("direction", "push")
-                            .with_field("action", "failed")
-                            .with_error("push timeout after 10s")
-                            .with_chain("push timeout after 10s")
-                            .with_duration(started.elapsed()),
-                    );
-                    results.push(DevicePushResult {
-                        peer_id,
-                        ok: false,
-                        message: "push timeout after 10s".to_string(),
-                    });
-                }
-            }
-        }
-        results
-    }
-
-    /// 单台设备的连接 + 发送（复用推送逻辑，data 为预导出的快照）
-    async fn push_to_peer_once(
-        &self,
-        peer_id: &str,
-        peer_ips: Option<&[String]>,
-        data: &[u8],
-    ) -> Result<()> {
-        let node_id: iroh::EndpointId = peer_id.parse().context("invalid peer endpoint id")?;
-        let addr = self.build_connect_addr(node_id, peer_ips.unwrap_or(&[]))?;
-
-        let conn = self
-            .endpoint
-            .connect(addr, ALPN)
-            .await
-            .context("connect to peer")?;
-        let mut send = conn.open_uni().await.context("open uni stream")?;
-        // 网络线格式：8 字节 CARDMIND magic + export_all 输出（M2：识别推送帧）
-        send.write_all(&encode_push_wire(data))
-            .await
-            .context("write snapshot data")?;
-        send.finish().context("finish uni stream")?;
-        // 保持连接存活直到对端读完并关闭；超时保护（push_to_paired_devices 外层也有 10s 超时）
-        tokio::time::timeout(std::time::Duration::from_secs(10), conn.closed())
-            .await
-            .ok();
-        Ok(())
+        self.ctx.push_to_paired_devices(devices).await
     }
 
     /// 监听并接受对端的推送，返回原始字节数据
     ///
-    /// 调用方收到数据后应调用 `import_all` 导入。
+    /// 调用方收到数据后应调用 `import_all` 导入：导入成功后才以关闭码 0 确认
+    /// 送达，失败（或未导入即再次 accept）以 [`ABORT_CLOSE_CODE`] 关闭，发送方
+    /// 不记水位、稍后重试。
     pub async fn accept_push(&self) -> Result<Vec<u8>> {
         let started = std::time::Instant::now();
         let result: Result<Vec<u8>> = (async {
             loop {
                 let incoming = self
+                    .ctx
                     .endpoint
                     .accept()
                     .await
@@ -1737,21 +2457,49 @@ impl SyncService {
     ///
     /// 帧标记（M2 修复——不能用单字节判定，否则推送 payload 首字节 0x01 与配对帧
     /// 冲突）：
-    /// - 前 8 字节 == `LORO_MAGIC`（"CARDMIND"）→ 推送帧：读完整 payload，
-    ///   关闭连接通知发送端可释放，返回剥离 magic 后的 `Ok(Some(data))`
-    ///   （data 即 `export_all` 输出，`import_all` 直接消费）。
+    /// - 前 8 字节 == `LORO_MAGIC`（"CARDMIND"）→ 推送帧：按 [`TransferLimits`]
+    ///   逐条读完整 payload，返回剥离 magic 后的 `Ok(Some(data))`（data 即
+    ///   `export_all` 格式，`import_all` 直接消费；导入后才应答发送方）。
+    /// - 前 8 字节 == `DELTA_MAGIC` → 增量推送帧：回复版本摘要后读增量，
+    ///   同样返回 `Ok(Some(data))`（与全量同格式，`import_all` 直接消费）。
+    /// - 前 8 字节 == `SESSION_MAGIC` → 双向同步会话：回复发起方缺失的增量后
+    ///   读其推回的增量，同样返回 `Ok(Some(data))`。
     /// - 首字节 `PAIRING_FRAME_REQUEST (0x01)` → 配对请求帧：解析并存入
     ///   `pending_pairing`（供 `confirm_pairing` 在同一连接上回复握手响应），
     ///   返回 `Ok(None)`。
     /// - 其他 → 报错（未知帧标记）。
+    ///
+    /// 已绑定投影（`trust_store`）时，未配对/已撤销设备的同步帧被拒，同样返回
+    /// `Ok(None)`（`accept_push` 继续等待下一连接）。
     async fn accept_incoming_routed(
         &self,
         incoming: iroh::endpoint::Incoming,
     ) -> Result<Option<Vec<u8>>> {
         // 统一路由自由函数（任务 O 后台接收器与主服务共用同一路由/同一
         // pending_pairing——配对帧与推送帧不丢帧、不互抢）
-        let routed = route_incoming(incoming, &self.pending_pairing).await?;
-        Ok(routed.map(|(_sender, data)| data))
+        let trust = self.ctx.trust_store.lock().unwrap().clone();
+        let device_id = self.device_id();
+        let route = RouteContext {
+            pending_pairing: &self.ctx.pending_pairing,
+            core: &self.ctx.core,
+            trust: trust.as_ref(),
+            log: &self.ctx.log,
+            events: &self.ctx.events,
+            device_id: &device_id,
+        };
+        let routed = route_incoming(incoming, &route, InboundMode::Buffer).await?;
+        Ok(routed.and_then(|(sender, inbound)| {
+            // 对端主动连入：证明其在线，立即结束退避
+            self.ctx.peer_reappeared(&sender.to_string(), "inbound");
+            match inbound {
+                Inbound::Data(data, ack) => {
+                    // 替换掉的旧连接（数据未导入）随 drop 以中止关闭
+                    *self.pending_ack.lock().unwrap() = Some(ack);
+                    Some(data)
+                }
+                Inbound::Imported(_) => None,
+            }
+        }))
     }
 
     /// 非阻塞接受对端推送（周期拉取用）：等待最多 `timeout`，超时返回 `Ok(None)`。
@@ -1759,7 +2507,7 @@ impl SyncService {
     /// 通过统一帧路由避免与配对流程争用 accept 通道；配对请求被本函数抢到时
     /// 会被正确存入 `pending_pairing`（`confirm_pairing` 仍可完成握手）。
     pub async fn try_accept_push(&self, timeout: Duration) -> Result<Option<Vec<u8>>> {
-        let incoming = match tokio::time::timeout(timeout, self.endpoint.accept()).await {
+        let incoming = match tokio::time::timeout(timeout, self.ctx.endpoint.accept()).await {
             Ok(Some(incoming)) => incoming,
             _ => return Ok(None),
         };
@@ -1770,227 +2518,193 @@ impl SyncService {
 
     /// 设置同步开关（决策 6 能力）：false 时调度器暂停推送与拉取。
     /// 移动端由 Flutter 侧按网络类型调用；桌面端默认 true。
+    ///
+    /// 由关闭切回开启时唤醒后台同步任务（如在运行），暂停期间的编辑随即推送。
     pub fn set_sync_allowed(&self, allowed: bool) {
-        self.sync_allowed.store(allowed, Ordering::Relaxed);
+        let was_allowed = self.ctx.sync_allowed.swap(allowed, Ordering::Relaxed);
+        if allowed && !was_allowed {
+            self.ctx.local_edits.notify_one();
+        }
     }
 
     /// 当前同步开关状态。
     pub fn sync_allowed(&self) -> bool {
-        self.sync_allowed.load(Ordering::Relaxed)
+        self.ctx.sync_allowed()
+    }
+
+    /// 设置入站同步数据上限（主服务与后台接收任务立即生效；进行中的传输沿用
+    /// 开始时的上限）。
+    pub fn set_transfer_limits(&self, limits: TransferLimits) {
+        self.ctx.core.lock().unwrap().transfer_limits = limits;
+    }
+
+    /// 当前入站同步数据上限。
+    pub fn transfer_limits(&self) -> TransferLimits {
+        self.ctx.transfer_limits()
+    }
+
+    /// 设置墓碑回收期限：删除超过该时长的墓碑即使仍有配对设备未确认也会回收
+    /// （该设备之后带回旧副本时由复活守卫拦截）。持久化于 `cardmind.sync`，
+    /// 重启后沿用。
+    pub fn set_tombstone_horizon(&self, horizon: Duration) -> Result<()> {
+        let mut core = self.ctx.core.lock().unwrap();
+        let previous = std::mem::replace(&mut core.tombstone_horizon, horizon);
+        if let Err(err) = persist_peer_acks(&core) {
+            core.tombstone_horizon = previous;
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    /// 当前墓碑回收期限。
+    pub fn tombstone_horizon(&self) -> Duration {
+        self.ctx.core.lock().unwrap().tombstone_horizon
     }
 
-    /// 待同步笔记计数（模块 5 基础）：本地编辑后待推送的笔记数。
+    /// 回收墓碑，返回回收数：
+    /// - 全部配对设备（`store.list_paired_devices`）都已确认的墓碑立即回收
+    /// - 其余墓碑删除时间超过回收期限（[`Self::set_tombstone_horizon`]）后回收
     ///
-    /// 内存态不持久化：重启后经持久化加载重标全部待同步（保守正确）。
-    pub fn pending_sync_count(&self) -> u32 {
-        self.pending_dirty.lock().unwrap().len() as u32
+    /// 回收后重写基线快照（日志中的墓碑记录一并清除），并记下已回收的 id：之后
+    /// 离线设备带回的这些笔记不会被导入（见 [`resurrected_tombstone`]）。
+    pub fn collect_tombstones(&self, store: &NoteStore) -> Result<usize> {
+        self.ctx.collect_tombstones(store)
     }
 
-    /// note_id → 最后成功推送时间（诊断/测试用快照）。
-    pub fn last_pushed_at_snapshot(&self) -> HashMap<String, DateTime<Utc>> {
-        self.last_pushed_at.lock().unwrap().clone()
+    /// 待同步笔记计数（模块 5 基础）：至少一台对端尚未确认的笔记/墓碑数。
+    ///
+    /// 按对端水位计算（见 [`Self::pending_sync_count_for_peer`]），水位持久化，
+    /// 重启后计数不变。对端集合取已绑定投影中的配对设备（未绑定时取有水位记录
+    /// 的对端）；没有任何对端时计入未被任何水位包含的全部笔记/墓碑（尚无设备
+    /// 收到的本地编辑仍算待同步）。
+    pub fn pending_sync_count(&self) -> u32 {
+        self.ctx.pending_sync_count()
     }
 
-    /// 编辑保存即推送：标记笔记待同步（推送由调度器异步执行，不阻塞编辑）。
-    fn mark_sync_pending(&self, note_id: &str) {
-        self.pending_dirty
-            .lock()
-            .unwrap()
-            .insert(note_id.to_string());
+    /// 单台对端尚未确认的笔记/墓碑数（本地版本未被该对端水位包含）。
+    pub fn pending_sync_count_for_peer(&self, peer_id: &str) -> u32 {
+        self.ctx.pending_sync_count_for_peer(peer_id)
     }
 
-    /// 重启后全部视为待同步（last_pushed_at 不持久化，保守正确——对端状态未知）。
-    fn mark_all_pending(&self) {
-        let mut dirty = self.pending_dirty.lock().unwrap();
-        let core = self.core.lock().unwrap();
-        for id in core.notes.keys() {
-            dirty.insert(id.clone());
-        }
-        for id in &core.tombstones {
-            dirty.insert(id.clone());
-        }
+    /// peer_id → 最近一次确认同步的时间（诊断/测试用快照）。
+    pub fn last_synced_at_snapshot(&self) -> HashMap<String, DateTime<Utc>> {
+        let core = self.ctx.core.lock().unwrap();
+        core.peer_acks
+            .iter()
+            .filter_map(|(peer, ack)| Some((peer.clone(), ack.synced_at?)))
+            .collect()
     }
 
-    /// 全量快照已成功推送给至少一台对端：清空待同步集并记录推送时间。
-    fn mark_synced_all(&self) {
-        let now = Utc::now();
-        let mut dirty = self.pending_dirty.lock().unwrap();
-        let mut pushed = self.last_pushed_at.lock().unwrap();
-        for id in dirty.iter() {
-            pushed.insert(id.clone(), now);
-        }
-        dirty.clear();
+    /// 对端退避状态（诊断/测试用）：`(连续失败次数, 距下次尝试的剩余时间)`；
+    /// 未处于退避时为 `None`。
+    pub fn peer_backoff(&self, peer_id: &str) -> Option<(u32, Duration)> {
+        self.ctx.peer_backoff(peer_id)
     }
 
-    /// 从 store 读取配对设备，为每台附上最近已知直连 IP（有则直连优先，无则走
-    /// relay/地址解析）。
-    fn paired_devices_with_ips(&self, store: &NoteStore) -> Vec<(String, Option<Vec<String>>)> {
-        let peer_ips = self.peer_ips.lock().unwrap();
-        store
-            .list_paired_devices()
-            .map(|rows| {
-                rows.into_iter()
-                    .map(|d| (d.peer_id.clone(), peer_ips.get(&d.peer_id).cloned()))
-                    .collect()
-            })
-            .unwrap_or_default()
+    /// 清除全部对端的退避（用户手动触发立即同步时调用）。
+    pub fn reset_sync_backoff(&self) {
+        self.ctx.peer_backoff.lock().unwrap().clear();
     }
 
     /// 推送待办（编辑保存即推送 / 调度器触发）。
     ///
     /// - 同步开关关闭时跳过推送（移动端蜂窝场景），pending 保留。
-    /// - 无配对设备时立即返回空结果。
-    /// - 至少一台对端推送成功 → 全量快照已传播 → 清 pending + 记录 last_pushed_at。
+    /// - 无配对设备、或全部对端都已确认最新状态时立即返回空结果。
+    /// - 只连接仍有未确认笔记的对端（按对端水位，见 [`Self::pending_sync_count_for_peer`]），
+    ///   且跳过仍在退避中的对端（连续失败后按指数退避推迟，见 [`Self::peer_backoff`]）。
+    /// - 每台对端确认收到即记录该对端的确认水位（见 [`SyncContext::record_peer_ack`]），
+    ///   待同步计数按对端重新计算。
     /// - 全部失败 → 静默（决策 18）：仅记录日志，pending 保留（下个周期兜底），
     ///   不向调用方返回错误。
     pub async fn push_pending(&self, store: &NoteStore) -> Vec<DevicePushResult> {
-        let started = std::time::Instant::now();
-        let pending_count = self.pending_sync_count();
-        if !self.sync_allowed() {
-            // 事件 #10：同步开关关闭 → 跳过
-            self.emit_log(
-                LogEvent::new("sync.push_pending", "sync.push_pending")
-                    .with_id(&self.device_id())
-                    .with_field("action", "skipped")
-                    .with_field("reason", "sync_disabled")
-                    .with_field("pending_count", pending_count.to_string()),
-            );
-            return Vec::new();
-        }
-        let devices = self.paired_devices_with_ips(store);
-        if devices.is_empty() {
-            // 事件 #10：无配对设备 → 跳过
-            self.emit_log(
-                LogEvent::new("sync.push_pending", "sync.push_pending")
-                    .with_id(&self.device_id())
-                    .with_field("action", "skipped")
-                    .with_field("reason", "no_devices")
-                    .with_field("pending_count", pending_count.to_string()),
-            );
-            return Vec::new();
-        }
-        let results = self.push_to_paired_devices(&devices).await;
-        let duration = started.elapsed();
-        if results.iter().any(|r| r.ok) {
-            self.mark_synced_all();
-            for r in &results {
-                if r.ok {
-                    self.touch_last_seen(store, &r.peer_id, "outbound_push");
-                }
-            }
-        } else {
-            // 全部失败 → 静默（决策 18）；逐台事件已在 push_to_paired_devices 内发出（脱敏）
-            for r in &results {
-                self.emit_log(
-                    LogEvent::new("sync.push_pending", "sync.push_pending")
-                        .with_id(&self.device_id())
-                        .with_id(&r.peer_id)
-                        .with_field("action", "all_failed_silent")
-                        .with_field("pending_count", pending_count.to_string())
-                        .with_duration(duration),
-                );
-            }
-        }
-        // 汇总事件：成功台数 + 待同步数 + 耗时
-        let ok_count = results.iter().filter(|r| r.ok).count();
-        self.emit_log(
-            LogEvent::new("sync.push_pending", "sync.push_pending")
-                .with_id(&self.device_id())
-                .with_field("action", "end")
-                .with_field("ok_count", ok_count.to_string())
-                .with_field("pending_count", pending_count.to_string())
-                .with_field("pending_after", self.pending_sync_count().to_string())
-                .with_duration(duration),
-        );
-        results
+        self.ctx.push_pending(store).await
     }
 
     /// 周期同步任务体（Flutter 侧 Timer 周期调用；测试直接调用）：
     /// 1. 同步开关关闭 → 跳过（决策 6）
-    /// 2. push 给所有配对设备（对等推拉——pull 语义用 push 协议实现：
-    ///    我方 push 即请求对端在各自周期里推回）
-    /// 3. 短窗口 accept 对端 push（非阻塞）→ import → 刷新 SQLite 投影
+    /// 2. 与每台配对设备并发建立一次双向同步会话（[`SESSION_MAGIC`]）：交换版本摘要，
+    ///    拉取本端缺失的增量并推回对端缺失的增量——一次连接双方收敛，
+    ///    不再依赖对端恰好在 accept 窗口内推送；仍在退避中的对端本轮跳过
+    /// 3. 拉取到内容 → 刷新 SQLite 投影
     pub async fn run_sync_cycle(&mut self, store: &NoteStore) -> Result<SyncCycleResult> {
-        let started = std::time::Instant::now();
-        if !self.sync_allowed() {
-            let result = SyncCycleResult {
-                pushed_count: 0,
-                accepted_push: false,
-                disabled: true,
-            };
-            self.emit_log(
-                LogEvent::new("sync.cycle", "sync.cycle")
-                    .with_id(&self.device_id())
-                    .with_field("action", "end")
-                    .with_field("disabled", "true"),
-            );
-            return Ok(result);
-        }
-        let devices = self.paired_devices_with_ips(store);
-        let results = if devices.is_empty() {
-            Vec::new()
-        } else {
-            self.push_to_paired_devices(&devices).await
-        };
-        let any_ok = results.iter().any(|r| r.ok);
-        if any_ok {
-            self.mark_synced_all();
-            for r in &results {
-                if r.ok {
-                    self.touch_last_seen(store, &r.peer_id, "outbound_push");
-                }
-            }
-        } else if !results.is_empty() {
-            // 逐台失败事件已在 push_to_paired_devices 内发出（脱敏）；汇总一次
-            for r in &results {
-                self.emit_log(
-                    LogEvent::new("sync.cycle", "sync.cycle")
-                        .with_id(&self.device_id())
-                        .with_id(&r.peer_id)
-                        .with_field("action", "push_failed_silent")
-                        .with_field("ok", "false")
-                        .with_duration(started.elapsed()),
-                );
-            }
+        self.ctx.run_sync_cycle(store).await
+    }
+
+    /// 撤销配对设备：本机签名撤销记录、写入 `revoked_devices` 并移除配对行。
+    ///
+    /// 撤销记录在之后的每次同步会话中发送给其他配对设备（对端验签后同样拒绝
+    /// 该设备）；被撤销设备此后发起的推送/会话一律在导入前拒绝。
+    pub fn revoke_device(&self, store: &NoteStore, peer_id: &str) -> Result<()> {
+        let device_id = self.device_id();
+        if peer_id == device_id {
+            anyhow::bail!("cannot revoke this device itself");
         }
-        let accepted = match self.try_accept_push(SYNC_ACCEPT_WINDOW).await? {
-            Some(data) => {
-                self.import_all(&data)?;
-                self.sync_notes_to_store(store)?;
-                true
-            }
-            None => false,
-        };
-        // 成功推送的对端设备数（真实计数，非 0/1 布尔）
-        let pushed_count = results.iter().filter(|r| r.ok).count() as u32;
-        // 事件 #10：周期同步汇总（触发原因由 Flutter 调度器记录；这里记录结果）
+        let _: iroh::EndpointId = peer_id.parse().context("invalid peer endpoint id")?;
+        let row = sign_revocation(&self.ctx.secret_key, peer_id, &Utc::now().to_rfc3339());
+        store.insert_revocation(&row)?;
+        self.ctx.bind_trust_store(store);
+        self.ctx.peer_ips.lock().unwrap().remove(peer_id);
         self.emit_log(
-            LogEvent::new("sync.cycle", "sync.cycle")
-                .with_id(&self.device_id())
-                .with_field("action", "end")
-                .with_field("pushed_count", pushed_count.to_string())
-                .with_field("accepted_push", accepted.to_string())
-                .with_field("pending_after", self.pending_sync_count().to_string())
-                .with_duration(started.elapsed()),
+            LogEvent::new("device.revoke", "device")
+                .with_id(&device_id)
+                .with_id(peer_id)
+                .with_field("action", "revoked"),
         );
-        Ok(SyncCycleResult {
-            pushed_count,
-            accepted_push: accepted,
-            disabled: false,
-        })
+        Ok(())
     }
 
-    /// 将所有 CRDT 笔记同步到 SQLite 存储（同时清理墓碑投影行，防被删笔记复活）。
-    pub fn sync_notes_to_store(&self, store: &NoteStore) -> Result<()> {
-        for (id, note) in self.iter_notes() {
-            store.sync_note(&id, &note)?;
+    /// 以本机身份签发 `peer_id` 的设备名册条目（配对成功后调用；重新配对覆盖
+    /// 设备名、并使此前的移除记录失效）。条目随之后的同步会话传播，其他成员
+    /// 据此信任该设备；`ips` 为配对时得知的直连地址，记入名册供其他成员连接。
+    fn add_roster_member(&self, peer_id: &str, name: &str, ips: &[String]) -> Result<()> {
+        let entry = sign_member(
+            &self.ctx.secret_key,
+            peer_id,
+            name,
+            &Utc::now().to_rfc3339(),
+        );
+        let mut core = self.ctx.core.lock().unwrap();
+        if !ips.is_empty() {
+            core.roster
+                .get_map("addrs")
+                .insert(peer_id, ips.join(",").as_str())
+                .map_err(|e| anyhow::anyhow!(e))?;
         }
-        for id in self.tombstones() {
-            store.purge_note(&id)?;
+        insert_roster_member(&mut core, &entry)
+    }
+
+    /// 移除配对设备：本机签名名册移除记录、删除配对行。
+    ///
+    /// 移除记录随之后的同步会话传播，其他成员同样移除该设备，名册不会再把它
+    /// 加回；与撤销不同，该设备之后可以重新配对（新签发的成员条目晚于移除
+    /// 记录即重新生效）。要永久拒绝它，使用 [`Self::revoke_device`]。
+    pub fn remove_paired_device(&self, store: &NoteStore, peer_id: &str) -> Result<()> {
+        let device_id = self.device_id();
+        if peer_id == device_id {
+            anyhow::bail!("cannot remove this device itself");
+        }
+        let removal = sign_removal(&self.ctx.secret_key, peer_id, &Utc::now().to_rfc3339());
+        {
+            let mut core = self.ctx.core.lock().unwrap();
+            insert_roster_removal(&mut core, &removal)?;
         }
+        store.remove_paired_device(peer_id)?;
+        self.ctx.bind_trust_store(store);
+        self.ctx.peer_ips.lock().unwrap().remove(peer_id);
+        self.emit_log(
+            LogEvent::new("device.roster", "device")
+                .with_id(&device_id)
+                .with_id(peer_id)
+                .with_field("action", "removed"),
+        );
         Ok(())
     }
 
+    /// 将所有 CRDT 笔记同步到 SQLite 存储（同时清理墓碑投影行，防被删笔记复活）。
+    pub fn sync_notes_to_store(&self, store: &NoteStore) -> Result<()> {
+        self.ctx.sync_notes_to_store(store)
+    }
+
     // ━━━ 后台持续接收器（任务 O）━━━
 
     /// 启动被动接收任务（幂等）：持续短窗口 accept 对端 push，收到即
@@ -2003,6 +2717,7 @@ impl SyncService {
     /// - 配对帧与推送帧统一路由（`route_incoming`），不丢帧、不互抢。
     pub async fn start_receiver(&self, store: NoteStore) -> Result<()> {
         let started = std::time::Instant::now();
+        self.ctx.bind_trust_store(&store);
         {
             let mut guard = self.receiver.lock().unwrap();
             if guard.join.is_some() {
@@ -2016,16 +2731,18 @@ impl SyncService {
             }
             let cancel = Arc::new(AtomicBool::new(false));
             let ctx = ReceiverContext {
-                endpoint: self.endpoint.clone(),
-                core: self.core.clone(),
-                pending_pairing: self.pending_pairing.clone(),
+                endpoint: self.ctx.endpoint.clone(),
+                core: self.ctx.core.clone(),
+                pending_pairing: self.ctx.pending_pairing.clone(),
                 store: store.clone(),
-                log: self.log.clone(),
+                log: self.ctx.log.clone(),
                 device_id: self.device_id(),
-                log_verbose: self.log_verbose.load(Ordering::Relaxed),
+                log_verbose: self.ctx.log_verbose.load(Ordering::Relaxed),
                 cancel: cancel.clone(),
+                peer_backoff: self.ctx.peer_backoff.clone(),
                 idle_windows: 0,
-                content_revision: self.content_revision.clone(),
+                content_revision: self.ctx.content_revision.clone(),
+                events: self.ctx.events.clone(),
             };
             let join = tokio::spawn(receiver_loop(ctx));
             *guard = ReceiverHandle {
@@ -2086,230 +2803,3497 @@ impl SyncService {
 
     /// Monotonic revision of successfully projected inbound content.
     pub fn receiver_content_revision(&self) -> u64 {
-        self.content_revision.load(Ordering::Acquire)
+        self.ctx.content_revision.load(Ordering::Acquire)
     }
 
-    /// 更新配对设备 last_seen 并输出结构化日志（触发原因配对/主动推送/被动接收）。
+    /// 本地变更已落盘：发出对应 UI 事件，并通知后台同步任务（未运行时通知被
+    /// 保留，任务启动后推送一次）。
+    fn note_local_edit(&self, event: SyncEvent) {
+        self.ctx.events.emit(event);
+        self.ctx.local_edits.notify_one();
+    }
+
+    /// 订阅同步服务事件（UI 经 `api::sync_events` 转发到 Dart Stream）。
+    pub fn subscribe_events(&self) -> tokio::sync::broadcast::Receiver<SyncEvent> {
+        self.ctx.events.subscribe()
+    }
+
+    /// 启动后台同步任务（幂等：已在运行时直接返回）。
     ///
-    /// 仅成功连接/同步后调用；失败路径不得调用（验收 14：失败不标记在线）。
-    fn touch_last_seen(&self, store: &NoteStore, peer_id: &str, reason: &str) {
-        match store.update_last_seen(peer_id) {
-            Ok(()) => {
-                self.emit_log(
-                    LogEvent::new("device.last_seen", "device")
-                        .with_id(&self.device_id())
-                        .with_id(peer_id)
-                        .with_field("reason", reason)
-                        .with_field("action", "updated"),
-                );
-            }
-            Err(e) => {
-                self.emit_log(
-                    LogEvent::new("device.last_seen", "device")
-                        .with_id(&self.device_id())
-                        .with_id(peer_id)
-                        .with_field("reason", reason)
-                        .with_field("action", "failed")
-                        .with_error(&e.to_string())
-                        .with_chain(&format!("{e:#}")),
-                );
-            }
+    /// 任务独立于 Flutter 侧计时器运行（无头宿主/测试同样自动同步）：
+    /// - 本地编辑后防抖 [`SYNC_EDIT_DEBOUNCE_MS`] 调用 [`Self::push_pending`]
+    /// - 启动时及此后每隔 `poll_interval` 调用 [`Self::run_sync_cycle`]
+    /// - 同步开关关闭时两者都跳过（由 push/cycle 自身检查）；切回开启立即推送
+    /// - 运行期间经 mDNS 广播本机在线状态并监听局域网：退避中的对端重新出现即
+    ///   结束其退避
+    ///
+    /// 失败静默（决策 18）：只记录日志，下一次编辑或周期重试。
+    pub async fn start_sync_loop(&self, store: NoteStore, poll_interval: Duration) -> Result<()> {
+        self.ctx.bind_trust_store(&store);
+        let mut guard = self.sync_loop.lock().unwrap();
+        if guard.join.is_some() {
+            self.emit_log(
+                LogEvent::new("sync.loop", "sync.loop")
+                    .with_id(&self.device_id())
+                    .with_field("action", "already_running"),
+            );
+            return Ok(());
         }
+        let stop = Arc::new(tokio::sync::Notify::new());
+        let join = tokio::spawn(sync_loop(
+            self.ctx.clone(),
+            store,
+            stop.clone(),
+            poll_interval,
+        ));
+        *guard = SyncLoopHandle {
+            stop: Some(stop),
+            join: Some(join),
+        };
+        drop(guard);
+        self.emit_log(
+            LogEvent::new("sync.loop", "sync.loop")
+                .with_id(&self.device_id())
+                .with_field("action", "start")
+                .with_field("poll_interval_ms", poll_interval.as_millis().to_string()),
+        );
+        Ok(())
     }
-}
-
-// ━━━ 共享核心状态纯函数（任务 O：主服务与后台接收器共用）━━━
 
-/// 全量快照导出（已持锁 core 的纯函数）。
-fn export_core_all(core: &CoreState) -> Result<Vec<u8>> {
-    let mut buf = Vec::new();
-    // 墓碑 section 前缀
-    buf.extend_from_slice(&(core.tombstones.len() as u32).to_le_bytes());
-    let mut sorted: Vec<&String> = core.tombstones.iter().collect();
-    sorted.sort();
-    for id in sorted {
-        let id_bytes = id.as_bytes();
-        buf.extend_from_slice(&(id_bytes.len() as u32).to_le_bytes());
-        buf.extend_from_slice(id_bytes);
+    /// 停止后台同步任务（幂等）。
+    ///
+    /// 任务在当前推送/会话结束后退出；超过 3 秒仍未结束（对端连接超时中）则
+    /// 中止任务——中止只发生在网络 await 点，core 与落盘状态保持一致。
+    pub async fn stop_sync_loop(&self) -> Result<()> {
+        let started = std::time::Instant::now();
+        let (stop, join) = {
+            let mut guard = self.sync_loop.lock().unwrap();
+            (guard.stop.take(), guard.join.take())
+        };
+        let was_running = join.is_some();
+        if let Some(stop) = stop {
+            stop.notify_one();
+        }
+        if let Some(mut join) = join {
+            match tokio::time::timeout(SYNC_LOOP_STOP_TIMEOUT, &mut join).await {
+                Ok(joined) => joined.map_err(|e| anyhow::anyhow!("sync task panicked: {e}"))?,
+                Err(_) => {
+                    join.abort();
+                    let _ = join.await;
+                }
+            }
+        }
+        self.emit_log(
+            LogEvent::new("sync.loop", "sync.loop")
+                .with_id(&self.device_id())
+                .with_field("action", "stop")
+                .with_field("was_running", was_running.to_string())
+                .with_duration(started.elapsed()),
+        );
+        Ok(())
     }
-    // 笔记记录流
-    for (note_id, note) in &core.notes {
-        let snapshot = note.export_snapshot()?;
-        let id_bytes = note_id.as_bytes();
-        buf.extend_from_slice(&(id_bytes.len() as u32).to_le_bytes());
-        buf.extend_from_slice(id_bytes);
-        buf.extend_from_slice(&(snapshot.len() as u32).to_le_bytes());
-        buf.extend_from_slice(&snapshot);
+
+    /// 后台同步任务是否运行中（诊断/测试用）。
+    pub fn sync_loop_running(&self) -> bool {
+        self.sync_loop.lock().unwrap().join.is_some()
     }
-    Ok(buf)
 }
 
-/// 导入全量快照（v3 语义：墓碑 section + 记录流；失败时整体回滚）。
-fn import_core_all(core: &mut CoreState, data: &[u8]) -> Result<()> {
-    let previous = export_core_all(core)?;
-    import_core_raw(core, LORO_VERSION, data)?;
-    if let Err(err) = persist_core(core) {
-        core.notes.clear();
-        core.tombstones.clear();
-        import_core_raw(core, LORO_VERSION, &previous)?;
-        return Err(err);
+impl SyncContext {
+    /// 输出日志事件：verbose 开关过滤 + sink 异常兜底（绝不打断主流程）。
+    fn emit_log(&self, event: LogEvent) {
+        if event.verbose && !self.log_verbose.load(Ordering::Relaxed) {
+            return;
+        }
+        debug_log::emit_to(&self.log, event);
     }
-    Ok(())
-}
 
-/// 导入 payload（已持锁 core）。`version` 决定是否含墓碑 section：
-/// - v3：`墓碑 section + 记录流`（导入的墓碑与本地 tombstones union 合并；
-///   记录流中遇到墓碑中的 id 跳过，不复活）
-/// - v1/v2：纯记录流（无墓碑 section，tombstones 为空，无损升级）
-fn import_core_raw(core: &mut CoreState, version: u32, data: &[u8]) -> Result<()> {
-    let mut offset = 0;
+    /// 见 [`SyncService::device_id`]。
+    fn device_id(&self) -> String {
+        self.endpoint.id().to_string()
+    }
 
-    // ━━ 墓碑 section（仅 v3）━━
-    let mut imported_tombstones: HashSet<String> = HashSet::new();
+    /// 见 [`SyncService::device_name`]。
+    fn device_name(&self) -> String {
+        self.device_name.lock().unwrap().clone()
+    }
+
+    /// 本端点当前监听端口（mDNS 广播用；与 `local_addrs` 同源）。
+    fn endpoint_listen_port(&self) -> u16 {
+        self.endpoint
+            .addr()
+            .ip_addrs()
+            .next()
+            .map(|a| a.port())
+            .unwrap_or(0)
+    }
+
+    /// 见 [`SyncService::build_connect_addr`]。
+    fn build_connect_addr(
+        &self,
+        node_id: iroh::EndpointId,
+        ips: &[String],
+    ) -> Result<EndpointAddr> {
+        if ips.is_empty() {
+            let mut addr = EndpointAddr::new(node_id);
+            let urls: Vec<iroh::RelayUrl> = self.relay_mode.relay_map().urls();
+            if let Some(u) = urls.into_iter().next() {
+                addr = addr.with_relay_url(u);
+            }
+            Ok(addr)
+        } else {
+            let ips: Vec<TransportAddr> = ips
+                .iter()
+                .filter_map(|ip| ip.parse::<std::net::SocketAddr>().ok())
+                .map(TransportAddr::Ip)
+                .collect();
+            if ips.is_empty() {
+                anyhow::bail!("no valid IPs provided");
+            }
+            Ok(EndpointAddr::from_parts(node_id, ips))
+        }
+    }
+
+    /// 传输方式标签（direct/relay/dns；对端已有直连 IP 记录 → direct）。
+    fn transport_label(&self, peer_id: &str) -> String {
+        if self
+            .peer_ips
+            .lock()
+            .unwrap()
+            .get(peer_id)
+            .map(|ips| !ips.is_empty())
+            .unwrap_or(false)
+        {
+            return "direct".to_string();
+        }
+        if self
+            .relay_mode
+            .relay_map()
+            .urls::<Vec<iroh::RelayUrl>>()
+            .into_iter()
+            .next()
+            .is_some()
+        {
+            return "relay".to_string();
+        }
+        "dns".to_string()
+    }
+
+    /// 见 [`SyncService::iter_notes`]。
+    fn iter_notes(&self) -> Vec<(String, NoteCrdt)> {
+        let core = self.core.lock().unwrap();
+        core.notes.clone().into_iter().collect()
+    }
+
+    /// 见 [`SyncService::tombstones`]。
+    fn tombstones(&self) -> HashSet<String> {
+        self.core
+            .lock()
+            .unwrap()
+            .tombstones
+            .keys()
+            .cloned()
+            .collect()
+    }
+
+    /// 见 [`SyncService::version_digest`]。
+    fn version_digest(&self) -> Vec<u8> {
+        let core = self.core.lock().unwrap();
+        encode_version_digest(&core)
+    }
+
+    /// 连接对端（`peer_ips` 为空时经 relay/地址解析，见 [`Self::build_connect_addr`]）。
+    async fn connect_peer(
+        &self,
+        peer_id: &str,
+        peer_ips: &[String],
+    ) -> Result<iroh::endpoint::Connection> {
+        let node_id: iroh::EndpointId = peer_id.parse().context("invalid peer endpoint id")?;
+        let addr = self.build_connect_addr(node_id, peer_ips)?;
+        self.endpoint
+            .connect(addr, ALPN)
+            .await
+            .context("connect to peer")
+    }
+
+    /// 同步连接握手（协议见 [`HELLO_MAGIC`]）：交换协议版本与能力位。
+    ///
+    /// 早期版本只接受单向流，双向流上的 hello 不会被读取。[`HELLO_TIMEOUT`]
+    /// 内未应答时，再经单向流发送一个 hello 探测帧：早期版本按未知帧标记报错
+    /// 并丢弃连接（关闭码 0），这是唯一判定为 [`Handshake::Legacy`] 的信号；新版本
+    /// 忽略探测帧，照常在双向流上应答。探测后仍未应答 → 报错（慢对端不会被
+    /// 误判为早期版本而收到全量快照）。
+    ///
+    /// 对端版本（早期版本按 [`LEGACY_PROTOCOL_VERSION`] 计）低于
+    /// [`MIN_PEER_PROTOCOL_VERSION`]，或对端以 [`UPGRADE_CLOSE_CODE`] 拒绝本端版本
+    /// → 输出 `sync.push` 失败事件（`reason` 区分哪一端过旧）并报错。
+    async fn handshake(
+        &self,
+        conn: &iroh::endpoint::Connection,
+        peer_id: &str,
+    ) -> Result<Handshake> {
+        let (mut send, mut recv) = conn.open_bi().await.context("open bi stream")?;
+        let mut hello = HELLO_MAGIC.to_vec();
+        hello.extend_from_slice(&SYNC_PROTOCOL_VERSION.to_le_bytes());
+        hello.push(SYNC_CAPABILITIES);
+        send.write_all(&hello).await.context("write hello")?;
+        let mut reply = [0u8; 3];
+        let mut answered = tokio::time::timeout(HELLO_TIMEOUT, recv.read_exact(&mut reply)).await;
+        if answered.is_err() {
+            // 未应答：单向流探测（早期版本读到未知帧标记即断开，新版本忽略）
+            let mut probe = conn.open_uni().await.context("open hello probe")?;
+            probe.write_all(&hello).await.context("write hello probe")?;
+            probe.finish().context("finish hello probe")?;
+            answered = tokio::time::timeout(HELLO_TIMEOUT, recv.read_exact(&mut reply)).await;
+        }
+        match answered {
+            Ok(Ok(())) => {}
+            Err(_) => anyhow::bail!("peer did not answer hello within {HELLO_TIMEOUT:?}"),
+            Ok(Err(e)) => match conn.close_reason() {
+                Some(iroh::endpoint::ConnectionError::ApplicationClosed(close)) => {
+                    match u64::from(close.error_code) {
+                        // 早期版本：读到未知帧标记后丢弃连接（新版本出错时以
+                        // ABORT_CLOSE_CODE 关闭，不会发出关闭码 0）
+                        0 => {
+                            if LEGACY_PROTOCOL_VERSION < MIN_PEER_PROTOCOL_VERSION {
+                                self.emit_protocol_mismatch(
+                                    peer_id,
+                                    "peer_too_old",
+                                    Some(LEGACY_PROTOCOL_VERSION),
+                                );
+                                anyhow::bail!(
+                                    "peer predates the hello protocol (minimum version {MIN_PEER_PROTOCOL_VERSION})"
+                                );
+                            }
+                            return Ok(Handshake::Legacy);
+                        }
+                        code if code == u64::from(UPGRADE_CLOSE_CODE) => {
+                            self.emit_protocol_mismatch(peer_id, "local_too_old", None);
+                            anyhow::bail!(
+                                "peer requires a newer sync protocol (local version {SYNC_PROTOCOL_VERSION})"
+                            );
+                        }
+                        code if code == u64::from(REJECT_CLOSE_CODE) => {
+                            anyhow::bail!("peer rejected sync: this device is not trusted")
+                        }
+                        code => anyhow::bail!("peer closed connection with code {code}"),
+                    }
+                }
+                _ => return Err(anyhow::anyhow!(e).context("read hello reply")),
+            },
+        }
+        let version = u16::from_le_bytes([reply[0], reply[1]]);
+        // 应答 hello 的对端至少是 hello 协议版本
+        if version < MIN_PEER_PROTOCOL_VERSION.max(LEGACY_PROTOCOL_VERSION + 1) {
+            self.emit_protocol_mismatch(peer_id, "peer_too_old", Some(version));
+            conn.close(UPGRADE_CLOSE_CODE.into(), b"upgrade required");
+            anyhow::bail!(
+                "peer sync protocol version {version} is too old (minimum {MIN_PEER_PROTOCOL_VERSION})"
+            );
+        }
+        let caps = reply[2];
+        if caps & !SYNC_CAPABILITIES != 0 {
+            anyhow::bail!("peer agreed to unsupported capabilities: {caps:#04x}");
+        }
+        Ok(Handshake::Negotiated { send, recv, caps })
+    }
+
+    /// 协议版本不兼容：输出 `sync.push` 失败事件（`reason` = `peer_too_old` /
+    /// `local_too_old`，附双方版本号）。
+    fn emit_protocol_mismatch(&self, peer_id: &str, reason: &str, peer_version: Option<u16>) {
+        let mut event = LogEvent::new("sync.push", "sync.protocol")
+            .with_id(&self.device_id())
+            .with_id(peer_id)
+            .with_field("direction", "push")
+            .with_field("action", "failed")
+            .with_field("reason", reason)
+            .with_field("local_version", SYNC_PROTOCOL_VERSION.to_string())
+            .with_field("min_version", MIN_PEER_PROTOCOL_VERSION.to_string());
+        if let Some(version) = peer_version {
+            event = event.with_field("peer_version", version.to_string());
+        }
+        self.emit_log(event);
+    }
+
+    /// 全量推送：`LORO_MAGIC` + 全部笔记快照 → 等待对端确认 → 记水位。
+    /// 返回发送的笔记记录条数。
+    async fn send_snapshot(
+        &self,
+        conn: &iroh::endpoint::Connection,
+        mut send: iroh::endpoint::SendStream,
+        peer_id: &str,
+        compress: bool,
+    ) -> Result<usize> {
+        // 先取水位再导出：确认的版本不会超出实际发送的内容
+        let ack = core_watermark(&self.core.lock().unwrap(), None);
+        // 网络线格式：8 字节 CARDMIND magic + 逐条写出的墓碑 section 与记录流
+        // （M2：magic 识别推送帧，防止墓碑数=1 时首字节 0x01 与配对帧标记冲突）
+        send.write_all(LORO_MAGIC)
+            .await
+            .context("write push marker")?;
+        let records = send_records(&self.core, &mut send, None, compress)
+            .await
+            .context("write snapshot data")?;
+        // finish() 显式发送流结束（EOF），接收端读到记录边界处的流结束即读完
+        send.finish().context("finish push stream")?;
+        // 等待对端读完数据并以关闭码 0 关闭连接（确认送达）；未确认不记水位
+        await_peer_ack(conn).await?;
+        self.record_peer_ack(peer_id, ack);
+        Ok(records)
+    }
+
+    /// 回退：向早于 hello 协议的对端发送 v3 快照格式的全量推送（单向流、记录体
+    /// 不压缩，即 `CARDMIND` + `export_all` 原始字节）。对端已关闭连接时重新连接。
+    ///
+    /// 早期版本没有应用层确认：只等待传输层确认收到全部数据，不记水位（该
+    /// 对端每轮都收到全量快照）。
+    async fn push_legacy_snapshot(
+        &self,
+        conn: iroh::endpoint::Connection,
+        peer_id: &str,
+        peer_ips: &[String],
+    ) -> Result<usize> {
+        let conn = match conn.close_reason() {
+            Some(_) => self.connect_peer(peer_id, peer_ips).await?,
+            None => conn,
+        };
+        self.emit_log(
+            LogEvent::new("sync.push", "sync.protocol")
+                .with_id(&self.device_id())
+                .with_id(peer_id)
+                .with_field("direction", "push")
+                .with_field("action", "legacy_fallback"),
+        );
+        let mut send = conn.open_uni().await.context("open uni stream")?;
+        send.write_all(LORO_MAGIC)
+            .await
+            .context("write push marker")?;
+        let records = send_records(&self.core, &mut send, None, false)
+            .await
+            .context("write snapshot data")?;
+        send.finish().context("finish push stream")?;
+        match tokio::time::timeout(Duration::from_secs(10), send.stopped()).await {
+            Ok(Ok(None)) => Ok(records),
+            Ok(Ok(Some(code))) => anyhow::bail!("peer stopped push stream with code {code}"),
+            // 读完即关闭连接（关闭码 0）的早期版本
+            Ok(Err(_)) if legacy_closed(&conn) => Ok(records),
+            Ok(Err(e)) => Err(anyhow::anyhow!(e).context("legacy push not delivered")),
+            Err(_) => anyhow::bail!("legacy push not delivered within 10s"),
+        }
+    }
+
+    /// 见 [`SyncService::push_to_paired_devices`]。
+    async fn push_to_paired_devices(
+        &self,
+        devices: &[(String, Option<Vec<String>>)],
+    ) -> Vec<DevicePushResult> {
+        let started = std::time::Instant::now();
+        let attempts = devices.iter().map(|(peer_id, ips)| async move {
+            let outcome = tokio::time::timeout(
+                std::time::Duration::from_secs(10),
+                self.push_to_peer_once(peer_id, ips.as_deref()),
+            )
+            .await
+            .unwrap_or_else(|_| Err(anyhow::anyhow!("push timeout after 10s")));
+            match outcome {
+                Ok(delta_count) => {
+                    self.record_peer_success(peer_id);
+                    // 事件 #10：后续同步单台成功（只记录数量）
+                    self.emit_log(
+                        LogEvent::new("sync.push", "sync.push")
+                            .with_id(&self.device_id())
+                            .with_id(peer_id)
+                            .with_field("direction", "push")
+                            .with_field("action", "success")
+                            .with_field("mode", "delta")
+                            .with_field("note_count", delta_count.to_string())
+                            .with_field("transport", self.transport_label(peer_id)),
+                    );
+                    DevicePushResult {
+                        peer_id: peer_id.clone(),
+                        ok: true,
+                        message: String::new(),
+                        delivered_count: delta_count as u32,
+                        pending_count: self.pending_sync_count_for_peer(peer_id),
+                    }
+                }
+                Err(e) => {
+                    let retry_in = self.record_peer_failure(peer_id);
+                    // 事件 #10：后续同步单台失败（错误链 + 耗时 + 退避；**不再打印完整 peer_id**）
+                    self.emit_log(
+                        LogEvent::new("sync.push", "sync.push")
+                            .with_id(&self.device_id())
+                            .with_id(peer_id)
+                            .with_field("direction", "push")
+                            .with_field("action", "failed")
+                            .with_field("retry_in_ms", retry_in.as_millis().to_string())
+                            .with_error(&e.to_string())
+                            .with_chain(&format!("{e:#}"))
+                            .with_duration(started.elapsed()),
+                    );
+                    DevicePushResult {
+                        peer_id: peer_id.clone(),
+                        ok: false,
+                        message: format!("{e:#}"),
+                        delivered_count: 0,
+                        pending_count: self.pending_sync_count_for_peer(peer_id),
+                    }
+                }
+            }
+        });
+        let results = futures::future::join_all(attempts).await;
+        self.flush_sync_state();
+        results
+    }
+
+    /// 单台设备的增量推送：连接并握手 → 同一双向流发 `DELTA_MAGIC` → 读对端
+    /// 版本摘要 → 逐条发送对端缺失的增量。返回发送的笔记记录条数（0 = 对端已是最新）。
+    ///
+    /// 上次推送中断时，对端已导入的部分已计入它回复的摘要，本次只发送其余部分。
+    /// 对端不支持增量（缺 [`CAP_DELTA`] / [`CAP_CHUNKED`]）或早于 hello 协议时
+    /// 回退为全量推送。
+    async fn push_to_peer_once(&self, peer_id: &str, peer_ips: Option<&[String]>) -> Result<usize> {
+        let peer_ips = peer_ips.unwrap_or(&[]);
+        let conn = self.connect_peer(peer_id, peer_ips).await?;
+        let (mut send, mut recv, caps) = match self.handshake(&conn, peer_id).await? {
+            Handshake::Negotiated { send, recv, caps } => (send, recv, caps),
+            Handshake::Legacy => return self.push_legacy_snapshot(conn, peer_id, peer_ips).await,
+        };
+        let compress = caps & CAP_LZ4 != 0;
+        if caps & (CAP_DELTA | CAP_CHUNKED) != CAP_DELTA | CAP_CHUNKED {
+            return self.send_snapshot(&conn, send, peer_id, compress).await;
+        }
+        send.write_all(DELTA_MAGIC)
+            .await
+            .context("write delta marker")?;
+        let digest = recv
+            .read_to_end(DELTA_DIGEST_MAX_LEN)
+            .await
+            .context("read version digest")?;
+        let remote = decode_version_digest(&digest)?;
+        let ack = core_watermark(&self.core.lock().unwrap(), Some(&remote));
+        let records = send_records(&self.core, &mut send, Some(&remote), compress)
+            .await
+            .context("write delta data")?;
+        send.finish().context("finish bi stream")?;
+        // 等待对端确认（push_to_paired_devices 外层也有 10s 超时）
+        await_peer_ack(&conn).await?;
+        // 对端此后至少包含：它回复的摘要 ∪ 本次发送的增量
+        self.record_peer_ack(peer_id, ack);
+        Ok(records)
+    }
+
+    /// 见 [`SyncService::sync_allowed`]。
+    fn sync_allowed(&self) -> bool {
+        self.sync_allowed.load(Ordering::Relaxed)
+    }
+
+    /// 见 [`SyncService::transfer_limits`]。
+    fn transfer_limits(&self) -> TransferLimits {
+        self.core.lock().unwrap().transfer_limits
+    }
+
+    /// 见 [`SyncService::collect_tombstones`]。
+    fn collect_tombstones(&self, store: &NoteStore) -> Result<usize> {
+        let peers: Vec<String> = store
+            .list_paired_devices()?
+            .into_iter()
+            .map(|row| row.peer_id)
+            .collect();
+        let mut core = self.core.lock().unwrap();
+        let now = Utc::now();
+        let horizon =
+            chrono::Duration::from_std(core.tombstone_horizon).unwrap_or(chrono::Duration::MAX);
+        let collected: Vec<(String, DateTime<Utc>)> = core
+            .tombstones
+            .iter()
+            .filter(|(id, purged_at)| {
+                let acked = !peers.is_empty()
+                    && peers.iter().all(|peer| {
+                        core.peer_acks
+                            .get(peer)
+                            .is_some_and(|ack| ack.tombstones.contains(*id))
+                    });
+                acked || now.signed_duration_since(**purged_at) >= horizon
+            })
+            .map(|(id, purged_at)| (id.clone(), *purged_at))
+            .collect();
+        if collected.is_empty() {
+            return Ok(0);
+        }
+        for (id, purged_at) in &collected {
+            core.tombstones.remove(id);
+            core.collected_tombstones.insert(id.clone(), *purged_at);
+        }
+        if let Err(err) = compact_core(&mut core) {
+            for (id, _) in &collected {
+                core.collected_tombstones.remove(id);
+            }
+            core.tombstones.extend(collected);
+            return Err(err);
+        }
+        for ack in core.peer_acks.values_mut() {
+            for (id, _) in &collected {
+                ack.tombstones.remove(id);
+            }
+        }
+        let persisted = persist_peer_acks(&core);
+        drop(core);
+        self.emit_log(
+            LogEvent::new("sync.tombstone", "sync.gc")
+                .with_id(&self.device_id())
+                .with_field("action", "collected")
+                .with_field("note_count", collected.len().to_string()),
+        );
+        persisted?;
+        Ok(collected.len())
+    }
+
+    /// 见 [`SyncService::pending_sync_count`]。
+    fn pending_sync_count(&self) -> u32 {
+        let peers = self.known_peers();
+        let core = self.core.lock().unwrap();
+        if peers.is_empty() {
+            let mut pending = pending_ids(&core, None);
+            for ack in core.peer_acks.values() {
+                let missing = pending_ids(&core, Some(ack));
+                pending.retain(|id| missing.contains(id));
+            }
+            return pending.len() as u32;
+        }
+        let mut pending = HashSet::new();
+        for peer in &peers {
+            pending.extend(pending_ids(&core, core.peer_acks.get(peer)));
+        }
+        pending.len() as u32
+    }
+
+    /// 见 [`SyncService::pending_sync_count_for_peer`]。
+    fn pending_sync_count_for_peer(&self, peer_id: &str) -> u32 {
+        let core = self.core.lock().unwrap();
+        pending_ids(&core, core.peer_acks.get(peer_id)).len() as u32
+    }
+
+    /// 待同步计数的对端集合：已绑定投影中的配对设备，未绑定时取有水位记录的对端。
+    fn known_peers(&self) -> Vec<String> {
+        let bound = self.trust_store.lock().unwrap().clone();
+        match bound.map(|store| store.list_paired_devices()) {
+            Some(Ok(rows)) => rows.into_iter().map(|row| row.peer_id).collect(),
+            _ => self
+                .core
+                .lock()
+                .unwrap()
+                .peer_acks
+                .keys()
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// 记录对端确认的同步水位（只改内存；本轮推送/会话全部结束后由
+    /// [`Self::flush_sync_state`] 一次落盘）。
+    fn record_peer_ack(&self, peer_id: &str, ack: PeerWatermark) {
+        merge_peer_ack(&mut self.core.lock().unwrap(), peer_id, ack);
+    }
+
+    /// 落盘本轮累积的同步状态变更（失败只记录日志：内存水位仍生效，最坏
+    /// 情况是重启后多推一次）。
+    fn flush_sync_state(&self) {
+        let flushed = flush_sync_state(&mut self.core.lock().unwrap());
+        if let Err(e) = flushed {
+            self.emit_log(
+                LogEvent::new("sync.watermark", "sync.watermark")
+                    .with_id(&self.device_id())
+                    .with_field("action", "persist_failed")
+                    .with_error(&e.to_string())
+                    .with_chain(&format!("{e:#}")),
+            );
+        }
+    }
+
+    /// 按退避状态筛选本轮应连接的对端，返回 `(应连接的对端, 推迟的台数)`。
+    fn due_peers(&self, devices: PeerTargets) -> (PeerTargets, usize) {
+        let now = std::time::Instant::now();
+        let backoff = self.peer_backoff.lock().unwrap();
+        let (due, deferred): (Vec<_>, Vec<_>) = devices.into_iter().partition(|(peer_id, _)| {
+            backoff
+                .get(peer_id)
+                .is_none_or(|state| state.next_attempt <= now)
+        });
+        (due, deferred.len())
+    }
+
+    /// 对端同步成功：清除其退避状态并标记在线。
+    fn record_peer_success(&self, peer_id: &str) {
+        self.peer_backoff.lock().unwrap().remove(peer_id);
+        self.events.peer_online(peer_id);
+    }
+
+    /// 对端同步失败：标记离线，连续失败次数加一并排定下次尝试时间，返回退避间隔。
+    fn record_peer_failure(&self, peer_id: &str) -> Duration {
+        self.events.peer_offline(peer_id);
+        let mut backoff = self.peer_backoff.lock().unwrap();
+        let failures = backoff.get(peer_id).map_or(0, |state| state.failures) + 1;
+        let delay = backoff_delay(failures);
+        backoff.insert(
+            peer_id.to_string(),
+            PeerBackoff {
+                failures,
+                next_attempt: std::time::Instant::now() + delay,
+            },
+        );
+        delay
+    }
+
+    /// 对端重新出现（mDNS 发现 / 入站连接）：标记在线并立即清除其退避，
+    /// 下一轮即尝试。
+    fn peer_reappeared(&self, peer_id: &str, reason: &str) {
+        self.events.peer_online(peer_id);
+        if clear_peer_backoff(&self.peer_backoff, peer_id) {
+            self.emit_log(
+                LogEvent::new("sync.backoff", "sync.backoff")
+                    .with_id(&self.device_id())
+                    .with_id(peer_id)
+                    .with_field("action", "reset")
+                    .with_field("reason", reason),
+            );
+        }
+    }
+
+    /// 局域网对端被 mDNS 看到：仅对处于退避中的对端生效（本机自己的广播、
+    /// 未配对或本就在线的设备忽略）。
+    fn peer_sighted(&self, peer_id: &str) {
+        if peer_id != self.device_id() && self.peer_backoff(peer_id).is_some() {
+            self.peer_reappeared(peer_id, "mdns");
+        }
+    }
+
+    /// 后台同步任务：广播本机在线状态并持续监听局域网对端（见
+    /// [`DiscoveryService::watch_peers`]）。mDNS 不可用时返回 None——退避仍按
+    /// 时间结束，入站连接同样会清除退避。
+    fn start_presence_watch(&self, discovery: &mut Option<DiscoveryService>) -> Option<PeerWatch> {
+        let result: Result<PeerWatch> = (|| {
+            if discovery.is_none() {
+                *discovery = Some(DiscoveryService::new()?);
+            }
+            let disc = discovery.as_mut().expect("discovery just ensured");
+            disc.start_presence(&self.device_id(), self.endpoint_listen_port())?;
+            disc.watch_peers()
+        })();
+        match result {
+            Ok(watch) => {
+                self.emit_log(
+                    LogEvent::new("discovery.mdns", "discovery.mdns")
+                        .with_id(&self.device_id())
+                        .with_field("action", "watch_start"),
+                );
+                Some(watch)
+            }
+            Err(e) => {
+                self.emit_log(
+                    LogEvent::new("discovery.mdns", "discovery.mdns")
+                        .with_id(&self.device_id())
+                        .with_field("action", "watch_failed")
+                        .with_error(&e.to_string())
+                        .with_chain(&format!("{e:#}")),
+                );
+                None
+            }
+        }
+    }
+
+    /// 见 [`SyncService::peer_backoff`]。
+    fn peer_backoff(&self, peer_id: &str) -> Option<(u32, Duration)> {
+        let backoff = self.peer_backoff.lock().unwrap();
+        let state = backoff.get(peer_id)?;
+        Some((
+            state.failures,
+            state
+                .next_attempt
+                .saturating_duration_since(std::time::Instant::now()),
+        ))
+    }
+
+    /// 从 store 读取配对设备，为每台附上最近已知直连 IP（有则直连优先，无则走
+    /// relay/地址解析）。
+    ///
+    /// 经其他成员加入设备网的设备（名册投影进配对表，见 [`apply_roster`]）同样
+    /// 是推送目标；本机未记录其 IP 时取名册中签发方记下的地址。只读，不改动
+    /// 名册或配对表。
+    fn paired_devices_with_ips(&self, store: &NoteStore) -> PeerTargets {
+        let hints = roster_addrs(&self.core.lock().unwrap().roster);
+        let peer_ips = self.peer_ips.lock().unwrap();
+        store
+            .list_paired_devices()
+            .map(|rows| {
+                rows.into_iter()
+                    .map(|d| {
+                        let ips = peer_ips
+                            .get(&d.peer_id)
+                            .or_else(|| hints.get(&d.peer_id))
+                            .cloned();
+                        (d.peer_id, ips)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// 见 [`SyncService::push_pending`]。
+    async fn push_pending(&self, store: &NoteStore) -> Vec<DevicePushResult> {
+        let started = std::time::Instant::now();
+        self.bind_trust_store(store);
+        let pending_count = self.pending_sync_count();
+        if !self.sync_allowed() {
+            // 事件 #10：同步开关关闭 → 跳过
+            self.emit_log(
+                LogEvent::new("sync.push_pending", "sync.push_pending")
+                    .with_id(&self.device_id())
+                    .with_field("action", "skipped")
+                    .with_field("reason", "sync_disabled")
+                    .with_field("pending_count", pending_count.to_string()),
+            );
+            return Vec::new();
+        }
+        let devices = self.paired_devices_with_ips(store);
+        if devices.is_empty() {
+            // 事件 #10：无配对设备 → 跳过
+            self.emit_log(
+                LogEvent::new("sync.push_pending", "sync.push_pending")
+                    .with_id(&self.device_id())
+                    .with_field("action", "skipped")
+                    .with_field("reason", "no_devices")
+                    .with_field("pending_count", pending_count.to_string()),
+            );
+            return Vec::new();
+        }
+        // 只推给尚有未确认变更的对端：上次已送达的设备不重复连接，
+        // 失败的设备水位未前进，下次调度自然重试
+        let devices: Vec<_> = devices
+            .into_iter()
+            .filter(|(peer_id, _)| self.pending_sync_count_for_peer(peer_id) > 0)
+            .collect();
+        if devices.is_empty() {
+            self.emit_log(
+                LogEvent::new("sync.push_pending", "sync.push_pending")
+                    .with_id(&self.device_id())
+                    .with_field("action", "skipped")
+                    .with_field("reason", "up_to_date")
+                    .with_field("pending_count", pending_count.to_string()),
+            );
+            return Vec::new();
+        }
+        let (devices, deferred) = self.due_peers(devices);
+        if devices.is_empty() {
+            self.emit_log(
+                LogEvent::new("sync.push_pending", "sync.push_pending")
+                    .with_id(&self.device_id())
+                    .with_field("action", "skipped")
+                    .with_field("reason", "backoff")
+                    .with_field("deferred_count", deferred.to_string())
+                    .with_field("pending_count", pending_count.to_string()),
+            );
+            return Vec::new();
+        }
+        self.events.emit(SyncEvent::SyncStarted);
+        let results = self.push_to_paired_devices(&devices).await;
+        self.emit_sync_outcome(&results, 0);
+        let duration = started.elapsed();
+        if results.iter().any(|r| r.ok) {
+            for r in &results {
+                if r.ok {
+                    self.touch_last_seen(store, &r.peer_id, "outbound_push");
+                }
+            }
+        } else {
+            // 全部失败 → 静默（决策 18）；逐台事件已在 push_to_paired_devices 内发出（脱敏）
+            for r in &results {
+                self.emit_log(
+                    LogEvent::new("sync.push_pending", "sync.push_pending")
+                        .with_id(&self.device_id())
+                        .with_id(&r.peer_id)
+                        .with_field("action", "all_failed_silent")
+                        .with_field("pending_count", pending_count.to_string())
+                        .with_duration(duration),
+                );
+            }
+        }
+        // 汇总事件：成功台数 + 待同步数 + 耗时
+        let ok_count = results.iter().filter(|r| r.ok).count();
+        self.emit_log(
+            LogEvent::new("sync.push_pending", "sync.push_pending")
+                .with_id(&self.device_id())
+                .with_field("action", "end")
+                .with_field("ok_count", ok_count.to_string())
+                .with_field("deferred_count", deferred.to_string())
+                .with_field("pending_count", pending_count.to_string())
+                .with_field("pending_after", self.pending_sync_count().to_string())
+                .with_duration(duration),
+        );
+        results
+    }
+
+    /// 见 [`SyncService::run_sync_cycle`]。
+    async fn run_sync_cycle(&self, store: &NoteStore) -> Result<SyncCycleResult> {
+        let started = std::time::Instant::now();
+        self.bind_trust_store(store);
+        if !self.sync_allowed() {
+            let result = SyncCycleResult {
+                pushed_count: 0,
+                accepted_push: false,
+                disabled: true,
+            };
+            self.emit_log(
+                LogEvent::new("sync.cycle", "sync.cycle")
+                    .with_id(&self.device_id())
+                    .with_field("action", "end")
+                    .with_field("disabled", "true"),
+            );
+            return Ok(result);
+        }
+        // 名册与配对表对齐后再取同步目标（经其他成员加入的设备同样同步）
+        self.sync_roster(store);
+        let (devices, deferred) = self.due_peers(self.paired_devices_with_ips(store));
+        let (before, tombstones_before) = {
+            let core = self.core.lock().unwrap();
+            (note_states(&core), core.tombstones.clone())
+        };
+        let (results, pulled) = if devices.is_empty() {
+            (Vec::new(), 0)
+        } else {
+            self.events.emit(SyncEvent::SyncStarted);
+            self.sync_sessions_with_devices(store, &devices).await
+        };
+        let any_ok = results.iter().any(|r| r.ok);
+        if any_ok {
+            for r in &results {
+                if r.ok {
+                    self.touch_last_seen(store, &r.peer_id, "sync_session");
+                }
+            }
+        } else if !results.is_empty() {
+            // 逐台失败事件已在 sync_sessions_with_devices 内发出（脱敏）；汇总一次
+            for r in &results {
+                self.emit_log(
+                    LogEvent::new("sync.cycle", "sync.cycle")
+                        .with_id(&self.device_id())
+                        .with_id(&r.peer_id)
+                        .with_field("action", "session_failed_silent")
+                        .with_field("ok", "false")
+                        .with_duration(started.elapsed()),
+                );
+            }
+        }
+        // 对端变更（按拉取前快照对比）：只带墓碑的增量没有笔记记录，同样需要刷新投影
+        let events = {
+            let core = self.core.lock().unwrap();
+            remote_change_events(&core, &before, &tombstones_before)
+        };
+        let accepted = pulled > 0 || !events.is_empty();
+        if accepted {
+            if let Err(e) = self.sync_notes_to_store(store) {
+                self.events.emit(SyncEvent::SyncFailed {
+                    message: format!("{e:#}"),
+                });
+                return Err(e);
+            }
+            self.content_revision.fetch_add(1, Ordering::Release);
+            // 投影已刷新后再通知 UI
+            for event in events {
+                self.events.emit(event);
+            }
+        }
+        if !devices.is_empty() {
+            self.emit_sync_outcome(&results, pulled);
+        }
+        // 本轮确认可能让墓碑满足回收条件（失败只记录日志，下一轮重试）
+        if let Err(e) = self.collect_tombstones(store) {
+            self.emit_log(
+                LogEvent::new("sync.tombstone", "sync.gc")
+                    .with_id(&self.device_id())
+                    .with_field("action", "failed")
+                    .with_error(&e.to_string())
+                    .with_chain(&format!("{e:#}")),
+            );
+        }
+        // 成功会话的对端设备数（真实计数，非 0/1 布尔）
+        let pushed_count = results.iter().filter(|r| r.ok).count() as u32;
+        // 事件 #10：周期同步汇总（触发原因由 Flutter 调度器记录；这里记录结果）
+        self.emit_log(
+            LogEvent::new("sync.cycle", "sync.cycle")
+                .with_id(&self.device_id())
+                .with_field("action", "end")
+                .with_field("pushed_count", pushed_count.to_string())
+                .with_field("accepted_push", accepted.to_string())
+                .with_field("pulled_count", pulled.to_string())
+                .with_field("deferred_count", deferred.to_string())
+                .with_field("pending_after", self.pending_sync_count().to_string())
+                .with_duration(started.elapsed()),
+        );
+        Ok(SyncCycleResult {
+            pushed_count,
+            accepted_push: accepted,
+            disabled: false,
+        })
+    }
+
+    /// 一轮同步结束：至少一台对端成功 → [`SyncEvent::SyncFinished`]；全部失败 →
+    /// [`SyncEvent::SyncFailed`]（附第一台的失败原因）。
+    fn emit_sync_outcome(&self, results: &[DevicePushResult], pulled: usize) {
+        let ok_count = results.iter().filter(|r| r.ok).count() as u32;
+        let failed_count = results.len() as u32 - ok_count;
+        let event = match results.iter().find(|r| !r.ok) {
+            Some(failed) if ok_count == 0 => SyncEvent::SyncFailed {
+                message: failed.message.clone(),
+            },
+            _ => SyncEvent::SyncFinished {
+                ok_count,
+                failed_count,
+                pulled_count: pulled as u32,
+            },
+        };
+        self.events.emit(event);
+    }
+
+    /// 并发与各台设备执行双向同步会话（单台失败不中断整体；单台超时 10 秒，
+    /// 离线设备不拖慢其他设备）。每台结果计入该对端的退避状态。
+    ///
+    /// 返回每台结果与拉取到的笔记记录总数（已导入共享 core 并持久化，
+    /// 由调用方刷新 SQLite 投影）。
+    async fn sync_sessions_with_devices(
+        &self,
+        store: &NoteStore,
+        devices: &[(String, Option<Vec<String>>)],
+    ) -> (Vec<DevicePushResult>, usize) {
+        let started = std::time::Instant::now();
+        let sessions = devices.iter().map(|(peer_id, ips)| async move {
+            let outcome = tokio::time::timeout(
+                std::time::Duration::from_secs(10),
+                self.sync_session_once(store, peer_id, ips.as_deref()),
+            )
+            .await
+            .unwrap_or_else(|_| Err(anyhow::anyhow!("sync session timeout after 10s")));
+            match outcome {
+                Ok((pulled, pushed)) => {
+                    self.record_peer_success(peer_id);
+                    self.emit_log(
+                        LogEvent::new("sync.session", "sync.session")
+                            .with_id(&self.device_id())
+                            .with_id(peer_id)
+                            .with_field("action", "success")
+                            .with_field("pulled_count", pulled.to_string())
+                            .with_field("pushed_count", pushed.to_string())
+                            .with_field("transport", self.transport_label(peer_id))
+                            .with_duration(started.elapsed()),
+                    );
+                    let result = DevicePushResult {
+                        peer_id: peer_id.clone(),
+                        ok: true,
+                        message: String::new(),
+                        delivered_count: pushed as u32,
+                        pending_count: self.pending_sync_count_for_peer(peer_id),
+                    };
+                    (result, pulled)
+                }
+                Err(e) => {
+                    let retry_in = self.record_peer_failure(peer_id);
+                    self.emit_log(
+                        LogEvent::new("sync.session", "sync.session")
+                            .with_id(&self.device_id())
+                            .with_id(peer_id)
+                            .with_field("action", "failed")
+                            .with_field("retry_in_ms", retry_in.as_millis().to_string())
+                            .with_error(&e.to_string())
+                            .with_chain(&format!("{e:#}"))
+                            .with_duration(started.elapsed()),
+                    );
+                    let result = DevicePushResult {
+                        peer_id: peer_id.clone(),
+                        ok: false,
+                        message: format!("{e:#}"),
+                        delivered_count: 0,
+                        pending_count: self.pending_sync_count_for_peer(peer_id),
+                    };
+                    (result, 0)
+                }
+            }
+        });
+        let outcomes = futures::future::join_all(sessions).await;
+        self.flush_sync_state();
+        let pulled_total: usize = outcomes.iter().map(|(_, pulled)| pulled).sum();
+        (
+            outcomes.into_iter().map(|(result, _)| result).collect(),
+            pulled_total,
+        )
+    }
+
+    /// 单台设备的双向同步会话（发起方，协议见 [`SESSION_MAGIC`]）。
+    ///
+    /// 返回 `(拉取的笔记记录数, 推回的笔记记录数)`。拉取的增量边读边导入共享
+    /// core（[`import_stream`]，中断时已导入部分照常落盘，下次会话只拉其余部分）
+    /// 再计算推回内容，避免把刚拉到的更新原样推回。双方已知的撤销记录与设备
+    /// 名册随会话交换，验签后写入 `store`。
+    ///
+    /// 对端不支持会话（缺 [`CAP_DELTA`] / [`CAP_CHUNKED`]）或早于 hello 协议时
+    /// 只向其推送全量快照（拉取数为 0）。
+    async fn sync_session_once(
+        &self,
+        store: &NoteStore,
+        peer_id: &str,
+        peer_ips: Option<&[String]>,
+    ) -> Result<(usize, usize)> {
+        let peer_ips = peer_ips.unwrap_or(&[]);
+        let conn = self.connect_peer(peer_id, peer_ips).await?;
+        let (mut send, mut recv, caps) = match self.handshake(&conn, peer_id).await? {
+            Handshake::Negotiated { send, recv, caps } => (send, recv, caps),
+            Handshake::Legacy => {
+                let pushed = self.push_legacy_snapshot(conn, peer_id, peer_ips).await?;
+                return Ok((0, pushed));
+            }
+        };
+        let compress = caps & CAP_LZ4 != 0;
+        if caps & (CAP_DELTA | CAP_CHUNKED) != CAP_DELTA | CAP_CHUNKED {
+            let pushed = self.send_snapshot(&conn, send, peer_id, compress).await?;
+            return Ok((0, pushed));
+        }
+        let mut request = SESSION_MAGIC.to_vec();
+        push_bytes(&mut request, &self.version_digest());
+        push_bytes(
+            &mut request,
+            &encode_revocations(&store.list_revoked_devices()?),
+        );
+        push_bytes(&mut request, &export_roster(&self.core.lock().unwrap())?);
+        send.write_all(&request)
+            .await
+            .context("write session summary")?;
+
+        // 响应方回复：响应方版本摘要 + 撤销记录 + 设备名册，之后是本端缺失的增量
+        // （同一读取器，累计字节数一并受传输上限约束）
+        let limits = self.transfer_limits();
+        let mut reader = RecordReader::new(&mut recv, limits, compress);
+        let remote_digest = reader
+            .section("session digest", DELTA_DIGEST_MAX_LEN)
+            .await?;
+        let remote = decode_version_digest(&remote_digest)?;
+        let remote_revocations = reader
+            .section("session revocations", SESSION_META_MAX_LEN)
+            .await?;
+        let remote_roster = reader
+            .section("session roster", SESSION_META_MAX_LEN)
+            .await?;
+        let device_id = self.device_id();
+        for peer in apply_revocations(store, &decode_revocations(&remote_revocations)?, &device_id)?
+        {
+            self.emit_log(
+                LogEvent::new("device.revoke", "device")
+                    .with_id(&device_id)
+                    .with_id(&peer)
+                    .with_field("action", "received"),
+            );
+        }
+        let changes = {
+            let mut core = self.core.lock().unwrap();
+            merge_roster(&mut core, &remote_roster)?;
+            apply_roster(&core, store, &device_id)?
+        };
+        for (peer, action) in changes {
+            self.emit_log(
+                LogEvent::new("device.roster", "device")
+                    .with_id(&device_id)
+                    .with_id(&peer)
+                    .with_field("action", action),
+            );
+        }
+
+        let pulled = import_stream(&mut reader, &self.core).await;
+        if let Some(e) = pulled.interrupted {
+            conn.close(ABORT_CLOSE_CODE.into(), b"aborted");
+            return Err(e.context("read session delta"));
+        }
+        if !pulled.resurrected.is_empty() {
+            self.emit_log(
+                LogEvent::new("sync.tombstone", "sync.session")
+                    .with_id(&device_id)
+                    .with_id(peer_id)
+                    .with_field("action", "resurrection_blocked")
+                    .with_field("note_count", pulled.resurrected.len().to_string()),
+            );
+        }
+        let ack = core_watermark(&self.core.lock().unwrap(), Some(&remote));
+        let pushed = send_records(&self.core, &mut send, Some(&remote), compress)
+            .await
+            .context("write session delta")?;
+        send.finish().context("finish session stream")?;
+        // 等待响应方读完推回的增量并确认（外层也有 10s 超时）
+        await_peer_ack(&conn).await?;
+        self.record_peer_ack(peer_id, ack);
+        Ok((pulled.records, pushed))
+    }
+
+    /// 设备名册与投影对齐（失败只记录日志，不影响本轮同步），见
+    /// [`Self::reconcile_roster`]。
+    fn sync_roster(&self, store: &NoteStore) {
+        let device_id = self.device_id();
+        match self.reconcile_roster(store) {
+            Ok(changes) => {
+                for (peer, action) in changes {
+                    self.emit_log(
+                        LogEvent::new("device.roster", "device")
+                            .with_id(&device_id)
+                            .with_id(&peer)
+                            .with_field("action", action),
+                    );
+                }
+            }
+            Err(e) => self.emit_log(
+                LogEvent::new("device.roster", "device")
+                    .with_id(&device_id)
+                    .with_field("action", "failed")
+                    .with_error(&e.to_string())
+                    .with_chain(&format!("{e:#}")),
+            ),
+        }
+    }
+
+    /// 名册与配对表双向对齐：
+    /// - 配对表中尚无名册条目的设备（升级前已配对）由本机补签入册；
+    /// - 名册投影进配对表（见 [`apply_roster`]），返回变更的 `(peer_id, 动作)`。
+    fn reconcile_roster(&self, store: &NoteStore) -> Result<Vec<(String, &'static str)>> {
+        let mut core = self.core.lock().unwrap();
+        let listed: HashSet<String> = roster_entries(&core.roster)
+            .into_iter()
+            .map(|entry| entry.peer_id)
+            .collect();
+        for row in store.list_paired_devices()? {
+            if listed.contains(&row.peer_id) || row.peer_id.parse::<iroh::EndpointId>().is_err() {
+                continue;
+            }
+            let entry = sign_member(
+                &self.secret_key,
+                &row.peer_id,
+                &row.name,
+                &Utc::now().to_rfc3339(),
+            );
+            insert_roster_member(&mut core, &entry)?;
+        }
+        apply_roster(&core, store, &self.device_id())
+    }
+
+    /// 绑定主服务 accept 路径用于鉴权的投影（见 `trust_store` 字段）。
+    fn bind_trust_store(&self, store: &NoteStore) {
+        *self.trust_store.lock().unwrap() = Some(store.clone());
+    }
+
+    /// 见 [`SyncService::sync_notes_to_store`]。
+    fn sync_notes_to_store(&self, store: &NoteStore) -> Result<()> {
+        self.bind_trust_store(store);
+        for (id, note) in self.iter_notes() {
+            store.sync_note(&id, &note)?;
+        }
+        for id in self.tombstones() {
+            store.purge_note(&id)?;
+        }
+        Ok(())
+    }
+
+    /// 更新配对设备 last_seen 并输出结构化日志（触发原因配对/主动推送/被动接收）。
+    ///
+    /// 仅成功连接/同步后调用；失败路径不得调用（验收 14：失败不标记在线）。
+    fn touch_last_seen(&self, store: &NoteStore, peer_id: &str, reason: &str) {
+        self.events.peer_online(peer_id);
+        match store.update_last_seen(peer_id) {
+            Ok(()) => {
+                self.emit_log(
+                    LogEvent::new("device.last_seen", "device")
+                        .with_id(&self.device_id())
+                        .with_id(peer_id)
+                        .with_field("reason", reason)
+                        .with_field("action", "updated"),
+                );
+            }
+            Err(e) => {
+                self.emit_log(
+                    LogEvent::new("device.last_seen", "device")
+                        .with_id(&self.device_id())
+                        .with_id(peer_id)
+                        .with_field("reason", reason)
+                        .with_field("action", "failed")
+                        .with_error(&e.to_string())
+                        .with_chain(&format!("{e:#}")),
+                );
+            }
+        }
+    }
+}
+
+// ━━━ 共享核心状态纯函数（任务 O：主服务与后台接收器共用）━━━
+
+/// 全量快照导出（已持锁 core 的纯函数）。
+fn export_core_all(core: &CoreState) -> Result<Vec<u8>> {
+    let mut buf = Vec::new();
+    write_tombstone_section(&mut buf, core);
+    // 笔记记录流
+    for (note_id, note) in &core.notes {
+        push_record(&mut buf, note_id, &note.export_snapshot()?);
+    }
+    Ok(buf)
+}
+
+/// 增量导出（已持锁 core 的纯函数）：与 `export_core_all` 同格式（墓碑 section +
+/// 记录流），记录体为对端缺失的 Loro 更新，`import_core_all` 直接消费。
+///
+/// - 对端摘要无该笔记 → 全量快照
+/// - 对端版本已包含本地版本 → 跳过（不发送）
+/// - 其他 → `ExportMode::updates(对端版本)` 增量
+///
+/// 返回 `(payload, 记录条数)`；墓碑始终全量携带（只含 id，体积可忽略）。
+fn export_core_delta(
+    core: &CoreState,
+    remote: &HashMap<String, VersionVector>,
+) -> Result<(Vec<u8>, usize)> {
+    let mut buf = Vec::new();
+    write_tombstone_section(&mut buf, core);
+    let mut records = 0;
+    for (note_id, note) in &core.notes {
+        let body = match remote.get(note_id) {
+            None => note.export_snapshot()?,
+            Some(vv) if vv.includes_vv(&note.version_vector()) => continue,
+            Some(vv) => note.export_updates(vv)?,
+        };
+        push_record(&mut buf, note_id, &body);
+        records += 1;
+    }
+    Ok((buf, records))
+}
+
+/// 写入墓碑 section：`(墓碑数: u32 LE, (id_len: u32 LE, id)*)`，id 排序保证输出稳定。
+fn write_tombstone_section(buf: &mut Vec<u8>, core: &CoreState) {
+    buf.extend_from_slice(&(core.tombstones.len() as u32).to_le_bytes());
+    let mut sorted: Vec<&String> = core.tombstones.keys().collect();
+    sorted.sort();
+    for id in sorted {
+        let id_bytes = id.as_bytes();
+        buf.extend_from_slice(&(id_bytes.len() as u32).to_le_bytes());
+        buf.extend_from_slice(id_bytes);
+    }
+}
+
+/// 写入带删除时间的墓碑 section（仅本地 v5 快照）：
+/// `(墓碑数: u32 LE, (id_len: u32 LE, id, purged_at: i64 LE 毫秒)*)`。
+///
+/// 同步线路仍用 [`write_tombstone_section`]：删除时间只对本端回收有意义，对端
+/// 按各自收到墓碑的时间计。
+fn write_timed_tombstone_section(buf: &mut Vec<u8>, core: &CoreState) {
+    buf.extend_from_slice(&(core.tombstones.len() as u32).to_le_bytes());
+    let mut sorted: Vec<(&String, &DateTime<Utc>)> = core.tombstones.iter().collect();
+    sorted.sort();
+    for (id, purged_at) in sorted {
+        push_bytes(buf, id.as_bytes());
+        buf.extend_from_slice(&purged_at.timestamp_millis().to_le_bytes());
+    }
+}
+
+/// 本地快照导出（v5 payload：带删除时间的墓碑 section + 记录流）。
+fn export_core_snapshot(core: &CoreState) -> Result<Vec<u8>> {
+    let mut buf = Vec::new();
+    write_timed_tombstone_section(&mut buf, core);
+    for (note_id, note) in &core.notes {
+        push_record(&mut buf, note_id, &note.export_snapshot()?);
+    }
+    Ok(buf)
+}
+
+/// 追加一条笔记记录 `(note_id_len: u32 LE, note_id, body_len: u32 LE, body)`。
+fn push_record(buf: &mut Vec<u8>, note_id: &str, body: &[u8]) {
+    push_bytes(buf, note_id.as_bytes());
+    push_bytes(buf, body);
+}
+
+/// 版本摘要（增量握手时接收方回复）：`(笔记数: u32 LE, (id_len, id, vv_len, vv)*)`，
+/// vv 为 `VersionVector::encode` 输出。
+fn encode_version_digest(core: &CoreState) -> Vec<u8> {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&(core.notes.len() as u32).to_le_bytes());
+    for (note_id, note) in &core.notes {
+        push_record(&mut buf, note_id, &note.version_vector().encode());
+    }
+    buf
+}
+
+/// 解码版本摘要（格式见 [`encode_version_digest`]）。
+fn decode_version_digest(data: &[u8]) -> Result<HashMap<String, VersionVector>> {
+    let mut offset = 0;
+    if data.len() < 4 {
+        anyhow::bail!("truncated version digest: missing note count");
+    }
+    let count = u32::from_le_bytes(data[0..4].try_into().unwrap()) as usize;
+    offset += 4;
+    let mut digest = HashMap::with_capacity(count.min(data.len() / 8));
+    for _ in 0..count {
+        let id = take_bytes(data, &mut offset, "version digest note_id")?;
+        let id = String::from_utf8(id.to_vec()).context("invalid UTF-8 in version digest")?;
+        let vv = take_bytes(data, &mut offset, "version digest vv")?;
+        let vv = VersionVector::decode(vv).map_err(|e| anyhow::anyhow!(e))?;
+        digest.insert(id, vv);
+    }
+    Ok(digest)
+}
+
+/// 导入全量快照或增量（同步线路格式，见 [`SYNC_PAYLOAD_VERSION`]），返回被复活
+/// 守卫拦下的笔记 id。
+///
+/// 改动按 [`ImportBatch`] 记录（只为 payload 触及的笔记留回滚快照）：解析或
+/// 落盘失败时回滚这些笔记、墓碑与冲突记录，不随笔记总数线性增长。
+fn import_core_all(core: &mut CoreState, data: &[u8]) -> Result<Vec<String>> {
+    let mut batch = ImportBatch::default();
+    let resurrected = match import_core_raw(core, &mut batch, SYNC_PAYLOAD_VERSION, data) {
+        Ok(resurrected) => resurrected,
+        Err(err) => {
+            rollback_import_batch(core, batch)?;
+            return Err(err);
+        }
+    };
+    flush_import_batch(core, &mut batch)?;
+    if !resurrected.is_empty() {
+        // 重新记入墓碑的 id 移出已回收集合。尽力落盘：失败时重启后两处都有该
+        // id，同样拦截
+        core.sync_state_dirty = true;
+        let _ = flush_sync_state(core);
+    }
+    Ok(resurrected)
+}
+
+/// 同步目标：(peer_id, 最近已知直连 IP)；无 IP 时走 relay/地址解析。
+type PeerTargets = Vec<(String, Option<Vec<String>>)>;
+
+/// 笔记状态快照：note_id → (版本向量, 是否在回收站)。导入前后对比得出 UI 事件。
+type NoteStates = HashMap<String, (VersionVector, bool)>;
+
+fn note_states(core: &CoreState) -> NoteStates {
+    core.notes
+        .iter()
+        .map(|(id, note)| {
+            let deleted = note.get_deleted_at().is_some();
+            (id.clone(), (note.version_vector(), deleted))
+        })
+        .collect()
+}
+
+/// 对比导入前的笔记状态与墓碑，得出对端变更对应的 UI 事件（id 有序）：
+/// 回收站状态翻转 → 删除/恢复事件；其余版本变化（含新笔记）→ 变更事件；
+/// 新增墓碑（本端此前有该笔记）→ 彻底删除事件。对端重传本端已回收的墓碑时
+/// 本端已无该笔记，不重复通知。
+fn remote_change_events(
+    core: &CoreState,
+    before: &NoteStates,
+    tombstones_before: &HashMap<String, DateTime<Utc>>,
+) -> Vec<SyncEvent> {
+    let mut ids: Vec<&String> = core.notes.keys().collect();
+    ids.sort();
+    let mut changed = Vec::new();
+    let mut trash_events = Vec::new();
+    for id in ids {
+        let note = &core.notes[id];
+        let deleted = note.get_deleted_at().is_some();
+        match before.get(id) {
+            Some((vv, _)) if note.version_vector() == *vv => {}
+            Some((_, was_deleted)) if *was_deleted != deleted => {
+                let note_id = id.clone();
+                trash_events.push(if deleted {
+                    SyncEvent::NoteDeleted {
+                        note_id,
+                        remote: true,
+                    }
+                } else {
+                    SyncEvent::NoteRestored {
+                        note_id,
+                        remote: true,
+                    }
+                });
+            }
+            _ => changed.push(id.clone()),
+        }
+    }
+    let mut purged: Vec<String> = core
+        .tombstones
+        .keys()
+        .filter(|id| !tombstones_before.contains_key(*id) && before.contains_key(*id))
+        .cloned()
+        .collect();
+    purged.sort();
+    let mut events = Vec::new();
+    if !changed.is_empty() {
+        events.push(SyncEvent::NotesChanged {
+            note_ids: changed,
+            remote: true,
+        });
+    }
+    events.extend(trash_events);
+    if !purged.is_empty() {
+        events.push(SyncEvent::NotesPurged {
+            note_ids: purged,
+            remote: true,
+        });
+    }
+    events
+}
+
+/// [`import_core_all`] 并返回导入带来的 UI 事件与被复活守卫拦下的笔记 id
+/// （失败时整体回滚，不产生事件）。
+fn import_core_tracked(core: &mut CoreState, data: &[u8]) -> Result<(Vec<SyncEvent>, Vec<String>)> {
+    let before = note_states(core);
+    let tombstones_before = core.tombstones.clone();
+    let resurrected = import_core_all(core, data)?;
+    Ok((
+        remote_change_events(core, &before, &tombstones_before),
+        resurrected,
+    ))
+}
+
+/// 导入 payload（已持锁 core）。`version` 决定是否含墓碑 section：
+/// - v5：`带删除时间的墓碑 section + 记录流`（本地快照）
+/// - v3/v4：`墓碑 section + 记录流`（导入的墓碑与本地 tombstones union 合并，
+///   删除时间记为导入时间；记录流中遇到墓碑中的 id 跳过，不复活；本地已有的
+///   同 id 笔记随之移除）
+/// - v1/v2：纯记录流（无墓碑 section，tombstones 为空，无损升级）
+///
+/// 本端已回收的墓碑不再收回；本地没有的笔记经复活守卫（见
+/// [`resurrected_tombstone`]）判定为已回收墓碑的旧副本时不导入、重新记入墓碑，
+/// 返回这些 id。改动记入 `batch`，由调用方落盘或回滚。
+fn import_core_raw(
+    core: &mut CoreState,
+    batch: &mut ImportBatch,
+    version: u32,
+    data: &[u8],
+) -> Result<Vec<String>> {
+    let mut offset = 0;
+    let now = Utc::now();
+
+    // ━━ 墓碑 section（v3 起）━━
+    let mut imported_tombstones: HashMap<String, DateTime<Utc>> = HashMap::new();
     if version >= 3 {
         if offset + 4 > data.len() {
             anyhow::bail!("truncated data: missing tombstone count");
         }
-        let tombstone_count =
-            u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
-        offset += 4;
-        for _ in 0..tombstone_count {
-            if offset + 4 > data.len() {
-                anyhow::bail!("truncated data: missing tombstone id length");
+        let tombstone_count =
+            u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+        offset += 4;
+        for _ in 0..tombstone_count {
+            if offset + 4 > data.len() {
+                anyhow::bail!("truncated data: missing tombstone id length");
+            }
+            let id_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+            offset += 4;
+            if offset + id_len > data.len() {
+                anyhow::bail!("truncated data: missing tombstone id");
+            }
+            let id = String::from_utf8(data[offset..offset + id_len].to_vec())
+                .context("invalid UTF-8 in tombstone id")?;
+            offset += id_len;
+            let purged_at = if version >= 5 {
+                let millis = data
+                    .get(offset..offset + 8)
+                    .ok_or_else(|| anyhow::anyhow!("truncated data: missing tombstone time"))?;
+                offset += 8;
+                DateTime::from_timestamp_millis(i64::from_le_bytes(millis.try_into().unwrap()))
+                    .ok_or_else(|| anyhow::anyhow!("invalid tombstone time"))?
+            } else {
+                now
+            };
+            imported_tombstones.insert(id, purged_at);
+        }
+    }
+
+    // ━━ 笔记记录流 ━━
+    let mut resurrected = Vec::new();
+    while offset < data.len() {
+        // 读取 note_id_len (u32 LE)
+        if offset + 4 > data.len() {
+            anyhow::bail!("truncated data: missing note_id length");
+        }
+        let id_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+        offset += 4;
+
+        // 读取 note_id
+        if offset + id_len > data.len() {
+            anyhow::bail!("truncated data: missing note_id");
+        }
+        let note_id = String::from_utf8(data[offset..offset + id_len].to_vec())
+            .context("invalid UTF-8 in note_id")?;
+        offset += id_len;
+
+        // 读取 snapshot_len (u32 LE)
+        if offset + 4 > data.len() {
+            anyhow::bail!("truncated data: missing snapshot length");
+        }
+        let snapshot_len =
+            u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+        offset += 4;
+
+        // 读取 snapshot
+        if offset + snapshot_len > data.len() {
+            anyhow::bail!("truncated data: missing snapshot body");
+        }
+        let snapshot = data[offset..offset + snapshot_len].to_vec();
+        offset += snapshot_len;
+
+        // 墓碑中的 id：跳过该记录（不复活）
+        if imported_tombstones.contains_key(&note_id) {
+            continue;
+        }
+        if import_record(core, batch, note_id.clone(), &snapshot)? {
+            resurrected.push(note_id);
+        }
+    }
+
+    // 墓碑 union 合并（本端已有的墓碑保留原删除时间）；被删笔记不再随快照
+    // 导出——墓碑回收后不会经本端复活。本端已回收的墓碑不收回，否则两端
+    // 轮流回收、互相重传
+    for (id, purged_at) in imported_tombstones {
+        if !core.tombstones.contains_key(&id) && !core.collected_tombstones.contains_key(&id) {
+            insert_batch_tombstone(core, batch, id, purged_at);
+        }
+    }
+    Ok(resurrected)
+}
+
+/// 持久化（已持锁 core 的纯函数）：增量追加，不重写整个快照。
+///
+/// - 基线快照缺失（首次持久化）→ 直接压缩写出完整快照
+/// - 否则只把 `dirty_notes` 中超出 `persisted_versions` 的 Loro 更新与新增墓碑
+///   追加到更新日志（[`update_log_path`]），每条记录带 CRC32 校验，写完
+///   `sync_data` 落盘
+/// - 日志记录数/字节数超过阈值 → 压缩（重写快照 + 清空日志）；压缩失败不影响
+///   已落盘的日志记录，下次 persist 重试
+///
+/// 写失败时按写前长度截断日志并保持 `persisted_*` 不变，调用方回滚内存态即可。
+fn persist_core(core: &mut CoreState) -> Result<()> {
+    let Some(path) = core.persistent_path.clone() else {
+        core.dirty_notes.clear();
+        return Ok(());
+    };
+    if !path.is_file() {
+        return compact_core(core);
+    }
+    let mut buf = Vec::new();
+    let mut appended = 0;
+    let mut versions = Vec::new();
+    for note_id in &core.dirty_notes {
+        let Some(note) = core.notes.get(note_id) else {
+            continue;
+        };
+        let vv = note.version_vector();
+        let body = match core.persisted_versions.get(note_id) {
+            None => note.export_snapshot()?,
+            Some(persisted) if persisted.includes_vv(&vv) => continue,
+            Some(persisted) => note.export_updates(persisted)?,
+        };
+        push_log_record(
+            &mut buf,
+            LOG_RECORD_NOTE,
+            note_id,
+            &body,
+            core.vault_key.as_ref(),
+        )?;
+        versions.push((note_id.clone(), vv));
+        appended += 1;
+    }
+    let new_tombstones: Vec<String> = core
+        .tombstones
+        .keys()
+        .filter(|id| !core.persisted_tombstones.contains(*id))
+        .cloned()
+        .collect();
+    for id in &new_tombstones {
+        push_log_record(
+            &mut buf,
+            LOG_RECORD_TOMBSTONE,
+            id,
+            &core.tombstones[id].timestamp_millis().to_le_bytes(),
+            core.vault_key.as_ref(),
+        )?;
+        appended += 1;
+    }
+    if appended == 0 {
+        core.dirty_notes.clear();
+        return Ok(());
+    }
+
+    let log_path = update_log_path(&path);
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&log_path)
+        .with_context(|| format!("open update log {}", log_path.display()))?;
+    let written = std::io::Write::write_all(&mut file, &buf)
+        .and_then(|()| file.sync_data())
+        .with_context(|| format!("append update log {}", log_path.display()));
+    if let Err(err) = written {
+        // 尽力截断残缺尾部（失败也无妨：重放时按校验和丢弃）
+        let _ = file.set_len(core.log_bytes);
+        return Err(err);
+    }
+    core.log_bytes += buf.len() as u64;
+    core.log_records += appended;
+    core.persisted_versions.extend(versions);
+    core.persisted_tombstones.extend(new_tombstones);
+    core.dirty_notes.clear();
+
+    if core.log_records >= LOG_COMPACT_RECORDS || core.log_bytes >= LOG_COMPACT_BYTES {
+        // 本次变更已在日志中落盘：压缩失败不算 persist 失败（调用方会回滚已
+        // 落盘的编辑），日志保留、阈值仍超，下次 persist 再压缩
+        let _ = compact_core(core);
+    }
+    Ok(())
+}
+
+/// 压缩（已持锁 core 的纯函数）：原子重写基线快照（v5 envelope，payload 经
+/// LZ4 压缩），再清空更新日志。
+/// 启用静态加密时快照整体加密后写出。
+///
+/// 快照提交后、日志清空前崩溃是安全的：重放已包含在快照中的 Loro 更新/墓碑
+/// 是幂等的。
+fn compact_core(core: &mut CoreState) -> Result<()> {
+    let Some(path) = core.persistent_path.clone() else {
+        return Ok(());
+    };
+    let payload = export_core_snapshot(core)?;
+    let bytes = vault::seal_at_rest(core.vault_key.as_ref(), encode_envelope(&payload))?;
+    let mut file = AtomicWriteFile::options()
+        .open(&path)
+        .with_context(|| format!("open atomic Loro file {}", path.display()))?;
+    std::io::Write::write_all(&mut file, &bytes)?;
+    file.commit().context("commit Loro file")?;
+    let log_path = update_log_path(&path);
+    if log_path.exists() {
+        std::fs::File::create(&log_path)
+            .and_then(|f| f.sync_all())
+            .with_context(|| format!("truncate update log {}", log_path.display()))?;
+    }
+    mark_core_persisted(core, 0, 0);
+    Ok(())
+}
+
+/// 以内存当前状态为已落盘基线（加载/压缩后调用）。
+fn mark_core_persisted(core: &mut CoreState, log_records: usize, log_bytes: u64) {
+    core.persisted_versions = core
+        .notes
+        .iter()
+        .map(|(id, note)| (id.clone(), note.version_vector()))
+        .collect();
+    core.persisted_tombstones = core.tombstones.keys().cloned().collect();
+    core.dirty_notes.clear();
+    core.log_records = log_records;
+    core.log_bytes = log_bytes;
+}
+
+/// 崩溃恢复：在已加载的基线快照之上按序重放更新日志。
+///
+/// 遇到长度越界或校验和不符的记录即视为崩溃时的残缺尾部：丢弃其后全部内容并
+/// 把日志截断到最后一条完整记录。加密记录需要 `core.vault_key`；校验和正确但
+/// 解密失败说明密钥不符或被篡改，报错而不截断。
+///
+/// 返回 `(重放记录数, 截断字节数, 明文记录数)`。
+fn replay_update_log(core: &mut CoreState) -> Result<(usize, u64, usize)> {
+    let Some(path) = core.persistent_path.clone() else {
+        return Ok((0, 0, 0));
+    };
+    let log_path = update_log_path(&path);
+    if !log_path.exists() {
+        mark_core_persisted(core, 0, 0);
+        return Ok((0, 0, 0));
+    }
+    let data = std::fs::read(&log_path)
+        .with_context(|| format!("read update log {}", log_path.display()))?;
+    let mut offset = 0;
+    let mut replayed = 0;
+    let mut plaintext = 0;
+    while let Some((record, next)) = next_log_record(&data, offset) {
+        let opened;
+        let record = if record.first() == Some(&LOG_RECORD_SEALED) {
+            let Some(key) = core.vault_key.as_ref() else {
+                anyhow::bail!("update log is encrypted but no vault key is unlocked");
+            };
+            opened = key
+                .open(&record[1..])
+                .with_context(|| format!("decrypt update log {}", log_path.display()))?;
+            &opened[..]
+        } else {
+            if let Some(key) = core.vault_key.as_ref() {
+                key.check_plaintext()
+                    .with_context(|| format!("read update log {}", log_path.display()))?;
+            }
+            plaintext += 1;
+            record
+        };
+        let (kind, note_id, body) = parse_log_record(record)
+            .ok_or_else(|| anyhow::anyhow!("malformed update log record at {offset}"))?;
+        match kind {
+            LOG_RECORD_NOTE if !core.tombstones.contains_key(&note_id) => {
+                match core.notes.get(&note_id) {
+                    Some(existing) => existing.import_snapshot(body)?,
+                    None => {
+                        let note = NoteCrdt::new();
+                        note.import_snapshot(body)?;
+                        core.notes.insert(note_id, note);
+                    }
+                }
+            }
+            LOG_RECORD_NOTE => {}
+            LOG_RECORD_TOMBSTONE => {
+                let purged_at = <[u8; 8]>::try_from(body)
+                    .ok()
+                    .and_then(|millis| DateTime::from_timestamp_millis(i64::from_le_bytes(millis)))
+                    .unwrap_or_else(Utc::now);
+                core.notes.remove(&note_id);
+                core.tombstones.entry(note_id).or_insert(purged_at);
+            }
+            other => anyhow::bail!("unknown update log record kind: {other:#04x}"),
+        }
+        offset = next;
+        replayed += 1;
+    }
+    let truncated = (data.len() - offset) as u64;
+    if truncated > 0 {
+        std::fs::OpenOptions::new()
+            .write(true)
+            .open(&log_path)
+            .and_then(|f| f.set_len(offset as u64))
+            .with_context(|| format!("truncate torn update log {}", log_path.display()))?;
+    }
+    mark_core_persisted(core, replayed, offset as u64);
+    Ok((replayed, truncated, plaintext))
+}
+
+/// 追加一条日志记录：`(body_len: u32 LE, crc32(body): u32 LE, body)`，
+/// body = `kind: u8 + (id_len: u32 LE, id) + 记录体`；有 `vault_key` 时
+/// body = `LOG_RECORD_SEALED + 加密后的原 body`（校验和覆盖密文）。
+fn push_log_record(
+    buf: &mut Vec<u8>,
+    kind: u8,
+    note_id: &str,
+    payload: &[u8],
+    vault_key: Option<&VaultKey>,
+) -> Result<()> {
+    let mut body = Vec::with_capacity(1 + 4 + note_id.len() + payload.len());
+    body.push(kind);
+    push_bytes(&mut body, note_id.as_bytes());
+    body.extend_from_slice(payload);
+    if let Some(key) = vault_key {
+        let mut sealed = vec![LOG_RECORD_SEALED];
+        sealed.extend_from_slice(&key.seal(&body)?);
+        body = sealed;
+    }
+    buf.extend_from_slice(&(body.len() as u32).to_le_bytes());
+    buf.extend_from_slice(&crc32(&body).to_le_bytes());
+    buf.extend_from_slice(&body);
+    Ok(())
+}
+
+/// 取 `offset` 处的一条日志记录 body；残缺或校验失败返回 `None`。
+/// 成功返回 `(body, 下一条记录偏移)`。
+fn next_log_record(data: &[u8], offset: usize) -> Option<(&[u8], usize)> {
+    let header = data.get(offset..offset + 8)?;
+    let len = u32::from_le_bytes(header[0..4].try_into().unwrap()) as usize;
+    let checksum = u32::from_le_bytes(header[4..8].try_into().unwrap());
+    let body = data.get(offset + 8..(offset + 8).checked_add(len)?)?;
+    if crc32(body) != checksum {
+        return None;
+    }
+    Some((body, offset + 8 + len))
+}
+
+/// 解析（明文）记录 body 为 `(kind, note_id, 记录体)`。
+fn parse_log_record(body: &[u8]) -> Option<(u8, String, &[u8])> {
+    let (&kind, rest) = body.split_first()?;
+    let mut pos = 0;
+    let id = take_bytes(rest, &mut pos, "log note_id").ok()?;
+    let note_id = String::from_utf8(id.to_vec()).ok()?;
+    Some((kind, note_id, &rest[pos..]))
+}
+
+/// CRC-32（IEEE 802.3，反射多项式 0xEDB88320），日志记录校验用。
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+// ━━━ 统一 incoming 路由（任务 O：接收器 / 配对 accept / 周期 accept 共用）━━━
+
+/// 同步连接握手结果（见 [`SyncService::handshake`]）。
+enum Handshake {
+    /// 对端支持 hello：同一双向流继续发送同步帧，`caps` 为双方共同支持的能力位
+    Negotiated {
+        send: iroh::endpoint::SendStream,
+        recv: iroh::endpoint::RecvStream,
+        caps: u8,
+    },
+    /// 对端早于 hello 协议：只能接收 v3 快照格式的全量推送
+    Legacy,
+}
+
+/// [`route_incoming`] 所需的共享状态（主服务与后台接收任务各自借出）。
+struct RouteContext<'a> {
+    pending_pairing: &'a Mutex<Option<PendingPairing>>,
+    core: &'a Mutex<CoreState>,
+    /// 鉴权用投影（None = 尚未绑定投影：拒绝全部同步帧）
+    trust: Option<&'a NoteStore>,
+    log: &'a Arc<dyn LogSink>,
+    events: &'a EventHub,
+    device_id: &'a str,
+}
+
+/// 统一 incoming 处理：接受连接并按帧标记路由（后台接收器与主服务共用）。
+///
+/// 帧标记（M2 修复——不能用单字节判定，否则推送 payload 首字节 0x01 与配对帧
+/// 冲突）：
+/// - 前 8 字节 == `HELLO_MAGIC`（"CARDHELO"，双向流）→ 握手帧：回复本端协议
+///   版本与共同能力位，再按同一流上随后的同步帧路由（见 [`HELLO_MAGIC`]）；
+///   发起方版本低于 [`MIN_PEER_PROTOCOL_VERSION`] 时以 [`UPGRADE_CLOSE_CODE`]
+///   关闭连接、输出 `sync.reject` 事件并返回 `Ok(None)`。
+/// - 前 8 字节 == `LORO_MAGIC`（"CARDMIND"）→ 推送帧：按 `mode` 读取记录流，
+///   返回 `Ok(Some((sender_id, inbound)))`（`Inbound::Data` 即 `export_all`
+///   格式，`import_core_all` 直接消费；sender_id 取自连接 TLS 证书——任务 O
+///   据此更新发送方 last_seen，无需改协议）。
+/// - 前 8 字节 == `DELTA_MAGIC`（"CARDDELT"，双向流）→ 增量推送帧：回复本端
+///   版本摘要（读共享 core），读取对端据此导出的增量，返回 `Ok(Some(...))`。
+/// - 前 8 字节 == `SESSION_MAGIC`（"CARDSESS"，双向流）→ 双向同步会话：回复
+///   本端摘要并逐条发送发起方缺失的增量，再读取发起方推回的增量，返回
+///   `Ok(Some(...))`（发起方拉取部分由发起方自行导入）。
+/// - 首字节 `PAIRING_FRAME_REQUEST (0x01)` → 配对请求帧：解析并存入
+///   `pending_pairing`（供 `confirm_pairing` 在同一连接上回复握手响应），
+///   发出 [`SyncEvent::PairingRequested`]，返回 `Ok(None)`。
+/// - 其他 → 报错（未知帧标记）。
+///
+/// 未经握手的同步帧来自早于 hello 协议的对端：记录体按未压缩处理（单向流上
+/// 的推送帧即 v3 快照格式）。
+///
+/// 记录流按 [`TransferLimits`] 逐条读取（见 [`receive_records`]）：导入完成后
+/// 以关闭码 0 确认送达（`InboundMode::Buffer` 由调用方导入后经 [`PendingAck`]
+/// 确认）；超限或中断以 [`ABORT_CLOSE_CODE`] 关闭。`InboundMode::Import`
+/// 中断时仍返回已导入部分（`StreamImport::interrupted` 记录原因）。
+///
+/// 鉴权：同步帧（推送/增量/会话）的发送方必须在 `trust` 投影的
+/// `paired_devices` 中且未被撤销，否则在回复任何数据之前以
+/// [`REJECT_CLOSE_CODE`] 关闭连接、输出 `sync.reject` 事件并返回 `Ok(None)`
+/// （不导入）。尚未绑定投影（`trust = None`）时无从鉴权，同步帧一律拒绝
+/// （reason=untrusted）。配对请求帧不鉴权（新设备尚未配对）。
+///
+/// 多个消费者（后台接收器 + 配对轮询 + 周期 accept）并发调用本函数安全：
+/// 每个 `endpoint.accept()` 只取一个 incoming；路由目标（pending_pairing /
+/// 返回的推送数据）互不冲突，不丢帧。
+async fn route_incoming(
+    incoming: iroh::endpoint::Incoming,
+    route: &RouteContext<'_>,
+    mode: InboundMode,
+) -> Result<Option<(iroh::EndpointId, Inbound)>> {
+    let conn = incoming.accept()?.await.context("accept connection")?;
+    let routed = route_connection(conn.clone(), route, mode).await;
+    if routed.is_err() {
+        // 显式以 ABORT 关闭：隐式释放连接的关闭码 0 是早期版本的信号（见
+        // [`SyncService::handshake`]），新版本出错时不能发出
+        conn.close(ABORT_CLOSE_CODE.into(), b"aborted");
+    }
+    routed
+}
+
+/// [`route_incoming`] 的主体（连接已建立）。
+async fn route_connection(
+    conn: iroh::endpoint::Connection,
+    route: &RouteContext<'_>,
+    mode: InboundMode,
+) -> Result<Option<(iroh::EndpointId, Inbound)>> {
+    // 发送方身份：连接 TLS 证书中的 EndpointId（识别 inbound push 来源，
+    // 用于精确更新 last_seen——无需在协议帧中带 sender_id）
+    let sender_id = conn.remote_id();
+    // 配对帧与早期版本的推送帧走单向流；握手后的同步帧走双向流
+    let (mut recv, mut reply) = tokio::select! {
+        biased;
+        bi = conn.accept_bi() => {
+            let (send, recv) = bi.context("accept bi stream")?;
+            (recv, Some(send))
+        }
+        uni = conn.accept_uni() => (uni.context("accept uni stream")?, None),
+    };
+    let mut marker = [0u8; LORO_MAGIC.len()];
+    recv.read_exact(&mut marker)
+        .await
+        .context("read frame marker")?;
+    if reply.is_none() && &marker == HELLO_MAGIC {
+        // 发起方的 hello 探测帧（早期版本识别用）：忽略，hello 在双向流上
+        let (send, bi_recv) = conn.accept_bi().await.context("accept bi stream")?;
+        (recv, reply) = (bi_recv, Some(send));
+        recv.read_exact(&mut marker)
+            .await
+            .context("read frame marker")?;
+    }
+    let frame = match &marker {
+        m if m == HELLO_MAGIC => Some("hello"),
+        m if m == DELTA_MAGIC => Some("delta"),
+        m if m == SESSION_MAGIC => Some("session"),
+        m if m == LORO_MAGIC => Some("push"),
+        _ => None,
+    };
+    if let Some(frame) = frame {
+        let sender = sender_id.to_string();
+        let rejection = match route.trust {
+            Some(store) => peer_rejection(store, &sender)?,
+            None => Some("untrusted"),
+        };
+        if let Some(reason) = rejection {
+            debug_log::emit_to(
+                route.log,
+                LogEvent::new("sync.reject", "sync.route")
+                    .with_id(route.device_id)
+                    .with_id(&sender)
+                    .with_field("action", "rejected")
+                    .with_field("reason", reason)
+                    .with_field("frame", frame),
+            );
+            conn.close(REJECT_CLOSE_CODE.into(), b"unauthorized");
+            return Ok(None);
+        }
+    }
+    if frame.is_none() {
+        if marker[0] == PAIRING_FRAME_REQUEST {
+            // 配对请求帧：marker(8) + 剩余 = 完整帧（从 0x01 开始）
+            let mut rest = recv
+                .read_to_end(PAIRING_FRAME_MAX_LEN)
+                .await
+                .context("read pairing request")?;
+            let mut data = Vec::with_capacity(marker.len() + rest.len());
+            data.extend_from_slice(&marker);
+            data.append(&mut rest);
+            let request = decode_pairing_request(&data)?;
+            let event = SyncEvent::PairingRequested {
+                device_id: request.device_id.clone(),
+                device_name: request.device_name.clone(),
+            };
+            *route.pending_pairing.lock().unwrap() = Some(PendingPairing {
+                request: request.clone(),
+                conn,
+            });
+            // 请求已就绪（confirm_pairing 可直接确认）后再通知 UI
+            route.events.emit(event);
+            return Ok(None);
+        }
+        anyhow::bail!("unknown incoming frame marker: {:?}", marker);
+    }
+    // 未经握手 = 早于 hello 协议的对端：记录体不压缩
+    let mut caps = 0;
+    if &marker == HELLO_MAGIC {
+        let Some(send) = reply.as_mut() else {
+            anyhow::bail!("hello frame must arrive on a bi-directional stream");
+        };
+        let mut hello = [0u8; 3];
+        recv.read_exact(&mut hello).await.context("read hello")?;
+        let version = u16::from_le_bytes([hello[0], hello[1]]);
+        if version < MIN_PEER_PROTOCOL_VERSION {
+            debug_log::emit_to(
+                route.log,
+                LogEvent::new("sync.reject", "sync.route")
+                    .with_id(route.device_id)
+                    .with_id(&sender_id.to_string())
+                    .with_field("action", "rejected")
+                    .with_field("reason", "protocol_too_old")
+                    .with_field("peer_version", version.to_string())
+                    .with_field("min_version", MIN_PEER_PROTOCOL_VERSION.to_string()),
+            );
+            conn.close(UPGRADE_CLOSE_CODE.into(), b"upgrade required");
+            return Ok(None);
+        }
+        caps = hello[2] & SYNC_CAPABILITIES;
+        let mut response = SYNC_PROTOCOL_VERSION.to_le_bytes().to_vec();
+        response.push(caps);
+        send.write_all(&response)
+            .await
+            .context("write hello reply")?;
+        recv.read_exact(&mut marker)
+            .await
+            .context("read frame marker")?;
+        if ![LORO_MAGIC, DELTA_MAGIC, SESSION_MAGIC].contains(&&marker) {
+            anyhow::bail!("unexpected frame marker after hello: {:?}", marker);
+        }
+    }
+    let compress = caps & CAP_LZ4 != 0;
+    if &marker == DELTA_MAGIC {
+        // 增量推送帧：回复本端版本摘要，再读对端按摘要计算的增量
+        // （与全量推送帧同格式的记录流）
+        let Some(mut send) = reply else {
+            anyhow::bail!("delta frame must arrive on a bi-directional stream");
+        };
+        let digest = encode_version_digest(&route.core.lock().unwrap());
+        send.write_all(&digest)
+            .await
+            .context("write version digest")?;
+        send.finish().context("finish version digest")?;
+        let inbound = receive_records(&conn, &mut recv, route.core, mode, compress)
+            .await
+            .context("read delta data")?;
+        return Ok(Some((sender_id, inbound)));
+    }
+    if &marker == SESSION_MAGIC {
+        // 双向同步会话：读发起方摘要 → 回复本端摘要并逐条发送发起方缺失的
+        // 增量 → 读发起方推回的增量（与全量推送帧同格式的记录流）
+        let Some(mut send) = reply else {
+            anyhow::bail!("session frame must arrive on a bi-directional stream");
+        };
+        let limits = route.core.lock().unwrap().transfer_limits;
+        let mut reader = RecordReader::new(&mut recv, limits, compress);
+        let remote_digest = reader
+            .section("session summary", DELTA_DIGEST_MAX_LEN)
+            .await?;
+        let remote = decode_version_digest(&remote_digest)?;
+        let remote_revocations = reader
+            .section("session revocations", SESSION_META_MAX_LEN)
+            .await?;
+        let remote_roster = reader
+            .section("session roster", SESSION_META_MAX_LEN)
+            .await?;
+        let mut local_revocations = Vec::new();
+        if let Some(store) = route.trust {
+            let revoked = apply_revocations(
+                store,
+                &decode_revocations(&remote_revocations)?,
+                route.device_id,
+            )?;
+            for peer in &revoked {
+                debug_log::emit_to(
+                    route.log,
+                    LogEvent::new("device.revoke", "device")
+                        .with_id(route.device_id)
+                        .with_id(peer)
+                        .with_field("action", "received"),
+                );
+            }
+            local_revocations = store.list_revoked_devices()?;
+        }
+        let mut response = Vec::new();
+        let (changes, ack) = {
+            let mut core = route.core.lock().unwrap();
+            merge_roster(&mut core, &remote_roster)?;
+            let changes = match route.trust {
+                Some(store) => apply_roster(&core, store, route.device_id)?,
+                None => Vec::new(),
+            };
+            push_bytes(&mut response, &encode_version_digest(&core));
+            push_bytes(&mut response, &encode_revocations(&local_revocations));
+            push_bytes(&mut response, &export_roster(&core)?);
+            // 先取水位再导出：确认的版本不会超出实际发送的内容
+            (changes, core_watermark(&core, Some(&remote)))
+        };
+        for (peer, action) in &changes {
+            debug_log::emit_to(
+                route.log,
+                LogEvent::new("device.roster", "device")
+                    .with_id(route.device_id)
+                    .with_id(peer)
+                    .with_field("action", *action),
+            );
+        }
+        send.write_all(&response)
+            .await
+            .context("write session reply")?;
+        send_records(route.core, &mut send, Some(&remote), compress)
+            .await
+            .context("write session delta")?;
+        send.finish().context("finish session reply")?;
+        let inbound = receive_records(&conn, &mut recv, route.core, mode, compress)
+            .await
+            .context("read session delta")?;
+        if inbound.is_complete() {
+            // 发起方读完回复才会推回增量：读到流结束即确认它已收到本端增量
+            merge_peer_ack(&mut route.core.lock().unwrap(), &sender_id.to_string(), ack);
+        }
+        flush_route_sync_state(route, &sender_id.to_string());
+        return Ok(Some((sender_id, inbound)));
+    }
+    // 推送帧：剩余部分 = 墓碑 section + 记录流（export_all 格式）
+    if let Some(mut send) = reply {
+        send.finish().context("finish hello reply")?;
+    }
+    let inbound = receive_records(&conn, &mut recv, route.core, mode, compress)
+        .await
+        .context("read push data")?;
+    flush_route_sync_state(route, &sender_id.to_string());
+    Ok(Some((sender_id, inbound)))
+}
+
+/// 入站连接结束：落盘本次导入与确认累积的同步状态（每个连接至多写一次；
+/// 失败只记录日志）。
+fn flush_route_sync_state(route: &RouteContext<'_>, sender: &str) {
+    let flushed = flush_sync_state(&mut route.core.lock().unwrap());
+    if let Err(e) = flushed {
+        debug_log::emit_to(
+            route.log,
+            LogEvent::new("sync.watermark", "sync.watermark")
+                .with_id(route.device_id)
+                .with_id(sender)
+                .with_field("action", "persist_failed")
+                .with_error(&e.to_string())
+                .with_chain(&format!("{e:#}")),
+        );
+    }
+}
+
+// ━━━ 设备鉴权与撤销记录 ━━━
+
+/// 入站同步帧发送方的拒绝原因：已撤销 → `"revoked"`；不在配对设备表 →
+/// `"unknown"`；可信 → `None`。
+fn peer_rejection(store: &NoteStore, peer_id: &str) -> Result<Option<&'static str>> {
+    if store.is_revoked_device(peer_id)? {
+        return Ok(Some("revoked"));
+    }
+    if !store.is_paired_device(peer_id)? {
+        return Ok(Some("unknown"));
+    }
+    Ok(None)
+}
+
+/// 撤销记录签名载荷：`REVOCATION_SIGN_CONTEXT + (peer_id, revoked_by, revoked_at)`
+/// （各 length-prefixed）。
+fn revocation_payload(peer_id: &str, revoked_by: &str, revoked_at: &str) -> Vec<u8> {
+    let mut payload = REVOCATION_SIGN_CONTEXT.to_vec();
+    push_str(&mut payload, peer_id);
+    push_str(&mut payload, revoked_by);
+    push_str(&mut payload, revoked_at);
+    payload
+}
+
+/// 以本机身份密钥签发撤销记录。
+fn sign_revocation(secret_key: &SecretKey, peer_id: &str, revoked_at: &str) -> RevokedDeviceRow {
+    let revoked_by = secret_key.public().to_string();
+    let signature = secret_key.sign(&revocation_payload(peer_id, &revoked_by, revoked_at));
+    RevokedDeviceRow {
+        peer_id: peer_id.to_string(),
+        revoked_by,
+        revoked_at: revoked_at.to_string(),
+        signature: encode_hex(&signature.to_bytes()),
+    }
+}
+
+/// 校验撤销记录签名（签名公钥 = `revoked_by`）。
+fn verify_revocation(row: &RevokedDeviceRow) -> Result<()> {
+    let signer: PublicKey = row
+        .revoked_by
+        .parse()
+        .context("invalid revocation signer id")?;
+    let bytes: [u8; 64] = decode_hex(&row.signature)?
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("revocation signature must be 64 bytes"))?;
+    signer
+        .verify(
+            &revocation_payload(&row.peer_id, &row.revoked_by, &row.revoked_at),
+            &Signature::from_bytes(&bytes),
+        )
+        .map_err(|_| anyhow::anyhow!("revocation signature invalid"))
+}
+
+/// 应用对端发来的撤销记录，返回新撤销的 peer_id。
+///
+/// 只接受验签通过、且签发方是本机或本机可信设备（已配对未撤销）的记录；
+/// 其余静默忽略（不因一条坏记录中断同步）。
+fn apply_revocations(
+    store: &NoteStore,
+    rows: &[RevokedDeviceRow],
+    device_id: &str,
+) -> Result<Vec<String>> {
+    let mut revoked = Vec::new();
+    for row in rows {
+        // 针对本机的撤销记录不生效也不转发：设备不会因他人记录把自己拒之门外
+        if row.peer_id == device_id {
+            continue;
+        }
+        if store.is_revoked_device(&row.peer_id)? || verify_revocation(row).is_err() {
+            continue;
+        }
+        if row.revoked_by != device_id && peer_rejection(store, &row.revoked_by)?.is_some() {
+            continue;
+        }
+        if store.insert_revocation(row)? {
+            revoked.push(row.peer_id.clone());
+        }
+    }
+    Ok(revoked)
+}
+
+/// 撤销记录列表编码：`(count: u32 LE) + 每条 (peer_id, revoked_by, revoked_at,
+/// signature)`（各 length-prefixed）。
+fn encode_revocations(rows: &[RevokedDeviceRow]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&(rows.len() as u32).to_le_bytes());
+    for row in rows {
+        push_str(&mut buf, &row.peer_id);
+        push_str(&mut buf, &row.revoked_by);
+        push_str(&mut buf, &row.revoked_at);
+        push_str(&mut buf, &row.signature);
+    }
+    buf
+}
+
+fn decode_revocations(data: &[u8]) -> Result<Vec<RevokedDeviceRow>> {
+    if data.len() < 4 {
+        anyhow::bail!("truncated revocation list");
+    }
+    let count = u32::from_le_bytes(data[0..4].try_into().unwrap()) as usize;
+    let mut offset = 4;
+    let mut take = |field: &str| -> Result<String> {
+        let bytes = take_bytes(data, &mut offset, field)?;
+        String::from_utf8(bytes.to_vec()).with_context(|| format!("invalid {field}"))
+    };
+    let mut rows = Vec::new();
+    for _ in 0..count {
+        rows.push(RevokedDeviceRow {
+            peer_id: take("revocation peer_id")?,
+            revoked_by: take("revocation revoked_by")?,
+            revoked_at: take("revocation revoked_at")?,
+            signature: take("revocation signature")?,
+        });
+    }
+    Ok(rows)
+}
+
+/// 等待对端确认同步帧：对端读完整帧后以关闭码 0 关闭连接即为送达；被拒
+/// （[`REJECT_CLOSE_CODE`]）、中止（[`ABORT_CLOSE_CODE`]）、异常断开或 10 秒内
+/// 未关闭均视为未送达。
+/// 连接已被对端以关闭码 0 关闭（早期版本读完推送后的正常关闭）。
+fn legacy_closed(conn: &iroh::endpoint::Connection) -> bool {
+    matches!(
+        conn.close_reason(),
+        Some(iroh::endpoint::ConnectionError::ApplicationClosed(close))
+            if u64::from(close.error_code) == 0
+    )
+}
+
+async fn await_peer_ack(conn: &iroh::endpoint::Connection) -> Result<()> {
+    let closed = tokio::time::timeout(Duration::from_secs(10), conn.closed())
+        .await
+        .map_err(|_| anyhow::anyhow!("peer did not acknowledge within 10s"))?;
+    match closed {
+        iroh::endpoint::ConnectionError::ApplicationClosed(close) => {
+            match u64::from(close.error_code) {
+                0 => Ok(()),
+                code if code == u64::from(REJECT_CLOSE_CODE) => {
+                    anyhow::bail!("peer rejected sync: this device is not trusted")
+                }
+                code if code == u64::from(ABORT_CLOSE_CODE) => {
+                    anyhow::bail!(
+                        "peer aborted sync: transfer exceeds its size limit or was interrupted"
+                    )
+                }
+                code => anyhow::bail!("peer closed connection with code {code}"),
+            }
+        }
+        other => Err(anyhow::anyhow!(other).context("connection lost before acknowledgement")),
+    }
+}
+
+/// 连续失败 `failures` 次后的重试间隔：[`SYNC_BACKOFF_BASE_SECS`] 逐次翻倍、
+/// 封顶 [`SYNC_BACKOFF_MAX_SECS`]，再乘以 0.8–1.2 的随机抖动（同时离线的多台
+/// 设备不会在同一时刻集中重试）。
+fn backoff_delay(failures: u32) -> Duration {
+    let exponent = failures.saturating_sub(1).min(16);
+    let secs = SYNC_BACKOFF_BASE_SECS
+        .saturating_mul(1 << exponent)
+        .min(SYNC_BACKOFF_MAX_SECS);
+    let jitter: f64 = rand::thread_rng().gen_range(0.8..=1.2);
+    Duration::from_secs_f64(secs as f64 * jitter)
+}
+
+/// 清除对端退避（对端重新出现：mDNS 发现或入站连接）。返回此前是否处于退避。
+fn clear_peer_backoff(backoff: &Mutex<HashMap<String, PeerBackoff>>, peer_id: &str) -> bool {
+    backoff.lock().unwrap().remove(peer_id).is_some()
+}
+
+// ━━━ 分块流式传输 ━━━
+
+/// 入站记录流读取器：按长度前缀逐条读取，单条与累计字节数受 [`TransferLimits`]
+/// 约束（超限立即报错，不按对端声明的总长度预先缓冲）。
+struct RecordReader<'a> {
+    recv: &'a mut iroh::endpoint::RecvStream,
+    limits: TransferLimits,
+    /// 记录体是否 LZ4 压缩（见 [`compress_record_body`]）
+    compressed: bool,
+    /// 已读取的字节数（含长度前缀；压缩记录体按解压后长度计）
+    received: u64,
+}
+
+impl<'a> RecordReader<'a> {
+    fn new(
+        recv: &'a mut iroh::endpoint::RecvStream,
+        limits: TransferLimits,
+        compressed: bool,
+    ) -> Self {
+        Self {
+            recv,
+            limits,
+            compressed,
+            received: 0,
+        }
+    }
+
+    /// 读取 `(len: u32 LE, bytes)`；`at_boundary` 时流在此处正常结束返回 `None`。
+    async fn next_bytes(&mut self, field: &str, at_boundary: bool) -> Result<Option<Vec<u8>>> {
+        let mut len = [0u8; 4];
+        let first = self
+            .recv
+            .read(&mut len)
+            .await
+            .with_context(|| format!("read {field} length"))?;
+        let first = match first {
+            Some(n) => n,
+            None if at_boundary => return Ok(None),
+            None => anyhow::bail!("truncated data: missing {field} length"),
+        };
+        self.recv
+            .read_exact(&mut len[first..])
+            .await
+            .with_context(|| format!("read {field} length"))?;
+        let len = u32::from_le_bytes(len);
+        if len > self.limits.max_record_bytes {
+            anyhow::bail!(
+                "{field} too large: {len} bytes (limit {})",
+                self.limits.max_record_bytes
+            );
+        }
+        self.read_body(len as usize, field).await.map(Some)
+    }
+
+    /// 读取同步会话中的一个 `(len: u32 LE, bytes)` section（上限 `max_len`，
+    /// 与记录一样计入累计字节数）。
+    async fn section(&mut self, field: &str, max_len: usize) -> Result<Vec<u8>> {
+        let mut len = [0u8; 4];
+        self.recv
+            .read_exact(&mut len)
+            .await
+            .with_context(|| format!("read {field} length"))?;
+        let len = u32::from_le_bytes(len) as usize;
+        if len > max_len {
+            anyhow::bail!("{field} too large: {len} bytes (limit {max_len})");
+        }
+        self.read_body(len, field).await
+    }
+
+    /// 按块读取 `len` 字节（长度前缀已读）：声明的长度只是上限，内存随实际
+    /// 收到的数据增长；连同长度前缀计入累计字节数。
+    async fn read_body(&mut self, len: usize, field: &str) -> Result<Vec<u8>> {
+        self.received += 4 + len as u64;
+        if self.received > self.limits.max_transfer_bytes {
+            anyhow::bail!(
+                "transfer exceeds limit of {} bytes",
+                self.limits.max_transfer_bytes
+            );
+        }
+        let mut data = Vec::with_capacity(len.min(RECORD_READ_CHUNK));
+        while data.len() < len {
+            let filled = data.len();
+            data.resize(filled + (len - filled).min(RECORD_READ_CHUNK), 0);
+            self.recv
+                .read_exact(&mut data[filled..])
+                .await
+                .with_context(|| format!("read {field}"))?;
+        }
+        Ok(data)
+    }
+
+    /// 读取记录内的必需字段（流在此处结束即 truncated）。
+    async fn bytes(&mut self, field: &str) -> Result<Vec<u8>> {
+        self.next_bytes(field, false)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("truncated data: missing {field}"))
+    }
+
+    /// 读取墓碑 section（格式见 [`write_tombstone_section`]）。
+    async fn tombstones(&mut self) -> Result<Vec<String>> {
+        let mut count = [0u8; 4];
+        self.recv
+            .read_exact(&mut count)
+            .await
+            .context("read tombstone count")?;
+        self.received += 4;
+        let mut ids = Vec::new();
+        for _ in 0..u32::from_le_bytes(count) {
+            let id = self.bytes("tombstone id").await?;
+            ids.push(String::from_utf8(id).context("invalid UTF-8 in tombstone id")?);
+        }
+        Ok(ids)
+    }
+
+    /// 读取下一条笔记记录 `(note_id, 记录体)`；流在记录边界结束返回 `None`。
+    async fn next_record(&mut self) -> Result<Option<(String, Vec<u8>)>> {
+        let Some(id) = self.next_bytes("note_id", true).await? else {
+            return Ok(None);
+        };
+        let note_id = String::from_utf8(id).context("invalid UTF-8 in note_id")?;
+        let body = self.bytes("snapshot body").await?;
+        if !self.compressed {
+            return Ok(Some((note_id, body)));
+        }
+        let raw = decompress_record_body(&body, self.limits.max_record_bytes)?;
+        // 累计上限按解压后长度计：高压缩比的记录不能绕过内存上限
+        self.received += (raw.len() as u64).saturating_sub(body.len() as u64);
+        if self.received > self.limits.max_transfer_bytes {
+            anyhow::bail!(
+                "transfer exceeds limit of {} bytes",
+                self.limits.max_transfer_bytes
+            );
+        }
+        Ok(Some((note_id, raw)))
+    }
+}
+
+/// 压缩记录体：`(原始长度: u32 LE, LZ4 块)`。
+fn compress_record_body(body: &[u8]) -> Vec<u8> {
+    lz4_flex::block::compress_prepend_size(body)
+}
+
+/// 解压记录体（格式见 [`compress_record_body`]）；声明的原始长度超过
+/// `max_len` 时在分配内存前报错。
+fn decompress_record_body(data: &[u8], max_len: u32) -> Result<Vec<u8>> {
+    if data.len() < 4 {
+        anyhow::bail!("truncated data: missing snapshot body length");
+    }
+    let raw_len = u32::from_le_bytes(data[..4].try_into().unwrap());
+    if raw_len > max_len {
+        anyhow::bail!("snapshot body too large: {raw_len} bytes (limit {max_len})");
+    }
+    let body = lz4_flex::block::decompress(&data[4..], raw_len as usize)
+        .context("decompress snapshot body")?;
+    if body.len() != raw_len as usize {
+        anyhow::bail!("invalid snapshot body length after decompression");
+    }
+    Ok(body)
+}
+
+/// 入站同步帧的数据消费方式（见 [`route_incoming`]）。
+#[derive(Debug, Clone, Copy)]
+enum InboundMode {
+    /// 限额内逐条读入内存，原样返回（`accept_push` 调用方随后 `import_all`，
+    /// 导入后才确认送达）
+    Buffer,
+    /// 边读边导入共享 core（后台接收任务）
+    Import,
+}
+
+/// 入站同步帧的数据（按 [`InboundMode`]）。
+enum Inbound {
+    /// 与 `export_all` 同格式的完整 payload 及待导入后应答的连接
+    Data(Vec<u8>, PendingAck),
+    /// 已流式导入的结果
+    Imported(StreamImport),
+}
+
+impl Inbound {
+    /// 是否完整读完（流式导入未中断）。
+    fn is_complete(&self) -> bool {
+        !matches!(
+            self,
+            Inbound::Imported(StreamImport {
+                interrupted: Some(_),
+                ..
+            })
+        )
+    }
+}
+
+/// 流式导入结果（见 [`import_stream`]）。
+#[derive(Default)]
+struct StreamImport {
+    /// 已导入并落盘的笔记记录数
+    records: usize,
+    /// 已读取的字节数
+    bytes: u64,
+    /// 导入带来的 UI 事件（含中断前已导入的部分）
+    events: Vec<SyncEvent>,
+    /// 被复活守卫拦下并重新记入墓碑的笔记 id（见 [`resurrected_tombstone`]）
+    resurrected: Vec<String>,
+    /// 中断原因（连接断开、超限或导入失败；None = 读到流结束、全部导入）
+    interrupted: Option<anyhow::Error>,
+}
+
+/// 流式导入中尚未落盘的一批记录（落盘失败时据此回滚内存态）。
+#[derive(Default)]
+struct ImportBatch {
+    /// 批内首次改动前已存在笔记的快照
+    previous: HashMap<String, Vec<u8>>,
+    /// 批内新建的笔记
+    created: HashSet<String>,
+    /// 批内新增的墓碑
+    tombstones: Vec<String>,
+    /// 批内随墓碑移除的笔记（回滚时恢复）
+    removed: Vec<(String, NoteCrdt)>,
+    /// 批内被复活守卫重新记入墓碑的已回收墓碑（回滚时放回已回收集合）
+    uncollected: Vec<(String, DateTime<Utc>)>,
+    /// 批内首次记录冲突前该笔记原有的冲突记录（回滚时恢复）
+    conflicts: Vec<(String, Option<NoteConflict>)>,
+    records: usize,
+    bytes: usize,
+}
+
+/// 流式发送记录（格式同 [`export_core_all`] / [`export_core_delta`]）：逐条导出、
+/// 逐条写出，内存中只有当前一条记录。`remote` 为对端版本摘要（None = 全量快照）。
+///
+/// 笔记按 id 排序发送；发送期间被彻底删除的笔记跳过。`compress` 时记录体
+/// 经 [`compress_record_body`] 压缩（墓碑 section 不压缩）。返回发送的笔记记录条数。
+async fn send_records(
+    core: &Mutex<CoreState>,
+    send: &mut iroh::endpoint::SendStream,
+    remote: Option<&HashMap<String, VersionVector>>,
+    compress: bool,
+) -> Result<usize> {
+    let (section, mut note_ids) = {
+        let core = core.lock().unwrap();
+        let mut section = Vec::new();
+        write_tombstone_section(&mut section, &core);
+        (section, core.notes.keys().cloned().collect::<Vec<_>>())
+    };
+    note_ids.sort();
+    send.write_all(&section)
+        .await
+        .context("write tombstone section")?;
+    let mut records = 0;
+    for note_id in note_ids {
+        let record = {
+            let core = core.lock().unwrap();
+            let Some(note) = core.notes.get(&note_id) else {
+                continue;
+            };
+            let body = match remote.and_then(|remote| remote.get(&note_id)) {
+                None => note.export_snapshot()?,
+                Some(vv) if vv.includes_vv(&note.version_vector()) => continue,
+                Some(vv) => note.export_updates(vv)?,
+            };
+            let body = if compress {
+                compress_record_body(&body)
+            } else {
+                body
+            };
+            let mut record = Vec::with_capacity(8 + note_id.len() + body.len());
+            push_record(&mut record, &note_id, &body);
+            record
+        };
+        send.write_all(&record).await.context("write note record")?;
+        records += 1;
+    }
+    Ok(records)
+}
+
+/// 限额内把整个记录流逐条读入内存，重新编码为 `export_all` 格式。
+async fn read_records(reader: &mut RecordReader<'_>) -> Result<Vec<u8>> {
+    let tombstones = reader.tombstones().await?;
+    let mut data = Vec::new();
+    data.extend_from_slice(&(tombstones.len() as u32).to_le_bytes());
+    for id in &tombstones {
+        push_bytes(&mut data, id.as_bytes());
+    }
+    while let Some((note_id, body)) = reader.next_record().await? {
+        push_record(&mut data, &note_id, &body);
+    }
+    Ok(data)
+}
+
+/// 流式导入：边读边导入共享 core，每 [`IMPORT_BATCH_RECORDS`] 条或
+/// [`IMPORT_BATCH_BYTES`] 字节落盘一次，内存中只有当前记录与一批回滚快照。
+///
+/// 连接中断、对端数据超限或单条导入失败时，此前已导入的记录照常落盘并计入
+/// 事件：对端下次握手拿到的版本摘要已包含这些笔记，只需重传其余部分（断点
+/// 续传）。落盘失败时回滚当前批次。
+async fn import_stream(reader: &mut RecordReader<'_>, core: &Mutex<CoreState>) -> StreamImport {
+    let (before, tombstones_before) = {
+        let core = core.lock().unwrap();
+        (note_states(&core), core.tombstones.clone())
+    };
+    let mut outcome = StreamImport::default();
+    let mut batch = ImportBatch::default();
+    let result = import_stream_records(reader, core, &mut batch, &mut outcome).await;
+    let mut core = core.lock().unwrap();
+    // 中断时也落盘已导入的部分；读取/导入错误优先于落盘错误上报
+    let flushed = match flush_import_batch(&mut core, &mut batch) {
+        Ok(records) => {
+            outcome.records += records;
+            Ok(())
+        }
+        Err(e) => Err(e),
+    };
+    outcome.interrupted = result.and(flushed).err();
+    outcome.bytes = reader.received;
+    outcome.events = remote_change_events(&core, &before, &tombstones_before);
+    if !outcome.resurrected.is_empty() {
+        // 已回收集合有变：随本轮同步/本次入站连接结束时落盘
+        core.sync_state_dirty = true;
+    }
+    outcome
+}
+
+async fn import_stream_records(
+    reader: &mut RecordReader<'_>,
+    core: &Mutex<CoreState>,
+    batch: &mut ImportBatch,
+    outcome: &mut StreamImport,
+) -> Result<()> {
+    let tombstones = reader.tombstones().await?;
+    {
+        let mut core = core.lock().unwrap();
+        let now = Utc::now();
+        for id in tombstones {
+            // 本端已回收的墓碑不收回（见 import_core_raw）
+            if !core.tombstones.contains_key(&id) && !core.collected_tombstones.contains_key(&id) {
+                insert_batch_tombstone(&mut core, batch, id, now);
             }
-            let id_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
-            offset += 4;
-            if offset + id_len > data.len() {
-                anyhow::bail!("truncated data: missing tombstone id");
+        }
+    }
+    while let Some((note_id, body)) = reader.next_record().await? {
+        let mut core = core.lock().unwrap();
+        if import_record(&mut core, batch, note_id.clone(), &body)? {
+            outcome.resurrected.push(note_id);
+        }
+        if batch.records >= IMPORT_BATCH_RECORDS || batch.bytes >= IMPORT_BATCH_BYTES {
+            outcome.records += flush_import_batch(&mut core, batch)?;
+        }
+    }
+    Ok(())
+}
+
+/// 批内新增墓碑：移除本地同 id 笔记（回滚时恢复）并记录删除时间。
+fn insert_batch_tombstone(
+    core: &mut CoreState,
+    batch: &mut ImportBatch,
+    id: String,
+    purged_at: DateTime<Utc>,
+) {
+    if let Some(note) = core.notes.remove(&id) {
+        batch.removed.push((id.clone(), note));
+    }
+    core.tombstones.insert(id.clone(), purged_at);
+    batch.tombstones.push(id);
+}
+
+/// 导入单条笔记记录（语义同 [`import_core_raw`]：墓碑中的 id 跳过不复活；
+/// 已存在则合并进本地文档并检测并发冲突，不存在则新建）。
+///
+/// 本地没有的笔记经复活守卫（[`resurrected_tombstone`]）判定为已回收墓碑的
+/// 旧副本时：不导入，重新记入墓碑（随下次同步传回对端），返回 `true`。
+fn import_record(
+    core: &mut CoreState,
+    batch: &mut ImportBatch,
+    note_id: String,
+    body: &[u8],
+) -> Result<bool> {
+    if core.tombstones.contains_key(&note_id) {
+        return Ok(false);
+    }
+    core.dirty_notes.insert(note_id.clone());
+    match core.notes.get(&note_id) {
+        Some(existing) => {
+            if !batch.created.contains(&note_id) && !batch.previous.contains_key(&note_id) {
+                batch
+                    .previous
+                    .insert(note_id.clone(), existing.export_snapshot()?);
             }
-            let id = String::from_utf8(data[offset..offset + id_len].to_vec())
-                .context("invalid UTF-8 in tombstone id")?;
-            offset += id_len;
-            imported_tombstones.insert(id);
+            if let Some(conflict) = existing.import_detecting_conflict(&note_id, body)? {
+                if !batch.conflicts.iter().any(|(id, _)| *id == note_id) {
+                    let previous = core.conflicts.get(&note_id).cloned();
+                    batch.conflicts.push((note_id.clone(), previous));
+                }
+                record_conflict(core, conflict)?;
+            }
+        }
+        None => {
+            if let Some(purged_at) = resurrected_tombstone(core, &note_id) {
+                batch.uncollected.push((note_id.clone(), purged_at));
+                insert_batch_tombstone(core, batch, note_id, purged_at);
+                return Ok(true);
+            }
+            let note = NoteCrdt::new();
+            note.import_snapshot(body)?;
+            core.notes.insert(note_id.clone(), note);
+            batch.created.insert(note_id);
         }
     }
+    batch.records += 1;
+    batch.bytes += body.len();
+    Ok(false)
+}
 
-    // ━━ 笔记记录流 ━━
-    while offset < data.len() {
-        // 读取 note_id_len (u32 LE)
-        if offset + 4 > data.len() {
-            anyhow::bail!("truncated data: missing note_id length");
+/// 复活守卫：对端带来的、本地没有的笔记是否为已回收墓碑的旧副本（id 在已回收
+/// 墓碑中）。是则从已回收集合取出并返回原删除时间：重新记入墓碑时沿用，不重置
+/// 回收期限，下次回收即再次回收，不会在两端之间无限往返。
+///
+/// 只按 id 判定：本端没见过的笔记可能经设备网中的其他设备转来，不能按变更
+/// 时间推断它已被删除。
+fn resurrected_tombstone(core: &mut CoreState, note_id: &str) -> Option<DateTime<Utc>> {
+    core.collected_tombstones.remove(note_id)
+}
+
+/// 落盘当前批次并清空，返回批内记录数；落盘失败时回滚批内改动。
+fn flush_import_batch(core: &mut CoreState, batch: &mut ImportBatch) -> Result<usize> {
+    let batch = std::mem::take(batch);
+    if batch.records == 0 && batch.tombstones.is_empty() {
+        return Ok(0);
+    }
+    let records = batch.records;
+    if let Err(err) = persist_core(core) {
+        rollback_import_batch(core, batch)?;
+        return Err(err);
+    }
+    Ok(records)
+}
+
+/// 回滚一批未落盘的导入：恢复批内改动前的笔记、墓碑、已回收集合与冲突记录。
+fn rollback_import_batch(core: &mut CoreState, batch: ImportBatch) -> Result<()> {
+    for id in &batch.created {
+        core.notes.remove(id);
+    }
+    for (id, snapshot) in batch.previous {
+        let note = NoteCrdt::new();
+        note.import_snapshot(&snapshot)?;
+        core.notes.insert(id, note);
+    }
+    for id in &batch.tombstones {
+        core.tombstones.remove(id);
+    }
+    core.notes.extend(batch.removed);
+    core.collected_tombstones.extend(batch.uncollected);
+    if !batch.conflicts.is_empty() {
+        for (id, previous) in batch.conflicts {
+            match previous {
+                Some(conflict) => core.conflicts.insert(id, conflict),
+                None => core.conflicts.remove(&id),
+            };
         }
-        let id_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
-        offset += 4;
+        persist_conflicts(core)?;
+    }
+    Ok(())
+}
 
-        // 读取 note_id
-        if offset + id_len > data.len() {
-            anyhow::bail!("truncated data: missing note_id");
+/// 读取入站记录流并应答：流式导入未中断以关闭码 0 确认送达；超限、中断或
+/// 格式错误以 [`ABORT_CLOSE_CODE`] 关闭，发送方不记水位、稍后重试。`Buffer`
+/// 模式读完暂不应答，连接随数据交给调用方（见 [`PendingAck`]）。
+/// `compressed` = 协商启用了记录体压缩。
+async fn receive_records(
+    conn: &iroh::endpoint::Connection,
+    recv: &mut iroh::endpoint::RecvStream,
+    core: &Mutex<CoreState>,
+    mode: InboundMode,
+    compressed: bool,
+) -> Result<Inbound> {
+    let mut limits = core.lock().unwrap().transfer_limits;
+    if let InboundMode::Buffer = mode {
+        // 整帧留在内存直到导入：累计上限另受 max_buffered_bytes 约束
+        limits.max_transfer_bytes = limits.max_transfer_bytes.min(limits.max_buffered_bytes);
+    }
+    let mut reader = RecordReader::new(recv, limits, compressed);
+    let inbound = match mode {
+        InboundMode::Buffer => read_records(&mut reader)
+            .await
+            .map(|data| Inbound::Data(data, PendingAck(Some(conn.clone())))),
+        InboundMode::Import => Ok(Inbound::Imported(import_stream(&mut reader, core).await)),
+    };
+    match &inbound {
+        Ok(Inbound::Data(..)) => {}
+        Ok(inbound) if inbound.is_complete() => conn.close(0u32.into(), b"done"),
+        _ => conn.close(ABORT_CLOSE_CODE.into(), b"aborted"),
+    }
+    inbound
+}
+
+/// 已读入内存、等待调用方导入的入站连接：导入成功后以关闭码 0 确认送达。
+///
+/// 未应答即 drop（导入失败、数据被丢弃或服务关闭）时以 [`ABORT_CLOSE_CODE`]
+/// 关闭——连接的最后一个句柄被隐式释放时 QUIC 会以关闭码 0 关闭，发送方会误记
+/// 水位。
+struct PendingAck(Option<iroh::endpoint::Connection>);
+
+impl PendingAck {
+    /// 按导入结果应答发送方。
+    fn settle(mut self, imported: bool) {
+        if let Some(conn) = self.0.take() {
+            if imported {
+                conn.close(0u32.into(), b"done");
+            } else {
+                conn.close(ABORT_CLOSE_CODE.into(), b"aborted");
+            }
         }
-        let note_id = String::from_utf8(data[offset..offset + id_len].to_vec())
-            .context("invalid UTF-8 in note_id")?;
-        offset += id_len;
+    }
+}
 
-        // 读取 snapshot_len (u32 LE)
-        if offset + 4 > data.len() {
-            anyhow::bail!("truncated data: missing snapshot length");
+impl Drop for PendingAck {
+    fn drop(&mut self) {
+        if let Some(conn) = self.0.take() {
+            conn.close(ABORT_CLOSE_CODE.into(), b"aborted");
         }
-        let snapshot_len =
-            u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
-        offset += 4;
+    }
+}
 
-        // 读取 snapshot
-        if offset + snapshot_len > data.len() {
-            anyhow::bail!("truncated data: missing snapshot body");
+// ━━━ 设备名册（复制 CRDT）━━━
+
+/// 设备名册中的一条成员记录：`added_by` 设备签发，证明 `peer_id` 已加入设备网。
+#[derive(Debug, Clone)]
+struct RosterEntry {
+    peer_id: String,
+    name: String,
+    added_by: String,
+    added_at: String,
+    /// 签发方对 `(peer_id, name, added_by, added_at)` 的 Ed25519 签名（hex）
+    signature: String,
+}
+
+/// 成员条目签名载荷：`ROSTER_SIGN_CONTEXT + (peer_id, name, added_by, added_at)`
+/// （各 length-prefixed）。
+fn member_payload(peer_id: &str, name: &str, added_by: &str, added_at: &str) -> Vec<u8> {
+    let mut payload = ROSTER_SIGN_CONTEXT.to_vec();
+    push_str(&mut payload, peer_id);
+    push_str(&mut payload, name);
+    push_str(&mut payload, added_by);
+    push_str(&mut payload, added_at);
+    payload
+}
+
+/// 以本机身份密钥签发成员条目。
+fn sign_member(secret_key: &SecretKey, peer_id: &str, name: &str, added_at: &str) -> RosterEntry {
+    let added_by = secret_key.public().to_string();
+    let signature = secret_key.sign(&member_payload(peer_id, name, &added_by, added_at));
+    RosterEntry {
+        peer_id: peer_id.to_string(),
+        name: name.to_string(),
+        added_by,
+        added_at: added_at.to_string(),
+        signature: encode_hex(&signature.to_bytes()),
+    }
+}
+
+/// 校验成员条目：`peer_id` 为合法 node id，签名公钥 = `added_by`。
+fn verify_member(entry: &RosterEntry) -> Result<()> {
+    let _: iroh::EndpointId = entry.peer_id.parse().context("invalid member id")?;
+    let signer: PublicKey = entry.added_by.parse().context("invalid member signer id")?;
+    let bytes: [u8; 64] = decode_hex(&entry.signature)?
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("member signature must be 64 bytes"))?;
+    signer
+        .verify(
+            &member_payload(
+                &entry.peer_id,
+                &entry.name,
+                &entry.added_by,
+                &entry.added_at,
+            ),
+            &Signature::from_bytes(&bytes),
+        )
+        .map_err(|_| anyhow::anyhow!("member signature invalid"))
+}
+
+/// 名册 Map 的值：`(name, added_by, added_at, signature)` 编码后转 hex——
+/// 整条作为单个 LWW 值，并发写同一成员时不会拼出签名不符的混合条目。
+fn encode_member(entry: &RosterEntry) -> String {
+    let mut buf = Vec::new();
+    push_str(&mut buf, &entry.name);
+    push_str(&mut buf, &entry.added_by);
+    push_str(&mut buf, &entry.added_at);
+    push_str(&mut buf, &entry.signature);
+    encode_hex(&buf)
+}
+
+fn decode_member(peer_id: &str, value: &str) -> Result<RosterEntry> {
+    let data = decode_hex(value)?;
+    let mut offset = 0;
+    Ok(RosterEntry {
+        peer_id: peer_id.to_string(),
+        name: take_str(&data, &mut offset, "member name")?,
+        added_by: take_str(&data, &mut offset, "member added_by")?,
+        added_at: take_str(&data, &mut offset, "member added_at")?,
+        signature: take_str(&data, &mut offset, "member signature")?,
+    })
+}
+
+/// 设备名册中的一条移除记录：`removed_by` 设备签发，`removed_at` 之前签发的
+/// `peer_id` 成员条目失效（之后重新配对签发的新条目不受影响）。
+#[derive(Debug, Clone)]
+struct RosterRemoval {
+    peer_id: String,
+    removed_by: String,
+    removed_at: String,
+    /// 签发方对 `(peer_id, removed_by, removed_at)` 的 Ed25519 签名（hex）
+    signature: String,
+}
+
+/// 移除记录签名载荷：`ROSTER_REMOVE_SIGN_CONTEXT + (peer_id, removed_by,
+/// removed_at)`（各 length-prefixed）。
+fn removal_payload(peer_id: &str, removed_by: &str, removed_at: &str) -> Vec<u8> {
+    let mut payload = ROSTER_REMOVE_SIGN_CONTEXT.to_vec();
+    push_str(&mut payload, peer_id);
+    push_str(&mut payload, removed_by);
+    push_str(&mut payload, removed_at);
+    payload
+}
+
+/// 以本机身份密钥签发移除记录。
+fn sign_removal(secret_key: &SecretKey, peer_id: &str, removed_at: &str) -> RosterRemoval {
+    let removed_by = secret_key.public().to_string();
+    let signature = secret_key.sign(&removal_payload(peer_id, &removed_by, removed_at));
+    RosterRemoval {
+        peer_id: peer_id.to_string(),
+        removed_by,
+        removed_at: removed_at.to_string(),
+        signature: encode_hex(&signature.to_bytes()),
+    }
+}
+
+/// 校验移除记录签名（签名公钥 = `removed_by`）。
+fn verify_removal(removal: &RosterRemoval) -> Result<()> {
+    let signer: PublicKey = removal
+        .removed_by
+        .parse()
+        .context("invalid removal signer id")?;
+    let bytes: [u8; 64] = decode_hex(&removal.signature)?
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("removal signature must be 64 bytes"))?;
+    signer
+        .verify(
+            &removal_payload(&removal.peer_id, &removal.removed_by, &removal.removed_at),
+            &Signature::from_bytes(&bytes),
+        )
+        .map_err(|_| anyhow::anyhow!("removal signature invalid"))
+}
+
+/// 名册 `removed` Map 的值：`(removed_by, removed_at, signature)` 编码后转 hex
+/// （整条作为单个 LWW 值，同 [`encode_member`]）。
+fn encode_removal(removal: &RosterRemoval) -> String {
+    let mut buf = Vec::new();
+    push_str(&mut buf, &removal.removed_by);
+    push_str(&mut buf, &removal.removed_at);
+    push_str(&mut buf, &removal.signature);
+    encode_hex(&buf)
+}
+
+fn decode_removal(peer_id: &str, value: &str) -> Result<RosterRemoval> {
+    let data = decode_hex(value)?;
+    let mut offset = 0;
+    Ok(RosterRemoval {
+        peer_id: peer_id.to_string(),
+        removed_by: take_str(&data, &mut offset, "removal removed_by")?,
+        removed_at: take_str(&data, &mut offset, "removal removed_at")?,
+        signature: take_str(&data, &mut offset, "removal signature")?,
+    })
+}
+
+/// 名册全部移除记录（peer_id → 记录；无法解码或验签失败的跳过）。
+fn roster_removals(roster: &LoroDoc) -> HashMap<String, RosterRemoval> {
+    let mut removals = HashMap::new();
+    roster.get_map("removed").for_each(|peer_id, value| {
+        if let ValueOrContainer::Value(LoroValue::String(s)) = value {
+            if let Ok(removal) = decode_removal(peer_id, &s) {
+                if verify_removal(&removal).is_ok() {
+                    removals.insert(peer_id.to_string(), removal);
+                }
+            }
         }
-        let snapshot = data[offset..offset + snapshot_len].to_vec();
-        offset += snapshot_len;
+    });
+    removals
+}
 
-        // 墓碑中的 id：跳过该记录（不复活）
-        if imported_tombstones.contains(&note_id) || core.tombstones.contains(&note_id) {
-            continue;
+/// 写入一条移除记录并落盘名册。
+fn insert_roster_removal(core: &mut CoreState, removal: &RosterRemoval) -> Result<()> {
+    core.roster
+        .get_map("removed")
+        .insert(&removal.peer_id, encode_removal(removal).as_str())
+        .map_err(|e| anyhow::anyhow!(e))?;
+    core.roster.commit();
+    persist_roster(core)
+}
+
+/// 名册中签发方记下的成员直连地址（`addrs` Map：peer_id → 逗号分隔的
+/// `ip:port`）。只是连接提示：连接时仍按 node id 校验对端身份。
+fn roster_addrs(roster: &LoroDoc) -> HashMap<String, Vec<String>> {
+    let mut addrs = HashMap::new();
+    roster.get_map("addrs").for_each(|peer_id, value| {
+        if let ValueOrContainer::Value(LoroValue::String(s)) = value {
+            let ips: Vec<String> = s
+                .split(',')
+                .filter(|ip| !ip.is_empty())
+                .map(str::to_string)
+                .collect();
+            if !ips.is_empty() {
+                addrs.insert(peer_id.to_string(), ips);
+            }
+        }
+    });
+    addrs
+}
+
+/// 移除记录是否使 `entry` 失效：记录不早于条目签发时间（时间无法解析时按
+/// 失效处理——宁可要求重新配对，也不把已移除的设备加回）。
+fn removal_covers(removal: &RosterRemoval, entry: &RosterEntry) -> bool {
+    match (
+        DateTime::parse_from_rfc3339(&removal.removed_at),
+        DateTime::parse_from_rfc3339(&entry.added_at),
+    ) {
+        (Ok(removed_at), Ok(added_at)) => removed_at >= added_at,
+        _ => true,
+    }
+}
+
+/// 名册全部条目（无法解码的值跳过；签名由 [`apply_roster`] 校验）。
+fn roster_entries(roster: &LoroDoc) -> Vec<RosterEntry> {
+    let mut entries = Vec::new();
+    roster.get_map("members").for_each(|peer_id, value| {
+        if let ValueOrContainer::Value(LoroValue::String(s)) = value {
+            if let Ok(entry) = decode_member(peer_id, &s) {
+                entries.push(entry);
+            }
+        }
+    });
+    entries
+}
+
+/// 写入一条成员条目并落盘名册。
+fn insert_roster_member(core: &mut CoreState, entry: &RosterEntry) -> Result<()> {
+    core.roster
+        .get_map("members")
+        .insert(&entry.peer_id, encode_member(entry).as_str())
+        .map_err(|e| anyhow::anyhow!(e))?;
+    core.roster.commit();
+    persist_roster(core)
+}
+
+/// 合并对端名册（Loro import；有新操作时落盘）。
+fn merge_roster(core: &mut CoreState, update: &[u8]) -> Result<()> {
+    if update.is_empty() {
+        return Ok(());
+    }
+    let before = core.roster.oplog_vv();
+    core.roster
+        .import(update)
+        .map_err(|e| anyhow::anyhow!(e))
+        .context("import device roster")?;
+    if core.roster.oplog_vv() != before {
+        persist_roster(core)?;
+    }
+    Ok(())
+}
+
+fn export_roster(core: &CoreState) -> Result<Vec<u8>> {
+    core.roster
+        .export(ExportMode::snapshot())
+        .map_err(|e| anyhow::anyhow!(e))
+}
+
+/// 名册投影进配对设备表，返回变更的 `(peer_id, "joined" | "removed")`。
+///
+/// 信任自本机与已配对设备出发逐轮扩展：条目签名有效、签发方已可信、成员
+/// 未被撤销且未被可信设备移除 → 写入 `paired_devices`（已存在则只同步设备名），
+/// 成员本身随即可信（它签发的条目下一轮生效）。被撤销设备签发的新条目因
+/// 签发方不再可信而忽略。最后，被可信设备移除（移除记录晚于成员条目）的
+/// 设备从配对表删除。
+fn apply_roster(
+    core: &CoreState,
+    store: &NoteStore,
+    device_id: &str,
+) -> Result<Vec<(String, &'static str)>> {
+    let entries = roster_entries(&core.roster);
+    let removals = roster_removals(&core.roster);
+    let removed = |entry: &RosterEntry, trusted: &HashSet<String>| {
+        removals.get(&entry.peer_id).is_some_and(|removal| {
+            trusted.contains(&removal.removed_by) && removal_covers(removal, entry)
+        })
+    };
+    let paired: HashMap<String, String> = store
+        .list_paired_devices()?
+        .into_iter()
+        .map(|row| (row.peer_id, row.name))
+        .collect();
+    let mut trusted: HashSet<String> = paired.keys().cloned().collect();
+    trusted.insert(device_id.to_string());
+    let mut changes = Vec::new();
+    let mut done: HashSet<String> = HashSet::new();
+    loop {
+        let mut progressed = false;
+        for entry in &entries {
+            if done.contains(&entry.peer_id)
+                || entry.peer_id == device_id
+                || !trusted.contains(&entry.added_by)
+                || verify_member(entry).is_err()
+                || removed(entry, &trusted)
+                || store.is_revoked_device(&entry.peer_id)?
+            {
+                continue;
+            }
+            done.insert(entry.peer_id.clone());
+            match paired.get(&entry.peer_id) {
+                Some(name) if *name == entry.name => {}
+                Some(_) => store.upsert_paired_device(&entry.peer_id, &entry.name)?,
+                None => {
+                    store.upsert_paired_device(&entry.peer_id, &entry.name)?;
+                    changes.push((entry.peer_id.clone(), "joined"));
+                }
+            }
+            progressed |= trusted.insert(entry.peer_id.clone());
+        }
+        if !progressed {
+            break;
+        }
+    }
+    for entry in &entries {
+        if paired.contains_key(&entry.peer_id)
+            && !done.contains(&entry.peer_id)
+            && removed(entry, &trusted)
+        {
+            store.remove_paired_device(&entry.peer_id)?;
+            changes.push((entry.peer_id.clone(), "removed"));
+        }
+    }
+    Ok(changes)
+}
+
+/// 设备名册文件：基线快照旁的 `cardmind.roster`（内存版无文件）。
+fn roster_path(path: &Path) -> PathBuf {
+    path.with_extension("roster")
+}
+
+/// 加载设备名册（无文件 = 空名册）。
+fn load_roster(path: Option<&Path>, vault_key: Option<&VaultKey>) -> Result<LoroDoc> {
+    let roster = LoroDoc::new();
+    let Some(path) = path.map(roster_path) else {
+        return Ok(roster);
+    };
+    if let Some(bytes) = read_sidecar(&path, vault_key)? {
+        roster
+            .import(&bytes)
+            .map_err(|e| anyhow::anyhow!(e))
+            .with_context(|| format!("import device roster {}", path.display()))?;
+    }
+    Ok(roster)
+}
+
+/// 读取快照旁的小型状态文件（名册/同步水位；不存在 = None），按需解密；
+/// 启用加密后读到明文文件就地改写为密文。
+fn read_sidecar(path: &Path, vault_key: Option<&VaultKey>) -> Result<Option<Vec<u8>>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let raw = std::fs::read(path).with_context(|| format!("read {}", path.display()))?;
+    let sealed = vault::is_sealed(&raw);
+    let bytes = vault::open_at_rest(vault_key, raw)
+        .with_context(|| format!("decrypt {}", path.display()))?;
+    if let (Some(key), false) = (vault_key, sealed) {
+        vault::write_file_atomic(path, &key.seal(&bytes)?)?;
+    }
+    Ok(Some(bytes))
+}
+
+/// 整体重写名册文件（名册很小，变更稀少，不走追加日志）。
+fn persist_roster(core: &CoreState) -> Result<()> {
+    let Some(path) = core.persistent_path.as_deref().map(roster_path) else {
+        return Ok(());
+    };
+    let bytes = vault::seal_at_rest(core.vault_key.as_ref(), export_roster(core)?)?;
+    vault::write_file_atomic(&path, &bytes)
+}
+
+// ━━━ 对端同步水位 ━━━
+
+/// 对端已确认包含的本地状态（每次同步成功后更新，持久化为 `cardmind.sync`）。
+#[derive(Debug, Clone, Default)]
+struct PeerWatermark {
+    /// note_id → 对端已确认包含的版本向量
+    notes: HashMap<String, VersionVector>,
+    /// 对端已确认的墓碑
+    tombstones: HashSet<String>,
+    /// 最近一次确认时间
+    synced_at: Option<DateTime<Utc>>,
+}
+
+/// 本地当前状态作为一次确认的水位；`remote` = 对端在本次同步前回复的版本
+/// 摘要（对端此后同时包含二者，逐笔记合并；本端尚无的笔记取对端版本，本端
+/// 随后从对端收到这些笔记时不会把它们算作待推回）。
+fn core_watermark(
+    core: &CoreState,
+    remote: Option<&HashMap<String, VersionVector>>,
+) -> PeerWatermark {
+    let mut notes: HashMap<String, VersionVector> = remote
+        .map(|remote| {
+            remote
+                .iter()
+                .filter(|(id, _)| !core.tombstones.contains_key(*id))
+                .map(|(id, vv)| (id.clone(), vv.clone()))
+                .collect()
+        })
+        .unwrap_or_default();
+    for (id, note) in &core.notes {
+        notes
+            .entry(id.clone())
+            .or_default()
+            .merge(&note.version_vector());
+    }
+    PeerWatermark {
+        notes,
+        tombstones: core.tombstones.keys().cloned().collect(),
+        synced_at: Some(Utc::now()),
+    }
+}
+
+/// 相对某台对端水位的待同步 id：本地版本未被确认包含的笔记 + 未确认的墓碑
+/// （`ack = None` = 从未确认，全部待同步）。
+fn pending_ids(core: &CoreState, ack: Option<&PeerWatermark>) -> HashSet<String> {
+    let mut pending = HashSet::new();
+    for (id, note) in &core.notes {
+        let acked = ack
+            .and_then(|ack| ack.notes.get(id))
+            .is_some_and(|vv| vv.includes_vv(&note.version_vector()));
+        if !acked {
+            pending.insert(id.clone());
+        }
+    }
+    for id in core.tombstones.keys() {
+        if !ack.is_some_and(|ack| ack.tombstones.contains(id)) {
+            pending.insert(id.clone());
+        }
+    }
+    pending
+}
+
+/// 合并一次确认进对端水位（版本向量逐笔记合并、墓碑取并集），只改内存，
+/// 由 [`flush_sync_state`] 落盘。已彻底删除的笔记不再保留版本向量。
+fn merge_peer_ack(core: &mut CoreState, peer_id: &str, ack: PeerWatermark) {
+    let entry = core.peer_acks.entry(peer_id.to_string()).or_default();
+    for (id, vv) in ack.notes {
+        entry.notes.entry(id).or_default().merge(&vv);
+    }
+    entry.tombstones.extend(ack.tombstones);
+    entry.synced_at = ack.synced_at.or(entry.synced_at);
+    let tombstones = &core.tombstones;
+    for ack in core.peer_acks.values_mut() {
+        ack.notes.retain(|id, _| !tombstones.contains_key(id));
+    }
+    core.sync_state_dirty = true;
+}
+
+/// 同步状态有未落盘的变更时整体重写一次（失败时保留标记，下次再写）。
+fn flush_sync_state(core: &mut CoreState) -> Result<()> {
+    if !core.sync_state_dirty {
+        return Ok(());
+    }
+    persist_peer_acks(core)?;
+    core.sync_state_dirty = false;
+    Ok(())
+}
+
+/// 同步水位文件：基线快照旁的 `cardmind.sync`。
+fn peer_acks_path(path: &Path) -> PathBuf {
+    path.with_extension("sync")
+}
+
+/// `cardmind.sync` 的内容：对端水位 + 墓碑回收状态。
+#[derive(Default)]
+struct SyncState {
+    peer_acks: HashMap<String, PeerWatermark>,
+    /// 墓碑回收期限（None = 早期文件未记录，取默认值）
+    tombstone_horizon: Option<Duration>,
+    collected_tombstones: HashMap<String, DateTime<Utc>>,
+}
+
+/// 同步状态编码：`(对端数: u32 LE)` + 每台 `(peer_id, synced_at, 版本摘要,
+/// 墓碑数: u32 LE, 墓碑 id*)`（字符串/摘要各 length-prefixed；摘要格式同
+/// [`encode_version_digest`]，synced_at 为空串 = 未知），其后为 `(回收期限秒数:
+/// u64 LE, 已回收墓碑数: u32 LE, (note_id, 删除时间)*)`（时间均为 RFC3339 字符串，
+/// 空串 = 无；早期版本的文件没有这一段）。
+fn encode_sync_state(core: &CoreState) -> Vec<u8> {
+    let acks = &core.peer_acks;
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&(acks.len() as u32).to_le_bytes());
+    for (peer_id, ack) in acks {
+        push_str(&mut buf, peer_id);
+        push_str(
+            &mut buf,
+            &ack.synced_at.map(|t| t.to_rfc3339()).unwrap_or_default(),
+        );
+        let mut digest = Vec::new();
+        digest.extend_from_slice(&(ack.notes.len() as u32).to_le_bytes());
+        for (id, vv) in &ack.notes {
+            push_record(&mut digest, id, &vv.encode());
+        }
+        push_bytes(&mut buf, &digest);
+        buf.extend_from_slice(&(ack.tombstones.len() as u32).to_le_bytes());
+        for id in &ack.tombstones {
+            push_str(&mut buf, id);
         }
+    }
+    buf.extend_from_slice(&core.tombstone_horizon.as_secs().to_le_bytes());
+    buf.extend_from_slice(&(core.collected_tombstones.len() as u32).to_le_bytes());
+    for (note_id, purged_at) in &core.collected_tombstones {
+        push_str(&mut buf, note_id);
+        push_str(&mut buf, &purged_at.to_rfc3339());
+    }
+    buf
+}
+
+/// 解析同步状态中的 RFC3339 时间（空串 = 无）。
+fn parse_state_time(value: &str, field: &str) -> Result<Option<DateTime<Utc>>> {
+    if value.is_empty() {
+        return Ok(None);
+    }
+    let time = DateTime::parse_from_rfc3339(value)
+        .with_context(|| format!("invalid sync state {field}"))?;
+    Ok(Some(time.with_timezone(&Utc)))
+}
 
-        // 导入笔记
-        let note = NoteCrdt::new();
-        note.import_snapshot(&snapshot)?;
-        core.notes.insert(note_id, note);
+fn decode_sync_state(data: &[u8]) -> Result<SyncState> {
+    let mut offset = 0;
+    let count = take_u32(data, &mut offset, "sync state peer count")? as usize;
+    let mut acks = HashMap::with_capacity(count.min(data.len() / 8));
+    for _ in 0..count {
+        let peer_id = take_str(data, &mut offset, "sync state peer_id")?;
+        let synced_at = take_str(data, &mut offset, "sync state synced_at")?;
+        let synced_at = parse_state_time(&synced_at, "synced_at")?;
+        let notes = decode_version_digest(take_bytes(data, &mut offset, "sync state digest")?)?;
+        let mut tombstones = HashSet::new();
+        for _ in 0..take_u32(data, &mut offset, "sync state tombstone count")? {
+            tombstones.insert(take_str(data, &mut offset, "sync state tombstone")?);
+        }
+        acks.insert(
+            peer_id,
+            PeerWatermark {
+                notes,
+                tombstones,
+                synced_at,
+            },
+        );
+    }
+    let mut state = SyncState {
+        peer_acks: acks,
+        ..SyncState::default()
+    };
+    if offset == data.len() {
+        return Ok(state);
+    }
+    let horizon = data
+        .get(offset..offset + 8)
+        .ok_or_else(|| anyhow::anyhow!("truncated sync state: missing tombstone horizon"))?;
+    offset += 8;
+    state.tombstone_horizon = Some(Duration::from_secs(u64::from_le_bytes(
+        horizon.try_into().unwrap(),
+    )));
+    for _ in 0..take_u32(data, &mut offset, "sync state collected tombstone count")? {
+        let note_id = take_str(data, &mut offset, "sync state collected tombstone")?;
+        let purged_at = take_str(data, &mut offset, "sync state collected purged_at")?;
+        if let Some(purged_at) = parse_state_time(&purged_at, "collected purged_at")? {
+            state.collected_tombstones.insert(note_id, purged_at);
+        }
     }
+    Ok(state)
+}
 
-    // 墓碑 union 合并
-    core.tombstones.extend(imported_tombstones);
-    Ok(())
+/// 加载同步状态（无文件 = 尚无任何对端确认）。
+fn load_sync_state(path: Option<&Path>, vault_key: Option<&VaultKey>) -> Result<SyncState> {
+    let Some(path) = path.map(peer_acks_path) else {
+        return Ok(SyncState::default());
+    };
+    match read_sidecar(&path, vault_key)? {
+        Some(bytes) => decode_sync_state(&bytes)
+            .with_context(|| format!("decode sync state {}", path.display())),
+        None => Ok(SyncState::default()),
+    }
 }
 
-/// 持久化（已持锁 core 的纯函数）。
-fn persist_core(core: &CoreState) -> Result<()> {
-    let Some(path) = &core.persistent_path else {
+/// 整体重写同步状态文件（一轮同步/一次入站连接结束、回收墓碑或修改回收期限
+/// 后；内存版无文件）。
+fn persist_peer_acks(core: &CoreState) -> Result<()> {
+    let Some(path) = core.persistent_path.as_deref().map(peer_acks_path) else {
         return Ok(());
     };
-    let payload = export_core_all(core)?;
-    let bytes = encode_envelope(&payload);
-    let mut file = AtomicWriteFile::options()
-        .open(path)
-        .with_context(|| format!("open atomic Loro file {}", path.display()))?;
-    std::io::Write::write_all(&mut file, &bytes)?;
-    file.commit().context("commit Loro file")?;
-    Ok(())
+    let bytes = vault::seal_at_rest(core.vault_key.as_ref(), encode_sync_state(core))?;
+    vault::write_file_atomic(&path, &bytes)
 }
 
-// ━━━ 统一 incoming 路由（任务 O：接收器 / 配对 accept / 周期 accept 共用）━━━
+// ━━━ 并发编辑冲突 ━━━
 
-/// 统一 incoming 处理：接受连接并按帧标记路由（后台接收器与主服务共用）。
-///
-/// 帧标记（M2 修复——不能用单字节判定，否则推送 payload 首字节 0x01 与配对帧
-/// 冲突）：
-/// - 前 8 字节 == `LORO_MAGIC`（"CARDMIND"）→ 推送帧：读完整 payload，
-///   关闭连接通知发送端可释放，返回 `Ok(Some((sender_id, data)))`
-///   （data 即 `export_all` 输出，`import_core_all` 直接消费；sender_id 取自
-///   连接 TLS 证书——任务 O 据此更新发送方 last_seen，无需改协议）。
-/// - 首字节 `PAIRING_FRAME_REQUEST (0x01)` → 配对请求帧：解析并存入
-///   `pending_pairing`（供 `confirm_pairing` 在同一连接上回复握手响应），
-///   返回 `Ok(None)`。
-/// - 其他 → 报错（未知帧标记）。
-///
-/// 多个消费者（后台接收器 + 配对轮询 + 周期 accept）并发调用本函数安全：
-/// 每个 `endpoint.accept()` 只取一个 incoming；路由目标（pending_pairing /
-/// 返回的推送数据）互不冲突，不丢帧。
-async fn route_incoming(
-    incoming: iroh::endpoint::Incoming,
-    pending_pairing: &Mutex<Option<PendingPairing>>,
-) -> Result<Option<(iroh::EndpointId, Vec<u8>)>> {
-    let conn = incoming.accept()?.await.context("accept connection")?;
-    // 发送方身份：连接 TLS 证书中的 EndpointId（识别 inbound push 来源，
-    // 用于精确更新 last_seen——无需在协议帧中带 sender_id）
-    let sender_id = conn.remote_id();
-    let mut recv = conn.accept_uni().await.context("accept uni stream")?;
-    let mut marker = [0u8; LORO_MAGIC.len()];
-    recv.read_exact(&mut marker)
-        .await
-        .context("read frame marker")?;
-    if &marker == LORO_MAGIC {
-        // 推送帧：剩余部分 = export_all 输出（[墓碑数][记录流]）
-        let data = recv
-            .read_to_end(usize::MAX)
-            .await
-            .context("read push data")?;
-        // 数据已读入内存，主动关闭连接，通知发送端可释放
-        conn.close(0u32.into(), b"done");
-        return Ok(Some((sender_id, data)));
+/// 冲突记录文件：基线快照旁的 `cardmind.conflicts`。
+fn conflicts_path(path: &Path) -> PathBuf {
+    path.with_extension("conflicts")
+}
+
+/// 记录一条冲突（同一笔记只保留最近一次检测）并落盘。
+fn record_conflict(core: &mut CoreState, conflict: NoteConflict) -> Result<()> {
+    core.conflicts.insert(conflict.note_id.clone(), conflict);
+    persist_conflicts(core)
+}
+
+/// 冲突记录编码：`(冲突数: u32 LE)` + 每条 `(note_id, detected_at, 本端设备列表,
+/// 对端设备列表, 本端版本, 对端版本, 共同祖先版本)`；列表为 `(项数: u32 LE, 字符串*)`，
+/// 字符串 length-prefixed。
+fn encode_conflicts(conflicts: &HashMap<String, NoteConflict>) -> Vec<u8> {
+    let push_list = |buf: &mut Vec<u8>, items: &[String]| {
+        buf.extend_from_slice(&(items.len() as u32).to_le_bytes());
+        for item in items {
+            push_str(buf, item);
+        }
+    };
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&(conflicts.len() as u32).to_le_bytes());
+    for conflict in conflicts.values() {
+        push_str(&mut buf, &conflict.note_id);
+        push_str(&mut buf, &conflict.detected_at);
+        push_list(&mut buf, &conflict.local_devices);
+        push_list(&mut buf, &conflict.remote_devices);
+        push_list(&mut buf, &conflict.local_version);
+        push_list(&mut buf, &conflict.remote_version);
+        push_list(&mut buf, &conflict.base_version);
     }
-    if marker[0] == PAIRING_FRAME_REQUEST {
-        // 配对请求帧：marker(8) + 剩余 = 完整帧（从 0x01 开始）
-        let mut rest = recv
-            .read_to_end(usize::MAX)
-            .await
-            .context("read pairing request")?;
-        let mut data = Vec::with_capacity(marker.len() + rest.len());
-        data.extend_from_slice(&marker);
-        data.append(&mut rest);
-        let request = decode_pairing_request(&data)?;
-        *pending_pairing.lock().unwrap() = Some(PendingPairing {
-            request: request.clone(),
-            conn,
-        });
-        return Ok(None);
+    buf
+}
+
+fn decode_conflicts(data: &[u8]) -> Result<HashMap<String, NoteConflict>> {
+    let take_list = |offset: &mut usize, field: &str| -> Result<Vec<String>> {
+        let count = take_u32(data, offset, field)?;
+        (0..count).map(|_| take_str(data, offset, field)).collect()
+    };
+    let mut offset = 0;
+    let count = take_u32(data, &mut offset, "conflict count")? as usize;
+    let mut conflicts = HashMap::with_capacity(count.min(data.len() / 8));
+    for _ in 0..count {
+        let conflict = NoteConflict {
+            note_id: take_str(data, &mut offset, "conflict note_id")?,
+            detected_at: take_str(data, &mut offset, "conflict detected_at")?,
+            local_devices: take_list(&mut offset, "conflict local devices")?,
+            remote_devices: take_list(&mut offset, "conflict remote devices")?,
+            local_version: take_list(&mut offset, "conflict local version")?,
+            remote_version: take_list(&mut offset, "conflict remote version")?,
+            base_version: take_list(&mut offset, "conflict base version")?,
+        };
+        conflicts.insert(conflict.note_id.clone(), conflict);
+    }
+    Ok(conflicts)
+}
+
+/// 加载冲突记录（无文件 = 无冲突）。
+fn load_conflicts(
+    path: Option<&Path>,
+    vault_key: Option<&VaultKey>,
+) -> Result<HashMap<String, NoteConflict>> {
+    let Some(path) = path.map(conflicts_path) else {
+        return Ok(HashMap::new());
+    };
+    match read_sidecar(&path, vault_key)? {
+        Some(bytes) => {
+            decode_conflicts(&bytes).with_context(|| format!("decode conflicts {}", path.display()))
+        }
+        None => Ok(HashMap::new()),
     }
-    anyhow::bail!("unknown incoming frame marker: {:?}", marker);
+}
+
+/// 整体重写冲突记录文件（冲突稀少，不走追加日志；内存版无文件）。
+fn persist_conflicts(core: &CoreState) -> Result<()> {
+    let Some(path) = core.persistent_path.as_deref().map(conflicts_path) else {
+        return Ok(());
+    };
+    let bytes = vault::seal_at_rest(core.vault_key.as_ref(), encode_conflicts(&core.conflicts))?;
+    vault::write_file_atomic(&path, &bytes)
 }
 
 // ━━━ 后台接收任务循环（任务 O）━━━
@@ -2379,42 +6363,70 @@ async fn receiver_loop(mut ctx: ReceiverContext) {
     }
 }
 
-/// 接收任务处理单个 incoming：统一路由 + 推送帧 import/投影/last_seen。
+/// 接收任务处理单个 incoming：统一路由 + 推送帧流式 import/投影/last_seen。
 async fn receiver_handle_incoming(
     ctx: &mut ReceiverContext,
     incoming: iroh::endpoint::Incoming,
 ) -> Result<()> {
-    let Some((sender_id, data)) = route_incoming(
-        incoming,
+    let started = std::time::Instant::now();
+    let route = RouteContext {
         // 接收器也参与配对帧路由：配对请求被接收器抢到时正确存入
         // pending_pairing（confirm_pairing 仍可完成握手）——验收 9 统一路由
-        &ctx.pending_pairing,
-    )
-    .await?
+        pending_pairing: &ctx.pending_pairing,
+        core: &ctx.core,
+        // 接收器按自身投影鉴权入站同步帧（未配对/已撤销 → 拒绝并记日志）
+        trust: Some(&ctx.store),
+        log: &ctx.log,
+        events: &ctx.events,
+        device_id: &ctx.device_id,
+    };
+    // 边读边导入共享 core：大库推送不在内存中缓冲整帧
+    let Some((sender_id, Inbound::Imported(outcome))) =
+        route_incoming(incoming, &route, InboundMode::Import).await?
     else {
-        // 配对帧：已路由到 pending_pairing，接收器继续等待
+        // 配对帧（已路由到 pending_pairing）或被拒的同步帧：接收器继续等待
         return Ok(());
     };
-    let started = std::time::Instant::now();
     let sender_str = sender_id.to_string();
-    // sync.receive 成功日志（脱敏发送方）
+    // 对端主动连入：证明其在线，立即结束本端对它的退避
+    ctx.events.peer_online(&sender_str);
+    if clear_peer_backoff(&ctx.peer_backoff, &sender_str) {
+        receiver_log(
+            ctx,
+            "sync.backoff",
+            "reset",
+            Some(&format!("peer={} reason=inbound", redact_peer(&sender_str))),
+        );
+    }
+    // sync.receive 日志（脱敏发送方）
     receiver_log(
         ctx,
         "sync.receive",
-        "success",
+        if outcome.interrupted.is_none() {
+            "success"
+        } else {
+            "interrupted"
+        },
         Some(&format!(
             "bytes={} sender={}",
-            data.len(),
+            outcome.bytes,
             redact_peer(&sender_str)
         )),
     );
-    // 立即 import（共享 core）
-    let import_result = {
-        let mut core = ctx.core.lock().unwrap();
-        import_core_all(&mut core, &data)
-    };
-    match import_result {
-        Ok(()) => {
+    if !outcome.resurrected.is_empty() {
+        receiver_log(
+            ctx,
+            "sync.tombstone",
+            "resurrection_blocked",
+            Some(&format!(
+                "note_count={} sender={}",
+                outcome.resurrected.len(),
+                redact_peer(&sender_str)
+            )),
+        );
+    }
+    match &outcome.interrupted {
+        None => {
             let note_count = {
                 let core = ctx.core.lock().unwrap();
                 core.notes.len() + core.tombstones.len()
@@ -2426,39 +6438,47 @@ async fn receiver_handle_incoming(
                 Some(&format!(
                     "note_count={} bytes={} duration_ms={}",
                     note_count,
-                    data.len(),
+                    outcome.bytes,
                     started.elapsed().as_millis()
                 )),
             );
-            // 刷新 SQLite 投影（收到 push 立即投影——设计目标 2）
-            let core = ctx.core.lock().unwrap();
-            let proj = sync_core_to_store(&core, &ctx.store);
-            drop(core);
-            proj?;
-            // 更新发送方 last_seen（验收 12；触发原因 inbound_push）
-            ctx.store
-                .update_last_seen(&sender_str)
-                .context("update sender last_seen")?;
-            ctx.content_revision.fetch_add(1, Ordering::Release);
-            receiver_log(
-                ctx,
-                "device.last_seen",
-                "updated",
-                Some(&format!(
-                    "peer={} reason=inbound_push",
-                    redact_peer(&sender_str)
-                )),
-            );
         }
-        Err(e) => {
+        Some(e) => {
+            // 已导入部分已落盘：照常投影，对端下次只重传其余部分
             receiver_log(
                 ctx,
                 "sync.import",
                 "failed_tolerated",
-                Some(&format!("error={e:#}")),
+                Some(&format!("records={} error={e:#}", outcome.records)),
             );
+            if outcome.events.is_empty() {
+                return Ok(());
+            }
         }
     }
+    // 刷新 SQLite 投影（收到 push 立即投影——设计目标 2）
+    let core = ctx.core.lock().unwrap();
+    let proj = sync_core_to_store(&core, &ctx.store);
+    drop(core);
+    proj?;
+    // 更新发送方 last_seen（验收 12；触发原因 inbound_push）
+    ctx.store
+        .update_last_seen(&sender_str)
+        .context("update sender last_seen")?;
+    ctx.content_revision.fetch_add(1, Ordering::Release);
+    // 投影已刷新：UI 按事件只重载变更的笔记
+    for event in outcome.events {
+        ctx.events.emit(event);
+    }
+    receiver_log(
+        ctx,
+        "device.last_seen",
+        "updated",
+        Some(&format!(
+            "peer={} reason=inbound_push",
+            redact_peer(&sender_str)
+        )),
+    );
     Ok(())
 }
 
@@ -2485,17 +6505,105 @@ fn redact_peer(id: &str) -> String {
     debug_log::redact_device_id(id)
 }
 
+/// 后台同步任务主循环（见 [`SyncService::start_sync_loop`]）。
+///
+/// 周期同步与编辑推送在同一任务内顺序执行，不会并发连接同一对端；执行期间
+/// 到达的编辑通知被保留，结束后立即处理。
+async fn sync_loop(
+    ctx: Arc<SyncContext>,
+    store: NoteStore,
+    stop: Arc<tokio::sync::Notify>,
+    poll_interval: Duration,
+) {
+    let edits = &ctx.local_edits;
+    let debounce = Duration::from_millis(SYNC_EDIT_DEBOUNCE_MS);
+    // 局域网内重新出现的对端立即结束退避（不必等用户手动扫描）
+    let mut discovery = None;
+    let mut watch = ctx.start_presence_watch(&mut discovery);
+    // 启动即同步一次：追上离线期间对端的变更
+    let mut next_poll = tokio::time::Instant::now();
+    loop {
+        tokio::select! {
+            _ = stop.notified() => break,
+            sighting = next_sighting(watch.as_ref()) => match sighting {
+                Some(peer) => ctx.peer_sighted(&peer.device_id),
+                None => watch = None,
+            },
+            _ = tokio::time::sleep_until(next_poll) => {
+                ctx.emit_log(
+                    LogEvent::new("sync.loop", "sync.loop")
+                        .with_id(&ctx.device_id())
+                        .with_field("action", "trigger")
+                        .with_field("reason", "poll"),
+                );
+                if let Err(e) = ctx.run_sync_cycle(&store).await {
+                    ctx.emit_log(
+                        LogEvent::new("sync.loop", "sync.loop")
+                            .with_id(&ctx.device_id())
+                            .with_field("action", "cycle_failed")
+                            .with_error(&e.to_string())
+                            .with_chain(&format!("{e:#}")),
+                    );
+                }
+                next_poll = tokio::time::Instant::now() + poll_interval;
+            }
+            _ = edits.notified() => {
+                // 防抖：窗口内的后续编辑顺延推送，连续输入只推送一次
+                let mut stopped = false;
+                loop {
+                    tokio::select! {
+                        _ = stop.notified() => {
+                            stopped = true;
+                            break;
+                        }
+                        _ = edits.notified() => {}
+                        _ = tokio::time::sleep(debounce) => break,
+                    }
+                }
+                if stopped {
+                    break;
+                }
+                ctx.emit_log(
+                    LogEvent::new("sync.loop", "sync.loop")
+                        .with_id(&ctx.device_id())
+                        .with_field("action", "trigger")
+                        .with_field("reason", "edit"),
+                );
+                ctx.push_pending(&store).await;
+            }
+        }
+    }
+    ctx.emit_log(
+        LogEvent::new("sync.loop", "sync.loop")
+            .with_id(&ctx.device_id())
+            .with_field("action", "end"),
+    );
+}
+
+/// 下一次 mDNS 对端发现；未在监听时永不完成（select 分支等同禁用）。
+async fn next_sighting(watch: Option<&PeerWatch>) -> Option<PeerInfo> {
+    match watch {
+        Some(watch) => watch.next().await,
+        None => std::future::pending().await,
+    }
+}
+
 /// 将 core 笔记投影到 SQLite（接收任务/主服务共用）。
 fn sync_core_to_store(core: &CoreState, store: &NoteStore) -> Result<()> {
     for (id, note) in &core.notes {
         store.sync_note(id, note)?;
     }
-    for id in &core.tombstones {
+    for id in core.tombstones.keys() {
         store.purge_note(id)?;
     }
     Ok(())
 }
 
+/// 追加更新日志路径：基线快照旁的 `<快照文件名>.log`（如 `cardmind.loro.log`）。
+fn update_log_path(path: &Path) -> PathBuf {
+    path.with_extension("loro.log")
+}
+
 fn loro_path(path: &Path) -> PathBuf {
     if path.extension().is_some_and(|ext| ext == "loro") {
         path.to_path_buf()
@@ -2545,32 +6653,55 @@ fn load_relay_mode(data_dir: Option<&Path>) -> Result<RelayMode> {
 /// - `dir = Some(数据目录)`：读取 `device.key`（32 字节 hex）；不存在则生成并写入，
 ///   使持久化服务的 device_id 跨重启稳定。
 /// - `dir = None`（内存版）：每次生成随机密钥，测试用。
-fn load_or_create_secret_key(dir: Option<&Path>) -> Result<SecretKey> {
+///
+/// 启用静态加密（`vault_key = Some`）时 `device.key` 以密文落盘；读到启用前的
+/// 明文文件会就地改写为密文。
+fn load_or_create_secret_key(
+    dir: Option<&Path>,
+    vault_key: Option<&VaultKey>,
+) -> Result<SecretKey> {
     let Some(dir) = dir else {
         return Ok(SecretKey::generate());
     };
     let key_path = dir.join("device.key");
     if key_path.exists() {
-        let hex = std::fs::read_to_string(&key_path)
+        let raw = std::fs::read(&key_path)
             .with_context(|| format!("read device key {}", key_path.display()))?;
+        let sealed = vault::is_sealed(&raw);
+        let raw = vault::open_at_rest(vault_key, raw)
+            .with_context(|| format!("decrypt device key {}", key_path.display()))?;
+        let hex = String::from_utf8(raw)
+            .with_context(|| format!("invalid device key in {}", key_path.display()))?;
         let bytes = decode_hex(hex.trim())
             .with_context(|| format!("invalid device key in {}", key_path.display()))?;
         let bytes: [u8; 32] = bytes
             .try_into()
             .map_err(|_| anyhow::anyhow!("device key must be 32 bytes"))?;
-        Ok(SecretKey::from_bytes(&bytes))
+        let key = SecretKey::from_bytes(&bytes);
+        if vault_key.is_some() && !sealed {
+            write_secret_key(&key_path, &key, vault_key)?;
+        }
+        Ok(key)
     } else {
         let key = SecretKey::generate();
-        let hex = encode_hex(key.to_bytes());
-        std::fs::write(&key_path, hex)
-            .with_context(|| format!("write device key {}", key_path.display()))?;
+        write_secret_key(&key_path, &key, vault_key)?;
         Ok(key)
     }
 }
 
-/// 32 字节 → 64 字符小写 hex
-fn encode_hex(bytes: [u8; 32]) -> String {
-    let mut s = String::with_capacity(64);
+/// 写出 `device.key`（hex；有 `vault_key` 时加密后原子写出）。
+fn write_secret_key(key_path: &Path, key: &SecretKey, vault_key: Option<&VaultKey>) -> Result<()> {
+    let hex = encode_hex(&key.to_bytes());
+    match vault_key {
+        Some(vault_key) => vault::write_file_atomic(key_path, &vault_key.seal(hex.as_bytes())?),
+        None => std::fs::write(key_path, hex)
+            .with_context(|| format!("write device key {}", key_path.display())),
+    }
+}
+
+/// 字节 → 小写 hex（每字节两个字符）
+fn encode_hex(bytes: &[u8]) -> String {
+    let mut s = String::with_capacity(bytes.len() * 2);
     for b in bytes {
         s.push_str(&format!("{:02x}", b));
     }
@@ -2592,46 +6723,55 @@ fn decode_hex(hex: &str) -> Result<Vec<u8>> {
         .collect()
 }
 
+/// 编码 v5 信封：`magic + version + 存储长度 + (原始长度: u64 LE, LZ4 块)`。
 fn encode_envelope(payload: &[u8]) -> Vec<u8> {
-    let mut bytes = Vec::with_capacity(LORO_HEADER_LEN + payload.len());
+    let compressed = lz4_flex::block::compress(payload);
+    let stored_len = 8 + compressed.len();
+    let mut bytes = Vec::with_capacity(LORO_HEADER_LEN + stored_len);
     bytes.extend_from_slice(LORO_MAGIC);
     bytes.extend_from_slice(&LORO_VERSION.to_le_bytes());
+    bytes.extend_from_slice(&(stored_len as u64).to_le_bytes());
     bytes.extend_from_slice(&(payload.len() as u64).to_le_bytes());
-    bytes.extend_from_slice(payload);
+    bytes.extend_from_slice(&compressed);
     bytes
 }
 
-/// 网络推送线格式：8 字节 CARDMIND magic + `export_all` 输出。
-///
-/// M2 修复：接收端 `accept_incoming_routed` 以 magic 识别推送帧、以 0x01 识别
-/// 配对帧。若无此前缀，`export_all` 输出首字节 = 墓碑数（u32 LE），墓碑数 = 1
-/// （或 257/513…）时首字节恰为 0x01 = PAIRING_FRAME_REQUEST，推送帧会被误判为
-/// 配对帧而数据丢失。
-fn encode_push_wire(payload: &[u8]) -> Vec<u8> {
-    let mut wire = Vec::with_capacity(LORO_MAGIC.len() + payload.len());
-    wire.extend_from_slice(LORO_MAGIC);
-    wire.extend_from_slice(payload);
-    wire
-}
-
 /// 解码信封，返回 `(version, payload)`。
 ///
 /// version = 1 时返回旧 payload 供迁移（不报错）；version = 2/3 正常载入
 /// （v2 文件 = 纯记录流，v3 = 墓碑 section + 记录流，无损升级无需迁移数据）；
-/// 其他版本报错。
+/// version = 4/5 解压后返回（v4 内容同 v3，v5 墓碑带删除时间）；其他版本报错。
 fn decode_envelope(bytes: &[u8]) -> Result<(u32, Vec<u8>)> {
     if bytes.len() < LORO_HEADER_LEN || &bytes[..8] != LORO_MAGIC {
         anyhow::bail!("invalid cardmind.loro magic or truncated header");
     }
     let version = u32::from_le_bytes(bytes[8..12].try_into().unwrap());
-    if version != 1 && version != 2 && version != LORO_VERSION {
+    if !(1..=LORO_VERSION).contains(&version) {
         anyhow::bail!("unsupported cardmind.loro version: {}", version);
     }
     let length = u64::from_le_bytes(bytes[12..20].try_into().unwrap()) as usize;
     if length != bytes.len() - LORO_HEADER_LEN {
         anyhow::bail!("invalid cardmind.loro payload length");
     }
-    Ok((version, bytes[LORO_HEADER_LEN..].to_vec()))
+    let stored = &bytes[LORO_HEADER_LEN..];
+    if version < 4 {
+        return Ok((version, stored.to_vec()));
+    }
+    if stored.len() < 8 {
+        anyhow::bail!("truncated cardmind.loro compressed payload");
+    }
+    let raw_len = u64::from_le_bytes(stored[..8].try_into().unwrap());
+    let max_len = (stored.len() - 8).saturating_mul(LZ4_MAX_RATIO);
+    if raw_len > max_len as u64 {
+        anyhow::bail!("invalid cardmind.loro decompressed length: {raw_len} bytes");
+    }
+    let raw_len = raw_len as usize;
+    let payload = lz4_flex::block::decompress(&stored[8..], raw_len)
+        .context("decompress cardmind.loro payload")?;
+    if payload.len() != raw_len {
+        anyhow::bail!("invalid cardmind.loro decompressed length");
+    }
+    Ok((version, payload))
 }
 
 /// 默认设备名（主机名；无环境变量时回退固定名）
@@ -2726,28 +6866,6 @@ fn decode_pairing_response(data: &[u8]) -> Result<PairingResponse> {
     })
 }
 
-/// 写入 u32 长度前缀 + UTF-8 字符串
-fn push_str(buf: &mut Vec<u8>, s: &str) {
-    buf.extend_from_slice(&(s.len() as u32).to_le_bytes());
-    buf.extend_from_slice(s.as_bytes());
-}
-
-/// 读取 u32 长度前缀 + UTF-8 字符串
-fn take_str(data: &[u8], offset: &mut usize, field: &str) -> Result<String> {
-    if *offset + 4 > data.len() {
-        anyhow::bail!("truncated pairing frame: missing {field} length");
-    }
-    let len = u32::from_le_bytes(data[*offset..*offset + 4].try_into().unwrap()) as usize;
-    *offset += 4;
-    if *offset + len > data.len() {
-        anyhow::bail!("truncated pairing frame: missing {field}");
-    }
-    let s = String::from_utf8(data[*offset..*offset + len].to_vec())
-        .with_context(|| format!("invalid UTF-8 in {field}"))?;
-    *offset += len;
-    Ok(s)
-}
-
 /// 16 字节 nonce → 32 字符小写 hex 字符串（FRB 边界表示；测试取会话 nonce 用）。
 pub fn nonce_to_hex(nonce: &[u8; 16]) -> String {
     let mut s = String::with_capacity(32);
@@ -3035,10 +7153,10 @@ impl SyncService {
         let now = Utc::now();
         let issued_at = now.timestamp() as u64;
         let expires_at = issued_at + CREDENTIAL_TTL_SECS;
-        let node_id = *self.endpoint.id().as_bytes();
+        let node_id = *self.ctx.endpoint.id().as_bytes();
 
         let raw = encode_credential(
-            &self.secret_key,
+            &self.ctx.secret_key,
             issued_at,
             expires_at,
             &nonce,
@@ -3057,7 +7175,7 @@ impl SyncService {
             nonce,
         };
         *self.pairing_session.lock().unwrap() = Some(session);
-        *self.pending_pairing.lock().unwrap() = None;
+        *self.ctx.pending_pairing.lock().unwrap() = None;
 
         self.emit_log(
             LogEvent::new("pairing.show_code", "pairing.show_code")
@@ -3086,7 +7204,7 @@ impl SyncService {
         let display = self.begin_pairing_credential()?;
         // 当前会话 nonce（hex），随 mDNS TXT 广播，供发起方回填 PairingTarget
         let nonce_hex = self.session_nonce_hex();
-        let port = self.endpoint_listen_port();
+        let port = self.ctx.endpoint_listen_port();
         let mut guard = self.discovery.lock().await;
         if guard.is_none() {
             *guard = Some(DiscoveryService::new()?);
@@ -3173,21 +7291,26 @@ impl SyncService {
 impl NoteCrdt {
     /// 创建新笔记
     pub fn new() -> Self {
-        Self {
-            doc: LoroDoc::new(),
-        }
+        let doc = LoroDoc::new();
+        // 编辑历史：记录提交时间；同一设备间隔内的连续保存合并为一个版本
+        doc.set_record_timestamp(true);
+        doc.set_change_merge_interval(HISTORY_MERGE_INTERVAL_SECS);
+        Self { doc }
     }
 
-    /// 设置完整内容（替换）
+    /// 设置完整内容（最小差异更新）
     ///
-    /// 先删除已有内容，再在 0 位置插入新文本。
+    /// 与当前正文做 diff，只提交最小的插入/删除：两端离线编辑不同段落合并后
+    /// 互不覆盖，历史也不会每次保存都复制一份全文。超长正文按行 diff（见
+    /// [`CONTENT_LINE_DIFF_THRESHOLD`]），内容未变时不产生操作。
     pub fn set_content(&self, markdown: &str) {
         let text = self.doc.get_text("content");
-        let len = text.len_unicode();
-        if len > 0 {
-            text.delete(0, len).unwrap();
+        let options = UpdateOptions::default();
+        if text.len_unicode().max(markdown.len()) > CONTENT_LINE_DIFF_THRESHOLD {
+            text.update_by_line(markdown, options).unwrap();
+        } else {
+            text.update(markdown, options).unwrap();
         }
-        text.insert(0, markdown).unwrap();
     }
 
     /// 获取当前内容
@@ -3291,10 +7414,12 @@ impl NoteCrdt {
         }
     }
 
-    /// 解析正文中的 `[[target-id|alias]]` 链接 → `(target_id, alias)`
+    /// 解析正文中的 `[[target#锚点|alias]]` 链接
     ///
-    /// alias 缺省时取 target_id。格式：`[[target|alias]]`，无 alias 时 `[[target]]`。
-    pub fn parse_links(&self) -> Vec<(String, String)> {
+    /// target 为笔记 id 或标题（`[[Note Title]]`，由 `NoteStore` 解析为 id）；
+    /// 锚点可选，`#标题` 指向章节、`^块 id` 指向块；alias 缺省时取链接原文。
+    /// 代码块、行内代码与转义的方括号不算链接。
+    pub fn parse_links(&self) -> Vec<NoteLink> {
         parse_links_from_content(&self.get_content())
     }
 
@@ -3305,11 +7430,233 @@ impl NoteCrdt {
             .map_err(|e| anyhow::anyhow!(e))
     }
 
-    /// 导入全量快照
+    /// 导入全量快照（Loro import 同样接受增量更新，与已有历史合并）
     pub fn import_snapshot(&self, data: &[u8]) -> Result<()> {
         self.doc.import(data).map_err(|e| anyhow::anyhow!(e))?;
         Ok(())
     }
+
+    /// 当前 oplog 版本向量（增量同步摘要/持久化水位用）
+    ///
+    /// 只含已提交的变更：setter 写入后须经 [`commit`](Self::commit) /
+    /// [`commit_as`](Self::commit_as) 提交才计入。
+    pub fn version_vector(&self) -> VersionVector {
+        self.doc.oplog_vv()
+    }
+
+    /// 导出对端版本 `from` 之后的增量更新
+    pub fn export_updates(&self, from: &VersionVector) -> Result<Vec<u8>> {
+        self.doc
+            .export(ExportMode::updates(from))
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
+    /// 提交本地正文/标签编辑：刷新 meta.updated_at（投影与 `updated:` 过滤的
+    /// 更新时间来自这里）后 [`commit_as`](Self::commit_as)。
+    pub fn commit_edit(&self, device_id: &str) {
+        self.set_updated_at(&Utc::now().to_rfc3339());
+        self.commit_as(device_id);
+    }
+
+    /// 以 `device_id` 作为提交说明提交当前编辑（历史版本据此显示发起设备）。
+    pub fn commit_as(&self, device_id: &str) {
+        self.doc.set_next_commit_message(device_id);
+        self.doc.commit();
+    }
+
+    /// 提交当前编辑（不带提交说明；迁移与测试等非设备发起的写入）。
+    pub fn commit(&self) {
+        self.doc.commit();
+    }
+
+    /// 当前已提交的版本：本地编辑前记录，persist 失败时经
+    /// [`rollback_to`](Self::rollback_to) 回滚。
+    fn checkpoint(&self) -> Frontiers {
+        self.doc.oplog_frontiers()
+    }
+
+    /// 回滚到 `checkpoint`：返回只含该版本及之前历史的新文档，丢弃其后的本地
+    /// 变更而不产生新操作（以 setter 写回旧值会记为一次新编辑并同步出去）。
+    fn rollback_to(&self, checkpoint: &Frontiers) -> Result<NoteCrdt> {
+        let snapshot = self
+            .doc
+            .export(ExportMode::snapshot_at(checkpoint))
+            .map_err(|e| anyhow::anyhow!(e))?;
+        let note = NoteCrdt::new();
+        note.import_snapshot(&snapshot)?;
+        Ok(note)
+    }
+
+    /// 最近一次变更的提交时间（秒精度；未记录时间戳的旧文档为 None）。
+    pub fn last_changed_at(&self) -> Option<DateTime<Utc>> {
+        let mut latest = 0;
+        for (&peer, &end) in self.version_vector().iter() {
+            if end == 0 {
+                continue;
+            }
+            if let Some(meta) = self.doc.get_change(ID::new(peer, end - 1)) {
+                latest = latest.max(meta.timestamp);
+            }
+        }
+        DateTime::<Utc>::from_timestamp(latest, 0).filter(|_| latest > 0)
+    }
+
+    /// 历史版本列表（新 → 旧）：遍历 oplog 中每个 peer 的全部 change。
+    pub fn versions(&self) -> Vec<NoteVersion> {
+        let vv = self.version_vector();
+        let mut changes = Vec::new();
+        for (&peer, &end) in vv.iter() {
+            let mut counter = 0;
+            while counter < end {
+                let Some(meta) = self.doc.get_change(ID::new(peer, counter)) else {
+                    break;
+                };
+                counter = meta.id.counter + meta.len as i32;
+                changes.push(meta);
+            }
+        }
+        changes.sort_by(|a, b| {
+            (b.timestamp, b.lamport, b.id.peer).cmp(&(a.timestamp, a.lamport, a.id.peer))
+        });
+        changes
+            .into_iter()
+            .map(|meta| NoteVersion {
+                version: format!("{}@{}", meta.id.counter + meta.len as i32 - 1, meta.id.peer),
+                timestamp: DateTime::<Utc>::from_timestamp(meta.timestamp, 0)
+                    .filter(|_| meta.timestamp > 0)
+                    .map(|t| t.to_rfc3339())
+                    .unwrap_or_default(),
+                device_id: meta.message.as_deref().unwrap_or_default().to_string(),
+                change_len: meta.len as u32,
+            })
+            .collect()
+    }
+
+    /// 导入对端变更并检测并发编辑：导入的变更（含其因果依赖）未包含导入前本端
+    /// 已有的全部变更，且本端、对端两侧相对共同祖先都改动了正文且结果不同时，
+    /// 返回冲突记录（Loro 照常合并，记录只供用户复核）。
+    fn import_detecting_conflict(
+        &self,
+        note_id: &str,
+        data: &[u8],
+    ) -> Result<Option<NoteConflict>> {
+        let before = self.version_vector();
+        let local_frontiers = self.doc.oplog_frontiers();
+        self.import_snapshot(data)?;
+        let after = self.doc.oplog_vv();
+        // 各 peer 新导入的最后一个变更：其因果闭包即对端发出时已知的版本
+        let heads: Vec<ID> = after
+            .iter()
+            .filter(|(peer, end)| **end > before.get(*peer).copied().unwrap_or(0))
+            .map(|(&peer, &end)| ID::new(peer, end - 1))
+            .collect();
+        if heads.is_empty() {
+            return Ok(None);
+        }
+        let remote_frontiers = Frontiers::from(heads);
+        let Some(remote) = self.doc.frontiers_to_vv(&remote_frontiers) else {
+            return Ok(None);
+        };
+        // 对端已包含本端全部变更：快进，不是并发
+        if remote.includes_vv(&before) {
+            return Ok(None);
+        }
+        let mut base = VersionVector::new();
+        for (&peer, &end) in before.iter() {
+            let common = end.min(remote.get(&peer).copied().unwrap_or(0));
+            if common > 0 {
+                base.insert(peer, common);
+            }
+        }
+        let base_frontiers = self.doc.vv_to_frontiers(&base);
+        let base_content = self.content_at(&base_frontiers)?;
+        let local_content = self.content_at(&local_frontiers)?;
+        let remote_content = self.content_at(&remote_frontiers)?;
+        if local_content == base_content
+            || remote_content == base_content
+            || local_content == remote_content
+        {
+            return Ok(None);
+        }
+        Ok(Some(NoteConflict {
+            note_id: note_id.to_string(),
+            detected_at: Utc::now().to_rfc3339(),
+            local_devices: self.devices_beyond(&before, &remote),
+            remote_devices: self.devices_beyond(&remote, &before),
+            local_version: frontiers_to_strings(&local_frontiers),
+            remote_version: frontiers_to_strings(&remote_frontiers),
+            base_version: frontiers_to_strings(&base_frontiers),
+        }))
+    }
+
+    /// `ours` 中超出 `theirs` 的变更的发起设备（取各 peer 最后一个变更的提交说明）。
+    fn devices_beyond(&self, ours: &VersionVector, theirs: &VersionVector) -> Vec<String> {
+        let mut devices: Vec<String> = ours
+            .iter()
+            .filter(|(peer, end)| **end > theirs.get(*peer).copied().unwrap_or(0))
+            .filter_map(|(&peer, &end)| {
+                let meta = self.doc.get_change(ID::new(peer, end - 1))?;
+                meta.message.as_deref().map(str::to_string)
+            })
+            .filter(|device| !device.is_empty())
+            .collect();
+        devices.sort();
+        devices.dedup();
+        devices
+    }
+
+    /// 指定版本（frontiers）时的正文：在 fork 上 checkout，不影响当前文档。
+    fn content_at(&self, frontiers: &Frontiers) -> Result<String> {
+        let fork = self.doc.fork();
+        fork.checkout(frontiers).map_err(|e| anyhow::anyhow!(e))?;
+        Ok(NoteCrdt { doc: fork }.get_content())
+    }
+
+    /// 指定历史版本的只读副本：fork 当前文档后 checkout 到该版本，
+    /// 不影响（不 detach）当前文档。
+    pub fn at_version(&self, version: &str) -> Result<NoteCrdt> {
+        let id = parse_version_id(version)?;
+        if self.doc.get_change(id).is_none() {
+            anyhow::bail!("version not found: {version}");
+        }
+        let fork = self.doc.fork();
+        fork.checkout(&Frontiers::from(id))
+            .map_err(|e| anyhow::anyhow!(e))?;
+        Ok(NoteCrdt { doc: fork })
+    }
+}
+
+/// frontiers → 版本标识列表（`counter@peer`，有序）。
+fn frontiers_to_strings(frontiers: &Frontiers) -> Vec<String> {
+    let mut ids: Vec<String> = frontiers
+        .iter()
+        .map(|id| format!("{}@{}", id.counter, id.peer))
+        .collect();
+    ids.sort();
+    ids
+}
+
+/// 版本标识列表 → frontiers（[`frontiers_to_strings`] 的逆过程）。
+fn frontiers_from_strings(versions: &[String]) -> Result<Frontiers> {
+    let ids = versions
+        .iter()
+        .map(|version| parse_version_id(version))
+        .collect::<Result<Vec<ID>>>()?;
+    Ok(Frontiers::from(ids))
+}
+
+/// 解析版本标识 `counter@peer`（[`NoteCrdt::versions`] 输出格式）。
+fn parse_version_id(version: &str) -> Result<ID> {
+    let (counter, peer) = version
+        .split_once('@')
+        .ok_or_else(|| anyhow::anyhow!("invalid version id: {version}"))?;
+    let counter = counter
+        .parse()
+        .with_context(|| format!("invalid version counter: {version}"))?;
+    let peer = peer
+        .parse()
+        .with_context(|| format!("invalid version peer: {version}"))?;
+    Ok(ID::new(peer, counter))
 }
 
 fn remove_tag_marker(content: &str) -> String {
@@ -3343,27 +7690,243 @@ fn extract_tag_marker(content: &str) -> String {
         .to_string()
 }
 
-/// 解析正文中的 `[[target-id|alias]]` 链接
-fn parse_links_from_content(content: &str) -> Vec<(String, String)> {
+/// 解析正文中的 wiki 链接（见 [`wiki_link_spans`]）。
+fn parse_links_from_content(content: &str) -> Vec<NoteLink> {
+    parse_link_refs(content, |_| false)
+        .into_iter()
+        .map(|(_, link)| link)
+        .collect()
+}
+
+/// 解析正文中的 wiki 链接，附带拆分锚点前的目标原文（`NoteStore` 投影用）。
+/// `is_note(原文)` 见 [`split_link_anchor`]。
+pub(crate) fn parse_link_refs(
+    content: &str,
+    is_note: impl Fn(&str) -> bool,
+) -> Vec<(String, NoteLink)> {
+    wiki_link_spans(content, &is_note)
+        .into_iter()
+        .map(|(_, dest, link)| (dest, link))
+        .collect()
+}
+
+/// 按 Markdown 解析正文中的 wiki 链接、目标原文及其在正文中的字节区间（含
+/// `[[`/`]]`，嵌入含 `!`）。pulldown-cmark wikilink 扩展：代码块、行内代码与
+/// `\[[` 转义由解析器排除；`![[...]]` 嵌入同样算链接。
+fn wiki_link_spans(
+    content: &str,
+    is_note: &dyn Fn(&str) -> bool,
+) -> Vec<(std::ops::Range<usize>, String, NoteLink)> {
+    use pulldown_cmark::{Event, LinkType, Options, Parser, Tag, TagEnd};
+
     let mut links = Vec::new();
-    let mut rest = content;
-    while let Some(start) = rest.find("[[") {
-        let after = &rest[start + 2..];
-        let Some(end_rel) = after.find("]]") else {
-            break;
+    // 正在收集显示名的链接：(区间, 目标原文, 显示名)
+    let mut open: Option<(std::ops::Range<usize>, String, String)> = None;
+    for (event, range) in Parser::new_ext(content, Options::ENABLE_WIKILINKS).into_offset_iter() {
+        match event {
+            Event::Start(Tag::Link {
+                link_type: LinkType::WikiLink { .. },
+                dest_url,
+                ..
+            })
+            | Event::Start(Tag::Image {
+                link_type: LinkType::WikiLink { .. },
+                dest_url,
+                ..
+            }) => open = Some((range, dest_url.to_string(), String::new())),
+            Event::Text(text) | Event::Code(text) => {
+                if let Some((_, _, alias)) = open.as_mut() {
+                    alias.push_str(&text);
+                }
+            }
+            Event::End(TagEnd::Link) | Event::End(TagEnd::Image) => {
+                let Some((range, dest, alias)) = open.take() else {
+                    continue;
+                };
+                let dest = dest.trim();
+                let (target, anchor) = split_link_anchor(dest, is_note);
+                if target.is_empty() {
+                    continue;
+                }
+                let alias = alias.trim();
+                let alias = if alias.is_empty() { dest } else { alias };
+                links.push((
+                    range,
+                    dest.to_string(),
+                    NoteLink {
+                        target: target.to_string(),
+                        anchor: anchor.to_string(),
+                        alias: alias.to_string(),
+                    },
+                ));
+            }
+            _ => {}
+        }
+    }
+    links
+}
+
+/// 投影中有链接解析到 `note_id` 的源笔记 id（改名/合并只改写这些笔记里的标题链接，
+/// 固定解析到同名其他笔记的链接不动）。
+fn link_sources(store: &NoteStore, note_id: &str) -> Result<HashSet<String>> {
+    Ok(store
+        .backlinks(note_id)?
+        .into_iter()
+        .map(|link| link.id)
+        .collect())
+}
+
+/// 逐条改写正文中的 wiki 链接：`rewrite(链接, 原文显示名)` 返回新的 `[[...]]`
+/// 内部文本（None 或与原文相同 = 不改）。原文显示名为 `|` 之后的原始文本（未写 = None）。
+/// `is_note` 见 [`split_link_anchor`]。返回改写后的正文；没有任何改动 = None。
+fn rewrite_wiki_links(
+    content: &str,
+    is_note: &dyn Fn(&str) -> bool,
+    mut rewrite: impl FnMut(&NoteLink, Option<&str>) -> Option<String>,
+) -> Option<String> {
+    let mut out = String::with_capacity(content.len());
+    let mut cursor = 0;
+    for (range, _, link) in wiki_link_spans(content, is_note) {
+        let raw = &content[range.clone()];
+        let (Some(open), true) = (raw.find("[["), raw.ends_with("]]")) else {
+            continue;
         };
-        let inner = &after[..end_rel];
-        let (target, alias) = match inner.split_once('|') {
-            Some((t, a)) => (t.trim(), a.trim()),
-            None => (inner.trim(), ""),
+        let inner_start = range.start + open + 2;
+        let inner_end = range.end - 2;
+        if inner_start > inner_end {
+            continue;
+        }
+        let inner = &content[inner_start..inner_end];
+        let raw_alias = inner.split_once('|').map(|(_, alias)| alias);
+        let Some(new_inner) = rewrite(&link, raw_alias) else {
+            continue;
         };
-        if !target.is_empty() {
-            let alias = if alias.is_empty() { target } else { alias };
-            links.push((target.to_string(), alias.to_string()));
+        if new_inner == inner {
+            continue;
         }
-        rest = &after[end_rel + 2..];
+        out.push_str(&content[cursor..inner_start]);
+        out.push_str(&new_inner);
+        cursor = inner_end;
     }
-    links
+    if cursor == 0 {
+        return None;
+    }
+    out.push_str(&content[cursor..]);
+    Some(out)
+}
+
+/// 正文中未加链接地提到 `title` 的位置（字节区间，按出现顺序）：大小写不敏感，
+/// 跳过 wiki/Markdown 链接内、代码块与行内代码；标题首尾为 ASCII 字母数字时要求
+/// 词边界（`Rust` 不匹配 `Rusty`）。
+pub(crate) fn find_mentions(content: &str, title: &str) -> Vec<std::ops::Range<usize>> {
+    use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+
+    let needle: Vec<char> = title.trim().chars().collect();
+    let mut found = Vec::new();
+    if needle.is_empty() {
+        return found;
+    }
+    let mut in_link = 0usize;
+    let mut in_code_block = false;
+    for (event, range) in Parser::new_ext(content, Options::ENABLE_WIKILINKS).into_offset_iter() {
+        match event {
+            Event::Start(Tag::Link { .. }) | Event::Start(Tag::Image { .. }) => in_link += 1,
+            Event::End(TagEnd::Link) | Event::End(TagEnd::Image) => {
+                in_link = in_link.saturating_sub(1)
+            }
+            Event::Start(Tag::CodeBlock(_)) => in_code_block = true,
+            Event::End(TagEnd::CodeBlock) => in_code_block = false,
+            // 转义/实体处理过的文本与原文不一一对应，无法回写，跳过
+            Event::Text(text)
+                if in_link == 0 && !in_code_block && content.get(range.clone()) == Some(&*text) =>
+            {
+                for hit in find_case_insensitive(&text, &needle) {
+                    found.push(range.start + hit.start..range.start + hit.end);
+                }
+            }
+            _ => {}
+        }
+    }
+    found
+}
+
+/// 在 `hay` 中大小写不敏感地查找 `needle`（逐字符比较小写形式），返回不重叠的
+/// 字节区间；ASCII 字母数字边界要求见 [`find_mentions`]。
+fn find_case_insensitive(hay: &str, needle: &[char]) -> Vec<std::ops::Range<usize>> {
+    let is_word = |c: char| c.is_ascii_alphanumeric();
+    let mut hits = Vec::new();
+    let chars: Vec<(usize, char)> = hay.char_indices().collect();
+    let mut i = 0;
+    while i + needle.len() <= chars.len() {
+        let matched = needle
+            .iter()
+            .zip(&chars[i..])
+            .all(|(n, (_, c))| n.to_lowercase().eq(c.to_lowercase()));
+        let before_ok = i == 0 || !(is_word(chars[i - 1].1) && is_word(needle[0]));
+        let after = chars.get(i + needle.len()).map(|(_, c)| *c);
+        let after_ok = !matches!(after, Some(c) if is_word(c) && is_word(needle[needle.len() - 1]));
+        if matched && before_ok && after_ok {
+            let start = chars[i].0;
+            let end = after.map_or(hay.len(), |_| chars[i + needle.len()].0);
+            hits.push(start..end);
+            i += needle.len();
+        } else {
+            i += 1;
+        }
+    }
+    hits
+}
+
+/// 拼出 `[[...]]` 的内部文本：`目标锚点|显示名`。
+fn format_link_inner(target: &str, anchor: &str, alias: Option<&str>) -> String {
+    match alias {
+        Some(alias) => format!("{target}{anchor}|{alias}"),
+        None => format!("{target}{anchor}"),
+    }
+}
+
+/// 标题能否直接写作 `[[标题]]` 链接目标（不含链接语法与锚点字符）。
+fn is_link_safe_title(title: &str) -> bool {
+    !title.contains(['|', '#', '^', '[', ']'])
+}
+
+/// 替换正文首行的标题文本，保留 `#` 前缀（空正文 = 写入 `# 标题`）。
+fn replace_title_line(content: &str, title: &str) -> String {
+    let (first, rest) = match content.split_once('\n') {
+        Some((first, rest)) => (first, Some(rest)),
+        None => (content, None),
+    };
+    let hashes = first.trim_start().len() - first.trim_start().trim_start_matches('#').len();
+    let heading = if first.trim().is_empty() || hashes > 0 {
+        format!("{} {}", "#".repeat(hashes.max(1)), title)
+    } else {
+        title.to_string()
+    };
+    match rest {
+        Some(rest) => format!("{heading}\n{rest}"),
+        None => heading,
+    }
+}
+
+/// 拆分链接目标与锚点：`id#标题` → `("id", "#标题")`，`id^块` → `("id", "^块")`。
+///
+/// 标题本身可含 `#`/`^`（如 `C# 入门`）：整段原文指向一篇笔记（`is_note` 为真）
+/// 时不拆；否则从后往前取第一个指向笔记的前缀为目标（`C# 入门#变量`）；都不是
+/// 时按第一个 `#`/`^` 拆。
+pub(crate) fn split_link_anchor(dest: &str, is_note: impl Fn(&str) -> bool) -> (&str, &str) {
+    let Some(first) = dest.find(['#', '^']) else {
+        return (dest, "");
+    };
+    if is_note(dest) {
+        return (dest, "");
+    }
+    let split = dest
+        .match_indices(['#', '^'])
+        .map(|(pos, _)| pos)
+        .rev()
+        .find(|&pos| pos > first && is_note(dest[..pos].trim()))
+        .unwrap_or(first);
+    (dest[..split].trim(), dest[split..].trim())
 }
 
 /// 读取 meta Map 的字符串字段
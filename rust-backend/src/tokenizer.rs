@@ -0,0 +1,222 @@
+//! 全文索引的 CJK 分词器（注册为 FTS5 自定义 tokenizer `cjk_bigram`）。
+//!
+//! trigram 分词下 1–2 个字的中文查询（多数中文词）无法命中索引，只能退回无排序的
+//! LIKE 扫描。本分词器：
+//! - 中日韩文字连续段切为重叠二元组（`所有权` → `所有`、`有权`），每个位置另以
+//!   colocated 方式索引单字，单字查询同样命中；查询侧 ≥2 字只用二元组组成短语
+//! - 其余字母数字按单词切分并转小写；标点与空白为分隔符
+//!
+//! 分词逻辑为纯函数 [`tokenize`]；FFI 回调只负责在 SQLite 与它之间搬运数据。
+
+use anyhow::{bail, Result};
+use rusqlite::{ffi, Connection};
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int, c_void};
+use std::ptr;
+
+/// 注册名：建表时 `tokenize='cjk_bigram'`。
+pub(crate) const TOKENIZER_NAME: &str = "cjk_bigram";
+
+/// 一个词元：小写文本与其在原文中的字节区间（snippet/highlight 据此取原文）。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Token {
+    pub text: String,
+    pub start: usize,
+    pub end: usize,
+    /// 与上一个词元同位置（FTS5_TOKEN_COLOCATED）
+    pub colocated: bool,
+}
+
+/// 中日韩文字（汉字、假名、谚文）：逐字切分而非按空白分词。
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{2E80}'..='\u{2FDF}'
+        | '\u{3040}'..='\u{30FF}'
+        | '\u{3100}'..='\u{31BF}'
+        | '\u{3400}'..='\u{4DBF}'
+        | '\u{4E00}'..='\u{9FFF}'
+        | '\u{AC00}'..='\u{D7AF}'
+        | '\u{F900}'..='\u{FAFF}'
+        | '\u{20000}'..='\u{2FA1F}')
+}
+
+/// 切分文本。`query` = 查询侧：CJK 段 ≥2 字时只产出二元组（短语匹配文档侧的连续位置）。
+pub(crate) fn tokenize(text: &str, query: bool) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    while let Some(&(start, c)) = chars.peek() {
+        if is_cjk(c) {
+            let mut run = Vec::new();
+            while let Some(&(offset, c)) = chars.peek() {
+                if !is_cjk(c) {
+                    break;
+                }
+                run.push((offset, offset + c.len_utf8()));
+                chars.next();
+            }
+            push_cjk_run(text, &run, query, &mut tokens);
+        } else if c.is_alphanumeric() {
+            let mut end = start;
+            while let Some(&(offset, c)) = chars.peek() {
+                if !c.is_alphanumeric() || is_cjk(c) {
+                    break;
+                }
+                end = offset + c.len_utf8();
+                chars.next();
+            }
+            tokens.push(Token {
+                text: text[start..end].to_lowercase(),
+                start,
+                end,
+                colocated: false,
+            });
+        } else {
+            chars.next();
+        }
+    }
+    tokens
+}
+
+/// CJK 连续段：文档侧每个位置 = 二元组（末字无后继时为单字）+ colocated 单字；
+/// 查询侧单字段为单字，否则为二元组序列。
+fn push_cjk_run(text: &str, run: &[(usize, usize)], query: bool, tokens: &mut Vec<Token>) {
+    let token = |start: usize, end: usize, colocated: bool| Token {
+        text: text[start..end].to_string(),
+        start,
+        end,
+        colocated,
+    };
+    if run.len() == 1 {
+        tokens.push(token(run[0].0, run[0].1, false));
+        return;
+    }
+    for (i, &(start, end)) in run.iter().enumerate() {
+        match run.get(i + 1) {
+            Some(&(_, next_end)) => {
+                tokens.push(token(start, next_end, false));
+                if !query {
+                    tokens.push(token(start, end, true));
+                }
+            }
+            None if !query => tokens.push(token(start, end, false)),
+            None => {}
+        }
+    }
+}
+
+/// 在连接上注册 `cjk_bigram`（每个连接建表/查询前都需注册）。
+pub(crate) fn register(conn: &Connection) -> Result<()> {
+    let mut tokenizer = ffi::fts5_tokenizer {
+        xCreate: Some(x_create),
+        xDelete: Some(x_delete),
+        xTokenize: Some(x_tokenize),
+    };
+    let name = CString::new(TOKENIZER_NAME)?;
+    // SAFETY: handle 在 conn 存活期间有效；fts5_api 由 SQLite 持有，xCreateTokenizer
+    // 会复制 tokenizer 结构体，局部变量在调用后释放无妨。
+    unsafe {
+        let api = fts5_api(conn.handle())?;
+        let Some(create) = (*api).xCreateTokenizer else {
+            bail!("fts5_api has no xCreateTokenizer");
+        };
+        let rc = create(api, name.as_ptr(), ptr::null_mut(), &mut tokenizer, None);
+        if rc != ffi::SQLITE_OK {
+            bail!("register fts5 tokenizer failed: {rc}");
+        }
+    }
+    Ok(())
+}
+
+/// 取连接的 fts5_api 指针（官方约定：`SELECT fts5(?1)` 绑定 `fts5_api_ptr` 指针）。
+unsafe fn fts5_api(db: *mut ffi::sqlite3) -> Result<*mut ffi::fts5_api> {
+    let mut api: *mut ffi::fts5_api = ptr::null_mut();
+    let mut stmt: *mut ffi::sqlite3_stmt = ptr::null_mut();
+    if ffi::sqlite3_prepare_v2(
+        db,
+        c"SELECT fts5(?1)".as_ptr(),
+        -1,
+        &mut stmt,
+        ptr::null_mut(),
+    ) != ffi::SQLITE_OK
+    {
+        bail!("fts5 unavailable");
+    }
+    ffi::sqlite3_bind_pointer(
+        stmt,
+        1,
+        &mut api as *mut _ as *mut c_void,
+        c"fts5_api_ptr".as_ptr(),
+        None,
+    );
+    ffi::sqlite3_step(stmt);
+    ffi::sqlite3_finalize(stmt);
+    if api.is_null() {
+        bail!("fts5 unavailable");
+    }
+    Ok(api)
+}
+
+/// 分词器无状态；实例仅作为非空句柄。
+struct CjkBigram;
+
+unsafe extern "C" fn x_create(
+    _user_data: *mut c_void,
+    _args: *mut *const c_char,
+    _n_args: c_int,
+    out: *mut *mut ffi::Fts5Tokenizer,
+) -> c_int {
+    *out = Box::into_raw(Box::new(CjkBigram)) as *mut ffi::Fts5Tokenizer;
+    ffi::SQLITE_OK
+}
+
+unsafe extern "C" fn x_delete(tokenizer: *mut ffi::Fts5Tokenizer) {
+    drop(Box::from_raw(tokenizer as *mut CjkBigram));
+}
+
+type TokenCallback =
+    unsafe extern "C" fn(*mut c_void, c_int, *const c_char, c_int, c_int, c_int) -> c_int;
+
+unsafe extern "C" fn x_tokenize(
+    _tokenizer: *mut ffi::Fts5Tokenizer,
+    ctx: *mut c_void,
+    flags: c_int,
+    text: *const c_char,
+    n_text: c_int,
+    x_token: Option<TokenCallback>,
+) -> c_int {
+    let Some(x_token) = x_token else {
+        return ffi::SQLITE_ERROR;
+    };
+    let bytes = if text.is_null() || n_text <= 0 {
+        &[][..]
+    } else {
+        std::slice::from_raw_parts(text as *const u8, n_text as usize)
+    };
+    // 偏移量回报给 SQLite 须对应原始字节；lossy 替换会改变长度，非法 UTF-8 直接报错
+    let Ok(text) = std::str::from_utf8(bytes) else {
+        return ffi::SQLITE_ERROR;
+    };
+    let query = flags & ffi::FTS5_TOKENIZE_QUERY != 0;
+    let Ok(tokens) = std::panic::catch_unwind(|| tokenize(text, query)) else {
+        return ffi::SQLITE_ERROR;
+    };
+    for token in tokens {
+        let tflags = if token.colocated {
+            ffi::FTS5_TOKEN_COLOCATED
+        } else {
+            0
+        };
+        let rc = x_token(
+            ctx,
+            tflags,
+            token.text.as_ptr() as *const c_char,
+            token.text.len() as c_int,
+            token.start as c_int,
+            token.end as c_int,
+        );
+        if rc != ffi::SQLITE_OK {
+            return rc;
+        }
+    }
+    ffi::SQLITE_OK
+}
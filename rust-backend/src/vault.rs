@@ -0,0 +1,393 @@
+//! 静态加密（可选）：口令派生密钥保护数据目录中的 `cardmind.loro`、更新日志、
+//! `device.key` 与配对设备表。
+//!
+//! 密钥结构：随机 32 字节数据密钥（DEK）加密全部落盘数据；DEK 再由口令经
+//! Argon2id 派生的密钥（KEK）以 XChaCha20-Poly1305 包裹，存于数据目录的
+//! `vault.bin`。改口令只需重新包裹 DEK，不必重写数据文件。
+//!
+//! 加密文件格式（[`VaultKey::seal`]）：`SEAL_MAGIC + nonce(24) + 密文(含 16 字节 tag)`。
+//! 读取时以 magic 区分密文/明文：数据目录一旦提交 `vault.bin`，读到明文即报错
+//! （防止替换为伪造的明文文件）。
+//!
+//! 启用分两步：先写出 `vault.pending` 并把全部数据文件改写为密文，最后把它
+//! rename 为 `vault.bin` 提交。只有 `vault.pending` 时（启用过程中崩溃）仍接受
+//! 明文，下次加载 `SyncService` 时就地改写为密文并完成提交（见 [`commit`]）；
+//! 先打开的 `NoteStore` 只改写投影数据库，不提交。
+//!
+//! 解锁状态为进程级（按数据目录登记）：[`unlock`] 之后
+//! `SyncService::new_persistent` / `NoteStore::open` 才能加载该目录。
+
+use anyhow::{Context, Result};
+use argon2::{Algorithm, Argon2, Params, Version};
+use atomic_write_file::AtomicWriteFile;
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use rand::RngCore;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// 数据目录中的密钥文件名（存在 = 已启用静态加密）。
+pub const VAULT_FILE: &str = "vault.bin";
+/// 启用过程中的密钥文件名（数据文件全部改写为密文后 rename 为 [`VAULT_FILE`]）。
+pub const VAULT_PENDING_FILE: &str = "vault.pending";
+const VAULT_MAGIC: &[u8; 8] = b"CARDVALT";
+const VAULT_VERSION: u32 = 1;
+/// 加密文件/记录标记。
+const SEAL_MAGIC: &[u8; 8] = b"CARDSEAL";
+const KEY_LEN: usize = 32;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+const TAG_LEN: usize = 16;
+/// `vault.bin`：magic + version + (m_cost, t_cost, p_cost) + salt + 包裹后的 DEK。
+const VAULT_FILE_LEN: usize = 8 + 4 + 12 + SALT_LEN + NONCE_LEN + KEY_LEN + TAG_LEN;
+/// 读取 `vault.bin` 时接受的 Argon2 参数上限（m_cost 以 KiB 计，即 1 GiB）：
+/// 损坏或被篡改的文件不能让解锁耗尽内存或长时间运算。
+const MAX_M_COST: u32 = 1024 * 1024;
+const MAX_T_COST: u32 = 16;
+const MAX_P_COST: u32 = 16;
+
+/// 数据目录的加密状态。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VaultStatus {
+    /// 未启用加密（明文数据目录）
+    Disabled,
+    /// 已启用但本进程尚未解锁（加载前需 [`unlock`]）
+    Locked,
+    /// 已解锁，可加载
+    Unlocked,
+}
+
+/// 已解锁的数据密钥（DEK）。克隆共享同一 cipher 与提交状态，不复制密钥字节。
+#[derive(Clone)]
+pub struct VaultKey {
+    cipher: Arc<XChaCha20Poly1305>,
+    /// 来自 `vault.pending`（启用尚未提交）：此时读取仍接受明文
+    pending: Arc<AtomicBool>,
+}
+
+impl VaultKey {
+    fn from_bytes(bytes: &[u8; KEY_LEN], pending: bool) -> Self {
+        Self {
+            cipher: Arc::new(XChaCha20Poly1305::new(Key::from_slice(bytes))),
+            pending: Arc::new(AtomicBool::new(pending)),
+        }
+    }
+
+    /// 启用是否尚未提交（见 [`commit`]）。
+    pub(crate) fn is_pending(&self) -> bool {
+        self.pending.load(Ordering::SeqCst)
+    }
+
+    /// 读到明文数据时调用：仅启用尚未提交时放行。
+    pub(crate) fn check_plaintext(&self) -> Result<()> {
+        if !self.is_pending() {
+            anyhow::bail!("plaintext data in an encrypted data directory");
+        }
+        Ok(())
+    }
+
+    /// 加密：`SEAL_MAGIC + 随机 nonce + 密文`。
+    pub fn seal(&self, plaintext: &[u8]) -> Result<Vec<u8>> {
+        let mut nonce = [0u8; NONCE_LEN];
+        rand::rngs::OsRng.fill_bytes(&mut nonce);
+        let ciphertext = self
+            .cipher
+            .encrypt(XNonce::from_slice(&nonce), plaintext)
+            .map_err(|_| anyhow::anyhow!("encrypt data at rest"))?;
+        let mut out = Vec::with_capacity(SEAL_MAGIC.len() + NONCE_LEN + ciphertext.len());
+        out.extend_from_slice(SEAL_MAGIC);
+        out.extend_from_slice(&nonce);
+        out.extend_from_slice(&ciphertext);
+        Ok(out)
+    }
+
+    /// 解密 [`seal`](Self::seal) 的输出；密钥不符或内容被篡改时报错。
+    pub fn open(&self, sealed: &[u8]) -> Result<Vec<u8>> {
+        if !is_sealed(sealed) || sealed.len() < SEAL_MAGIC.len() + NONCE_LEN + TAG_LEN {
+            anyhow::bail!("invalid sealed data header");
+        }
+        let (nonce, ciphertext) = sealed[SEAL_MAGIC.len()..].split_at(NONCE_LEN);
+        self.cipher
+            .decrypt(XNonce::from_slice(nonce), ciphertext)
+            .map_err(|_| anyhow::anyhow!("decrypt data at rest: wrong key or corrupted data"))
+    }
+}
+
+/// 数据是否为 [`VaultKey::seal`] 输出（以 magic 判定）。
+pub fn is_sealed(bytes: &[u8]) -> bool {
+    bytes.starts_with(SEAL_MAGIC)
+}
+
+/// 落盘前加密：有密钥 → 密文；无密钥（未启用）→ 原样。
+pub(crate) fn seal_at_rest(key: Option<&VaultKey>, plaintext: Vec<u8>) -> Result<Vec<u8>> {
+    match key {
+        Some(key) => key.seal(&plaintext),
+        None => Ok(plaintext),
+    }
+}
+
+/// 读取后解密：密文需要密钥；明文仅在未启用或启用尚未提交时原样返回。
+pub(crate) fn open_at_rest(key: Option<&VaultKey>, bytes: Vec<u8>) -> Result<Vec<u8>> {
+    match (key, is_sealed(&bytes)) {
+        (Some(key), true) => key.open(&bytes),
+        (Some(key), false) => key.check_plaintext().map(|()| bytes),
+        (None, true) => anyhow::bail!("data is encrypted but no vault key is unlocked"),
+        (None, false) => Ok(bytes),
+    }
+}
+
+/// 进程级解锁登记：数据目录 → DEK。
+static UNLOCKED: Mutex<Option<HashMap<PathBuf, VaultKey>>> = Mutex::new(None);
+
+/// 登记用的目录键（存在时取规范路径，同一目录的不同写法视为同一项）。
+fn registry_key(data_dir: &Path) -> PathBuf {
+    data_dir
+        .canonicalize()
+        .unwrap_or_else(|_| data_dir.to_path_buf())
+}
+
+fn register(data_dir: &Path, key: VaultKey) {
+    UNLOCKED
+        .lock()
+        .unwrap()
+        .get_or_insert_with(HashMap::new)
+        .insert(registry_key(data_dir), key);
+}
+
+fn vault_path(data_dir: &Path) -> PathBuf {
+    data_dir.join(VAULT_FILE)
+}
+
+fn pending_path(data_dir: &Path) -> PathBuf {
+    data_dir.join(VAULT_PENDING_FILE)
+}
+
+/// 当前生效的密钥文件：`vault.bin`；只有 `vault.pending`（启用中断）时为后者。
+/// 返回 `(路径, 是否未提交)`。
+fn active_vault_path(data_dir: &Path) -> (PathBuf, bool) {
+    let pending = !vault_path(data_dir).is_file() && pending_path(data_dir).is_file();
+    if pending {
+        (pending_path(data_dir), true)
+    } else {
+        (vault_path(data_dir), false)
+    }
+}
+
+/// 是否已启用（含启用中断、只有 `vault.pending` 的目录）。
+fn has_vault(data_dir: &Path) -> bool {
+    vault_path(data_dir).is_file() || pending_path(data_dir).is_file()
+}
+
+/// 查询数据目录的加密状态（启用中断的目录同样需要解锁）。
+pub fn status(data_dir: impl AsRef<Path>) -> VaultStatus {
+    let data_dir = data_dir.as_ref();
+    if !has_vault(data_dir) {
+        return VaultStatus::Disabled;
+    }
+    let unlocked = UNLOCKED
+        .lock()
+        .unwrap()
+        .as_ref()
+        .is_some_and(|keys| keys.contains_key(&registry_key(data_dir)));
+    if unlocked {
+        VaultStatus::Unlocked
+    } else {
+        VaultStatus::Locked
+    }
+}
+
+/// 加载数据目录前取密钥：未启用 → `None`；已启用未解锁 → Err。
+pub(crate) fn key_for_data_dir(data_dir: &Path) -> Result<Option<VaultKey>> {
+    if !has_vault(data_dir) {
+        return Ok(None);
+    }
+    let key = UNLOCKED
+        .lock()
+        .unwrap()
+        .as_ref()
+        .and_then(|keys| keys.get(&registry_key(data_dir)).cloned());
+    match key {
+        Some(key) => Ok(Some(key)),
+        None => anyhow::bail!(
+            "data directory {} is encrypted; unlock it before loading",
+            data_dir.display()
+        ),
+    }
+}
+
+/// 用口令解锁数据目录（口令错误 → Err，登记不变）。
+pub fn unlock(data_dir: impl AsRef<Path>, passphrase: &str) -> Result<()> {
+    let data_dir = data_dir.as_ref();
+    let (path, pending) = active_vault_path(data_dir);
+    let dek = read_vault_file(&path)?.unwrap_dek(passphrase)?;
+    register(data_dir, VaultKey::from_bytes(&dek, pending));
+    Ok(())
+}
+
+/// 撤销本进程对数据目录的解锁（已加载的服务不受影响，下次加载前需重新解锁）。
+pub fn lock(data_dir: impl AsRef<Path>) {
+    if let Some(keys) = UNLOCKED.lock().unwrap().as_mut() {
+        keys.remove(&registry_key(data_dir.as_ref()));
+    }
+}
+
+/// 修改口令：用旧口令解开 DEK，再以新口令重新包裹（数据文件不变）。启用中断、
+/// 只有 `vault.pending` 的目录同样改写该文件（与 [`unlock`] 选择同一文件）。
+pub fn change_passphrase(
+    data_dir: impl AsRef<Path>,
+    old_passphrase: &str,
+    new_passphrase: &str,
+) -> Result<()> {
+    let (path, _) = active_vault_path(data_dir.as_ref());
+    let dek = read_vault_file(&path)?.unwrap_dek(old_passphrase)?;
+    write_file_atomic(&path, &VaultFile::wrap(&dek, new_passphrase)?.encode())
+}
+
+/// 启用加密第一步：生成 DEK、写出 `vault.pending` 并登记为已解锁（未提交）。
+/// 已启用 → Err；已有 `vault.pending`（上次启用中断）→ 以口令解开并沿用其 DEK。
+///
+/// 写出后数据目录即要求解锁；提交前尚未改写的明文文件仍可读取，因此改写
+/// 中途崩溃不会丢数据。
+pub(crate) fn create(data_dir: &Path, passphrase: &str) -> Result<VaultKey> {
+    if vault_path(data_dir).exists() {
+        anyhow::bail!("encryption is already enabled for {}", data_dir.display());
+    }
+    let pending = pending_path(data_dir);
+    let dek = if pending.is_file() {
+        read_vault_file(&pending)?.unwrap_dek(passphrase)?
+    } else {
+        let mut dek = [0u8; KEY_LEN];
+        rand::rngs::OsRng.fill_bytes(&mut dek);
+        write_file_atomic(&pending, &VaultFile::wrap(&dek, passphrase)?.encode())?;
+        dek
+    };
+    let key = VaultKey::from_bytes(&dek, true);
+    register(data_dir, key.clone());
+    Ok(key)
+}
+
+/// 启用加密最后一步：数据文件全部改写为密文后把 `vault.pending` rename 为
+/// `vault.bin`。此后读到明文即报错。已提交 → 无操作。
+pub(crate) fn commit(data_dir: &Path, key: &VaultKey) -> Result<()> {
+    if !key.is_pending() {
+        return Ok(());
+    }
+    let (pending, path) = (pending_path(data_dir), vault_path(data_dir));
+    std::fs::rename(&pending, &path).with_context(|| {
+        format!(
+            "commit vault file {} -> {}",
+            pending.display(),
+            path.display()
+        )
+    })?;
+    key.pending.store(false, Ordering::SeqCst);
+    Ok(())
+}
+
+/// 原子写出（密文）文件：先写临时文件再 rename，崩溃不留半截。
+pub(crate) fn write_file_atomic(path: &Path, bytes: &[u8]) -> Result<()> {
+    let mut file = AtomicWriteFile::options()
+        .open(path)
+        .with_context(|| format!("open atomic file {}", path.display()))?;
+    std::io::Write::write_all(&mut file, bytes)?;
+    file.commit()
+        .with_context(|| format!("commit atomic file {}", path.display()))
+}
+
+/// `vault.bin` 内容。
+struct VaultFile {
+    m_cost: u32,
+    t_cost: u32,
+    p_cost: u32,
+    salt: [u8; SALT_LEN],
+    /// `nonce + 密文(DEK) + tag`
+    wrapped_dek: Vec<u8>,
+}
+
+impl VaultFile {
+    /// 以口令包裹 DEK（新随机 salt，默认 Argon2id 参数）。
+    fn wrap(dek: &[u8; KEY_LEN], passphrase: &str) -> Result<Self> {
+        if passphrase.is_empty() {
+            anyhow::bail!("passphrase must not be empty");
+        }
+        let mut salt = [0u8; SALT_LEN];
+        rand::rngs::OsRng.fill_bytes(&mut salt);
+        let mut file = Self {
+            m_cost: Params::DEFAULT_M_COST,
+            t_cost: Params::DEFAULT_T_COST,
+            p_cost: Params::DEFAULT_P_COST,
+            salt,
+            wrapped_dek: Vec::new(),
+        };
+        let kek = file.derive_kek(passphrase)?;
+        let sealed = kek.seal(dek)?;
+        file.wrapped_dek = sealed[SEAL_MAGIC.len()..].to_vec();
+        Ok(file)
+    }
+
+    /// 以口令解开 DEK；口令错误时 AEAD 校验失败。
+    fn unwrap_dek(&self, passphrase: &str) -> Result<[u8; KEY_LEN]> {
+        let kek = self.derive_kek(passphrase)?;
+        let mut sealed = SEAL_MAGIC.to_vec();
+        sealed.extend_from_slice(&self.wrapped_dek);
+        let dek = kek
+            .open(&sealed)
+            .map_err(|_| anyhow::anyhow!("wrong passphrase"))?;
+        dek.try_into()
+            .map_err(|_| anyhow::anyhow!("vault data key must be {KEY_LEN} bytes"))
+    }
+
+    fn derive_kek(&self, passphrase: &str) -> Result<VaultKey> {
+        let params = Params::new(self.m_cost, self.t_cost, self.p_cost, Some(KEY_LEN))
+            .map_err(|e| anyhow::anyhow!("invalid Argon2 params: {e}"))?;
+        let mut kek = [0u8; KEY_LEN];
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+            .hash_password_into(passphrase.as_bytes(), &self.salt, &mut kek)
+            .map_err(|e| anyhow::anyhow!("derive key from passphrase: {e}"))?;
+        Ok(VaultKey::from_bytes(&kek, false))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(VAULT_FILE_LEN);
+        bytes.extend_from_slice(VAULT_MAGIC);
+        bytes.extend_from_slice(&VAULT_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&self.m_cost.to_le_bytes());
+        bytes.extend_from_slice(&self.t_cost.to_le_bytes());
+        bytes.extend_from_slice(&self.p_cost.to_le_bytes());
+        bytes.extend_from_slice(&self.salt);
+        bytes.extend_from_slice(&self.wrapped_dek);
+        bytes
+    }
+
+    fn decode(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != VAULT_FILE_LEN || &bytes[..8] != VAULT_MAGIC {
+            anyhow::bail!("invalid vault file magic or length");
+        }
+        let u32_at = |pos: usize| u32::from_le_bytes(bytes[pos..pos + 4].try_into().unwrap());
+        let version = u32_at(8);
+        if version != VAULT_VERSION {
+            anyhow::bail!("unsupported vault file version: {version}");
+        }
+        let (m_cost, t_cost, p_cost) = (u32_at(12), u32_at(16), u32_at(20));
+        if m_cost > MAX_M_COST || t_cost > MAX_T_COST || p_cost > MAX_P_COST {
+            anyhow::bail!(
+                "vault file Argon2 params out of range: m_cost={m_cost}, t_cost={t_cost}, p_cost={p_cost}"
+            );
+        }
+        Ok(Self {
+            m_cost,
+            t_cost,
+            p_cost,
+            salt: bytes[24..24 + SALT_LEN].try_into().unwrap(),
+            wrapped_dek: bytes[24 + SALT_LEN..].to_vec(),
+        })
+    }
+}
+
+fn read_vault_file(path: &Path) -> Result<VaultFile> {
+    let bytes =
+        std::fs::read(path).with_context(|| format!("read vault file {}", path.display()))?;
+    VaultFile::decode(&bytes)
+}
@@ -0,0 +1,132 @@
+//! 分块流式传输集成测试：入站记录受大小上限约束，中断的传输从断点续传。
+//!
+//! 1. B 的单条记录上限小于 A 的大笔记 → 推送被中止、不导入；放宽上限后重试成功
+//! 2. B 拉取时累计上限只够一半 → 已导入的笔记保留，下一轮会话只拉其余部分
+//! 3. 会话头部（版本摘要等 section）同样计入累计上限：超限即中止，不导入
+
+mod common;
+
+use std::time::Duration;
+
+use cardmind_backend::events::SyncEvent;
+use cardmind_backend::store::NoteStore;
+use cardmind_backend::sync::{SyncService, TransferLimits};
+
+use common::{pair_up, rt};
+
+#[test]
+fn test_oversized_record_is_rejected_until_limit_allows_it() {
+    rt().block_on(async {
+        let (mut a, a_store, b, b_store) = pair_up(
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+        )
+        .await;
+        b.start_receiver(b_store.clone()).await.unwrap();
+        b.set_transfer_limits(TransferLimits {
+            max_record_bytes: 4 * 1024,
+            ..TransferLimits::default()
+        });
+
+        // 不可压缩的正文：LZ4 压缩后仍远超 4 KiB 的单条上限
+        let body: String = (0..8192u32)
+            .map(|i| format!("{:08x}", i.wrapping_mul(2_654_435_761).rotate_left(i % 32)))
+            .collect();
+        let content = format!("# 大笔记\n\n{body}");
+        a.create_note("big".into(), &content).unwrap();
+        let results = a.push_pending(&a_store).await;
+        assert_eq!(results.len(), 1);
+        assert!(!results[0].ok, "超限记录不应算送达: {results:?}");
+        assert_eq!(results[0].pending_count, 1);
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert!(b.get_note("big").is_none(), "超限记录不应导入");
+
+        // 放宽上限后重试：整条记录送达
+        b.set_transfer_limits(TransferLimits::default());
+        a.reset_sync_backoff();
+        let results = a.push_pending(&a_store).await;
+        assert!(results[0].ok, "放宽上限后应送达: {results:?}");
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert_eq!(b.get_note("big").as_deref(), Some(content.as_str()));
+        b.stop_receiver().await.unwrap();
+    });
+}
+
+#[test]
+fn test_interrupted_session_resumes_where_it_stopped() {
+    rt().block_on(async {
+        let (mut b, b_store, mut a, a_store) = pair_up(
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+        )
+        .await;
+        for i in 0..100 {
+            let content = format!("# 笔记 {i}\n\n{}", "内容".repeat(500));
+            a.create_note(format!("n{i:03}"), &content).unwrap();
+        }
+        a.start_receiver(a_store.clone()).await.unwrap();
+
+        // B 的累计上限只够约一半：会话中断，已收到的笔记保留
+        let half = a.export_all().unwrap().len() as u64 / 2;
+        b.set_transfer_limits(TransferLimits {
+            max_transfer_bytes: half,
+            ..TransferLimits::default()
+        });
+        let cycle = b.run_sync_cycle(&b_store).await.unwrap();
+        assert_eq!(cycle.pushed_count, 0, "超限会话不应算成功");
+        let partial = b.iter_notes().len();
+        assert!(
+            partial > 0 && partial < 100,
+            "中断前已导入的笔记应保留，实际 {partial}"
+        );
+        assert_eq!(b_store.list_notes().unwrap().len(), partial);
+
+        // 放宽上限后下一轮会话只拉取其余部分
+        b.set_transfer_limits(TransferLimits::default());
+        b.reset_sync_backoff();
+        let mut events = b.subscribe_events();
+        let cycle = b.run_sync_cycle(&b_store).await.unwrap();
+        assert_eq!(cycle.pushed_count, 1);
+        assert_eq!(b.iter_notes().len(), 100);
+        let mut pulled = None;
+        while let Ok(event) = events.try_recv() {
+            if let SyncEvent::SyncFinished { pulled_count, .. } = event {
+                pulled = Some(pulled_count as usize);
+            }
+        }
+        assert_eq!(pulled, Some(100 - partial), "只应重传中断后缺失的笔记");
+        a.stop_receiver().await.unwrap();
+    });
+}
+
+#[test]
+fn test_session_sections_count_against_transfer_limit() {
+    rt().block_on(async {
+        let (mut b, b_store, mut a, a_store) = pair_up(
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+        )
+        .await;
+        for i in 0..20 {
+            a.create_note(format!("n{i:03}"), &format!("# 笔记 {i}"))
+                .unwrap();
+        }
+        a.start_receiver(a_store.clone()).await.unwrap();
+
+        // 累计上限小于 A 的版本摘要：读会话头部即中止
+        b.set_transfer_limits(TransferLimits {
+            max_transfer_bytes: 64,
+            ..TransferLimits::default()
+        });
+        let cycle = b.run_sync_cycle(&b_store).await.unwrap();
+        assert_eq!(cycle.pushed_count, 0, "头部超限的会话不应算成功");
+        assert!(b.iter_notes().is_empty(), "头部超限时不应导入任何笔记");
+        a.stop_receiver().await.unwrap();
+    });
+}
@@ -0,0 +1,71 @@
+//! 中文全文搜索集成测试（FTS5 `cjk_bigram` 分词）。
+//!
+//! 1. 单字、双字中文查询走 FTS：按 bm25 排序、snippet 取匹配上下文；短语不跨词误配
+//! 2. 旧库的 trigram 索引在打开时迁移为新分词器并重建
+
+use cardmind_backend::store::NoteStore;
+use cardmind_backend::sync::NoteCrdt;
+use rusqlite::Connection;
+
+fn sync(store: &NoteStore, id: &str, content: &str) {
+    let note = NoteCrdt::new();
+    note.set_content(content);
+    store.sync_note(id, &note).unwrap();
+}
+
+#[test]
+fn test_short_cjk_queries_are_ranked() {
+    let store = NoteStore::new(":memory:").unwrap();
+    let long = format!("# 杂记\n\n{}顺便写了周报", "排查线上问题。".repeat(15));
+    sync(&store, "once", &long);
+    sync(&store, "many", "# 周报\n\n周报模板：本周周报要点");
+    sync(&store, "none", "# 会议\n\n报销流程与周末安排");
+
+    let results = store.search_notes("周报").unwrap();
+    let ids: Vec<&str> = results.iter().map(|r| r.id.as_str()).collect();
+    assert_eq!(
+        ids,
+        vec!["many", "once"],
+        "按 bm25 排序，不匹配“周末”“报销”"
+    );
+    assert!(
+        results[1].content_preview.contains("周报"),
+        "preview 为匹配处的 snippet 而非正文开头"
+    );
+
+    let results = store.search_notes("销").unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].id, "none", "单字查询命中");
+}
+
+#[test]
+fn test_trigram_index_is_migrated() {
+    let path = std::env::temp_dir().join(format!("cardmind-cjk-{}.db", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    {
+        let conn = Connection::open(&path).unwrap();
+        conn.execute_batch(
+            "CREATE TABLE notes (
+                id TEXT PRIMARY KEY, title TEXT NOT NULL, content TEXT NOT NULL,
+                tags TEXT NOT NULL DEFAULT '', created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL, deleted_at TEXT NULL,
+                title_key TEXT NOT NULL DEFAULT ''
+            );
+            CREATE VIRTUAL TABLE notes_fts USING fts5(
+                title, content, tags, content='notes', content_rowid='rowid',
+                tokenize='trigram'
+            );
+            INSERT INTO notes VALUES ('n1', '周报', '# 周报\n\n本周进展', '',
+                '2026-01-01T00:00:00Z', '2026-01-01T00:00:00Z', NULL, '周报');
+            INSERT INTO notes_fts(notes_fts) VALUES('rebuild');",
+        )
+        .unwrap();
+    }
+
+    let store = NoteStore::new(path.to_str().unwrap()).unwrap();
+    let results = store.search_notes("进展").unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].id, "n1");
+    drop(store);
+    let _ = std::fs::remove_file(&path);
+}
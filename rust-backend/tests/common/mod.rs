@@ -0,0 +1,66 @@
+//! 集成测试共用夹具：tokio 运行时、临时数据目录与真实配对的两台设备。
+
+#![allow(dead_code)]
+
+use cardmind_backend::store::NoteStore;
+use cardmind_backend::sync::{PairingTarget, SyncService};
+
+pub fn rt() -> tokio::runtime::Runtime {
+    tokio::runtime::Runtime::new().unwrap()
+}
+
+/// 清空并新建临时数据目录（按测试二进制的进程号区分）。
+pub fn temp_dir(label: &str) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(format!("cardmind-{label}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&path);
+    std::fs::create_dir_all(&path).unwrap();
+    path
+}
+
+/// 真实配对两个 SyncService（initiator ↔ confirmer），双方 paired_devices 互相包含。
+pub async fn pair_up(
+    initiator: SyncService,
+    initiator_store: NoteStore,
+    mut confirmer: SyncService,
+    confirmer_store: NoteStore,
+) -> (SyncService, NoteStore, SyncService, NoteStore) {
+    let code = confirmer.begin_pairing_accept().unwrap();
+    let target = PairingTarget {
+        device_id: confirmer.device_id(),
+        ips: confirmer.local_addrs(),
+        nonce: confirmer.session_nonce_hex(),
+    };
+    assert!(!target.ips.is_empty(), "确认方应至少有一个本地 IPv4 地址");
+
+    let confirmer_code = code.clone();
+    let confirmer_handle = tokio::spawn(async move {
+        let request = confirmer
+            .accept_pairing_request()
+            .await
+            .expect("confirmer accept pairing request");
+        let result = confirmer
+            .confirm_pairing(&confirmer_store, &confirmer_code, &request)
+            .await
+            .expect("confirmer confirm pairing");
+        (confirmer, confirmer_store, result)
+    });
+
+    let mut initiator = initiator;
+    let initiator_handle = tokio::spawn(async move {
+        let result = initiator
+            .begin_pairing_connect(&initiator_store, &code, target)
+            .await
+            .expect("initiator connect pairing");
+        // 导入确认方首次全量同步推送（决策 8）
+        let data = initiator.accept_push().await.unwrap();
+        initiator.import_all(&data).unwrap();
+        (initiator, initiator_store, result)
+    });
+
+    let (confirmer, confirmer_store, confirm_result) = confirmer_handle.await.unwrap();
+    let (initiator, initiator_store, connect_result) = initiator_handle.await.unwrap();
+    assert_eq!(confirm_result.peer_id, initiator.device_id());
+    assert_eq!(connect_result.peer_id, confirmer.device_id());
+
+    (initiator, initiator_store, confirmer, confirmer_store)
+}
@@ -0,0 +1,103 @@
+//! LZ4 压缩集成测试：本地快照写出压缩 envelope（v4 起），旧版 v3 文件仍可载入，
+//! 声明原始长度超出压缩比上限的 envelope 在分配内存前拒绝。
+//!
+//! 同步线路上的记录体压缩由各同步集成测试（配对、推送、会话）共同覆盖：
+//! 双方协商启用压缩后记录体经 LZ4 编码传输。
+
+mod common;
+
+use cardmind_backend::sync::{NoteCrdt, SyncService};
+
+use common::{rt, temp_dir};
+
+/// 临时数据目录（测试结束清理）
+#[test]
+fn test_snapshot_envelope_is_compressed() {
+    rt().block_on(async {
+        let dir = temp_dir("lz4");
+        let content = format!(
+            "# 周报\n\n{}",
+            "- 完成同步模块的联调与回归测试\n".repeat(2000)
+        );
+        let mut svc = SyncService::new_persistent(&dir).await.unwrap();
+        svc.create_note("report".into(), &content).unwrap();
+        drop(svc);
+
+        let bytes = std::fs::read(dir.join("cardmind.loro")).unwrap();
+        assert_eq!(&bytes[..8], b"CARDMIND");
+        assert_eq!(u32::from_le_bytes(bytes[8..12].try_into().unwrap()), 5);
+        let stored_len = u64::from_le_bytes(bytes[12..20].try_into().unwrap());
+        assert_eq!(stored_len, bytes.len() as u64 - 20);
+        let raw_len = u64::from_le_bytes(bytes[20..28].try_into().unwrap());
+        assert!(
+            stored_len * 2 < raw_len,
+            "Markdown 快照应明显压缩：{stored_len} / {raw_len}"
+        );
+
+        let restored = SyncService::new_persistent(&dir).await.unwrap();
+        assert_eq!(
+            restored.get_note("report").as_deref(),
+            Some(content.as_str())
+        );
+        let _ = std::fs::remove_dir_all(dir);
+    });
+}
+
+#[test]
+fn test_v3_envelope_still_loads() {
+    rt().block_on(async {
+        let dir = temp_dir("v3");
+        // 手工构造 v3 envelope：magic + version=3 + 未压缩 payload（墓碑 section + 记录流）
+        let note = NoteCrdt::new();
+        note.set_content("# V3\n\n旧格式正文");
+        let snapshot = note.export_snapshot().unwrap();
+        let mut payload = Vec::new();
+        payload.extend_from_slice(&1u32.to_le_bytes());
+        payload.extend_from_slice(&5u32.to_le_bytes());
+        payload.extend_from_slice(b"ghost");
+        payload.extend_from_slice(&7u32.to_le_bytes());
+        payload.extend_from_slice(b"v3-note");
+        payload.extend_from_slice(&(snapshot.len() as u32).to_le_bytes());
+        payload.extend_from_slice(&snapshot);
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(b"CARDMIND");
+        bytes.extend_from_slice(&3u32.to_le_bytes());
+        bytes.extend_from_slice(&(payload.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(&payload);
+        std::fs::write(dir.join("cardmind.loro"), &bytes).unwrap();
+
+        let svc = SyncService::new_persistent(&dir).await.unwrap();
+        assert_eq!(
+            svc.get_note("v3-note").as_deref(),
+            Some("# V3\n\n旧格式正文"),
+            "v3 数据完整（无需迁移）"
+        );
+        assert!(svc.tombstones().contains("ghost"), "v3 墓碑 section 保留");
+        let _ = std::fs::remove_dir_all(dir);
+    });
+}
+
+#[test]
+fn test_oversized_raw_len_is_rejected() {
+    rt().block_on(async {
+        let dir = temp_dir("raw-len");
+        let block = [0u8; 16];
+        let mut stored = u64::MAX.to_le_bytes().to_vec();
+        stored.extend_from_slice(&block);
+        let mut bytes = b"CARDMIND".to_vec();
+        bytes.extend_from_slice(&5u32.to_le_bytes());
+        bytes.extend_from_slice(&(stored.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(&stored);
+        std::fs::write(dir.join("cardmind.loro"), bytes).unwrap();
+
+        let err = SyncService::new_persistent(&dir)
+            .await
+            .err()
+            .expect("伪造的原始长度应拒绝加载");
+        assert!(
+            format!("{err:#}").contains("decompressed length"),
+            "unexpected error: {err:#}"
+        );
+        let _ = std::fs::remove_dir_all(dir);
+    });
+}
@@ -0,0 +1,84 @@
+//! 并发编辑冲突集成测试：两台设备离线编辑同一笔记，导入时记录冲突并可处理。
+//!
+//! 1. A、B 基于同一版本各自改写正文 → A 导入 B 的变更后记录冲突，两侧正文可见；
+//!    选择本端一侧后正文恢复为 A 的版本，冲突清除
+//! 2. 只有对端编辑（本端无未同步变更）→ 快进合并，不记录冲突
+//! 3. 冲突记录之后 payload 截断、导入失败 → 笔记与冲突记录一并回滚
+
+mod common;
+
+use cardmind_backend::sync::{ConflictResolution, SyncService};
+
+use common::rt;
+
+#[test]
+fn test_concurrent_edits_are_recorded_and_resolvable() {
+    rt().block_on(async {
+        let mut a = SyncService::new().await.unwrap();
+        let mut b = SyncService::new().await.unwrap();
+        a.create_note("n1".into(), "# 会议\n\n原始议程").unwrap();
+        b.import_all(&a.export_all().unwrap()).unwrap();
+
+        // 离线期间两端各自改写同一段
+        a.update_note("n1", "# 会议\n\nA 的议程").unwrap();
+        b.update_note("n1", "# 会议\n\nB 的议程").unwrap();
+        a.import_all(&b.export_all().unwrap()).unwrap();
+
+        let conflicts = a.list_conflicts();
+        assert_eq!(conflicts.len(), 1, "应记录一条冲突");
+        let detail = &conflicts[0];
+        assert_eq!(detail.conflict.note_id, "n1");
+        assert_eq!(detail.conflict.local_devices, vec![a.device_id()]);
+        assert_eq!(detail.conflict.remote_devices, vec![b.device_id()]);
+        assert_eq!(detail.base_content, "# 会议\n\n原始议程");
+        assert_eq!(detail.local_content, "# 会议\n\nA 的议程");
+        assert_eq!(detail.remote_content, "# 会议\n\nB 的议程");
+        assert_eq!(detail.merged_content, a.get_note("n1").unwrap());
+
+        a.resolve_conflict("n1", ConflictResolution::KeepLocal)
+            .unwrap();
+        assert_eq!(a.get_note("n1").as_deref(), Some("# 会议\n\nA 的议程"));
+        assert!(a.list_conflicts().is_empty());
+        assert!(
+            a.resolve_conflict("n1", ConflictResolution::KeepMerged)
+                .is_err(),
+            "已处理的冲突不能再次处理"
+        );
+    });
+}
+
+#[test]
+fn test_fast_forward_import_records_no_conflict() {
+    rt().block_on(async {
+        let mut a = SyncService::new().await.unwrap();
+        let mut b = SyncService::new().await.unwrap();
+        a.create_note("n1".into(), "# 计划\n\n第一版").unwrap();
+        b.import_all(&a.export_all().unwrap()).unwrap();
+
+        b.update_note("n1", "# 计划\n\n第二版").unwrap();
+        a.import_all(&b.export_all().unwrap()).unwrap();
+
+        assert_eq!(a.get_note("n1").as_deref(), Some("# 计划\n\n第二版"));
+        assert!(a.list_conflicts().is_empty(), "快进合并不是冲突");
+    });
+}
+
+#[test]
+fn test_failed_import_rolls_back_conflict_records() {
+    rt().block_on(async {
+        let mut a = SyncService::new().await.unwrap();
+        let mut b = SyncService::new().await.unwrap();
+        a.create_note("n1".into(), "# 会议\n\n原始议程").unwrap();
+        b.import_all(&a.export_all().unwrap()).unwrap();
+
+        a.update_note("n1", "# 会议\n\nA 的议程").unwrap();
+        b.update_note("n1", "# 会议\n\nB 的议程").unwrap();
+        let mut payload = b.export_all().unwrap();
+        // 冲突记录之后跟一条截断的记录
+        payload.extend_from_slice(&[1, 0]);
+        assert!(a.import_all(&payload).is_err());
+
+        assert_eq!(a.get_note("n1").as_deref(), Some("# 会议\n\nA 的议程"));
+        assert!(a.list_conflicts().is_empty(), "失败的导入不应留下冲突记录");
+    });
+}
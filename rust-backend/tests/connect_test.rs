@@ -19,6 +19,15 @@ fn temp_dir(label: &str) -> std::path::PathBuf {
     path
 }
 
+/// 让 `service` 信任 `peers`（入站同步帧须来自已配对设备）。
+fn trust(service: &SyncService, peers: &[String]) {
+    let store = NoteStore::new(":memory:").unwrap();
+    for peer in peers {
+        store.upsert_paired_device(peer, "peer").unwrap();
+    }
+    service.sync_notes_to_store(&store).unwrap();
+}
+
 // ━━━ 验收 1：设备身份持久化 ━━━
 
 #[test]
@@ -129,6 +138,7 @@ fn test_push_receive_roundtrip_relay_or_direct() {
         let b_id = b.device_id();
         let b_ips = b.local_addrs();
         assert!(!b_ips.is_empty(), "B 应至少有一个本地 IPv4 地址");
+        trust(&b, &[a.device_id()]);
 
         // B 进入接收态
         let b_handle = tokio::spawn(async move {
@@ -166,6 +176,8 @@ fn test_push_multi_device_partial_failure() {
         let c_id = c.device_id();
         let b_ips = b.local_addrs();
         let c_ips = c.local_addrs();
+        trust(&b, &[a.device_id()]);
+        trust(&c, &[a.device_id()]);
 
         // B、C 真实设备进入接收态
         let b_handle = tokio::spawn(async move {
@@ -535,9 +535,11 @@ fn test_initial_sync_emits_counts_only() {
                     .expect("accept_push 应在 15s 内返回")
                     .unwrap();
             initiator.import_all(&data).unwrap();
+            // 保持 endpoint 存活：导入后的确认须送达确认方
+            initiator
         });
         confirmer_handle.await.unwrap();
-        initiator_handle.await.unwrap();
+        let _initiator = initiator_handle.await.unwrap();
 
         // 发起方侧：应收到推送/导入事件，且只含数量与耗时，不含正文
         let events_b = sink_b.snapshot();
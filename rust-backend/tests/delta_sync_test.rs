@@ -0,0 +1,135 @@
+//! 增量同步集成测试：推送前交换各笔记版本向量，只发送对端缺失的 Loro 更新。
+//!
+//! 1. 对端已是最新 → 增量只含墓碑 section，不含任何笔记记录
+//! 2. 单篇编辑 → 增量只含该篇，体积远小于全量快照
+//! 3. 增量导入合并进已有文档，不覆盖接收端未同步的本地笔记
+//! 4. 网络路径：`push_pending` 经双向流握手后只推增量，对端导入可见
+
+mod common;
+
+use cardmind_backend::store::NoteStore;
+use cardmind_backend::sync::SyncService;
+
+use common::{pair_up, rt};
+
+#[test]
+fn test_delta_empty_when_peer_up_to_date() {
+    rt().block_on(async {
+        let mut a = SyncService::new().await.unwrap();
+        let mut b = SyncService::new().await.unwrap();
+        a.create_note("n1".into(), "# 一\n\n内容一").unwrap();
+        a.create_note("n2".into(), "# 二\n\n内容二").unwrap();
+        b.import_all(&a.export_all().unwrap()).unwrap();
+
+        let delta = a.export_delta(&b.version_digest()).unwrap();
+        assert_eq!(
+            delta,
+            vec![0u8, 0, 0, 0],
+            "对端已是最新时增量只含空墓碑 section"
+        );
+    });
+}
+
+#[test]
+fn test_delta_contains_only_changed_note() {
+    rt().block_on(async {
+        let mut a = SyncService::new().await.unwrap();
+        let mut b = SyncService::new().await.unwrap();
+        let long_body = "长正文。".repeat(500);
+        for i in 0..5 {
+            a.create_note(format!("n{i}"), &format!("# 笔记 {i}\n\n{long_body}"))
+                .unwrap();
+        }
+        b.import_all(&a.export_all().unwrap()).unwrap();
+
+        a.update_note("n3", &format!("# 笔记 3（改）\n\n{long_body}"))
+            .unwrap();
+        let full = a.export_all().unwrap();
+        let delta = a.export_delta(&b.version_digest()).unwrap();
+        assert!(
+            delta.len() * 3 < full.len(),
+            "单篇编辑的增量应远小于全量：delta={} full={}",
+            delta.len(),
+            full.len()
+        );
+
+        b.import_all(&delta).unwrap();
+        assert_eq!(b.get_note("n3"), a.get_note("n3"));
+        assert_eq!(b.get_note("n0"), a.get_note("n0"), "未改笔记不受影响");
+    });
+}
+
+#[test]
+fn test_delta_import_keeps_local_unsynced_notes() {
+    rt().block_on(async {
+        let mut a = SyncService::new().await.unwrap();
+        let mut b = SyncService::new().await.unwrap();
+        a.create_note("shared".into(), "# 共享\n\nv1").unwrap();
+        b.import_all(&a.export_all().unwrap()).unwrap();
+
+        // B 离线新建笔记；A 编辑共享笔记
+        b.create_note("b-only".into(), "# B 本地\n\n未同步")
+            .unwrap();
+        a.update_note("shared", "# 共享\n\nv2").unwrap();
+
+        let delta = a.export_delta(&b.version_digest()).unwrap();
+        b.import_all(&delta).unwrap();
+        assert_eq!(b.get_note("shared").as_deref(), Some("# 共享\n\nv2"));
+        assert_eq!(
+            b.get_note("b-only").as_deref(),
+            Some("# B 本地\n\n未同步"),
+            "增量导入不得丢弃接收端本地笔记"
+        );
+    });
+}
+
+#[test]
+fn test_push_pending_sends_delta_over_network() {
+    rt().block_on(async {
+        let (mut a, a_store, mut b, b_store) = pair_up(
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+        )
+        .await;
+
+        let long_body = "增量同步正文。".repeat(400);
+        a.create_note("n1".into(), &format!("# 一\n\n{long_body}"))
+            .unwrap();
+        a.create_note("n2".into(), &format!("# 二\n\n{long_body}"))
+            .unwrap();
+
+        // 第一轮：B 没有任何笔记 → 收到两篇完整记录
+        let b_handle = tokio::spawn(async move {
+            let data = b.accept_push().await.unwrap();
+            b.import_all(&data).unwrap();
+            b
+        });
+        let results = a.push_pending(&a_store).await;
+        assert!(results.iter().any(|r| r.ok), "推送应成功: {results:?}");
+        let mut b = b_handle.await.unwrap();
+        assert_eq!(b.get_note("n1"), a.get_note("n1"));
+        assert_eq!(b.get_note("n2"), a.get_note("n2"));
+
+        // 第二轮：只改 n2 → B 收到的数据远小于全量快照
+        a.update_note("n2", &format!("# 二（改）\n\n{long_body}"))
+            .unwrap();
+        let b_handle = tokio::spawn(async move {
+            let data = b.accept_push().await.unwrap();
+            let received = data.len();
+            b.import_all(&data).unwrap();
+            (b, received)
+        });
+        let results = a.push_pending(&a_store).await;
+        assert!(results.iter().any(|r| r.ok), "推送应成功: {results:?}");
+        let (b, received) = b_handle.await.unwrap();
+        let full = a.export_all().unwrap().len();
+        assert!(
+            received * 3 < full,
+            "第二轮应只推增量：received={received} full={full}"
+        );
+        assert_eq!(b.get_note("n2"), a.get_note("n2"));
+        drop((b, b_store, a_store));
+    });
+}
@@ -0,0 +1,105 @@
+//! 设备撤销集成测试：入站同步帧按配对设备表鉴权，撤销记录签名后随同步会话传播。
+//!
+//! 1. 未配对设备的推送在导入前被拒，并输出 `sync.reject`（reason=unknown）
+//! 2. B 撤销 C → B 与 A 同步一次后 A 也撤销 C；C 再向 A 推送被拒（reason=revoked）
+
+mod common;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use cardmind_backend::debug_log::{CollectingSink, LogEvent};
+use cardmind_backend::store::NoteStore;
+use cardmind_backend::sync::SyncService;
+
+use common::{pair_up, rt};
+
+fn rejections(sink: &CollectingSink, reason: &str) -> Vec<LogEvent> {
+    sink.snapshot()
+        .into_iter()
+        .filter(|e| e.event == "sync.reject")
+        .filter(|e| e.fields.iter().any(|(k, v)| k == "reason" && v == reason))
+        .collect()
+}
+
+#[test]
+fn test_unpaired_push_is_rejected_and_logged() {
+    rt().block_on(async {
+        let sink = Arc::new(CollectingSink::new());
+        let mut stranger = SyncService::new().await.unwrap();
+        let b = SyncService::new_with_log_sink(sink.clone()).await.unwrap();
+        let b_store = NoteStore::new(":memory:").unwrap();
+        b.start_receiver(b_store.clone()).await.unwrap();
+
+        stranger
+            .create_note("n1".into(), "# 陌生设备\n\n不应被导入")
+            .unwrap();
+        // 接收方拒绝后关闭连接；推送方结果不重要，关键是接收方不导入
+        let _ = stranger.push_to_peer(&b.device_id(), b.local_addrs()).await;
+        tokio::time::sleep(Duration::from_millis(500)).await;
+
+        assert!(b.get_note("n1").is_none(), "未配对设备的推送不得导入");
+        assert!(
+            !rejections(&sink, "unknown").is_empty(),
+            "应输出 sync.reject（reason=unknown）"
+        );
+        b.stop_receiver().await.unwrap();
+    });
+}
+
+#[test]
+fn test_revocation_propagates_and_blocks_revoked_device() {
+    rt().block_on(async {
+        let a_sink = Arc::new(CollectingSink::new());
+        let (a, a_store, b, b_store) = pair_up(
+            SyncService::new_with_log_sink(a_sink.clone())
+                .await
+                .unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+        )
+        .await;
+        let (a, a_store, c, c_store) = pair_up(
+            a,
+            a_store,
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+        )
+        .await;
+        let (mut b, b_store, mut c, c_store) = pair_up(b, b_store, c, c_store).await;
+        let c_id = c.device_id();
+        assert!(a_store.is_paired_device(&c_id).unwrap());
+
+        // B 撤销 C：本机立即生效
+        b.revoke_device(&b_store, &c_id).unwrap();
+        assert!(b_store.is_revoked_device(&c_id).unwrap());
+        assert!(!b_store.is_paired_device(&c_id).unwrap());
+        let record = b_store.list_revoked_devices().unwrap().remove(0);
+        assert_eq!(record.revoked_by, b.device_id());
+
+        // B 与 A 同步一次 → 撤销记录传播到 A
+        a.start_receiver(a_store.clone()).await.unwrap();
+        let cycle = b.run_sync_cycle(&b_store).await.unwrap();
+        assert_eq!(cycle.pushed_count, 1, "B 只剩 A 一台配对设备");
+        assert!(
+            a_store.is_revoked_device(&c_id).unwrap(),
+            "A 应接受 B 签名的撤销记录"
+        );
+        assert!(!a_store.is_paired_device(&c_id).unwrap());
+        assert_eq!(a_store.list_revoked_devices().unwrap(), vec![record]);
+
+        // C 再向 A 推送 → 导入前被拒
+        c.create_note("c1".into(), "# 被撤销设备\n\n不应同步")
+            .unwrap();
+        let _ = c.push_to_peer(&a.device_id(), a.local_addrs()).await;
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        assert!(a.get_note("c1").is_none(), "被撤销设备的推送不得导入");
+        assert!(
+            !rejections(&a_sink, "revoked").is_empty(),
+            "应输出 sync.reject（reason=revoked）"
+        );
+        a.stop_receiver().await.unwrap();
+        drop((b_store, c_store));
+    });
+}
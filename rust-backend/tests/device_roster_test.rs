@@ -0,0 +1,120 @@
+//! 设备名册集成测试：配对一台设备即加入整个设备网。
+//!
+//! 1. A 分别与 B、C 配对（B、C 互不配对）→ A 同步一轮后 B、C 经名册互相认识
+//!    （node id + 设备名）→ B 的同步周期直接推送到 C，C 接受并导入。
+//! 2. A 移除 C → 移除记录随名册传播，B 同样移除 C，名册不再把 C 加回。
+
+mod common;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use cardmind_backend::debug_log::CollectingSink;
+use cardmind_backend::store::NoteStore;
+use cardmind_backend::sync::SyncService;
+
+use common::{pair_up, rt};
+
+async fn named(name: &str) -> SyncService {
+    let svc = SyncService::new().await.unwrap();
+    svc.set_device_name(name);
+    svc
+}
+
+#[test]
+fn test_pairing_one_member_trusts_whole_mesh() {
+    rt().block_on(async {
+        let (a, a_store, mut b, b_store) = pair_up(
+            named("desktop").await,
+            NoteStore::new(":memory:").unwrap(),
+            named("laptop").await,
+            NoteStore::new(":memory:").unwrap(),
+        )
+        .await;
+        let c_sink = Arc::new(CollectingSink::new());
+        let c = SyncService::new_with_log_sink(c_sink.clone())
+            .await
+            .unwrap();
+        c.set_device_name("phone");
+        let (mut a, a_store, c, c_store) =
+            pair_up(a, a_store, c, NoteStore::new(":memory:").unwrap()).await;
+        let (b_id, c_id) = (b.device_id(), c.device_id());
+        assert!(!b_store.is_paired_device(&c_id).unwrap());
+        assert!(!c_store.is_paired_device(&b_id).unwrap());
+
+        // A 同步一轮：名册随会话传播到 B 与 C
+        b.start_receiver(b_store.clone()).await.unwrap();
+        c.start_receiver(c_store.clone()).await.unwrap();
+        let cycle = a.run_sync_cycle(&a_store).await.unwrap();
+        assert_eq!(cycle.pushed_count, 2);
+        let b_devices = b_store.list_paired_devices().unwrap();
+        let phone = b_devices
+            .iter()
+            .find(|d| d.peer_id == c_id)
+            .expect("B 应经名册认识 C");
+        assert_eq!(phone.name, "phone");
+        let c_devices = c_store.list_paired_devices().unwrap();
+        let laptop = c_devices
+            .iter()
+            .find(|d| d.peer_id == b_id)
+            .expect("C 应经名册认识 B");
+        assert_eq!(laptop.name, "laptop");
+        assert!(c_sink.snapshot().iter().any(|e| e.event == "device.roster"
+            && e.fields.iter().any(|(k, v)| k == "action" && v == "joined")));
+
+        // B 的同步周期推送到全部成员（A 与 C），C 接受 B 的会话
+        a.start_receiver(a_store.clone()).await.unwrap();
+        b.create_note("b1".into(), "# 来自笔记本\n\n经设备网同步")
+            .unwrap();
+        let cycle = b.run_sync_cycle(&b_store).await.unwrap();
+        assert_eq!(cycle.pushed_count, 2, "B 应与 A、C 都完成同步会话");
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        assert_eq!(
+            c.get_note("b1").as_deref(),
+            Some("# 来自笔记本\n\n经设备网同步")
+        );
+        a.stop_receiver().await.unwrap();
+        b.stop_receiver().await.unwrap();
+        c.stop_receiver().await.unwrap();
+    });
+}
+
+#[test]
+fn test_removed_device_leaves_whole_mesh() {
+    rt().block_on(async {
+        let (a, a_store, b, b_store) = pair_up(
+            named("desktop").await,
+            NoteStore::new(":memory:").unwrap(),
+            named("laptop").await,
+            NoteStore::new(":memory:").unwrap(),
+        )
+        .await;
+        let (mut a, a_store, c, c_store) = pair_up(
+            a,
+            a_store,
+            named("phone").await,
+            NoteStore::new(":memory:").unwrap(),
+        )
+        .await;
+        let c_id = c.device_id();
+        b.start_receiver(b_store.clone()).await.unwrap();
+        c.start_receiver(c_store.clone()).await.unwrap();
+        a.run_sync_cycle(&a_store).await.unwrap();
+        assert!(b_store.is_paired_device(&c_id).unwrap());
+
+        a.remove_paired_device(&a_store, &c_id).unwrap();
+        assert!(!a_store.is_paired_device(&c_id).unwrap());
+        let cycle = a.run_sync_cycle(&a_store).await.unwrap();
+        assert_eq!(cycle.pushed_count, 1, "只同步仍配对的 B");
+        assert!(
+            !a_store.is_paired_device(&c_id).unwrap(),
+            "名册不应把已移除的设备加回"
+        );
+        assert!(
+            !b_store.is_paired_device(&c_id).unwrap(),
+            "B 应随移除记录移除 C"
+        );
+        b.stop_receiver().await.unwrap();
+        c.stop_receiver().await.unwrap();
+    });
+}
@@ -0,0 +1,270 @@
+//! 静态加密集成测试：启用后数据目录不含明文，未解锁/口令错误时拒绝加载，
+//! 正确口令解锁后笔记、设备身份与配对设备完整恢复。
+//!
+//! 1. 启用加密 → 快照/日志/device.key 为密文，明文数据库删除；重启需解锁
+//! 2. 启用后的编辑以加密记录追加到更新日志，重启重放正常
+//! 3. 修改口令：旧口令失效，新口令可解锁
+//! 4. 启用后被替换为明文的文件拒绝加载
+//! 5. 启用中断（只有 vault.pending）：解锁后加载时改写明文并提交
+//! 6. vault.bin 中超出上限的 Argon2 参数在派生密钥前被拒绝
+//! 7. 启用中断后先打开投影：只改写明文数据库、vault 保持未提交，
+//!    加载 SyncService 后才提交
+//! 8. 启用中断时修改口令：改写 vault.pending，提交后新口令生效
+
+mod common;
+
+use cardmind_backend::store::NoteStore;
+use cardmind_backend::sync::SyncService;
+use cardmind_backend::vault::{self, VaultStatus};
+
+use common::{rt, temp_dir};
+
+fn contains(haystack: &[u8], needle: &str) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle.as_bytes())
+}
+
+/// 新建数据目录：写一篇笔记 + 一台配对设备，然后以 `passphrase` 启用加密。
+async fn encrypted_dir(label: &str, passphrase: &str) -> (std::path::PathBuf, String) {
+    let dir = temp_dir(label);
+    let db = dir.join("cardmind.db");
+    let mut svc = SyncService::new_persistent(&dir).await.unwrap();
+    let store = NoteStore::open(&db.to_string_lossy()).unwrap();
+    svc.create_note("n1".into(), "# 机密\n\nSECRET-BODY-MARKER")
+        .unwrap();
+    store.upsert_paired_device("peer-laptop", "Laptop").unwrap();
+    assert_eq!(vault::status(&dir), VaultStatus::Disabled);
+
+    svc.enable_encryption(&store, passphrase).unwrap();
+    assert_eq!(vault::status(&dir), VaultStatus::Unlocked);
+    assert_eq!(
+        store.list_paired_devices().unwrap().len(),
+        1,
+        "启用后投影中的配对设备保留"
+    );
+    assert!(
+        store.list_notes().unwrap().iter().any(|row| row.id == "n1"),
+        "启用后投影重建"
+    );
+    let device_id = svc.device_id();
+    drop((svc, store));
+    vault::lock(&dir);
+    (dir, device_id)
+}
+
+#[test]
+fn test_enable_encrypts_files_and_requires_unlock() {
+    rt().block_on(async {
+        let (dir, device_id) = encrypted_dir("enable", "correct horse").await;
+
+        let snapshot = std::fs::read(dir.join("cardmind.loro")).unwrap();
+        assert!(snapshot.starts_with(b"CARDSEAL"), "快照应为密文");
+        assert!(!contains(&snapshot, "SECRET-BODY-MARKER"));
+        let device_key = std::fs::read(dir.join("device.key")).unwrap();
+        assert!(device_key.starts_with(b"CARDSEAL"), "device.key 应为密文");
+        assert!(!dir.join("cardmind.db").exists(), "明文数据库应删除");
+        let devices = std::fs::read(dir.join("cardmind.devices")).unwrap();
+        assert!(!contains(&devices, "peer-laptop"), "配对设备表应为密文");
+
+        // 未解锁 / 口令错误 → 拒绝加载
+        assert_eq!(vault::status(&dir), VaultStatus::Locked);
+        assert!(SyncService::new_persistent(&dir).await.is_err());
+        assert!(NoteStore::open(&dir.join("cardmind.db").to_string_lossy()).is_err());
+        assert!(vault::unlock(&dir, "wrong horse").is_err());
+        assert_eq!(vault::status(&dir), VaultStatus::Locked);
+
+        vault::unlock(&dir, "correct horse").unwrap();
+        let svc = SyncService::new_persistent(&dir).await.unwrap();
+        assert_eq!(
+            svc.get_note("n1").as_deref(),
+            Some("# 机密\n\nSECRET-BODY-MARKER")
+        );
+        assert_eq!(svc.device_id(), device_id, "设备身份跨加密保持不变");
+        let store = NoteStore::open(&dir.join("cardmind.db").to_string_lossy()).unwrap();
+        let devices = store.list_paired_devices().unwrap();
+        assert_eq!(devices.len(), 1);
+        assert_eq!(devices[0].peer_id, "peer-laptop");
+        svc.sync_notes_to_store(&store).unwrap();
+        assert_eq!(store.list_notes().unwrap().len(), 1);
+        assert!(!dir.join("cardmind.db").exists(), "加密模式投影只在内存");
+        vault::lock(&dir);
+        let _ = std::fs::remove_dir_all(dir);
+    });
+}
+
+#[test]
+fn test_edits_after_enable_append_sealed_log() {
+    rt().block_on(async {
+        let (dir, _) = encrypted_dir("log", "pass").await;
+        vault::unlock(&dir, "pass").unwrap();
+        let mut svc = SyncService::new_persistent(&dir).await.unwrap();
+        svc.update_note("n1", "# 机密\n\nAPPENDED-EDIT-MARKER")
+            .unwrap();
+        let log = std::fs::read(dir.join("cardmind.loro.log")).unwrap();
+        assert!(!log.is_empty(), "编辑应追加到更新日志");
+        assert!(!contains(&log, "APPENDED-EDIT-MARKER"));
+        drop(svc);
+
+        let restored = SyncService::new_persistent(&dir).await.unwrap();
+        assert_eq!(
+            restored.get_note("n1").as_deref(),
+            Some("# 机密\n\nAPPENDED-EDIT-MARKER")
+        );
+        vault::lock(&dir);
+        let _ = std::fs::remove_dir_all(dir);
+    });
+}
+
+#[test]
+fn test_change_passphrase() {
+    rt().block_on(async {
+        let (dir, _) = encrypted_dir("passphrase", "old pass").await;
+        assert!(vault::change_passphrase(&dir, "not it", "new pass").is_err());
+        vault::change_passphrase(&dir, "old pass", "new pass").unwrap();
+        assert!(vault::unlock(&dir, "old pass").is_err());
+        vault::unlock(&dir, "new pass").unwrap();
+        let svc = SyncService::new_persistent(&dir).await.unwrap();
+        assert!(svc.get_note("n1").is_some());
+        vault::lock(&dir);
+        let _ = std::fs::remove_dir_all(dir);
+    });
+}
+
+#[test]
+fn test_tampered_argon2_params_rejected() {
+    rt().block_on(async {
+        let (dir, _) = encrypted_dir("argon2-params", "pass").await;
+        let path = dir.join(vault::VAULT_FILE);
+        let original = std::fs::read(&path).unwrap();
+        // 头部 magic(8) + version(4) 之后依次为 m_cost、t_cost、p_cost（u32 LE）
+        for offset in [12, 16, 20] {
+            let mut tampered = original.clone();
+            tampered[offset..offset + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+            std::fs::write(&path, &tampered).unwrap();
+            let err = vault::unlock(&dir, "pass").unwrap_err();
+            assert!(format!("{err:#}").contains("out of range"), "{err:#}");
+        }
+        std::fs::write(&path, &original).unwrap();
+        vault::unlock(&dir, "pass").unwrap();
+        vault::lock(&dir);
+        let _ = std::fs::remove_dir_all(dir);
+    });
+}
+
+#[test]
+fn test_plaintext_rejected_after_enable() {
+    rt().block_on(async {
+        let (dir, _) = encrypted_dir("plaintext", "pass").await;
+        vault::unlock(&dir, "pass").unwrap();
+        let db = dir.join("cardmind.db");
+
+        // 伪造明文配对设备库
+        NoteStore::new(&db.to_string_lossy())
+            .unwrap()
+            .upsert_paired_device("peer-attacker", "Attacker")
+            .unwrap();
+        assert!(NoteStore::open(&db.to_string_lossy()).is_err());
+        std::fs::remove_file(&db).unwrap();
+
+        // 伪造明文设备身份
+        std::fs::write(dir.join("device.key"), "11".repeat(32)).unwrap();
+        assert!(SyncService::new_persistent(&dir).await.is_err());
+        vault::lock(&dir);
+        let _ = std::fs::remove_dir_all(dir);
+    });
+}
+
+#[test]
+fn test_interrupted_enable_completes_on_load() {
+    rt().block_on(async {
+        let (dir, device_id) = encrypted_dir("interrupted", "pass").await;
+        // 模拟提交前崩溃：vault 仍为 pending，配对设备仍在明文库中
+        std::fs::rename(dir.join("vault.bin"), dir.join("vault.pending")).unwrap();
+        let db = dir.join("cardmind.db");
+        NoteStore::new(&db.to_string_lossy())
+            .unwrap()
+            .upsert_paired_device("peer-phone", "Phone")
+            .unwrap();
+        assert_eq!(vault::status(&dir), VaultStatus::Locked);
+
+        vault::unlock(&dir, "pass").unwrap();
+        let svc = SyncService::new_persistent(&dir).await.unwrap();
+        assert_eq!(svc.device_id(), device_id);
+        let store = NoteStore::open(&db.to_string_lossy()).unwrap();
+        let mut peers: Vec<String> = store
+            .list_paired_devices()
+            .unwrap()
+            .into_iter()
+            .map(|row| row.peer_id)
+            .collect();
+        peers.sort();
+        assert_eq!(peers, ["peer-laptop", "peer-phone"]);
+        assert!(!db.exists(), "明文数据库应改写后删除");
+        assert!(dir.join("vault.bin").exists(), "加载完成后提交 vault");
+        assert!(!dir.join("vault.pending").exists());
+        drop((svc, store));
+
+        vault::lock(&dir);
+        vault::unlock(&dir, "pass").unwrap();
+        assert!(SyncService::new_persistent(&dir).await.is_ok());
+        vault::lock(&dir);
+        let _ = std::fs::remove_dir_all(dir);
+    });
+}
+
+#[test]
+fn test_interrupted_enable_store_first_leaves_vault_pending() {
+    rt().block_on(async {
+        let (dir, _) = encrypted_dir("store-first", "pass").await;
+        std::fs::rename(dir.join("vault.bin"), dir.join("vault.pending")).unwrap();
+        let db = dir.join("cardmind.db");
+        NoteStore::new(&db.to_string_lossy())
+            .unwrap()
+            .upsert_paired_device("peer-phone", "Phone")
+            .unwrap();
+
+        vault::unlock(&dir, "pass").unwrap();
+        let store = NoteStore::open(&db.to_string_lossy()).unwrap();
+        assert!(!db.exists(), "明文数据库应改写后删除");
+        assert!(dir.join("vault.pending").exists(), "投影不应提交 vault");
+        assert!(!dir.join("vault.bin").exists());
+
+        let svc = SyncService::new_persistent(&dir).await.unwrap();
+        assert!(
+            dir.join("vault.bin").exists(),
+            "SyncService 加载后提交 vault"
+        );
+        assert!(!dir.join("vault.pending").exists());
+        assert_eq!(store.list_paired_devices().unwrap().len(), 2);
+        drop((svc, store));
+
+        vault::lock(&dir);
+        vault::unlock(&dir, "pass").unwrap();
+        assert!(SyncService::new_persistent(&dir).await.is_ok());
+        assert!(NoteStore::open(&db.to_string_lossy()).is_ok());
+        vault::lock(&dir);
+        let _ = std::fs::remove_dir_all(dir);
+    });
+}
+
+#[test]
+fn test_change_passphrase_while_enable_pending() {
+    rt().block_on(async {
+        let (dir, _) = encrypted_dir("pending-passphrase", "old pass").await;
+        std::fs::rename(dir.join("vault.bin"), dir.join("vault.pending")).unwrap();
+        vault::change_passphrase(&dir, "old pass", "new pass").unwrap();
+        assert!(!dir.join("vault.bin").exists(), "修改口令不应提交 vault");
+        assert!(vault::unlock(&dir, "old pass").is_err());
+
+        vault::unlock(&dir, "new pass").unwrap();
+        let svc = SyncService::new_persistent(&dir).await.unwrap();
+        assert!(svc.get_note("n1").is_some());
+        assert!(dir.join("vault.bin").exists());
+        drop(svc);
+        vault::lock(&dir);
+        vault::unlock(&dir, "new pass").unwrap();
+        vault::lock(&dir);
+        let _ = std::fs::remove_dir_all(dir);
+    });
+}
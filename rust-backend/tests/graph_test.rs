@@ -0,0 +1,74 @@
+//! 知识图谱查询集成测试：全图导出、局部图谱、孤立笔记、最短路径与连通分量。
+//!
+//! 图：a → b → c，a → b 另有一条带锚点的链接，d → 悬空目标，e 在回收站且链接 a。
+
+use cardmind_backend::store::NoteStore;
+use cardmind_backend::sync::NoteCrdt;
+
+fn sample_store() -> NoteStore {
+    let store = NoteStore::new(":memory:").unwrap();
+    let notes = [
+        ("a", "# A\n\n[[b|到 B]] [[b#细节]]", false),
+        ("b", "# B\n\n[[c]]", false),
+        ("c", "# C\n\n终点", false),
+        ("d", "# D\n\n[[不存在]]", false),
+        ("e", "# E\n\n[[a]]", true),
+    ];
+    for (id, content, deleted) in notes {
+        let note = NoteCrdt::new();
+        note.set_content(content);
+        if deleted {
+            note.set_deleted_at(Some("2026-01-01T00:00:00Z".to_string()));
+        }
+        store.sync_note(id, &note).unwrap();
+    }
+    store
+}
+
+#[test]
+fn test_graph_export_neighborhood_and_orphans() {
+    let store = sample_store();
+
+    let graph = store.graph().unwrap();
+    let ids: Vec<&str> = graph.nodes.iter().map(|n| n.id.as_str()).collect();
+    assert_eq!(ids, vec!["a", "b", "c", "d"], "回收站笔记不入图");
+    let edges: Vec<(&str, &str, &str)> = graph
+        .edges
+        .iter()
+        .map(|e| (e.source.as_str(), e.target.as_str(), e.alias.as_str()))
+        .collect();
+    assert_eq!(edges, vec![("a", "b", "到 B"), ("b", "c", "c")]);
+    let degrees: Vec<u32> = graph.nodes.iter().map(|n| n.degree).collect();
+    assert_eq!(degrees, vec![1, 2, 1, 0]);
+
+    let local = store.neighborhood("a", 1).unwrap();
+    let ids: Vec<&str> = local.nodes.iter().map(|n| n.id.as_str()).collect();
+    assert_eq!(ids, vec!["a", "b"]);
+    assert_eq!(local.edges.len(), 1);
+    assert_eq!(store.neighborhood("c", 2).unwrap().nodes.len(), 3);
+    assert!(store.neighborhood("e", 1).unwrap().nodes.is_empty());
+
+    let orphans = store.orphan_notes().unwrap();
+    assert_eq!(orphans.len(), 1);
+    assert_eq!(orphans[0].id, "d", "只有悬空链接的笔记仍是孤立的");
+}
+
+#[test]
+fn test_shortest_path_and_components() {
+    let store = sample_store();
+
+    assert_eq!(store.shortest_path("a", "c").unwrap(), vec!["a", "b", "c"]);
+    assert_eq!(
+        store.shortest_path("c", "a").unwrap(),
+        vec!["c", "b", "a"],
+        "路径不分链接方向"
+    );
+    assert_eq!(store.shortest_path("b", "b").unwrap(), vec!["b"]);
+    assert!(store.shortest_path("a", "d").unwrap().is_empty());
+    assert!(store.shortest_path("a", "e").unwrap().is_empty());
+
+    assert_eq!(
+        store.connected_components().unwrap(),
+        vec![vec!["a", "b", "c"], vec!["d"]]
+    );
+}
@@ -0,0 +1,81 @@
+//! 链接改写集成测试：改标题与合并笔记时，指向它的链接作为 CRDT 编辑一并改写。
+//!
+//! 1. 改标题 → `[[旧标题]]` 改为新标题、显示名等于旧标题的 id 链接按需改写，
+//!    代码里的链接不动；SQLite 投影中的链接仍解析到原笔记
+//! 2. 合并笔记 → 正文并入目标、源笔记进回收站，指向源笔记的链接改指向目标
+
+mod common;
+
+use cardmind_backend::store::NoteStore;
+use cardmind_backend::sync::SyncService;
+
+use common::rt;
+
+#[test]
+fn test_rename_rewrites_title_links_and_aliases() {
+    rt().block_on(async {
+        let mut svc = SyncService::new().await.unwrap();
+        let store = NoteStore::new(":memory:").unwrap();
+        svc.create_note("report".into(), "# 周报\n\n本周进展")
+            .unwrap();
+        svc.create_note(
+            "a".into(),
+            "见 [[周报]]、[[report|周报]] 与 [[report#进展|进展]]",
+        )
+        .unwrap();
+        svc.create_note("b".into(), "代码示例：`[[周报]]`").unwrap();
+        svc.sync_notes_to_store(&store).unwrap();
+
+        let rewritten = svc.rename_note(&store, "report", "月报", true).unwrap();
+        assert_eq!(rewritten, 1, "只有 a 的链接被改写");
+        assert_eq!(
+            svc.get_note("report").as_deref(),
+            Some("# 月报\n\n本周进展")
+        );
+        assert_eq!(
+            svc.get_note("a").as_deref(),
+            Some("见 [[月报]]、[[report|月报]] 与 [[report#进展|进展]]")
+        );
+        assert_eq!(svc.get_note("b").as_deref(), Some("代码示例：`[[周报]]`"));
+
+        let out = store.outgoing_links("a").unwrap();
+        assert_eq!(out.len(), 2, "同一目标按锚点区分：整篇 + #进展");
+        assert!(out.iter().all(|l| l.id == "report" && l.resolved));
+        assert_eq!(out[0].title, "月报");
+    });
+}
+
+#[test]
+fn test_merge_redirects_links_to_target() {
+    rt().block_on(async {
+        let mut svc = SyncService::new().await.unwrap();
+        let store = NoteStore::new(":memory:").unwrap();
+        svc.create_note("draft".into(), "# 草稿\n\n草稿正文")
+            .unwrap();
+        svc.create_note("final".into(), "# 定稿\n\n定稿正文")
+            .unwrap();
+        svc.create_note("a".into(), "[[草稿]] / [[draft^b1|那一段]]")
+            .unwrap();
+        svc.sync_notes_to_store(&store).unwrap();
+
+        let rewritten = svc.merge_notes(&store, "draft", "final").unwrap();
+        assert_eq!(rewritten, 1);
+        assert_eq!(
+            svc.get_note("a").as_deref(),
+            Some("[[final|草稿]] / [[final^b1|那一段]]")
+        );
+        assert_eq!(
+            svc.get_note("final").as_deref(),
+            Some("# 定稿\n\n定稿正文\n\n# 草稿\n\n草稿正文")
+        );
+        assert!(
+            store.deleted_at("draft").unwrap().is_some(),
+            "源笔记进回收站"
+        );
+        let back = store.backlinks("final").unwrap();
+        assert_eq!(back.len(), 2);
+        assert!(back.iter().all(|l| l.id == "a"));
+        assert!(store.backlinks("draft").unwrap().is_empty());
+        assert!(svc.merge_notes(&store, "final", "final").is_err());
+    });
+}
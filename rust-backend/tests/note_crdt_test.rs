@@ -1,4 +1,4 @@
-use cardmind_backend::sync::NoteCrdt;
+use cardmind_backend::sync::{NoteCrdt, NoteLink};
 
 #[test]
 fn test_create_and_read() {
@@ -100,6 +100,14 @@ fn test_meta_dates() {
     assert_eq!(note.get_updated_at(), "2026-01-02T00:00:00Z");
 }
 
+fn link(target: &str, anchor: &str, alias: &str) -> NoteLink {
+    NoteLink {
+        target: target.to_string(),
+        anchor: anchor.to_string(),
+        alias: alias.to_string(),
+    }
+}
+
 #[test]
 fn test_parse_links() {
     // 无链接
@@ -110,28 +118,38 @@ fn test_parse_links() {
     // 单链接带 alias
     let note = NoteCrdt::new();
     note.set_content("看 [[abc123|别名A]] 这里");
-    assert_eq!(
-        note.parse_links(),
-        vec![("abc123".to_string(), "别名A".to_string())]
-    );
+    assert_eq!(note.parse_links(), vec![link("abc123", "", "别名A")]);
 
     // 单链接缺 alias → alias 取 target_id
     let note = NoteCrdt::new();
     note.set_content("链接 [[abc123]]");
+    assert_eq!(note.parse_links(), vec![link("abc123", "", "abc123")]);
+
+    // 多链接
+    let note = NoteCrdt::new();
+    note.set_content("[[a|A]] 与 [[b]] 与 [[c|C]]");
     assert_eq!(
         note.parse_links(),
-        vec![("abc123".to_string(), "abc123".to_string())]
+        vec![link("a", "", "A"), link("b", "", "b"), link("c", "", "C")]
     );
+}
 
-    // 多链接
+#[test]
+fn test_parse_links_skips_code_and_keeps_anchors() {
     let note = NoteCrdt::new();
-    note.set_content("[[a|A]] 与 [[b]] 与 [[c|C]]");
+    note.set_content(
+        "# 索引\n\n\
+         见 [[abc#安装步骤|安装]] 与 [[abc^k3f9]]\n\n\
+         行内 `[[not-a-link]]`，转义 \\[[escaped]]\n\n\
+         ```\n[[in-fence]]\n```\n\n\
+         ![[embedded]]",
+    );
     assert_eq!(
         note.parse_links(),
         vec![
-            ("a".to_string(), "A".to_string()),
-            ("b".to_string(), "b".to_string()),
-            ("c".to_string(), "C".to_string()),
+            link("abc", "#安装步骤", "安装"),
+            link("abc", "^k3f9", "abc^k3f9"),
+            link("embedded", "", "embedded"),
         ]
     );
 }
@@ -170,3 +188,72 @@ fn test_generate_note_id() {
     // 两次生成应不同
     assert_ne!(id, NoteCrdt::generate_note_id());
 }
+
+#[test]
+fn test_concurrent_paragraph_edits_merge() {
+    let base = "# 标题\n\n第一段：原文。\n\n第二段：原文。\n";
+    let a = NoteCrdt::new();
+    a.set_content(base);
+    let b = NoteCrdt::new();
+    b.import_snapshot(&a.export_snapshot().unwrap()).unwrap();
+
+    // 离线各改一段
+    a.set_content("# 标题\n\n第一段：A 改过。\n\n第二段：原文。\n");
+    b.set_content("# 标题\n\n第一段：原文。\n\n第二段：B 改过。\n");
+
+    a.commit();
+    b.commit();
+    let from_a = a.export_updates(&b.version_vector()).unwrap();
+    let from_b = b.export_updates(&a.version_vector()).unwrap();
+    a.import_snapshot(&from_b).unwrap();
+    b.import_snapshot(&from_a).unwrap();
+
+    let merged = "# 标题\n\n第一段：A 改过。\n\n第二段：B 改过。\n";
+    assert_eq!(a.get_content(), merged, "不同段落的并发编辑应互不覆盖");
+    assert_eq!(b.get_content(), merged);
+}
+
+#[test]
+fn test_set_content_records_minimal_change() {
+    let body = "长正文。".repeat(2000);
+    let note = NoteCrdt::new();
+    note.set_content(&format!("# 标题\n\n{body}"));
+    note.commit();
+    let before = note.version_vector();
+
+    // 内容未变：不产生任何操作
+    note.set_content(&format!("# 标题\n\n{body}"));
+    note.commit();
+    assert_eq!(note.version_vector(), before, "内容未变时不应产生操作");
+
+    // 只改标题：增量远小于全文
+    note.set_content(&format!("# 新标题\n\n{body}"));
+    let update = note.export_updates(&before).unwrap();
+    assert!(
+        update.len() < body.len() / 10,
+        "小改动的增量应远小于全文：update={} body={}",
+        update.len(),
+        body.len()
+    );
+    assert_eq!(note.get_content(), format!("# 新标题\n\n{body}"));
+}
+
+#[test]
+fn test_set_content_large_text_uses_line_diff() {
+    let lines: Vec<String> = (0..5000).map(|i| format!("第 {i} 行内容")).collect();
+    let note = NoteCrdt::new();
+    note.set_content(&lines.join("\n"));
+    note.commit();
+    let before = note.version_vector();
+
+    let mut edited = lines.clone();
+    edited[2500] = "中间一行被改写".to_string();
+    note.set_content(&edited.join("\n"));
+    assert_eq!(note.get_content(), edited.join("\n"));
+    let update = note.export_updates(&before).unwrap();
+    assert!(
+        update.len() < 1024,
+        "按行 diff 只应记录改动行：{}",
+        update.len()
+    );
+}
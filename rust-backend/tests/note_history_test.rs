@@ -0,0 +1,101 @@
+//! 编辑历史集成测试：列出笔记历史版本、按版本渲染正文、恢复为新编辑并同步。
+//!
+//! 场景：A 写好笔记 → 同步给 B → B 误覆盖 → 覆盖同步回 A。A 在历史中找到
+//! 覆盖前的版本并恢复，恢复作为新编辑再同步给 B。
+
+mod common;
+
+use cardmind_backend::sync::SyncService;
+
+use common::rt;
+
+/// A 创建 → B 覆盖 → 覆盖同步回 A；返回 (A, B)。
+async fn overwritten_on_both() -> (SyncService, SyncService) {
+    let mut a = SyncService::new().await.unwrap();
+    let mut b = SyncService::new().await.unwrap();
+    a.create_note("n1".into(), "# 原稿\n\n辛苦写好的正文")
+        .unwrap();
+    b.import_all(&a.export_all().unwrap()).unwrap();
+    b.update_note("n1", "# 原稿\n\n误粘贴覆盖").unwrap();
+    a.import_all(&b.export_delta(&a.version_digest()).unwrap())
+        .unwrap();
+    assert_eq!(a.get_note("n1").as_deref(), Some("# 原稿\n\n误粘贴覆盖"));
+    (a, b)
+}
+
+#[test]
+fn test_versions_list_device_and_timestamp() {
+    rt().block_on(async {
+        let (a, b) = overwritten_on_both().await;
+        let versions = a.note_versions("n1").unwrap();
+        assert_eq!(versions.len(), 2, "两台设备各一个版本: {versions:?}");
+        // 新 → 旧：最新是 B 的覆盖
+        assert_eq!(versions[0].device_id, b.device_id());
+        assert_eq!(versions[1].device_id, a.device_id());
+        for v in &versions {
+            assert!(!v.timestamp.is_empty(), "应记录提交时间: {v:?}");
+            chrono::DateTime::parse_from_rfc3339(&v.timestamp).unwrap();
+            assert!(v.change_len > 0);
+        }
+    });
+}
+
+#[test]
+fn test_content_at_version_is_read_only() {
+    rt().block_on(async {
+        let (a, _b) = overwritten_on_both().await;
+        let versions = a.note_versions("n1").unwrap();
+        let original = &versions[1].version;
+        assert_eq!(
+            a.note_content_at_version("n1", original).unwrap(),
+            "# 原稿\n\n辛苦写好的正文"
+        );
+        assert_eq!(
+            a.get_note("n1").as_deref(),
+            Some("# 原稿\n\n误粘贴覆盖"),
+            "按版本渲染不应改变当前内容"
+        );
+    });
+}
+
+#[test]
+fn test_restore_version_syncs_as_new_edit() {
+    rt().block_on(async {
+        let (mut a, mut b) = overwritten_on_both().await;
+        let original = a.note_versions("n1").unwrap()[1].version.clone();
+
+        a.restore_note_version("n1", &original).unwrap();
+        assert_eq!(
+            a.get_note("n1").as_deref(),
+            Some("# 原稿\n\n辛苦写好的正文")
+        );
+        let versions = a.note_versions("n1").unwrap();
+        assert_eq!(versions.len(), 3, "恢复应追加新版本而非回退历史");
+        assert_eq!(versions[0].device_id, a.device_id());
+        assert_eq!(
+            a.pending_sync_count_for_peer(&b.device_id()),
+            1,
+            "恢复后应标记待同步"
+        );
+
+        // 恢复作为普通增量同步给 B
+        b.import_all(&a.export_delta(&b.version_digest()).unwrap())
+            .unwrap();
+        assert_eq!(
+            b.get_note("n1").as_deref(),
+            Some("# 原稿\n\n辛苦写好的正文")
+        );
+    });
+}
+
+#[test]
+fn test_unknown_or_malformed_version_is_rejected() {
+    rt().block_on(async {
+        let (mut a, _b) = overwritten_on_both().await;
+        assert!(a.note_content_at_version("n1", "999@1").is_err());
+        assert!(a.note_content_at_version("n1", "not-a-version").is_err());
+        assert!(a.restore_note_version("n1", "999@1").is_err());
+        assert!(a.note_versions("missing").is_err());
+        assert_eq!(a.get_note("n1").as_deref(), Some("# 原稿\n\n误粘贴覆盖"));
+    });
+}
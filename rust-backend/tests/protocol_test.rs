@@ -0,0 +1,150 @@
+//! 同步协议握手集成测试：hello 帧协商协议版本与能力位。
+//!
+//! 1. 早于 hello 协议的对端（只接受单向流）→ 回退为 v3 快照格式的全量推送
+//! 2. 对端协议版本过旧 → 推送失败并输出 `sync.push`（reason=peer_too_old）
+//! 3. 应答 hello 较慢的新版本对端 → 照常协商，不回退为早期版本格式
+
+mod common;
+
+use std::sync::Arc;
+
+use cardmind_backend::debug_log::CollectingSink;
+use cardmind_backend::sync::SyncService;
+use iroh::endpoint::{presets, ConnectionError};
+use iroh::{Endpoint, RelayMode, SecretKey};
+
+use common::rt;
+
+const ALPN: &[u8] = b"cardmind-v2";
+
+/// 模拟对端：裸 iroh endpoint，返回 `(endpoint, device_id, "ip:port" 列表)`。
+async fn fake_peer() -> (Endpoint, String, Vec<String>) {
+    let endpoint = Endpoint::builder(presets::N0)
+        .secret_key(SecretKey::generate())
+        .alpns(vec![ALPN.to_vec()])
+        .relay_mode(RelayMode::Disabled)
+        .bind()
+        .await
+        .unwrap();
+    let ips = endpoint
+        .addr()
+        .ip_addrs()
+        .filter(|a| a.is_ipv4())
+        .map(|a| a.to_string())
+        .collect();
+    let id = endpoint.id().to_string();
+    (endpoint, id, ips)
+}
+
+#[test]
+fn test_legacy_peer_receives_v3_snapshot_push() {
+    rt().block_on(async {
+        let (legacy, legacy_id, legacy_ips) = fake_peer().await;
+        // 早期版本接收端：只接受单向流；读到未知帧标记（hello 探测帧）即丢弃
+        // 连接，读完 `CARDMIND + export_all` 后关闭连接
+        let receiver = tokio::spawn(async move {
+            loop {
+                let conn = legacy.accept().await.unwrap().await.unwrap();
+                let mut recv = conn.accept_uni().await.unwrap();
+                let data = recv.read_to_end(usize::MAX).await.unwrap();
+                if data.starts_with(b"CARDMIND") {
+                    conn.close(0u32.into(), b"done");
+                    return (legacy, data);
+                }
+                drop(conn);
+            }
+        });
+
+        let mut a = SyncService::new().await.unwrap();
+        a.create_note("n1".into(), "# 旧设备\n\n回退推送").unwrap();
+        a.push_to_peer(&legacy_id, legacy_ips)
+            .await
+            .expect("早期版本对端应收到回退推送");
+
+        let (_legacy, data) = receiver.await.unwrap();
+        assert_eq!(&data[..8], b"CARDMIND");
+        let mut old = SyncService::new().await.unwrap();
+        old.import_all(&data[8..])
+            .expect("应为 v3 快照格式（未压缩）");
+        assert_eq!(old.get_note("n1").as_deref(), Some("# 旧设备\n\n回退推送"));
+    });
+}
+
+#[test]
+fn test_too_old_peer_fails_with_push_event() {
+    rt().block_on(async {
+        let (old_peer, old_id, old_ips) = fake_peer().await;
+        // 支持 hello 但协议版本为 1 的对端
+        let responder = tokio::spawn(async move {
+            let conn = old_peer.accept().await.unwrap().await.unwrap();
+            let (mut send, mut recv) = conn.accept_bi().await.unwrap();
+            let mut hello = [0u8; 11];
+            recv.read_exact(&mut hello).await.unwrap();
+            send.write_all(&[1, 0, 0]).await.unwrap();
+            match conn.closed().await {
+                ConnectionError::ApplicationClosed(close) => u64::from(close.error_code),
+                other => panic!("unexpected close: {other:?}"),
+            }
+        });
+
+        let sink = Arc::new(CollectingSink::new());
+        let a = SyncService::new_with_log_sink(sink.clone()).await.unwrap();
+        let err = a
+            .push_to_peer(&old_id, old_ips)
+            .await
+            .expect_err("版本过旧的对端不应推送");
+        assert!(format!("{err:#}").contains("too old"), "{err:#}");
+        assert_eq!(responder.await.unwrap(), 426, "应以升级关闭码告知对端");
+
+        let events = sink.snapshot();
+        let event = events
+            .iter()
+            .find(|e| {
+                e.event == "sync.push"
+                    && e.fields
+                        .iter()
+                        .any(|(k, v)| k == "reason" && v == "peer_too_old")
+            })
+            .expect("应输出 sync.push（reason=peer_too_old）");
+        assert!(event
+            .fields
+            .iter()
+            .any(|(k, v)| k == "peer_version" && v == "1"));
+    });
+}
+
+#[test]
+fn test_slow_peer_is_not_treated_as_legacy() {
+    rt().block_on(async {
+        let (slow, slow_id, slow_ips) = fake_peer().await;
+        // 新版本对端：超过 hello 等待时长才应答（不协商任何能力 → 全量推送帧）
+        let responder = tokio::spawn(async move {
+            let conn = slow.accept().await.unwrap().await.unwrap();
+            let (mut send, mut recv) = conn.accept_bi().await.unwrap();
+            let mut hello = [0u8; 11];
+            recv.read_exact(&mut hello).await.unwrap();
+            tokio::time::sleep(std::time::Duration::from_secs(4)).await;
+            send.write_all(&[2, 0, 0]).await.unwrap();
+            let data = recv.read_to_end(usize::MAX).await.unwrap();
+            conn.close(0u32.into(), b"done");
+            conn.closed().await;
+            (slow, data)
+        });
+
+        let sink = Arc::new(CollectingSink::new());
+        let mut a = SyncService::new_with_log_sink(sink.clone()).await.unwrap();
+        a.create_note("n1".into(), "# 慢设备").unwrap();
+        a.push_to_peer(&slow_id, slow_ips)
+            .await
+            .expect("慢对端应照常完成推送");
+        let (_slow, data) = responder.await.unwrap();
+        assert_eq!(&data[..8], b"CARDMIND");
+        assert!(
+            !sink.snapshot().iter().any(|e| e
+                .fields
+                .iter()
+                .any(|(k, v)| k == "action" && v == "legacy_fallback")),
+            "慢对端不应回退为早期版本格式"
+        );
+    });
+}
@@ -0,0 +1,102 @@
+//! 按对端确认推送集成测试：每台对端独立确认，未送达的对端单独重试。
+//!
+//! 1. B 确认、C 拒收 → B 的结果计送达数、C 的结果保留待同步数；整体待同步
+//!    不清零（C 仍缺）
+//! 2. 下一次推送只连接 C，补齐后待同步归零
+//! 3. `accept_push` 接收端导入失败 → 推送方不算送达，待同步不清零
+
+mod common;
+
+use std::time::Duration;
+
+use cardmind_backend::store::NoteStore;
+use cardmind_backend::sync::SyncService;
+
+use common::{pair_up, rt};
+
+#[test]
+fn test_failed_peer_is_retried_alone() {
+    rt().block_on(async {
+        let (a, a_store, b, b_store) = pair_up(
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+        )
+        .await;
+        let (mut a, a_store, c, c_store) = pair_up(
+            a,
+            a_store,
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+        )
+        .await;
+        let (a_id, b_id, c_id) = (a.device_id(), b.device_id(), c.device_id());
+        b.start_receiver(b_store.clone()).await.unwrap();
+        c.start_receiver(c_store.clone()).await.unwrap();
+
+        // C 暂时不信任 A：C 的接收器以拒绝关闭连接，A 收不到确认
+        c_store.remove_paired_device(&a_id).unwrap();
+        a.create_note("n1".into(), "# 一").unwrap();
+        a.create_note("n2".into(), "# 二").unwrap();
+        let results = a.push_pending(&a_store).await;
+        assert_eq!(results.len(), 2);
+        let to_b = results.iter().find(|r| r.peer_id == b_id).unwrap();
+        assert!(to_b.ok, "B 应确认: {to_b:?}");
+        assert_eq!(to_b.delivered_count, 2);
+        assert_eq!(to_b.pending_count, 0);
+        let to_c = results.iter().find(|r| r.peer_id == c_id).unwrap();
+        assert!(!to_c.ok, "C 拒收不应算送达: {to_c:?}");
+        assert_eq!(to_c.delivered_count, 0);
+        assert_eq!(to_c.pending_count, 2);
+        assert_eq!(a.pending_sync_count(), 2, "C 仍缺，整体待同步不清零");
+        assert_eq!(a.pending_sync_count_for_peer(&b_id), 0);
+
+        // C 恢复信任 → 下一次只重试 C（C 失败后处于退避，手动立即同步跳过退避）
+        c_store.upsert_paired_device(&a_id, "A").unwrap();
+        assert!(a.peer_backoff(&c_id).is_some(), "C 失败后应进入退避");
+        assert!(a.peer_backoff(&b_id).is_none());
+        a.reset_sync_backoff();
+        let results = a.push_pending(&a_store).await;
+        assert_eq!(results.len(), 1, "已确认的 B 不应再被连接: {results:?}");
+        assert_eq!(results[0].peer_id, c_id);
+        assert!(results[0].ok);
+        assert_eq!(results[0].delivered_count, 2);
+        assert_eq!(a.pending_sync_count(), 0);
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert_eq!(c.get_note("n2").as_deref(), Some("# 二"));
+
+        // 全部对端已确认 → 不发起任何连接
+        assert!(a.push_pending(&a_store).await.is_empty());
+        b.stop_receiver().await.unwrap();
+        c.stop_receiver().await.unwrap();
+    });
+}
+
+#[test]
+fn test_buffered_push_is_acked_only_after_import() {
+    rt().block_on(async {
+        let (mut a, a_store, b, b_store) = pair_up(
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+        )
+        .await;
+        let (b_id, b_ips) = (b.device_id(), b.local_addrs());
+        a.create_note("n1".into(), "# 一").unwrap();
+
+        // B 读完推送但导入失败：连接以中止关闭，A 不得记为送达
+        let b_handle = tokio::spawn(async move {
+            let mut b = b;
+            b.accept_push().await.unwrap();
+            assert!(b.import_all(b"not a snapshot").is_err());
+            b
+        });
+        let err = a.push_to_peer(&b_id, b_ips).await.unwrap_err();
+        assert!(err.to_string().contains("aborted"), "{err:#}");
+        assert_eq!(a.pending_sync_count_for_peer(&b_id), 1);
+        let _b = b_handle.await.unwrap();
+        drop((a_store, b_store));
+    });
+}
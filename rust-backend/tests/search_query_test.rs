@@ -0,0 +1,135 @@
+//! 搜索语法集成测试：标签/日期/链接/回收站过滤、短语、OR 与排除，以及语法错误。
+//!
+//! 1. 各过滤器与全文词组合后编译为 FTS5 MATCH + SQL 条件，结果正确
+//! 2. 语法错误返回带 kind 与位置的 SearchQueryError（search_notes 中可 downcast）
+//! 3. 不带引号的词按前缀匹配，带引号的短语精确匹配
+//! 4. `updated:` 按 CRDT 的 meta.updated_at 过滤，重复投影不改变更新时间
+
+use cardmind_backend::search::{parse_query, SearchQueryError, SearchQueryErrorKind};
+use cardmind_backend::store::NoteStore;
+use cardmind_backend::sync::NoteCrdt;
+
+fn sample_store() -> NoteStore {
+    let store = NoteStore::new(":memory:").unwrap();
+    let notes = [
+        (
+            "rust",
+            "# Rust 所有权\n\n借用检查器很严格",
+            &["rust"][..],
+            None,
+        ),
+        (
+            "draft",
+            "# 草稿\n\n借用规则待整理，见 [[rust]]",
+            &["rust", "draft"][..],
+            None,
+        ),
+        ("go", "# Go 并发\n\ngoroutine 与 channel", &["go"][..], None),
+        (
+            "old",
+            "# 旧笔记\n\n借用检查器笔记",
+            &[][..],
+            Some("2026-01-01T00:00:00Z"),
+        ),
+    ];
+    for (id, content, tags, deleted_at) in notes {
+        let note = NoteCrdt::new();
+        note.set_content(content);
+        note.set_tags(&tags.iter().map(|t| t.to_string()).collect::<Vec<_>>());
+        note.set_deleted_at(deleted_at.map(str::to_string));
+        store.sync_note(id, &note).unwrap();
+    }
+    store
+}
+
+fn ids(store: &NoteStore, query: &str) -> Vec<String> {
+    let mut ids: Vec<String> = store
+        .search_notes(query)
+        .unwrap()
+        .into_iter()
+        .map(|row| row.id)
+        .collect();
+    ids.sort();
+    ids
+}
+
+#[test]
+fn test_quoted_phrase_is_exact_and_bare_word_is_prefix() {
+    let store = NoteStore::new(":memory:").unwrap();
+    for (id, content) in [
+        ("owner", "# Ownership\n\nborrow rules"),
+        ("own", "# Own words\n\nwritten in my own words"),
+    ] {
+        let note = NoteCrdt::new();
+        note.set_content(content);
+        store.sync_note(id, &note).unwrap();
+    }
+
+    assert_eq!(ids(&store, "own"), vec!["own", "owner"]);
+    assert_eq!(ids(&store, "\"own\""), vec!["own"]);
+    assert_eq!(ids(&store, "\"own words\""), vec!["own"]);
+    assert!(ids(&store, "\"own word\"").is_empty());
+}
+
+#[test]
+fn test_updated_filter_uses_note_edit_time() {
+    let store = NoteStore::new(":memory:").unwrap();
+    let note = NoteCrdt::new();
+    note.set_content("# 旧编辑\n\n三月写的");
+    note.set_updated_at("2025-03-10T12:00:00Z");
+    store.sync_note("march", &note).unwrap();
+    // 重新投影（如启动时 sync_notes_to_store）不应把笔记算作刚更新
+    store.sync_note("march", &note).unwrap();
+
+    assert_eq!(ids(&store, "updated:2025-03-10"), vec!["march"]);
+    assert!(ids(&store, "updated:>2025-03-10").is_empty());
+    assert_eq!(ids(&store, "updated:<2025-03-11"), vec!["march"]);
+    let rows = store.list_notes().unwrap();
+    assert!(rows[0].updated_at.starts_with("2025-03-10T12:00:00"));
+}
+
+#[test]
+fn test_query_filters_and_operators() {
+    let store = sample_store();
+
+    assert_eq!(ids(&store, "tag:rust"), vec!["draft", "rust"]);
+    assert_eq!(ids(&store, "tag:rust -tag:draft"), vec!["rust"]);
+    assert_eq!(ids(&store, "借用 -草稿"), vec!["rust"]);
+    assert_eq!(ids(&store, "\"借用检查器\""), vec!["rust"]);
+    assert_eq!(ids(&store, "goroutine OR 所有权"), vec!["go", "rust"]);
+    assert_eq!(ids(&store, "tag:go OR links-to:rust"), vec!["draft", "go"]);
+    assert_eq!(ids(&store, "借用检查器 in:trash"), vec!["old"]);
+    assert_eq!(ids(&store, "updated:>2000-01-01").len(), 3);
+    assert!(ids(&store, "updated:<2000-01-01").is_empty());
+
+    let rows = store.search_notes("goroutine OR 所有权").unwrap();
+    assert!(rows.iter().all(|row| !row.content_preview.is_empty()));
+}
+
+#[test]
+fn test_query_syntax_errors() {
+    let kind = |query: &str| parse_query(query).unwrap_err().kind;
+    assert_eq!(kind("\"未闭合"), SearchQueryErrorKind::UnterminatedQuote);
+    assert_eq!(kind("tag:"), SearchQueryErrorKind::MissingValue);
+    assert_eq!(
+        kind("updated:>2026-13-01"),
+        SearchQueryErrorKind::InvalidDate
+    );
+    assert_eq!(kind("in:inbox"), SearchQueryErrorKind::UnsupportedFilter);
+    assert_eq!(kind("rust OR"), SearchQueryErrorKind::DanglingOr);
+    // 引号只能包住整项或过滤值
+    assert_eq!(kind("\"foo\"bar"), SearchQueryErrorKind::UnsupportedFilter);
+    assert_eq!(kind("x\"y z\""), SearchQueryErrorKind::UnsupportedFilter);
+    assert_eq!(kind("\"a\"\"b\""), SearchQueryErrorKind::UnsupportedFilter);
+    assert_eq!(
+        kind("tag:\"a b\"c"),
+        SearchQueryErrorKind::UnsupportedFilter
+    );
+    assert_eq!(parse_query("x\"y z\"").unwrap_err().position, 1);
+    assert!(parse_query("tag:\"两个 词\" -\"短语\"").is_ok());
+    assert_eq!(parse_query("a  OR").unwrap_err().position, 3);
+
+    let err = sample_store().search_notes("OR rust").unwrap_err();
+    let err = err.downcast_ref::<SearchQueryError>().unwrap();
+    assert_eq!(err.kind, SearchQueryErrorKind::DanglingOr);
+}
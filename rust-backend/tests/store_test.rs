@@ -9,18 +9,20 @@ fn test_sync_and_list() {
     // 创建笔记并通过 NoteCrdt 填充内容
     let note = NoteCrdt::new();
     note.set_content("# 测试笔记\n\n这是一条测试内容。");
+    note.set_updated_at("2026-01-01T08:00:00Z");
     store.sync_note("note-1", &note).unwrap();
 
     // 第二条笔记
     let note2 = NoteCrdt::new();
     note2.set_content("# 第二条笔记\n\n更多内容……");
+    note2.set_updated_at("2026-01-01T09:00:00Z");
     store.sync_note("note-2", &note2).unwrap();
 
     // 列出所有笔记
     let notes = store.list_notes().unwrap();
     assert_eq!(notes.len(), 2, "应有 2 条笔记");
 
-    // 按 updated_at DESC，所以 note-2 在前
+    // 按 updated_at（meta.updated_at）DESC，所以 note-2 在前
     assert_eq!(notes[0].id, "note-2");
     assert_eq!(notes[0].title, "第二条笔记");
     assert!(notes[0].content_preview.starts_with("更多内容"));
@@ -30,6 +32,7 @@ fn test_sync_and_list() {
 
     // 更新笔记后重新同步
     note.set_content("# 测试笔记(已更新)\n\n更新后的内容。");
+    note.set_updated_at("2026-01-01T10:00:00Z");
     store.sync_note("note-1", &note).unwrap();
 
     let notes = store.list_notes().unwrap();
@@ -124,6 +127,148 @@ fn test_links_rebuilt_on_resync() {
     assert_eq!(out[0].id, "new-target");
 }
 
+#[test]
+fn test_title_links_resolve_case_insensitively() {
+    let store = NoteStore::new(":memory:").unwrap();
+
+    // 两篇同名笔记：按 id 取最小者，各设备解析一致
+    let b2 = NoteCrdt::new();
+    b2.set_content("# Rust 入门\n\n第二篇");
+    store.sync_note("note-b2", &b2).unwrap();
+    let b1 = NoteCrdt::new();
+    b1.set_content("# rust 入门\n\n第一篇");
+    store.sync_note("note-b1", &b1).unwrap();
+
+    let a = NoteCrdt::new();
+    a.set_content("# A\n\n见 [[RUST 入门]] 与 [[note-b2|第二篇]]");
+    store.sync_note("note-a", &a).unwrap();
+
+    let out = store.outgoing_links("note-a").unwrap();
+    assert_eq!(out.len(), 2);
+    assert_eq!(out[0].id, "note-b1", "标题链接应解析为 id 最小的同名笔记");
+    assert_eq!(out[0].alias, "RUST 入门", "alias 缺省取正文里写的标题");
+    assert!(out[0].resolved && out[0].exists);
+    assert_eq!(out[1].id, "note-b2", "id 链接照旧精确匹配");
+    assert!(out[1].resolved);
+    assert_eq!(store.backlinks("note-b1").unwrap()[0].id, "note-a");
+}
+
+#[test]
+fn test_dangling_title_link_resolves_on_create_and_retitle() {
+    let store = NoteStore::new(":memory:").unwrap();
+    let a = NoteCrdt::new();
+    a.set_content("# A\n\n待写：[[周报]]");
+    store.sync_note("note-a", &a).unwrap();
+
+    let out = store.outgoing_links("note-a").unwrap();
+    assert_eq!(out[0].id, "周报", "未解析时 id 为目标原文");
+    assert!(!out[0].resolved && !out[0].exists);
+
+    // 新建同名笔记 → 悬空链接自动接上
+    let report = NoteCrdt::new();
+    report.set_content("# 周报\n\n本周进展");
+    store.sync_note("note-r", &report).unwrap();
+    let out = store.outgoing_links("note-a").unwrap();
+    assert_eq!(out[0].id, "note-r");
+    assert!(out[0].resolved);
+    assert_eq!(out[0].title, "周报");
+
+    // 改标题 → 旧标题链接回到未解析；写有新标题的链接接上
+    report.set_content("# 月报\n\n本月进展");
+    store.sync_note("note-r", &report).unwrap();
+    let out = store.outgoing_links("note-a").unwrap();
+    assert_eq!(out[0].id, "周报");
+    assert!(!out[0].resolved);
+
+    a.set_content("# A\n\n待写：[[月报]]");
+    store.sync_note("note-a", &a).unwrap();
+    assert!(store.outgoing_links("note-a").unwrap()[0].resolved);
+}
+
+#[test]
+fn test_resolved_title_link_stays_pinned_on_collision() {
+    let store = NoteStore::new(":memory:").unwrap();
+    let report = NoteCrdt::new();
+    report.set_content("# 周报\n\n原来的周报");
+    store.sync_note("note-z", &report).unwrap();
+    let a = NoteCrdt::new();
+    a.set_content("# A\n\n见 [[周报]]");
+    store.sync_note("note-a", &a).unwrap();
+    let out = store.outgoing_links("note-a").unwrap();
+    assert_eq!(out[0].id, "note-z");
+    assert!(!out[0].ambiguous);
+
+    // 后建的同名笔记 id 更小：已解析的链接不改指向，但标记为有歧义
+    let copy = NoteCrdt::new();
+    copy.set_content("# 周报\n\n新的周报");
+    store.sync_note("note-b", &copy).unwrap();
+    store.sync_note("note-a", &a).unwrap();
+    let out = store.outgoing_links("note-a").unwrap();
+    assert_eq!(out[0].id, "note-z", "链接固定在原目标上");
+    assert!(out[0].ambiguous);
+    assert!(store.backlinks("note-z").unwrap()[0].ambiguous);
+    assert!(store.backlinks("note-b").unwrap().is_empty());
+
+    // 原目标进回收站 → 改解析到剩下的同名笔记，歧义消失
+    report.set_deleted_at(Some("2026-01-01T00:00:00Z".into()));
+    store.sync_note("note-z", &report).unwrap();
+    let out = store.outgoing_links("note-a").unwrap();
+    assert_eq!(out[0].id, "note-b");
+    assert!(!out[0].ambiguous);
+}
+
+#[test]
+fn test_links_keep_anchor_and_skip_code() {
+    let store = NoteStore::new(":memory:").unwrap();
+    let target = NoteCrdt::new();
+    target.set_content("# 手册\n\n## 安装\n\n步骤 ^s1");
+    store.sync_note("note-t", &target).unwrap();
+
+    let a = NoteCrdt::new();
+    a.set_content("# A\n\n[[note-t#安装|安装章节]] [[note-t^s1]]\n\n`[[note-x]]`");
+    store.sync_note("note-a", &a).unwrap();
+
+    // 同一目标的不同锚点各占一行；行内代码里的不算链接
+    let out = store.outgoing_links("note-a").unwrap();
+    assert_eq!(out.len(), 2);
+    assert_eq!(out[0].anchor, "#安装");
+    assert_eq!(out[0].alias, "安装章节");
+    assert_eq!(out[1].anchor, "^s1");
+    assert!(out.iter().all(|l| l.id == "note-t" && l.exists));
+    assert_eq!(store.backlinks("note-t").unwrap().len(), 2);
+}
+
+#[test]
+fn test_title_with_anchor_characters_links_whole() {
+    let store = NoteStore::new(":memory:").unwrap();
+    let a = NoteCrdt::new();
+    a.set_content("# A\n\n[[C# 入门]] 与 [[C# 入门#变量]]");
+    store.sync_note("note-a", &a).unwrap();
+    assert!(
+        store
+            .outgoing_links("note-a")
+            .unwrap()
+            .iter()
+            .all(|l| !l.resolved),
+        "目标笔记尚不存在"
+    );
+
+    // 后建的笔记标题含 `#`：整段原文优先匹配，不拆为 `C` + `# 入门`
+    let csharp = NoteCrdt::new();
+    csharp.set_content("# C# 入门\n\n## 变量");
+    store.sync_note("note-cs", &csharp).unwrap();
+    let out = store.outgoing_links("note-a").unwrap();
+    assert_eq!(out.len(), 2);
+    assert!(out.iter().all(|l| l.id == "note-cs" && l.resolved));
+    let anchors: Vec<&str> = out.iter().map(|l| l.anchor.as_str()).collect();
+    assert_eq!(anchors, ["", "#变量"]);
+
+    // 源笔记重新投影时同样整段匹配
+    store.sync_note("note-a", &a).unwrap();
+    assert_eq!(store.outgoing_links("note-a").unwrap().len(), 2);
+    assert_eq!(store.backlinks("note-cs").unwrap().len(), 2);
+}
+
 #[test]
 fn test_search_notes_fts() {
     let store = NoteStore::new(":memory:").unwrap();
@@ -137,7 +282,7 @@ fn test_search_notes_fts() {
     note2.set_content("# Python 笔记\n\n列表推导。");
     store.sync_note("note-2", &note2).unwrap();
 
-    // 3+ 字符走 FTS5，命中 content，snippet 非空
+    // 走 FTS5，命中 content，snippet 非空
     let results = store.search_notes("所有权").unwrap();
     assert_eq!(results.len(), 1);
     assert_eq!(results[0].id, "note-1");
@@ -147,13 +292,13 @@ fn test_search_notes_fts() {
     let results = store.search_notes("不存在的词xyz").unwrap();
     assert!(results.is_empty());
 
-    // 2 字符回退 LIKE，preview 来自正文
+    // 2 字符同样走 FTS5（二元组分词），snippet 含匹配词
     let results = store.search_notes("所有").unwrap();
     assert_eq!(results.len(), 1);
     assert_eq!(results[0].id, "note-1");
     assert!(
         results[0].content_preview.contains("所有权"),
-        "LIKE 回退 preview 应含匹配词"
+        "短查询 snippet 应含匹配词"
     );
 }
 
@@ -0,0 +1,125 @@
+//! 同步退避集成测试：失败的对端按指数退避推迟重试，重新出现时立即恢复；
+//! 各对端并发推送，离线设备不拖慢其他设备。
+//!
+//! 1. B 拒收 → A 对 B 进入退避，退避期内推送/周期同步都不再连接 B；
+//!    B 恢复信任并主动连入 A 后退避清除，下一次推送立即成功
+//! 2. C 离线、B 在线 → B 不等待 C 的连接超时即收到推送
+
+mod common;
+
+use std::time::{Duration, Instant};
+
+use cardmind_backend::store::NoteStore;
+use cardmind_backend::sync::{SyncService, SYNC_BACKOFF_BASE_SECS};
+
+use common::{pair_up, rt};
+
+#[test]
+fn test_failed_peer_backs_off_until_it_connects_back() {
+    rt().block_on(async {
+        let (mut a, a_store, mut b, b_store) = pair_up(
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+        )
+        .await;
+        let (a_id, b_id) = (a.device_id(), b.device_id());
+        a.start_receiver(a_store.clone()).await.unwrap();
+        b.start_receiver(b_store.clone()).await.unwrap();
+
+        // B 暂时不信任 A：推送被拒，A 对 B 进入退避
+        b_store.remove_paired_device(&a_id).unwrap();
+        a.create_note("n1".into(), "# 一").unwrap();
+        let results = a.push_pending(&a_store).await;
+        assert_eq!(results.len(), 1);
+        assert!(!results[0].ok, "B 拒收不应算送达: {results:?}");
+        let (failures, retry_in) = a.peer_backoff(&b_id).expect("失败后应进入退避");
+        assert_eq!(failures, 1);
+        let base = Duration::from_secs(SYNC_BACKOFF_BASE_SECS);
+        assert!(
+            retry_in > base / 2 && retry_in <= base * 6 / 5,
+            "首次退避应约为基础间隔（含抖动），实际 {retry_in:?}"
+        );
+
+        // 退避期内：推送与周期同步都跳过 B，立即返回
+        let started = Instant::now();
+        assert!(a.push_pending(&a_store).await.is_empty());
+        let cycle = a.run_sync_cycle(&a_store).await.unwrap();
+        assert_eq!(cycle.pushed_count, 0);
+        assert!(
+            started.elapsed() < Duration::from_secs(1),
+            "退避中的对端不应被连接，实际耗时 {:?}",
+            started.elapsed()
+        );
+        assert_eq!(a.peer_backoff(&b_id).unwrap().0, 1, "跳过不计为失败");
+
+        // B 恢复信任并主动推送给 A → A 的接收器确认 B 在线，退避清除
+        b_store.upsert_paired_device(&a_id, "A").unwrap();
+        b.create_note("from-b".into(), "# 来自 B").unwrap();
+        let results = b.push_pending(&b_store).await;
+        assert!(
+            results.iter().all(|r| r.ok),
+            "B → A 推送应成功: {results:?}"
+        );
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert!(a.peer_backoff(&b_id).is_none(), "入站连接后应清除退避");
+
+        let results = a.push_pending(&a_store).await;
+        assert_eq!(results.len(), 1);
+        assert!(results[0].ok, "退避清除后应立即重试: {results:?}");
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert_eq!(b.get_note("n1").as_deref(), Some("# 一"));
+        a.stop_receiver().await.unwrap();
+        b.stop_receiver().await.unwrap();
+    });
+}
+
+#[test]
+fn test_offline_peer_does_not_delay_others() {
+    rt().block_on(async {
+        let (a, a_store, b, b_store) = pair_up(
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+        )
+        .await;
+        let (mut a, a_store, c, c_store) = pair_up(
+            a,
+            a_store,
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+        )
+        .await;
+        let (b_id, c_id) = (b.device_id(), c.device_id());
+        b.start_receiver(b_store.clone()).await.unwrap();
+        // C 离线：endpoint 关闭
+        drop(c);
+        drop(c_store);
+
+        a.create_note("n1".into(), "# 并发").unwrap();
+        let started = Instant::now();
+        let (results, delivered_after) = tokio::join!(a.push_pending(&a_store), async {
+            tokio::time::timeout(Duration::from_secs(15), async {
+                while b.get_note("n1").is_none() {
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                }
+                started.elapsed()
+            })
+            .await
+            .expect("B 应收到推送")
+        });
+        assert!(
+            delivered_after < Duration::from_secs(5),
+            "B 不应等待离线 C 的超时，实际 {delivered_after:?}"
+        );
+        let to_b = results.iter().find(|r| r.peer_id == b_id).unwrap();
+        assert!(to_b.ok, "{to_b:?}");
+        let to_c = results.iter().find(|r| r.peer_id == c_id).unwrap();
+        assert!(!to_c.ok, "{to_c:?}");
+        assert!(a.peer_backoff(&b_id).is_none());
+        assert_eq!(a.peer_backoff(&c_id).map(|(failures, _)| failures), Some(1));
+        b.stop_receiver().await.unwrap();
+    });
+}
@@ -0,0 +1,150 @@
+//! 同步服务事件流集成测试：UI 按类型化事件精确刷新。
+//!
+//! 1. 本地编辑：新建/编辑/软删/恢复/彻底删除各发出对应事件（remote = false）
+//! 2. 对端变更：周期同步拉到的新笔记/软删/彻底删除在投影刷新后以 remote = true
+//!    发出，并由同步开始/结束事件包围；确认方收到配对请求事件与对端上线事件
+
+mod common;
+
+use cardmind_backend::events::SyncEvent;
+use cardmind_backend::store::NoteStore;
+use cardmind_backend::sync::SyncService;
+use tokio::sync::broadcast::Receiver;
+
+use common::{pair_up, rt};
+
+/// 取出已到达的全部事件。
+fn drain(events: &mut Receiver<SyncEvent>) -> Vec<SyncEvent> {
+    let mut out = Vec::new();
+    while let Ok(event) = events.try_recv() {
+        out.push(event);
+    }
+    out
+}
+
+#[test]
+fn test_local_edits_emit_note_events() {
+    rt().block_on(async {
+        let mut svc = SyncService::new().await.unwrap();
+        let mut events = svc.subscribe_events();
+
+        svc.create_note("n1".into(), "# 一").unwrap();
+        svc.update_note("n1", "# 一\n\n改").unwrap();
+        svc.soft_delete_note("n1").unwrap();
+        svc.restore_note("n1").unwrap();
+        svc.purge_note("n1").unwrap();
+
+        let local = |id: &str| SyncEvent::NotesChanged {
+            note_ids: vec![id.to_string()],
+            remote: false,
+        };
+        assert_eq!(
+            drain(&mut events),
+            vec![
+                local("n1"),
+                local("n1"),
+                SyncEvent::NoteDeleted {
+                    note_id: "n1".into(),
+                    remote: false,
+                },
+                SyncEvent::NoteRestored {
+                    note_id: "n1".into(),
+                    remote: false,
+                },
+                SyncEvent::NotesPurged {
+                    note_ids: vec!["n1".into()],
+                    remote: false,
+                },
+            ]
+        );
+    });
+}
+
+#[test]
+fn test_remote_changes_emit_events_after_projection() {
+    rt().block_on(async {
+        let b = SyncService::new().await.unwrap();
+        let mut b_events = b.subscribe_events();
+        let (mut a, a_store, mut b, b_store) = pair_up(
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+            b,
+            NoteStore::new(":memory:").unwrap(),
+        )
+        .await;
+        let a_id = a.device_id();
+        let received = drain(&mut b_events);
+        assert!(
+            received.iter().any(|e| matches!(
+                e,
+                SyncEvent::PairingRequested { device_id, .. } if *device_id == a_id
+            )),
+            "确认方应收到配对请求事件: {received:?}"
+        );
+        assert!(received.contains(&SyncEvent::PeerOnline {
+            peer_id: a_id.clone()
+        }));
+        b.start_receiver(b_store.clone()).await.unwrap();
+        let mut a_events = a.subscribe_events();
+
+        // B 新建 → A 周期同步拉取：事件在投影刷新后发出，UI 可直接读取
+        b.create_note("from-b".into(), "# 来自 B").unwrap();
+        a.run_sync_cycle(&a_store).await.unwrap();
+        assert_eq!(
+            drain(&mut a_events),
+            vec![
+                SyncEvent::SyncStarted,
+                SyncEvent::NotesChanged {
+                    note_ids: vec!["from-b".into()],
+                    remote: true,
+                },
+                SyncEvent::SyncFinished {
+                    ok_count: 1,
+                    failed_count: 0,
+                    pulled_count: 1,
+                },
+            ]
+        );
+        assert!(a_store
+            .list_notes()
+            .unwrap()
+            .iter()
+            .any(|row| row.id == "from-b"));
+
+        // B 软删 → A 收到对端删除事件
+        b.soft_delete_note("from-b").unwrap();
+        a.run_sync_cycle(&a_store).await.unwrap();
+        let received = drain(&mut a_events);
+        assert!(
+            received.contains(&SyncEvent::NoteDeleted {
+                note_id: "from-b".into(),
+                remote: true,
+            }),
+            "{received:?}"
+        );
+        assert!(a_store
+            .trash_list()
+            .unwrap()
+            .iter()
+            .any(|row| row.id == "from-b"));
+
+        // B 彻底删除 → 只带墓碑的增量同样刷新投影并发出彻底删除事件
+        b.purge_note("from-b").unwrap();
+        let result = a.run_sync_cycle(&a_store).await.unwrap();
+        assert!(result.accepted_push);
+        let received = drain(&mut a_events);
+        assert!(
+            received.contains(&SyncEvent::NotesPurged {
+                note_ids: vec!["from-b".into()],
+                remote: true,
+            }),
+            "{received:?}"
+        );
+        assert!(a_store
+            .trash_list()
+            .unwrap()
+            .iter()
+            .all(|row| row.id != "from-b"));
+        b.stop_receiver().await.unwrap();
+    });
+}
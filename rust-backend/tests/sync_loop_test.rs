@@ -0,0 +1,103 @@
+//! 后台同步任务集成测试：不依赖 Flutter 计时器的自动同步。
+//!
+//! 1. 编辑后防抖推送：A 启动同步任务后新建笔记，B 在防抖窗口后不久收到；
+//!    重复 start/stop 幂等
+//! 2. 周期同步遵守同步开关：关闭期间不拉取，开启后下一周期拉到 B 的笔记；
+//!    停止后不再同步
+
+mod common;
+
+use std::time::Duration;
+
+use cardmind_backend::store::NoteStore;
+use cardmind_backend::sync::{SyncService, SYNC_EDIT_DEBOUNCE_MS};
+
+use common::{pair_up, rt};
+
+/// 轮询等待 `svc` 出现笔记 `id`（最多 `limit`）。
+async fn wait_for_note(svc: &SyncService, id: &str, limit: Duration) -> Option<String> {
+    let deadline = tokio::time::Instant::now() + limit;
+    while tokio::time::Instant::now() < deadline {
+        if let Some(content) = svc.get_note(id) {
+            return Some(content);
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    None
+}
+
+#[test]
+fn test_loop_pushes_shortly_after_edit() {
+    rt().block_on(async {
+        let (mut a, a_store, b, b_store) = pair_up(
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+        )
+        .await;
+        b.start_receiver(b_store.clone()).await.unwrap();
+        // 周期足够长：本测试只验证编辑触发的推送
+        let poll = Duration::from_secs(3600);
+        a.start_sync_loop(a_store.clone(), poll).await.unwrap();
+        a.start_sync_loop(a_store.clone(), poll).await.unwrap();
+        assert!(a.sync_loop_running());
+
+        a.create_note("n1".into(), "# 一").unwrap();
+        a.update_note("n1", "# 一\n\n连续编辑").unwrap();
+        let limit = Duration::from_millis(SYNC_EDIT_DEBOUNCE_MS) + Duration::from_secs(5);
+        assert_eq!(
+            wait_for_note(&b, "n1", limit).await.as_deref(),
+            Some("# 一\n\n连续编辑"),
+            "编辑后应在防抖窗口后推送给 B"
+        );
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert_eq!(a.pending_sync_count(), 0);
+
+        a.stop_sync_loop().await.unwrap();
+        a.stop_sync_loop().await.unwrap();
+        assert!(!a.sync_loop_running());
+        b.stop_receiver().await.unwrap();
+    });
+}
+
+#[test]
+fn test_loop_polls_only_while_sync_allowed() {
+    rt().block_on(async {
+        let (a, a_store, mut b, b_store) = pair_up(
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+        )
+        .await;
+        b.start_receiver(b_store.clone()).await.unwrap();
+        b.create_note("from-b".into(), "# 来自 B").unwrap();
+
+        // 同步开关关闭：周期到点也不拉取
+        a.set_sync_allowed(false);
+        let poll = Duration::from_millis(500);
+        a.start_sync_loop(a_store.clone(), poll).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(1500)).await;
+        assert_eq!(a.get_note("from-b"), None, "关闭期间不应同步");
+
+        // 开启后下一周期经同步会话拉到 B 的笔记，并刷新投影
+        a.set_sync_allowed(true);
+        assert_eq!(
+            wait_for_note(&a, "from-b", Duration::from_secs(5))
+                .await
+                .as_deref(),
+            Some("# 来自 B")
+        );
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        let projected = a_store.list_notes().unwrap();
+        assert!(projected.iter().any(|row| row.id == "from-b"));
+
+        // 停止后不再同步
+        a.stop_sync_loop().await.unwrap();
+        b.create_note("after-stop".into(), "# 停止后").unwrap();
+        tokio::time::sleep(Duration::from_millis(1500)).await;
+        assert_eq!(a.get_note("after-stop"), None, "停止后不应再同步");
+        b.stop_receiver().await.unwrap();
+    });
+}
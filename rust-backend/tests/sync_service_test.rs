@@ -103,7 +103,7 @@ fn test_persistent_restart_and_envelope_validation() {
         );
         let bytes = std::fs::read(dir.join("cardmind.loro")).unwrap();
         assert_eq!(&bytes[..8], b"CARDMIND");
-        assert_eq!(u32::from_le_bytes(bytes[8..12].try_into().unwrap()), 3);
+        assert_eq!(u32::from_le_bytes(bytes[8..12].try_into().unwrap()), 5);
         std::fs::write(dir.join("cardmind.loro"), b"broken").unwrap();
         assert!(SyncService::new_persistent(&dir).await.is_err());
         let _ = std::fs::remove_dir_all(dir);
@@ -118,10 +118,17 @@ fn test_persistence_failure_rolls_back_memory() {
         let file_path = dir.join("notes.loro");
         let mut service = SyncService::new_persistent(&file_path).await.unwrap();
         service.create_note("before".into(), "before").unwrap();
+        let version_ids = |service: &SyncService| -> Vec<String> {
+            let versions = service.note_versions("before").unwrap();
+            versions.into_iter().map(|v| v.version).collect()
+        };
+        let versions = version_ids(&service);
         std::fs::remove_file(&file_path).unwrap();
         std::fs::create_dir(&file_path).unwrap();
         assert!(service.update_note("before", "after").is_err());
         assert_eq!(service.get_note("before").as_deref(), Some("before"));
+        // 回滚丢弃失败的编辑，不另记一次"写回旧值"的编辑
+        assert_eq!(version_ids(&service), versions);
         let _ = std::fs::remove_dir_all(dir);
     });
 }
@@ -0,0 +1,123 @@
+//! 双向同步会话集成测试：一次连接内交换版本摘要，发起方拉取本端缺失、
+//! 推回对端缺失，双方收敛。
+//!
+//! 1. 两端各有对方没有的笔记 → 一轮 `run_sync_cycle` 后双方都有全部笔记
+//! 2. 响应方为被动 `accept_push`（无后台接收器）同样收敛
+//! 3. 对端无新内容 → 本轮不拉取（`accepted_push == false`），但会话成功
+
+mod common;
+
+use std::time::Duration;
+
+use cardmind_backend::store::NoteStore;
+use cardmind_backend::sync::SyncService;
+
+use common::{pair_up, rt};
+
+#[test]
+fn test_sync_cycle_converges_both_sides_in_one_session() {
+    rt().block_on(async {
+        let (mut a, a_store, mut b, b_store) = pair_up(
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+        )
+        .await;
+        b.start_receiver(b_store.clone()).await.unwrap();
+
+        a.create_note("from-a".into(), "# 来自 A\n\nA 正文")
+            .unwrap();
+        b.create_note("from-b".into(), "# 来自 B\n\nB 正文")
+            .unwrap();
+
+        let result = a.run_sync_cycle(&a_store).await.unwrap();
+        assert_eq!(result.pushed_count, 1, "会话应成功: {result:?}");
+        assert!(result.accepted_push, "A 应拉取到 B 的笔记: {result:?}");
+
+        // A 侧：拉取内容已导入并投影
+        assert_eq!(a.get_note("from-b").as_deref(), Some("# 来自 B\n\nB 正文"));
+        assert!(
+            a_store
+                .list_notes()
+                .unwrap()
+                .iter()
+                .any(|r| r.id == "from-b"),
+            "拉取的笔记应刷新到 A 的 SQLite 投影"
+        );
+
+        // B 侧：接收器导入 A 推回的增量
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(10);
+        while b.get_note("from-a").is_none() {
+            if tokio::time::Instant::now() >= deadline {
+                panic!("B 应在 10 秒内导入 A 推回的笔记");
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        assert_eq!(b.get_note("from-a").as_deref(), Some("# 来自 A\n\nA 正文"));
+
+        b.stop_receiver().await.unwrap();
+        drop((b, b_store, a_store));
+    });
+}
+
+#[test]
+fn test_sync_cycle_converges_with_passive_accept() {
+    rt().block_on(async {
+        let (mut a, a_store, mut b, b_store) = pair_up(
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+        )
+        .await;
+
+        a.create_note("shared".into(), "# 共享\n\nv1").unwrap();
+        b.create_note("from-b".into(), "# 来自 B\n\n离线新建")
+            .unwrap();
+
+        let b_handle = tokio::spawn(async move {
+            let data = b.accept_push().await.unwrap();
+            b.import_all(&data).unwrap();
+            b
+        });
+        let result = a.run_sync_cycle(&a_store).await.unwrap();
+        let b = b_handle.await.unwrap();
+
+        assert!(result.accepted_push, "A 应拉取到 B 的笔记: {result:?}");
+        assert_eq!(
+            a.get_note("from-b").as_deref(),
+            Some("# 来自 B\n\n离线新建")
+        );
+        assert_eq!(b.get_note("shared").as_deref(), Some("# 共享\n\nv1"));
+        assert_eq!(
+            b.get_note("from-b").as_deref(),
+            Some("# 来自 B\n\n离线新建"),
+            "导入 A 推回的增量不应影响 B 本地笔记"
+        );
+        drop((b, b_store, a_store));
+    });
+}
+
+#[test]
+fn test_sync_cycle_without_remote_changes_pulls_nothing() {
+    rt().block_on(async {
+        let (mut a, a_store, b, b_store) = pair_up(
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+        )
+        .await;
+        b.start_receiver(b_store.clone()).await.unwrap();
+
+        a.create_note("n1".into(), "# 仅 A\n\n正文").unwrap();
+        let result = a.run_sync_cycle(&a_store).await.unwrap();
+        assert_eq!(result.pushed_count, 1, "会话应成功: {result:?}");
+        assert!(!result.accepted_push, "B 无新内容时不应拉取: {result:?}");
+        assert_eq!(a.pending_sync_count(), 0, "会话成功后待同步计数应归零");
+
+        b.stop_receiver().await.unwrap();
+        drop((b, b_store, a_store));
+    });
+}
@@ -0,0 +1,92 @@
+//! 同步水位持久化集成测试：待同步计数按对端已确认的版本向量计算，水位落盘，
+//! 重启后从上次确认处继续。
+//!
+//! 1. 推送成功后计数归零 → 重启仍为 0（不再重推全部）；重启前后的未推送编辑
+//!    计数保持，按对端可查
+//! 2. 同步会话双方都记录确认水位：响应方无需再推回刚同步过的笔记
+
+mod common;
+
+use std::time::Duration;
+
+use cardmind_backend::store::NoteStore;
+use cardmind_backend::sync::SyncService;
+
+use common::{pair_up, rt, temp_dir};
+
+#[test]
+fn test_watermarks_survive_restart() {
+    rt().block_on(async {
+        let dir = temp_dir("restart");
+        let db = dir.join("cardmind.db").to_string_lossy().to_string();
+        let (mut a, a_store, b, b_store) = pair_up(
+            SyncService::new_persistent(&dir).await.unwrap(),
+            NoteStore::open(&db).unwrap(),
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+        )
+        .await;
+        let b_id = b.device_id();
+        b.start_receiver(b_store.clone()).await.unwrap();
+
+        a.create_note("n1".into(), "# 已同步").unwrap();
+        a.create_note("n2".into(), "# 也已同步").unwrap();
+        assert_eq!(a.pending_sync_count(), 2);
+        let results = a.push_pending(&a_store).await;
+        assert!(results.iter().all(|r| r.ok), "推送应成功: {results:?}");
+        assert_eq!(a.pending_sync_count(), 0);
+        assert!(a.last_synced_at_snapshot().contains_key(&b_id));
+        drop((a, a_store));
+
+        // 重启：水位从数据目录恢复，已确认的笔记不再计为待同步
+        let mut a = SyncService::new_persistent(&dir).await.unwrap();
+        assert_eq!(a.pending_sync_count(), 0, "重启后不应重标全部待同步");
+        a.update_note("n1", "# 已同步\n\n重启后编辑").unwrap();
+        assert_eq!(a.pending_sync_count(), 1);
+        assert_eq!(a.pending_sync_count_for_peer(&b_id), 1);
+        drop(a);
+
+        let a = SyncService::new_persistent(&dir).await.unwrap();
+        let a_store = NoteStore::open(&db).unwrap();
+        assert_eq!(a.pending_sync_count(), 1, "未推送的编辑跨重启保持待同步");
+        // 直连 B 的本地地址：不依赖 relay/地址解析，离线环境可运行
+        let results = a
+            .push_to_paired_devices(&[(b_id.clone(), Some(b.local_addrs()))])
+            .await;
+        assert!(results.iter().all(|r| r.ok), "推送应成功: {results:?}");
+        assert_eq!(a.pending_sync_count_for_peer(&b_id), 0);
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert_eq!(b.get_note("n1").as_deref(), Some("# 已同步\n\n重启后编辑"));
+        b.stop_receiver().await.unwrap();
+        drop((a, a_store, b_store));
+        let _ = std::fs::remove_dir_all(dir);
+    });
+}
+
+#[test]
+fn test_session_records_watermark_on_both_sides() {
+    rt().block_on(async {
+        let (mut a, a_store, mut b, b_store) = pair_up(
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+        )
+        .await;
+        a.create_note("a1".into(), "# 来自 A").unwrap();
+        b.create_note("b1".into(), "# 来自 B").unwrap();
+        let a_id = a.device_id();
+
+        b.start_receiver(b_store.clone()).await.unwrap();
+        let cycle = a.run_sync_cycle(&a_store).await.unwrap();
+        assert_eq!(cycle.pushed_count, 1);
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert_eq!(a.pending_sync_count(), 0);
+        assert_eq!(
+            b.pending_sync_count_for_peer(&a_id),
+            0,
+            "响应方也应记录发起方已确认的水位"
+        );
+        b.stop_receiver().await.unwrap();
+    });
+}
@@ -0,0 +1,188 @@
+//! 墓碑回收集成测试：全部配对设备确认后回收墓碑，离线设备带回的旧副本不复活。
+//!
+//! 1. A 彻底删除笔记并与 B 同步：B 确认墓碑后 A 在同一轮同步中回收
+//! 2. B 离线期间 A 删除笔记并按期限回收墓碑：B 重新上线推回旧副本 → A 拦截并
+//!    重新记入墓碑，删除随之传回 B
+//! 3. 旧副本经 `accept_push` + `import_all` 推回同样被拦截；回收期限跨重启保持
+//! 4. 三台设备：C 的笔记经 B 转到 A 时，A 即使在这之前回收过墓碑也照常导入，
+//!    不会把它当成旧副本删掉并传回 B、C
+
+mod common;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use cardmind_backend::debug_log::CollectingSink;
+use cardmind_backend::store::NoteStore;
+use cardmind_backend::sync::SyncService;
+
+use common::{pair_up, rt, temp_dir};
+
+#[test]
+fn test_tombstone_collected_after_all_peers_ack() {
+    rt().block_on(async {
+        let (mut a, a_store, b, b_store) = pair_up(
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+        )
+        .await;
+        b.start_receiver(b_store.clone()).await.unwrap();
+        a.create_note("n1".into(), "# 待删除\n\n正文").unwrap();
+        a.run_sync_cycle(&a_store).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert!(b.get_note("n1").is_some());
+
+        a.purge_note("n1").unwrap();
+        assert_eq!(a.collect_tombstones(&a_store).unwrap(), 0, "B 尚未确认");
+        assert!(a.tombstones().contains("n1"));
+
+        let cycle = a.run_sync_cycle(&a_store).await.unwrap();
+        assert_eq!(cycle.pushed_count, 1);
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert!(b.get_note("n1").is_none(), "删除应传播到 B");
+        assert!(a.tombstones().is_empty(), "B 确认后墓碑应回收");
+        assert!(a.get_note("n1").is_none(), "B 推回的旧记录不应复活");
+        b.stop_receiver().await.unwrap();
+    });
+}
+
+#[test]
+fn test_reappearing_peer_cannot_resurrect_collected_note() {
+    rt().block_on(async {
+        let sink = Arc::new(CollectingSink::new());
+        let (mut a, a_store, b, b_store) = pair_up(
+            SyncService::new_with_log_sink(sink.clone()).await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+        )
+        .await;
+        b.start_receiver(b_store.clone()).await.unwrap();
+        a.create_note("old".into(), "# 旧笔记\n\n正文").unwrap();
+        a.run_sync_cycle(&a_store).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert!(b.get_note("old").is_some());
+        b.stop_receiver().await.unwrap();
+
+        // B 离线：A 删除笔记，墓碑超过回收期限后回收（B 从未确认）
+        a.purge_note("old").unwrap();
+        a.set_tombstone_horizon(Duration::ZERO).unwrap();
+        assert_eq!(a.collect_tombstones(&a_store).unwrap(), 1);
+        assert!(a.tombstones().is_empty());
+
+        // B 重新上线，仍持有旧副本
+        b.start_receiver(b_store.clone()).await.unwrap();
+        a.reset_sync_backoff();
+        a.run_sync_cycle(&a_store).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert!(a.get_note("old").is_none(), "旧副本不应在 A 复活");
+        assert!(a_store.list_notes().unwrap().iter().all(|n| n.id != "old"));
+        assert!(b.get_note("old").is_none(), "重新记入的墓碑应传回 B");
+        assert!(sink.snapshot().iter().any(|e| {
+            e.event == "sync.tombstone"
+                && e.fields
+                    .iter()
+                    .any(|(k, v)| k == "action" && v == "resurrection_blocked")
+        }));
+        b.stop_receiver().await.unwrap();
+    });
+}
+
+#[test]
+fn test_buffered_push_cannot_resurrect_collected_note() {
+    rt().block_on(async {
+        let dir = temp_dir("buffered");
+        let (mut a, a_store, b, b_store) = pair_up(
+            SyncService::new_persistent(&dir).await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+        )
+        .await;
+        b.start_receiver(b_store.clone()).await.unwrap();
+        a.create_note("old".into(), "# 旧笔记\n\n正文").unwrap();
+        a.run_sync_cycle(&a_store).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert!(b.get_note("old").is_some());
+        b.stop_receiver().await.unwrap();
+
+        a.purge_note("old").unwrap();
+        a.set_tombstone_horizon(Duration::ZERO).unwrap();
+        assert_eq!(a.collect_tombstones(&a_store).unwrap(), 1);
+
+        // B 以全量推送带回旧副本，A 走 accept_push + import_all
+        let (a_id, a_ips) = (a.device_id(), a.local_addrs());
+        let a_handle = tokio::spawn(async move {
+            let data = a.accept_push().await.unwrap();
+            a.import_all(&data).unwrap();
+            a
+        });
+        b.push_to_peer(&a_id, a_ips).await.unwrap();
+        let a = a_handle.await.unwrap();
+        assert!(a.get_note("old").is_none(), "旧副本不应在 A 复活");
+        assert!(a.tombstones().contains("old"), "拦下的旧副本应重新记入墓碑");
+
+        drop(a);
+        let a = SyncService::new_persistent(&dir).await.unwrap();
+        assert_eq!(
+            a.tombstone_horizon(),
+            Duration::ZERO,
+            "回收期限应跨重启保持"
+        );
+        drop((a, a_store, b_store));
+        let _ = std::fs::remove_dir_all(dir);
+    });
+}
+
+#[test]
+fn test_note_relayed_by_another_device_is_not_mistaken_for_collected() {
+    rt().block_on(async {
+        let (mut a, a_store, b, b_store) = pair_up(
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+        )
+        .await;
+        let (mut c, c_store, b, b_store) = pair_up(
+            SyncService::new().await.unwrap(),
+            NoteStore::new(":memory:").unwrap(),
+            b,
+            b_store,
+        )
+        .await;
+
+        // C 先写下笔记（A 从未见过），之后 A 才完整导入一次 B 的数据
+        c.create_note("relayed".into(), "# 来自 C\n\n正文").unwrap();
+        tokio::time::sleep(Duration::from_millis(1100)).await;
+        b.start_receiver(b_store.clone()).await.unwrap();
+        a.run_sync_cycle(&a_store).await.unwrap();
+        assert!(a.get_note("relayed").is_none());
+
+        // A 回收一个删除时间更晚的墓碑
+        tokio::time::sleep(Duration::from_millis(1100)).await;
+        a.create_note("gone".into(), "# 已删除").unwrap();
+        a.purge_note("gone").unwrap();
+        a.set_tombstone_horizon(Duration::ZERO).unwrap();
+        assert_eq!(a.collect_tombstones(&a_store).unwrap(), 1);
+
+        // B 从 C 收到笔记，再与 A 同步
+        c.run_sync_cycle(&c_store).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert!(b.get_note("relayed").is_some());
+        a.reset_sync_backoff();
+        a.run_sync_cycle(&a_store).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert_eq!(
+            a.get_note("relayed").as_deref(),
+            Some("# 来自 C\n\n正文"),
+            "经 B 转来的笔记应在 A 导入"
+        );
+        assert!(!a.tombstones().contains("relayed"), "A 不应把它记入墓碑");
+        assert!(b.get_note("relayed").is_some(), "删除不应传回 B");
+        assert!(b.tombstones().is_empty());
+        b.stop_receiver().await.unwrap();
+    });
+}
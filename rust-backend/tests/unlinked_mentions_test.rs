@@ -0,0 +1,64 @@
+//! 未链接提及集成测试：正文提到笔记标题但没有链接它的笔记，以及一键转为链接。
+//!
+//! 1. 大小写不敏感地找出提及；已链接的笔记、代码里的提及与词内片段不算；
+//!    link_mention 把第一处提及改为 `[[id|原文]]`，随后该笔记不再出现在结果中
+//! 2. 两个字的中文标题（走 FTS 二元组分词）同样能找到提及
+
+mod common;
+
+use cardmind_backend::store::NoteStore;
+use cardmind_backend::sync::SyncService;
+
+use common::rt;
+
+#[test]
+fn test_unlinked_mentions_found_and_linked() {
+    rt().block_on(async {
+        let mut svc = SyncService::new().await.unwrap();
+        let store = NoteStore::new(":memory:").unwrap();
+        svc.create_note("rust".into(), "# Rust\n\n一门语言")
+            .unwrap();
+        svc.create_note("a".into(), "# A\n\n学习 Rust 很有趣，rust 也很快")
+            .unwrap();
+        svc.create_note("b".into(), "# B\n\n已链接 [[rust]]，这里的 Rust 不再提示")
+            .unwrap();
+        svc.create_note("c".into(), "# C\n\n`Rust` 在代码里；Rusty 不是提及")
+            .unwrap();
+        svc.sync_notes_to_store(&store).unwrap();
+
+        let rows = store.unlinked_mentions("rust").unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].id, "a");
+        assert_eq!(rows[0].mentions, 2);
+        assert_eq!(rows[0].snippet, "学习 Rust 很有趣，rust 也很快");
+
+        svc.link_mention(&store, "a", "rust").unwrap();
+        assert_eq!(
+            svc.get_note("a").as_deref(),
+            Some("# A\n\n学习 [[rust|Rust]] 很有趣，rust 也很快")
+        );
+        assert!(store.unlinked_mentions("rust").unwrap().is_empty());
+        assert!(store.backlinks("rust").unwrap().iter().any(|l| l.id == "a"));
+        assert!(
+            svc.link_mention(&store, "c", "rust").is_err(),
+            "c 没有可链接的提及"
+        );
+    });
+}
+
+#[test]
+fn test_short_cjk_title_mentions() {
+    rt().block_on(async {
+        let mut svc = SyncService::new().await.unwrap();
+        let store = NoteStore::new(":memory:").unwrap();
+        svc.create_note("weekly".into(), "# 周报\n\n模板").unwrap();
+        svc.create_note("todo".into(), "# 待办\n\n周五前写完周报")
+            .unwrap();
+        svc.sync_notes_to_store(&store).unwrap();
+
+        let rows = store.unlinked_mentions("weekly").unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].id, "todo");
+        assert_eq!(rows[0].snippet, "周五前写完周报");
+    });
+}
@@ -0,0 +1,168 @@
+//! 增量追加持久化集成测试：编辑只追加更新日志，不重写基线快照；
+//! 重启时在快照之上重放日志（崩溃恢复），残缺尾部截断丢弃。
+//!
+//! 1. 编辑只追加 `cardmind.loro.log`，`cardmind.loro` 字节不变；重启后内容完整
+//! 2. 日志尾部残缺（模拟写到一半崩溃）→ 重启成功，丢弃残缺尾部并截断
+//! 3. 校验和不符的记录及其后内容被丢弃，之前的记录正常重放
+//! 4. 彻底删除以墓碑记录落盘，重启后不复活
+//! 5. 日志达到压缩阈值 → 重写快照并清空日志
+
+mod common;
+
+use cardmind_backend::sync::SyncService;
+
+use common::{rt, temp_dir};
+
+#[test]
+fn test_edits_append_log_without_rewriting_snapshot() {
+    rt().block_on(async {
+        let dir = temp_dir("append");
+        let mut svc = SyncService::new_persistent(&dir).await.unwrap();
+        svc.create_note("n1".into(), "# 一\n\nv1").unwrap();
+        svc.create_note("n2".into(), "# 二\n\n不变").unwrap();
+        let snapshot = std::fs::read(dir.join("cardmind.loro")).unwrap();
+
+        svc.update_note("n1", "# 一\n\nv2").unwrap();
+        svc.update_metadata("n1", &["work".to_string()]).unwrap();
+        assert_eq!(
+            std::fs::read(dir.join("cardmind.loro")).unwrap(),
+            snapshot,
+            "编辑不应重写基线快照"
+        );
+        let log_len = std::fs::metadata(dir.join("cardmind.loro.log"))
+            .unwrap()
+            .len();
+        assert!(log_len > 0, "编辑应追加到更新日志");
+        drop(svc);
+
+        let restored = SyncService::new_persistent(&dir).await.unwrap();
+        assert_eq!(restored.get_note("n1").as_deref(), Some("# 一\n\nv2"));
+        assert_eq!(restored.get_note("n2").as_deref(), Some("# 二\n\n不变"));
+        let tags = restored
+            .iter_notes()
+            .into_iter()
+            .find(|(id, _)| id == "n1")
+            .map(|(_, note)| note.get_tags())
+            .unwrap();
+        assert_eq!(tags, vec!["work".to_string()]);
+        let _ = std::fs::remove_dir_all(dir);
+    });
+}
+
+#[test]
+fn test_torn_log_tail_is_truncated_on_recovery() {
+    rt().block_on(async {
+        let dir = temp_dir("torn");
+        let mut svc = SyncService::new_persistent(&dir).await.unwrap();
+        svc.create_note("n1".into(), "# 一\n\nv1").unwrap();
+        svc.update_note("n1", "# 一\n\nv2").unwrap();
+        drop(svc);
+
+        let log_path = dir.join("cardmind.loro.log");
+        let valid_len = std::fs::metadata(&log_path).unwrap().len();
+        // 模拟写到一半崩溃：记录头声明 1000 字节，实际只写了几个字节
+        let mut bytes = std::fs::read(&log_path).unwrap();
+        bytes.extend_from_slice(&1000u32.to_le_bytes());
+        bytes.extend_from_slice(&[0xAB; 6]);
+        std::fs::write(&log_path, &bytes).unwrap();
+
+        let mut restored = SyncService::new_persistent(&dir).await.unwrap();
+        assert_eq!(restored.get_note("n1").as_deref(), Some("# 一\n\nv2"));
+        assert_eq!(
+            std::fs::metadata(&log_path).unwrap().len(),
+            valid_len,
+            "残缺尾部应被截断"
+        );
+
+        // 截断后继续追加、再次重启仍正常
+        restored.update_note("n1", "# 一\n\nv3").unwrap();
+        drop(restored);
+        let again = SyncService::new_persistent(&dir).await.unwrap();
+        assert_eq!(again.get_note("n1").as_deref(), Some("# 一\n\nv3"));
+        let _ = std::fs::remove_dir_all(dir);
+    });
+}
+
+#[test]
+fn test_checksum_mismatch_drops_record_and_rest() {
+    rt().block_on(async {
+        let dir = temp_dir("checksum");
+        let mut svc = SyncService::new_persistent(&dir).await.unwrap();
+        svc.create_note("n1".into(), "# 一\n\nv1").unwrap();
+        svc.update_note("n1", "# 一\n\nv2").unwrap();
+        let log_path = dir.join("cardmind.loro.log");
+        let first_len = std::fs::metadata(&log_path).unwrap().len() as usize;
+        svc.update_note("n1", "# 一\n\nv3").unwrap();
+        drop(svc);
+
+        // 破坏第二条记录的最后一个字节（记录体被改 → CRC 不符）
+        let mut bytes = std::fs::read(&log_path).unwrap();
+        assert!(bytes.len() > first_len);
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xFF;
+        std::fs::write(&log_path, &bytes).unwrap();
+
+        let restored = SyncService::new_persistent(&dir).await.unwrap();
+        assert_eq!(
+            restored.get_note("n1").as_deref(),
+            Some("# 一\n\nv2"),
+            "校验失败的记录不应重放，之前的记录保留"
+        );
+        assert_eq!(
+            std::fs::metadata(&log_path).unwrap().len() as usize,
+            first_len
+        );
+        let _ = std::fs::remove_dir_all(dir);
+    });
+}
+
+#[test]
+fn test_purge_survives_restart_via_log() {
+    rt().block_on(async {
+        let dir = temp_dir("purge");
+        let mut svc = SyncService::new_persistent(&dir).await.unwrap();
+        svc.create_note("keep".into(), "# 保留").unwrap();
+        svc.create_note("gone".into(), "# 删除").unwrap();
+        svc.purge_note("gone").unwrap();
+        drop(svc);
+
+        let restored = SyncService::new_persistent(&dir).await.unwrap();
+        assert!(
+            restored.get_note("gone").is_none(),
+            "彻底删除的笔记不应复活"
+        );
+        assert!(restored.tombstones().contains("gone"));
+        assert_eq!(restored.get_note("keep").as_deref(), Some("# 保留"));
+        let _ = std::fs::remove_dir_all(dir);
+    });
+}
+
+#[test]
+fn test_log_compacts_into_snapshot() {
+    rt().block_on(async {
+        let dir = temp_dir("compact");
+        let mut svc = SyncService::new_persistent(&dir).await.unwrap();
+        svc.create_note("n1".into(), "# 一\n\nv0").unwrap();
+        let snapshot = std::fs::read(dir.join("cardmind.loro")).unwrap();
+        let log_path = dir.join("cardmind.loro.log");
+        let mut max_log_len = 0;
+        for i in 1..=600 {
+            svc.update_note("n1", &format!("# 一\n\nv{i}")).unwrap();
+            max_log_len = max_log_len.max(std::fs::metadata(&log_path).unwrap().len());
+        }
+        assert_ne!(
+            std::fs::read(dir.join("cardmind.loro")).unwrap(),
+            snapshot,
+            "超过阈值后应压缩重写快照"
+        );
+        assert!(
+            std::fs::metadata(&log_path).unwrap().len() < max_log_len,
+            "压缩后日志应清空重新累计"
+        );
+        drop(svc);
+
+        let restored = SyncService::new_persistent(&dir).await.unwrap();
+        assert_eq!(restored.get_note("n1").as_deref(), Some("# 一\n\nv600"));
+        let _ = std::fs::remove_dir_all(dir);
+    });
+}
@@ -147,13 +147,19 @@ void main() {
                 id: 'source-a',
                 title: 'Source A',
                 alias: '',
+                anchor: '',
                 exists: true,
+                resolved: true,
+                ambiguous: false,
               ),
               LinkRow(
                 id: 'gone-source',
                 title: '',
                 alias: '老笔记',
+                anchor: '',
                 exists: false,
+                resolved: true,
+                ambiguous: false,
               ),
             ];
       await _pumpEditor(tester, repository: repository, noteId: 'work-note');